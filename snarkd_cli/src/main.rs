@@ -21,6 +21,27 @@ enum PeersCommands {
     Listen,
 }
 
+#[derive(Debug, Subcommand)]
+enum MappingCommands {
+    /// Reads the value of a hex encoded key
+    Get {
+        program_id: String,
+        mapping: String,
+        key: String,
+    },
+    /// Lists keys and values in key order, a page at a time
+    List {
+        program_id: String,
+        mapping: String,
+        /// Number of entries to skip
+        #[arg(long, default_value_t = 0)]
+        offset: u32,
+        /// Maximum number of entries to list, capped by the node
+        #[arg(long)]
+        limit: Option<u32>,
+    },
+}
+
 #[derive(Debug, Subcommand)]
 enum Commands {
     Foo,
@@ -29,6 +50,8 @@ enum Commands {
     },
     #[command(subcommand)]
     Peers(PeersCommands),
+    #[command(subcommand)]
+    Mapping(MappingCommands),
 }
 
 #[tokio::main]
@@ -82,5 +105,34 @@ async fn main() {
                 }
             }
         },
+        Commands::Mapping(command) => match command {
+            MappingCommands::Get {
+                program_id,
+                mapping,
+                key,
+            } => {
+                println!(
+                    "{}",
+                    json!(client
+                        .get_mapping_value(program_id, mapping, key)
+                        .await
+                        .expect("error reading mapping value"))
+                )
+            }
+            MappingCommands::List {
+                program_id,
+                mapping,
+                offset,
+                limit,
+            } => {
+                println!(
+                    "{}",
+                    json!(client
+                        .get_mapping_entries(program_id, mapping, offset, limit)
+                        .await
+                        .expect("error listing mapping entries"))
+                )
+            }
+        },
     }
 }
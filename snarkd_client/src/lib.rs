@@ -1,7 +1,7 @@
 use anyhow::{anyhow, Result};
 use snarkd_rpc::{
     client::{websocket_client, Client},
-    common::{PeerData, PeerMessage, RpcClient, RpcError},
+    common::{MappingEntry, PeerData, PeerMessage, RpcClient, RpcError},
     jsonrpsee::core::client::Subscription,
 };
 use url::Url;
@@ -36,6 +36,27 @@ impl SnarkdClient {
         self.rpc.list_peers().await
     }
 
+    pub async fn get_mapping_value(
+        &self,
+        program_id: String,
+        mapping: String,
+        key: String,
+    ) -> Result<Option<String>, RpcError> {
+        self.rpc.get_mapping_value(program_id, mapping, key).await
+    }
+
+    pub async fn get_mapping_entries(
+        &self,
+        program_id: String,
+        mapping: String,
+        offset: u32,
+        limit: Option<u32>,
+    ) -> Result<Vec<MappingEntry>, RpcError> {
+        self.rpc
+            .get_mapping_entries(program_id, mapping, offset, limit)
+            .await
+    }
+
     pub async fn subscribe_peers(&self) -> Result<Subscription<PeerMessage>, RpcError> {
         self.rpc.subscribe_peers().await
     }
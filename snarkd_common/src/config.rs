@@ -1,4 +1,6 @@
 pub use crate::peer_config::*;
+pub use crate::producer_config::*;
+use crate::{network::Network, Digest};
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 use std::{
@@ -21,10 +23,16 @@ pub enum Verbosity {
 #[derive(Serialize, Deserialize)]
 #[serde(default)]
 pub struct Config {
+    /// Network to join, one of mainnet, testnet, devnet. Defaults to testnet
+    pub network: Network,
+    /// Hash of the genesis block of the devnet to join, as printed by `snarkd genesis`. Public networks pin their own
+    pub genesis_hash: Option<Digest>,
     /// Log level verbosity, defaults to `info`
     pub verbosity: Verbosity,
     /// If not specified, an in-memory database is used
     pub database_file: Option<String>,
+    /// Directory of the universal SRS, generated for devnets with `snarkd setup`, and of the parameters trimmed from it. Defaults to `./parameters`
+    pub parameters_dir: String,
     /// At least this number of connections will be maintained
     pub minimum_connection_count: usize,
     /// No more than this number of connections will be maintained
@@ -45,13 +53,20 @@ pub struct Config {
     pub rpc_ip: Ipv4Addr,
     /// Port that we are receiving RPC connections on, 0 for disabled
     pub rpc_port: u16,
+    /// Maximum number of canon blocks a competing fork may replace. Default 100.
+    pub max_reorg_depth: usize,
+    /// configuration for proving the coinbase puzzle and producing blocks. disabled by default.
+    pub producer: ProducerConfig,
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
+            network: Network::default(),
+            genesis_hash: None,
             verbosity: Verbosity::default(),
             database_file: None,
+            parameters_dir: "./parameters".to_string(),
             minimum_connection_count: 20,
             maximum_connection_count: 50,
             tracker: PeerConfig::default(),
@@ -62,10 +77,21 @@ impl Default for Config {
             inbound_port: None,
             rpc_ip: Ipv4Addr::UNSPECIFIED,
             rpc_port: 5422,
+            max_reorg_depth: 100,
+            producer: ProducerConfig::default(),
         }
     }
 }
 
+impl Config {
+    /// Returns the hash of the genesis block of the configured network, see [`crate::network::NetworkParameters::genesis_hash`].
+    pub fn genesis_hash(&self) -> Result<Digest> {
+        self.network
+            .parameters()
+            .genesis_hash(self.genesis_hash.as_ref())
+    }
+}
+
 pub const CONFIG_ENV_VAR: &str = "SNARKD_CONFIG";
 pub const CONFIG_NAME: &str = "snarkd.yaml";
 pub const FULL_CONFIG_PATH: &str = "/etc/snarkd.yaml";
@@ -93,13 +119,24 @@ pub fn load_config() -> Result<Config> {
     let config_raw = std::fs::read_to_string(&*CONFIG_PATH)
         .map_err(|e| anyhow!("cannot read config @ {}: {e:?}", CONFIG_PATH.display()))?;
 
-    let config: Config = serde_yaml::from_str(&config_raw)
+    let mut config: Config = serde_yaml::from_str(&config_raw)
         .map_err(|e| anyhow!("cannot parse config @ {}: {e:?}", CONFIG_PATH.display()))?;
 
+    if config.tracker.info_hash.is_empty() {
+        // a network whose genesis block is unknown is refused once the node starts, `snarkd genesis` and `snarkd setup` run without it
+        if let Ok(genesis_hash) = config.genesis_hash() {
+            config.tracker.info_hash = config.network.parameters().info_hash(&genesis_hash);
+        }
+    }
     config
         .tracker
         .validate()
         .map_err(|e| anyhow!("invalid tracker config @ {}: {e:?}", CONFIG_PATH.display()))?;
 
+    config
+        .producer
+        .validate()
+        .map_err(|e| anyhow!("invalid producer config @ {}: {e:?}", CONFIG_PATH.display()))?;
+
     Ok(config)
 }
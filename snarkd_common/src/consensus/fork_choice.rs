@@ -0,0 +1,66 @@
+use std::cmp::Reverse;
+
+use crate::{objects::Metadata, Digest, DigestTree};
+
+/// Returns the fork choice weight of a block, the cumulative proof target its coinbase solution had to reach.
+/// The proof target is derived from the coinbase target, so it adds no work of its own.
+pub fn block_weight(metadata: &Metadata) -> u128 {
+    metadata.coinbase_target as u128
+}
+
+/// Returns true if the chain ending in `tip` with cumulative `weight` is preferred over the chain ending in `other_tip`.
+/// Heavier chains win, and chains of equal weight are ordered by lowest tip digest so that every node picks the same one.
+pub fn is_preferred_chain(
+    weight: u128,
+    tip: &Digest,
+    other_weight: u128,
+    other_tip: &Digest,
+) -> bool {
+    (weight, Reverse(tip)) > (other_weight, Reverse(other_tip))
+}
+
+/// Returns the preferred chain of `tree` starting at its root, along with its cumulative weight.
+pub fn heaviest_chain(tree: &DigestTree, weight: impl Fn(&Digest) -> u128) -> (u128, Vec<&Digest>) {
+    // flatten in pre-order so that children always come after their parent, the tree may be too deep to recurse
+    let mut nodes = vec![(tree, None::<usize>)];
+    let mut i = 0;
+    while i < nodes.len() {
+        let node = nodes[i].0;
+        nodes.extend(node.children().iter().map(|child| (child, Some(i))));
+        i += 1;
+    }
+
+    // (weight, tip) of the preferred chain below each node, and the child it continues with
+    let mut best = vec![None::<(u128, &Digest, usize)>; nodes.len()];
+    let mut total_weight = 0;
+    for i in (0..nodes.len()).rev() {
+        let (node, parent) = nodes[i];
+        let (chain_weight, tip) = match best[i] {
+            Some((chain_weight, tip, _)) => (chain_weight, tip),
+            None => (0, node.root()),
+        };
+        let chain_weight = chain_weight.saturating_add(weight(node.root()));
+        match parent {
+            Some(parent) => {
+                let replace = match best[parent] {
+                    Some((other_weight, other_tip, _)) => {
+                        is_preferred_chain(chain_weight, tip, other_weight, other_tip)
+                    }
+                    None => true,
+                };
+                if replace {
+                    best[parent] = Some((chain_weight, tip, i));
+                }
+            }
+            None => total_weight = chain_weight,
+        }
+    }
+
+    let mut chain = vec![tree.root()];
+    let mut current = 0;
+    while let Some((_, _, next)) = best[current] {
+        chain.push(nodes[next].0.root());
+        current = next;
+    }
+    (total_weight, chain)
+}
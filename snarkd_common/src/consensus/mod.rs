@@ -0,0 +1,308 @@
+use anyhow::{anyhow, ensure, Result};
+use ruint::Uint;
+use snarkd_crypto::{
+    bls12_377::Scalar,
+    coinbase_puzzle::{CoinbaseSolution, EpochChallenge},
+    keys::Address,
+};
+
+use crate::{network::NetworkParameters, objects::Metadata, Digest32};
+
+mod fork_choice;
+pub use fork_choice::*;
+
+/// The starting supply of credits, in microcredits.
+pub const STARTING_SUPPLY: u64 = 1_000_000_000_000_000; // 1B credits
+/// The number of years over which the coinbase reward decays to zero.
+pub const COINBASE_REWARD_YEARS: u32 = 10;
+/// The maximum number of transactions a block may include.
+pub const MAX_TRANSACTIONS_PER_BLOCK: usize = 1 << 16; // 65,536 transactions
+/// The number of seconds a block timestamp may be ahead of the local clock.
+pub const MAX_TIMESTAMP_DRIFT: i64 = 60; // 1 minute
+
+/// Returns the epoch number for the block at `height`.
+pub const fn epoch_number(parameters: &NetworkParameters, height: u32) -> u32 {
+    height / parameters.num_blocks_per_epoch
+}
+
+/// Returns the height of the block whose hash seeds the epoch challenge of `epoch_number`.
+/// This is the last block of the previous epoch, or the genesis block for the first epoch.
+pub const fn epoch_block_height(parameters: &NetworkParameters, epoch_number: u32) -> u32 {
+    (epoch_number * parameters.num_blocks_per_epoch).saturating_sub(1)
+}
+
+/// Derives the epoch challenge for `epoch_number` from the hash of the block at [`epoch_block_height`].
+pub fn epoch_challenge(
+    parameters: &NetworkParameters,
+    epoch_number: u32,
+    epoch_block_hash: &Digest32,
+) -> Result<EpochChallenge> {
+    let hash = epoch_block_hash
+        .bytes::<32>()
+        .ok_or_else(|| anyhow!("epoch block hash must be 32 bytes"))?;
+    let mut epoch_block_hash = Scalar(Uint::from_le_bytes(hash));
+    epoch_block_hash.reduce();
+    EpochChallenge::new(
+        epoch_number,
+        epoch_block_hash,
+        parameters.coinbase_puzzle_degree,
+    )
+}
+
+/// Returns the coinbase target for a block with `timestamp`, given the coinbase target and timestamp of its parent.
+/// Blocks arriving faster than the anchor time of the network raise the target, slower blocks lower it.
+/// The target halves or doubles over half an epoch of drift, and never drops below the genesis coinbase target.
+pub fn coinbase_target(
+    parameters: &NetworkParameters,
+    previous_coinbase_target: u64,
+    previous_timestamp: i64,
+    timestamp: i64,
+) -> Result<u64> {
+    let half_life = (parameters.num_blocks_per_epoch / 2) * parameters.anchor_time as u32;
+    let candidate_target = retarget(
+        previous_coinbase_target,
+        previous_timestamp,
+        timestamp,
+        parameters.anchor_time,
+        half_life,
+        true,
+    )?;
+    Ok(candidate_target.max(parameters.genesis_coinbase_target))
+}
+
+/// Returns the minimum target a single prover solution must meet for a given coinbase target.
+pub fn proof_target(coinbase_target: u64) -> u64 {
+    (coinbase_target >> 7).saturating_add(1)
+}
+
+/// Retargets `previous_target` with an ASERT-style exponential adjustment anchored at `anchor_time`.
+/// The target doubles (or halves, if `inverse` is set) every `half_life` seconds of drift.
+fn retarget(
+    previous_target: u64,
+    previous_timestamp: i64,
+    timestamp: i64,
+    anchor_time: u16,
+    half_life: u32,
+    inverse: bool,
+) -> Result<u64> {
+    ensure!(half_life > 0, "retarget half life must be non-zero");
+
+    // A repeated timestamp still counts as one elapsed second.
+    let block_time_elapsed = timestamp.saturating_sub(previous_timestamp).max(1);
+    let mut drift = block_time_elapsed - anchor_time as i64;
+    if drift == 0 {
+        return Ok(previous_target);
+    }
+    if inverse {
+        drift = -drift;
+    }
+
+    // Fixed point arithmetic with `RBITS` fractional bits.
+    const RBITS: u32 = 16;
+    const RADIX: u128 = 1 << RBITS;
+
+    let exponent = (RADIX as i128).saturating_mul(drift as i128) / half_life as i128;
+    let integral = exponent >> RBITS;
+    let fractional = (exponent - (integral << RBITS)) as u128;
+    ensure!(
+        fractional < RADIX,
+        "fractional part is not within the fixed point size"
+    );
+
+    // 2^x ~= 1 + 0.695502049*x + 0.2262698*x^2 + 0.0782318*x^3, for 0 <= x < 1
+    let fractional_multiplier = RADIX
+        + ((195_766_423_245_049_u128 * fractional
+            + 971_821_376_u128 * fractional.pow(2)
+            + 5_127_u128 * fractional.pow(3)
+            + 2_u128.pow(RBITS * 3 - 1))
+            >> (RBITS * 3));
+
+    // The upper 64 bits absorb any overflow before the shift below.
+    let candidate_target = (previous_target as u128).saturating_mul(fractional_multiplier);
+
+    let shifts = integral - RBITS as i128;
+    let candidate_target = if shifts.is_negative() {
+        u32::try_from(shifts.unsigned_abs())
+            .ok()
+            .and_then(|shifts| candidate_target.checked_shr(shifts))
+            .unwrap_or(0)
+    } else {
+        // Shifting left past 128 bits saturates instead of wrapping to zero.
+        match u32::try_from(shifts)? {
+            shifts if shifts >= 128 || candidate_target.leading_zeros() < shifts => u128::MAX,
+            shifts => candidate_target << shifts,
+        }
+    };
+
+    Ok(candidate_target.min(u64::MAX as u128) as u64)
+}
+
+/// Returns the block height reached after `num_years` of blocks at `anchor_time` seconds each.
+pub const fn block_height_at_year(anchor_time: u16, num_years: u32) -> u32 {
+    let seconds_per_year: u32 = 365 * 24 * 60 * 60;
+    let blocks_per_year = seconds_per_year / anchor_time as u32;
+    blocks_per_year * num_years
+}
+
+/// Returns the anchor reward, the amount by which the coinbase reward decreases every block:
+/// `R_anchor = floor(2 * S / (H_Y10 * (H_Y10 + 1)))`
+pub const fn anchor_reward(parameters: &NetworkParameters) -> u64 {
+    let final_height = block_height_at_year(parameters.anchor_time, COINBASE_REWARD_YEARS) as u64;
+    (2 * STARTING_SUPPLY) / (final_height * (final_height + 1))
+}
+
+/// Returns the coinbase reward for the block at `height`, which decays linearly to zero over [`COINBASE_REWARD_YEARS`]:
+/// `R_coinbase = floor(2 * S * (H_Y10 - H) / (H_Y10 * (H_Y10 + 1)))`
+/// This is [`anchor_reward`] times the remaining blocks, without rounding the anchor reward down first,
+/// which would leave nothing to pay out on networks with a short anchor time.
+pub fn coinbase_reward(parameters: &NetworkParameters, height: u32) -> u64 {
+    let final_height = block_height_at_year(parameters.anchor_time, COINBASE_REWARD_YEARS) as u128;
+    let remaining_blocks = final_height.saturating_sub(height as u128);
+    ((2 * STARTING_SUPPLY as u128 * remaining_blocks) / (final_height * (final_height + 1))) as u64
+}
+
+/// Returns the share of `coinbase_reward` paid out to provers.
+pub fn puzzle_reward(coinbase_reward: u64) -> u64 {
+    coinbase_reward / 3 * 2
+}
+
+/// Returns the reward paid to the block producer: the coinbase reward not paid to provers, plus transaction fees.
+pub fn block_reward(coinbase_reward: u64, transaction_fees: u64) -> u64 {
+    coinbase_reward
+        .saturating_sub(puzzle_reward(coinbase_reward))
+        .saturating_add(transaction_fees)
+}
+
+/// Splits `puzzle_reward` between the provers of `coinbase_solution`, proportionally to the target of each partial solution.
+pub fn prover_rewards(
+    coinbase_solution: &CoinbaseSolution,
+    puzzle_reward: u64,
+) -> Result<Vec<(Address, u64)>> {
+    let cumulative_target = coinbase_solution.to_cumulative_proof_target()?;
+    ensure!(
+        cumulative_target > 0,
+        "coinbase solution has a cumulative proof target of zero"
+    );
+    coinbase_solution
+        .partial_solutions()
+        .iter()
+        .map(|solution| {
+            let reward = (puzzle_reward as u128).saturating_mul(solution.to_target()? as u128)
+                / cumulative_target;
+            Ok((solution.address(), reward as u64))
+        })
+        .collect()
+}
+
+/// Returns the rewards paid out by a block at `height`: the [`block_reward`] to its producer, then the [`prover_rewards`]
+/// to the provers of its coinbase solution in solution order. Rewards rounding down to zero are left out.
+/// Blocks without a coinbase solution, which is only genesis, pay no rewards.
+pub fn block_rewards(
+    parameters: &NetworkParameters,
+    height: u32,
+    producer: Address,
+    coinbase_solution: Option<&CoinbaseSolution>,
+    transaction_fees: u64,
+) -> Result<Vec<(Address, u64)>> {
+    let coinbase_solution = match coinbase_solution {
+        Some(x) => x,
+        None => return Ok(vec![]),
+    };
+    let coinbase_reward = coinbase_reward(parameters, height);
+    let mut rewards = vec![(producer, block_reward(coinbase_reward, transaction_fees))];
+    rewards.extend(prover_rewards(
+        coinbase_solution,
+        puzzle_reward(coinbase_reward),
+    )?);
+    rewards.retain(|(_, amount)| *amount > 0);
+    Ok(rewards)
+}
+
+/// Returns the metadata for a block produced at `timestamp` on top of a block with `previous` metadata.
+pub fn next_metadata(
+    parameters: &NetworkParameters,
+    previous: &Metadata,
+    timestamp: i64,
+) -> Result<Metadata> {
+    let coinbase_target = coinbase_target(
+        parameters,
+        previous.coinbase_target,
+        previous.timestamp,
+        timestamp,
+    )?;
+    Ok(Metadata {
+        network: previous.network,
+        round: previous
+            .round
+            .checked_add(1)
+            .ok_or_else(|| anyhow!("round overflowed"))?,
+        height: previous
+            .height
+            .checked_add(1)
+            .ok_or_else(|| anyhow!("height overflowed"))?,
+        coinbase_target,
+        proof_target: proof_target(coinbase_target),
+        timestamp,
+    })
+}
+
+/// Checks that `metadata` is a valid successor of `previous` on the network of `parameters`, including its coinbase and proof targets.
+/// `now` is the local time, which the block timestamp may be ahead of by at most [`MAX_TIMESTAMP_DRIFT`].
+pub fn verify_metadata(
+    parameters: &NetworkParameters,
+    previous: &Metadata,
+    metadata: &Metadata,
+    now: i64,
+) -> Result<()> {
+    ensure!(
+        metadata.network == parameters.id && previous.network == parameters.id,
+        "block is for network {}, expected {} ({})",
+        metadata.network,
+        parameters.id,
+        parameters.name
+    );
+    ensure!(
+        Some(metadata.height) == previous.height.checked_add(1),
+        "block height {} does not follow parent height {}",
+        metadata.height,
+        previous.height
+    );
+    ensure!(
+        metadata.round > previous.round,
+        "block round {} does not follow parent round {}",
+        metadata.round,
+        previous.round
+    );
+    ensure!(
+        metadata.timestamp > previous.timestamp,
+        "block timestamp {} is not after parent timestamp {}",
+        metadata.timestamp,
+        previous.timestamp
+    );
+    ensure!(
+        metadata.timestamp <= now.saturating_add(MAX_TIMESTAMP_DRIFT),
+        "block timestamp {} is more than {MAX_TIMESTAMP_DRIFT} seconds ahead of local time {now}",
+        metadata.timestamp
+    );
+    let expected_coinbase_target = coinbase_target(
+        parameters,
+        previous.coinbase_target,
+        previous.timestamp,
+        metadata.timestamp,
+    )?;
+    ensure!(
+        metadata.coinbase_target == expected_coinbase_target,
+        "block coinbase target {} does not match expected {expected_coinbase_target}",
+        metadata.coinbase_target
+    );
+    let expected_proof_target = proof_target(expected_coinbase_target);
+    ensure!(
+        metadata.proof_target == expected_proof_target,
+        "block proof target {} does not match expected {expected_proof_target}",
+        metadata.proof_target
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,470 @@
+use super::*;
+use crate::{network::Network, objects::BlockHeader, DigestTree};
+use snarkd_crypto::coinbase_puzzle::{GENESIS_COINBASE_TARGET, GENESIS_PROOF_TARGET};
+use snarkd_crypto::{
+    bls12_377::Scalar,
+    coinbase_puzzle::PartialSolution,
+    keys::{ComputeKey, PrivateKey},
+    polycommit::kzg10::{Commitment, Proof},
+};
+
+const MAINNET: &NetworkParameters = Network::Mainnet.parameters();
+/// A local time later than any block timestamp of these tests.
+const NOW: i64 = 2_000_000_000;
+
+fn metadata(height: u32, coinbase_target: u64, timestamp: i64) -> Metadata {
+    Metadata {
+        network: 0,
+        round: height as u64,
+        height,
+        coinbase_target,
+        proof_target: proof_target(coinbase_target),
+        timestamp,
+    }
+}
+
+#[test]
+fn test_genesis_proof_target() {
+    assert_eq!(proof_target(GENESIS_COINBASE_TARGET), GENESIS_PROOF_TARGET);
+}
+
+#[test]
+fn test_coinbase_target_anchor_time() {
+    let target = 1 << 30;
+    assert_eq!(
+        coinbase_target(MAINNET, target, 1000, 1000 + MAINNET.anchor_time as i64).unwrap(),
+        target
+    );
+}
+
+#[test]
+fn test_coinbase_target_direction() {
+    let target = 1 << 30;
+    let faster = coinbase_target(MAINNET, target, 1000, 1001).unwrap();
+    let slower =
+        coinbase_target(MAINNET, target, 1000, 1000 + 2 * MAINNET.anchor_time as i64).unwrap();
+    assert!(faster > target);
+    assert!(slower < target);
+}
+
+#[test]
+fn test_coinbase_target_half_life() {
+    let target = 1 << 30;
+    let half_life = (MAINNET.num_blocks_per_epoch / 2) as i64 * MAINNET.anchor_time as i64;
+    // A block that is a full half life late halves the target.
+    let halved = coinbase_target(
+        MAINNET,
+        target,
+        1000,
+        1000 + MAINNET.anchor_time as i64 + half_life,
+    )
+    .unwrap();
+    assert_eq!(halved, target / 2);
+}
+
+#[test]
+fn test_coinbase_target_bounds() {
+    // The target never drops below the minimum.
+    assert_eq!(
+        coinbase_target(MAINNET, MAINNET.genesis_coinbase_target, 0, i64::MAX / 2).unwrap(),
+        MAINNET.genesis_coinbase_target
+    );
+    // The target saturates instead of overflowing.
+    assert_eq!(
+        coinbase_target(MAINNET, u64::MAX, 1000, 1001).unwrap(),
+        u64::MAX
+    );
+    // Repeated timestamps are treated as one second apart.
+    assert_eq!(
+        coinbase_target(MAINNET, 1 << 30, 1000, 1000).unwrap(),
+        coinbase_target(MAINNET, 1 << 30, 1000, 1001).unwrap()
+    );
+}
+
+#[test]
+fn test_coinbase_reward_schedule() {
+    let final_height = block_height_at_year(MAINNET.anchor_time, COINBASE_REWARD_YEARS);
+    assert!(coinbase_reward(MAINNET, 0) > coinbase_reward(MAINNET, 1));
+    assert_eq!(coinbase_reward(MAINNET, final_height), 0);
+    assert_eq!(coinbase_reward(MAINNET, u32::MAX), 0);
+    // short anchor times still pay out, even though their anchor reward rounds down to zero
+    let devnet = Network::Devnet.parameters();
+    assert_eq!(anchor_reward(devnet), 0);
+    assert!(coinbase_reward(devnet, 0) > coinbase_reward(devnet, 1));
+
+    // The decaying rewards never exceed the starting supply.
+    let total = (final_height as u128)
+        * (coinbase_reward(MAINNET, 0) as u128 + anchor_reward(MAINNET) as u128)
+        / 2;
+    assert!(total <= STARTING_SUPPLY as u128);
+}
+
+#[test]
+fn test_reward_split() {
+    let coinbase = coinbase_reward(MAINNET, 0);
+    assert_eq!(
+        block_reward(coinbase, 0) + puzzle_reward(coinbase),
+        coinbase
+    );
+    assert_eq!(block_reward(coinbase, 7), block_reward(coinbase, 0) + 7);
+}
+
+#[test]
+fn test_block_rewards() {
+    let address = || ComputeKey::from(&PrivateKey::rand()).to_address();
+    let producer = address();
+    assert!(block_rewards(MAINNET, 0, producer, None, 7)
+        .unwrap()
+        .is_empty());
+
+    // only the targets of the partial solutions matter for the split, which are derived from the commitments
+    let provers = [address(), address()];
+    let solution = CoinbaseSolution::new(
+        provers
+            .iter()
+            .map(|x| PartialSolution::new(*x, 0, Commitment(x.0)))
+            .collect(),
+        Proof {
+            w: producer.0,
+            random_v: None::<Scalar>,
+        },
+    );
+    let rewards = block_rewards(MAINNET, 10, producer, Some(&solution), 7).unwrap();
+    let coinbase = coinbase_reward(MAINNET, 10);
+    assert_eq!(rewards[0], (producer, block_reward(coinbase, 7)));
+    assert_eq!(
+        rewards[1..],
+        prover_rewards(&solution, puzzle_reward(coinbase)).unwrap()[..]
+    );
+    let total = rewards.iter().map(|x| x.1).sum::<u64>();
+    assert!(total <= coinbase + 7);
+    assert!(total + provers.len() as u64 > coinbase + 7);
+
+    // nothing is paid once the coinbase reward has run out
+    let final_height = block_height_at_year(MAINNET.anchor_time, COINBASE_REWARD_YEARS);
+    assert!(
+        block_rewards(MAINNET, final_height, producer, Some(&solution), 0)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_epochs() {
+    assert_eq!(epoch_number(MAINNET, 0), 0);
+    assert_eq!(epoch_number(MAINNET, MAINNET.num_blocks_per_epoch - 1), 0);
+    assert_eq!(epoch_number(MAINNET, MAINNET.num_blocks_per_epoch), 1);
+    assert_eq!(epoch_block_height(MAINNET, 0), 0);
+    assert_eq!(
+        epoch_block_height(MAINNET, 1),
+        MAINNET.num_blocks_per_epoch - 1
+    );
+
+    let hash = Digest32::from([7u8; 32]);
+    let challenge = epoch_challenge(MAINNET, 3, &hash).unwrap();
+    assert_eq!(challenge.epoch_number(), 3);
+    assert_eq!(challenge, epoch_challenge(MAINNET, 3, &hash).unwrap());
+    assert!(epoch_challenge(MAINNET, 3, &Digest32::from([7u8; 16])).is_err());
+}
+
+#[test]
+fn test_next_metadata_verifies() {
+    let genesis = metadata(0, GENESIS_COINBASE_TARGET, 1000);
+    let mut previous = genesis;
+    for timestamp in [1001, 1030, 1100, 1101, 1200] {
+        let next = next_metadata(MAINNET, &previous, timestamp).unwrap();
+        verify_metadata(MAINNET, &previous, &next, NOW).unwrap();
+        previous = next;
+    }
+}
+
+#[test]
+fn test_verify_metadata_rejects() {
+    let previous = metadata(10, 1 << 20, 1000);
+    let next = next_metadata(MAINNET, &previous, 1010).unwrap();
+
+    let mut bad = next.clone();
+    bad.coinbase_target += 1;
+    assert!(verify_metadata(MAINNET, &previous, &bad, NOW).is_err());
+
+    let mut bad = next.clone();
+    bad.proof_target += 1;
+    assert!(verify_metadata(MAINNET, &previous, &bad, NOW).is_err());
+
+    let mut bad = next.clone();
+    bad.height += 1;
+    assert!(verify_metadata(MAINNET, &previous, &bad, NOW).is_err());
+
+    let mut bad = next.clone();
+    bad.network += 1;
+    assert!(verify_metadata(MAINNET, &previous, &bad, NOW).is_err());
+
+    let mut bad = next.clone();
+    bad.timestamp = previous.timestamp;
+    assert!(verify_metadata(MAINNET, &previous, &bad, NOW).is_err());
+
+    // timestamps may only run ahead of the local clock by the allowed drift
+    verify_metadata(
+        MAINNET,
+        &previous,
+        &next,
+        next.timestamp - MAX_TIMESTAMP_DRIFT,
+    )
+    .unwrap();
+    let future = next_metadata(MAINNET, &previous, 1_000_000).unwrap();
+    assert!(verify_metadata(MAINNET, &previous, &future, 1010).is_err());
+    verify_metadata(MAINNET, &previous, &future, 1_000_000).unwrap();
+}
+
+fn digest(n: u32) -> Digest32 {
+    let mut bytes = [0u8; 32];
+    bytes[..4].copy_from_slice(&n.to_be_bytes());
+    Digest32::from(bytes)
+}
+
+/// Builds the tree of blocks descending from `root`, given `(hash, parent)` pairs.
+fn build_tree(root: u32, blocks: &[(u32, u32)]) -> DigestTree {
+    let children = blocks
+        .iter()
+        .filter(|(_, parent)| *parent == root)
+        .map(|(hash, _)| build_tree(*hash, blocks))
+        .collect::<Vec<_>>();
+    if children.is_empty() {
+        DigestTree::Leaf(digest(root))
+    } else {
+        let longest = children.iter().map(|x| x.longest_length()).max().unwrap();
+        DigestTree::Node(digest(root), children, longest)
+    }
+}
+
+fn chain_of(chain: Vec<&Digest32>) -> Vec<u32> {
+    chain
+        .into_iter()
+        .map(|x| u32::from_be_bytes(x.bytes::<32>().unwrap()[..4].try_into().unwrap()))
+        .collect()
+}
+
+#[test]
+fn test_block_weight() {
+    let light = metadata(1, 1 << 20, 1000);
+    let heavy = metadata(1, 1 << 21, 1000);
+    assert!(block_weight(&heavy) > block_weight(&light));
+}
+
+#[test]
+fn test_is_preferred_chain() {
+    assert!(is_preferred_chain(2, &digest(9), 1, &digest(1)));
+    assert!(!is_preferred_chain(1, &digest(1), 2, &digest(9)));
+    // equal weights prefer the lowest tip
+    assert!(is_preferred_chain(2, &digest(1), 2, &digest(9)));
+    assert!(!is_preferred_chain(2, &digest(9), 2, &digest(1)));
+    assert!(!is_preferred_chain(2, &digest(1), 2, &digest(1)));
+}
+
+#[test]
+fn test_heaviest_chain_prefers_weight_over_length() {
+    // 0 -> 1 -> 2 -> 3 is longer, but 0 -> 4 carries more work
+    let tree = build_tree(0, &[(1, 0), (2, 1), (3, 2), (4, 0)]);
+    let weights = [10, 10, 10, 10, 50];
+    let (weight, chain) = heaviest_chain(&tree, |x| weights[chain_of(vec![x])[0] as usize]);
+    assert_eq!(weight, 60);
+    assert_eq!(chain_of(chain), vec![0, 4]);
+}
+
+#[test]
+fn test_heaviest_chain_tie_break() {
+    // both forks weigh the same, the lowest tip wins regardless of the order children are stored in
+    for blocks in [
+        [(1, 0), (2, 1), (3, 0), (4, 3)],
+        [(3, 0), (4, 3), (1, 0), (2, 1)],
+    ] {
+        let tree = build_tree(0, &blocks);
+        let (weight, chain) = heaviest_chain(&tree, |_| 5);
+        assert_eq!(weight, 15);
+        assert_eq!(chain_of(chain), vec![0, 1, 2]);
+    }
+}
+
+#[test]
+fn test_heaviest_chain_deep() {
+    let blocks = (1..2000).map(|x| (x, x - 1)).collect::<Vec<_>>();
+    let mut tree = DigestTree::Leaf(digest(1999));
+    for (_, parent) in blocks.iter().rev() {
+        tree = DigestTree::Node(digest(*parent), vec![tree], 0);
+    }
+    let (weight, chain) = heaviest_chain(&tree, |_| 1);
+    assert_eq!(weight, 2000);
+    assert_eq!(chain_of(chain), (0..2000).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_fork_choice_simulation() {
+    // two producers extend competing forks from block 0: fork A every round at the genesis
+    // target, fork B every third round at a target that grows as its blocks arrive faster
+    let mut blocks = vec![];
+    let mut weights = vec![GENESIS_COINBASE_TARGET as u128];
+    let (mut tip_a, mut weight_a) = (0, 0u128);
+    let (mut tip_b, mut weight_b) = (0, 0u128);
+    let mut target_b = GENESIS_COINBASE_TARGET;
+    for round in 0..30 {
+        let hash = weights.len() as u32;
+        blocks.push((hash, tip_a));
+        weights.push(GENESIS_COINBASE_TARGET as u128);
+        tip_a = hash;
+        weight_a += GENESIS_COINBASE_TARGET as u128;
+
+        if round % 3 == 2 {
+            target_b *= 4;
+            let hash = weights.len() as u32;
+            blocks.push((hash, tip_b));
+            weights.push(target_b as u128);
+            tip_b = hash;
+            weight_b += target_b as u128;
+        }
+
+        let tree = build_tree(0, &blocks);
+        let (weight, chain) = heaviest_chain(&tree, |x| weights[chain_of(vec![x])[0] as usize]);
+        let expected_tip = if is_preferred_chain(weight_a, &digest(tip_a), weight_b, &digest(tip_b))
+        {
+            tip_a
+        } else {
+            tip_b
+        };
+        assert_eq!(*chain_of(chain).last().unwrap(), expected_tip);
+        assert_eq!(weight, weights[0] + weight_a.max(weight_b));
+    }
+    // fork B overtook fork A despite being shorter
+    assert!(weight_b > weight_a);
+}
+
+#[test]
+fn test_network_consensus_parameters() {
+    // devnet blocks come faster, so a block time that holds the mainnet target raises the devnet one
+    let devnet = Network::Devnet.parameters();
+    let target = 1 << 30;
+    let block_time = 1000 + MAINNET.anchor_time as i64;
+    assert_eq!(
+        coinbase_target(MAINNET, target, 1000, block_time).unwrap(),
+        target
+    );
+    assert!(coinbase_target(devnet, target, 1000, block_time).unwrap() < target);
+    assert_eq!(
+        coinbase_target(devnet, target, 1000, 1000 + devnet.anchor_time as i64).unwrap(),
+        target
+    );
+
+    assert_eq!(epoch_number(devnet, devnet.num_blocks_per_epoch), 1);
+    assert_ne!(devnet.num_blocks_per_epoch, MAINNET.num_blocks_per_epoch);
+
+    // metadata is only valid on its own network
+    let previous = metadata(10, 1 << 20, 1000);
+    let next = next_metadata(MAINNET, &previous, 1010).unwrap();
+    assert!(verify_metadata(Network::Testnet.parameters(), &previous, &next, NOW).is_err());
+}
+
+#[test]
+fn test_genesis_block() {
+    const TIMESTAMP: i64 = 1_700_000_000;
+    let key = PrivateKey::rand();
+    for network in [Network::Mainnet, Network::Testnet, Network::Devnet] {
+        let parameters = network.parameters();
+        let genesis = parameters.genesis_block(TIMESTAMP, &key);
+        parameters.verify_genesis(&genesis.header).unwrap();
+        assert!(genesis.header.verify_signature());
+        assert_eq!(genesis.header.producer, ComputeKey::from(&key).to_address());
+        assert_eq!(Network::from_id(parameters.id), Some(network));
+
+        let next = next_metadata(parameters, &genesis.header.metadata, TIMESTAMP + 10).unwrap();
+        assert_eq!(next.network, parameters.id);
+        verify_metadata(parameters, &genesis.header.metadata, &next, NOW).unwrap();
+    }
+}
+
+#[test]
+fn test_genesis_hash() {
+    let devnet = Network::Devnet.parameters();
+    let genesis = devnet.genesis_block(1_700_000_000, &PrivateKey::rand());
+    let hash = genesis.header.block_hash.clone();
+
+    // public networks cannot be joined before their genesis block is pinned, nor can a node pick its own
+    for network in [Network::Mainnet, Network::Testnet] {
+        let parameters = network.parameters();
+        assert!(parameters.genesis_hash.is_none());
+        assert!(parameters.genesis_hash(None).is_err());
+        assert!(parameters.genesis_hash(Some(&hash)).is_err());
+    }
+    assert!(MAINNET.info_hash.is_none());
+
+    // devnets are joined through their configured genesis block, each with its own info hash
+    assert!(devnet.genesis_hash(None).is_err());
+    assert!(devnet
+        .genesis_hash(Some(&Digest32::from([1u8; 4])))
+        .is_err());
+    assert_eq!(devnet.genesis_hash(Some(&hash)).unwrap(), hash);
+    let other = devnet.genesis_block(1_700_000_000, &PrivateKey::rand());
+    assert_ne!(other.header.block_hash, hash);
+    assert_ne!(
+        devnet.info_hash(&hash),
+        devnet.info_hash(&other.header.block_hash)
+    );
+    assert_eq!(devnet.info_hash(&hash).len(), 40);
+}
+
+#[test]
+fn test_universal_srs_checksum() {
+    // public networks only run on their published universal SRS, devnets generate their own
+    for network in [Network::Mainnet, Network::Testnet] {
+        let parameters = network.parameters();
+        assert!(parameters.universal_srs_checksum.is_none());
+        assert!(parameters.universal_srs_checksum().is_err());
+    }
+    let pinned = NetworkParameters {
+        universal_srs_checksum: Some("00"),
+        ..*MAINNET
+    };
+    assert_eq!(pinned.universal_srs_checksum().unwrap(), Some("00"));
+    assert_eq!(
+        Network::Devnet
+            .parameters()
+            .universal_srs_checksum()
+            .unwrap(),
+        None
+    );
+}
+
+#[test]
+fn test_genesis_block_network_mismatch() {
+    let devnet = Network::Devnet.parameters();
+    let genesis = devnet.genesis_block(1_700_000_000, &PrivateKey::rand());
+    devnet.verify_genesis(&genesis.header).unwrap();
+    assert!(Network::Mainnet
+        .parameters()
+        .verify_genesis(&genesis.header)
+        .is_err());
+
+    // a signature from another block does not carry over
+    let late = devnet.genesis_block(1_700_000_001, &PrivateKey::rand());
+    let mut forged = genesis.clone();
+    forged.header.signature = late.header.signature;
+    assert!(!forged.header.verify_signature());
+    assert!(devnet.verify_genesis(&forged.header).is_err());
+
+    // nor does a signature by any other key over the same header
+    let mut forged = genesis.clone();
+    forged.header.signature = PrivateKey::rand().sign(&genesis.header.signature_message());
+    assert!(!forged.header.verify_signature());
+    assert!(devnet.verify_genesis(&forged.header).is_err());
+
+    // a genesis block signed by another producer has another hash, so it does not match the pinned one
+    let header = BlockHeader::new(
+        genesis.header.previous_hash.clone(),
+        genesis.header.previous_state_root.clone(),
+        genesis.header.transactions_root.clone(),
+        genesis.header.coinbase_root.clone(),
+        genesis.header.rewards_root.clone(),
+        genesis.header.metadata.clone(),
+        &PrivateKey::rand(),
+    );
+    devnet.verify_genesis(&header).unwrap();
+    assert_ne!(header.block_hash, genesis.header.block_hash);
+}
@@ -15,7 +15,7 @@ use smallvec::SmallVec;
 type InnerType = SmallVec<[u8; 64]>;
 
 /// A generic storage for small-size binary blobs, generally digests.
-#[derive(Clone, PartialEq, Eq, Hash, Default)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct Digest(pub InnerType);
 
 pub type Digest16 = Digest;
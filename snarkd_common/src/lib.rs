@@ -6,6 +6,9 @@ mod digest_tree;
 pub use digest_tree::DigestTree;
 
 pub mod config;
+pub mod consensus;
+pub mod network;
 mod peer_config;
+mod producer_config;
 
 pub mod objects;
@@ -0,0 +1,242 @@
+use std::net::SocketAddr;
+
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use serde::{Deserialize, Serialize};
+use snarkd_crypto::{
+    coinbase_puzzle::{
+        PuzzleConfig, ANCHOR_TIME, COINBASE_PUZZLE_DEGREE, GENESIS_COINBASE_TARGET,
+        NUM_BLOCKS_PER_EPOCH,
+    },
+    keys::PrivateKey,
+};
+
+use crate::{
+    consensus,
+    objects::{Block, BlockHeader, Metadata},
+    Digest,
+};
+
+/// A named network the node can join
+#[derive(Serialize, Deserialize, Default, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Network {
+    Mainnet,
+    #[default]
+    Testnet,
+    /// A private network, usually started from a genesis block made with `snarkd genesis`
+    Devnet,
+}
+
+/// Everything that differs between networks
+#[derive(Debug)]
+pub struct NetworkParameters {
+    /// Carried by every block of the network in `Metadata::network`
+    pub id: u16,
+    pub name: &'static str,
+    /// Bittorrent info hash used to find peers on trackers, or `None` to derive it from the genesis block hash
+    pub info_hash: Option<&'static str>,
+    /// Peers always connected to, in addition to those found through trackers
+    pub seed_peers: &'static [&'static str],
+    /// Coinbase target of the genesis block, the proof target follows from it
+    pub genesis_coinbase_target: u64,
+    /// Hex encoded hash of the genesis block every node of the network starts from, or `None` if it is not published yet.
+    /// Devnets are private, so their genesis block hash is configured instead
+    pub genesis_hash: Option<&'static str>,
+    /// Targeted number of seconds between blocks
+    pub anchor_time: u16,
+    /// Number of blocks sharing an epoch challenge, also the coinbase target retarget window
+    pub num_blocks_per_epoch: u32,
+    /// Degree of the coinbase puzzle polynomials, the universal SRS must support twice of it
+    pub coinbase_puzzle_degree: u32,
+    /// SHA-256 checksum of the universal SRS shared by the nodes of the network, or `None` if it is not published yet.
+    /// Devnets are private, so each node generates its own with `snarkd setup` instead
+    pub universal_srs_checksum: Option<&'static str>,
+}
+
+const MAINNET: NetworkParameters = NetworkParameters {
+    id: 0,
+    name: "mainnet",
+    info_hash: None,
+    seed_peers: &[],
+    genesis_coinbase_target: GENESIS_COINBASE_TARGET,
+    // pinned once the genesis block of the network is published
+    genesis_hash: None,
+    anchor_time: ANCHOR_TIME,
+    num_blocks_per_epoch: NUM_BLOCKS_PER_EPOCH,
+    coinbase_puzzle_degree: COINBASE_PUZZLE_DEGREE,
+    // pinned once the universal SRS of the network is published
+    universal_srs_checksum: None,
+};
+
+const TESTNET: NetworkParameters = NetworkParameters {
+    id: 1,
+    name: "testnet",
+    info_hash: Some("000000F214C636F3EBD358EC783C6E8A91BF81AE"),
+    seed_peers: &[],
+    genesis_coinbase_target: GENESIS_COINBASE_TARGET,
+    // pinned once the genesis block of the network is published
+    genesis_hash: None,
+    anchor_time: ANCHOR_TIME,
+    num_blocks_per_epoch: NUM_BLOCKS_PER_EPOCH,
+    coinbase_puzzle_degree: COINBASE_PUZZLE_DEGREE,
+    // pinned once the universal SRS of the network is published
+    universal_srs_checksum: None,
+};
+
+const DEVNET: NetworkParameters = NetworkParameters {
+    id: 2,
+    name: "devnet",
+    // each devnet finds its own peers
+    info_hash: None,
+    seed_peers: &[],
+    // local networks are usually run for short tests, so blocks and epochs come faster and are cheap to prove
+    genesis_coinbase_target: (1 << 5) - 1,
+    genesis_hash: None,
+    anchor_time: 5,
+    num_blocks_per_epoch: 1 << 5,
+    coinbase_puzzle_degree: (1 << 5) - 1,
+    // devnet parameters are generated locally, so there is nothing to pin
+    universal_srs_checksum: None,
+};
+
+impl Network {
+    pub const fn parameters(&self) -> &'static NetworkParameters {
+        match self {
+            Network::Mainnet => &MAINNET,
+            Network::Testnet => &TESTNET,
+            Network::Devnet => &DEVNET,
+        }
+    }
+
+    /// Returns the network carrying `id` in its block metadata.
+    pub fn from_id(id: u16) -> Option<Self> {
+        [Network::Mainnet, Network::Testnet, Network::Devnet]
+            .into_iter()
+            .find(|x| x.parameters().id == id)
+    }
+}
+
+impl NetworkParameters {
+    pub fn seed_peers(&self) -> Result<Vec<SocketAddr>> {
+        self.seed_peers
+            .iter()
+            .map(|x| {
+                x.parse()
+                    .with_context(|| format!("invalid seed peer {x} for {}", self.name))
+            })
+            .collect()
+    }
+
+    /// Configuration of the coinbase puzzle proven for blocks of this network.
+    pub fn puzzle_config(&self) -> PuzzleConfig {
+        PuzzleConfig {
+            degree: self.coinbase_puzzle_degree,
+        }
+    }
+
+    /// Metadata of the genesis block created at `timestamp`.
+    pub fn genesis_metadata(&self, timestamp: i64) -> Metadata {
+        Metadata {
+            network: self.id,
+            round: 0,
+            height: 0,
+            coinbase_target: self.genesis_coinbase_target,
+            proof_target: consensus::proof_target(self.genesis_coinbase_target),
+            timestamp,
+        }
+    }
+
+    /// Returns the hash of the genesis block nodes of this network start from.
+    /// Devnets take the `configured` one, public networks only their pinned one and cannot be joined before it is published.
+    pub fn genesis_hash(&self, configured: Option<&Digest>) -> Result<Digest> {
+        let hash = if self.id == DEVNET.id {
+            configured.cloned().ok_or_else(|| {
+                anyhow!("the devnet genesis block hash must be configured, it is printed by `snarkd genesis`")
+            })?
+        } else {
+            if configured.is_some() {
+                bail!(
+                    "the {} genesis block hash is pinned and cannot be configured",
+                    self.name
+                );
+            }
+            let pinned = self
+                .genesis_hash
+                .ok_or_else(|| anyhow!("the {} genesis block is not published yet", self.name))?;
+            let bytes = hex::decode(pinned)
+                .with_context(|| format!("invalid pinned genesis block hash for {}", self.name))?;
+            Digest::from(&bytes[..])
+        };
+        ensure!(
+            hash.len() == 32,
+            "genesis block hash {hash} is not 32 bytes long"
+        );
+        Ok(hash)
+    }
+
+    /// Returns the checksum the universal SRS of this network must match, or `None` on devnets, which generate their own.
+    /// Public networks cannot be joined before their universal SRS is published.
+    pub fn universal_srs_checksum(&self) -> Result<Option<&'static str>> {
+        if self.id == DEVNET.id {
+            return Ok(None);
+        }
+        let checksum = self
+            .universal_srs_checksum
+            .ok_or_else(|| anyhow!("the {} universal SRS is not published yet", self.name))?;
+        Ok(Some(checksum))
+    }
+
+    /// Returns the bittorrent info hash of the network started from the genesis block `genesis_hash`.
+    /// Networks without a pinned info hash use the leading 20 bytes of their genesis block hash, so that every devnet has its own.
+    pub fn info_hash(&self, genesis_hash: &Digest) -> String {
+        match self.info_hash {
+            Some(info_hash) => info_hash.to_string(),
+            None => hex::encode_upper(&genesis_hash[..20]),
+        }
+    }
+
+    /// Builds the genesis block created at `timestamp`, signed by `private_key`.
+    pub fn genesis_block(&self, timestamp: i64, private_key: &PrivateKey) -> Block {
+        let header = BlockHeader::new(
+            Digest::from([0u8; 32]),
+            Digest::from([0u8; 32]),
+            Block::transactions_root(&[]),
+            Block::coinbase_root(None),
+            Block::rewards_root(&[]),
+            self.genesis_metadata(timestamp),
+            private_key,
+        );
+        Block {
+            header,
+            transactions: vec![],
+            coinbase: None,
+            rewards: vec![],
+        }
+    }
+
+    /// Checks that `header` is a well formed genesis block of this network, signed by its producer.
+    /// Which genesis block a node starts from is decided by [`Self::genesis_hash`].
+    pub fn verify_genesis(&self, header: &BlockHeader) -> Result<()> {
+        ensure!(
+            header.previous_hash[..] == [0u8; 32],
+            "genesis block must not have a parent"
+        );
+        ensure!(
+            header.metadata.network == self.id,
+            "genesis block is for network {}, expected {} ({})",
+            header.metadata.network,
+            self.id,
+            self.name
+        );
+        ensure!(
+            header.metadata == self.genesis_metadata(header.metadata.timestamp),
+            "invalid genesis block metadata"
+        );
+        ensure!(
+            header.block_hash == header.hash(),
+            "genesis block hash does not match its header"
+        );
+        ensure!(header.verify_signature(), "invalid genesis block signature");
+        Ok(())
+    }
+}
@@ -1,5 +1,11 @@
+use ruint::Uint;
 use sha2::{Digest, Sha256};
-use snarkd_crypto::keys::Signature;
+use snarkd_crypto::{
+    bls12_377::{Field as _, Fp, Scalar},
+    coinbase_puzzle::CoinbaseSolution,
+    keys::{Address, ComputeKey, PrivateKey, Signature},
+    serialization::{CanonicalSerialize, Compress},
+};
 
 use crate::Digest32;
 
@@ -11,6 +17,64 @@ type BlockHash = Digest32;
 pub struct Block {
     pub header: BlockHeader,
     pub transactions: Vec<Transaction>,
+    /// Accumulated prover solutions rewarded by this block, absent for genesis.
+    pub coinbase: Option<CoinbaseSolution>,
+    /// Payouts of this block, see [`crate::consensus::block_rewards`].
+    pub rewards: Vec<(Address, u64)>,
+}
+
+impl Block {
+    /// Computes the root committing to the ordered transaction ids of a block.
+    pub fn transactions_root(transactions: &[Transaction]) -> Field {
+        let mut sha = Sha256::default();
+        for transaction in transactions {
+            sha.update(&transaction.id()[..]);
+        }
+        let output = sha.finalize();
+        let sha = sha2::Sha256::digest(output);
+        let mut root = [0u8; 32];
+        root.copy_from_slice(&sha[..]);
+        Field::from(root)
+    }
+
+    /// Computes the root committing to the coinbase solution of a block, including every partial solution and the accumulated proof.
+    pub fn coinbase_root(coinbase: Option<&CoinbaseSolution>) -> Field {
+        let mut sha = Sha256::default();
+        if let Some(coinbase) = coinbase {
+            for solution in coinbase.partial_solutions() {
+                sha.update(&solution.address().0.to_bytes(Compress::Yes)[..]);
+                sha.update(&solution.nonce().to_le_bytes()[..]);
+                sha.update(&solution.commitment().to_bytes(Compress::Yes)[..]);
+            }
+            sha.update(&coinbase.proof().to_bytes(Compress::Yes)[..]);
+        }
+        let output = sha.finalize();
+        let sha = sha2::Sha256::digest(output);
+        let mut root = [0u8; 32];
+        root.copy_from_slice(&sha[..]);
+        Field::from(root)
+    }
+
+    /// Returns the sum of the fees paid by `transactions`.
+    pub fn transaction_fees(transactions: &[Transaction]) -> u64 {
+        transactions
+            .iter()
+            .fold(0u64, |total, x| total.saturating_add(x.fee()))
+    }
+
+    /// Computes the root committing to the ordered rewards of a block.
+    pub fn rewards_root(rewards: &[(Address, u64)]) -> Field {
+        let mut sha = Sha256::default();
+        for (address, amount) in rewards {
+            sha.update(&address.0.to_bytes(Compress::Yes)[..]);
+            sha.update(&amount.to_le_bytes()[..]);
+        }
+        let output = sha.finalize();
+        let sha = sha2::Sha256::digest(output);
+        let mut root = [0u8; 32];
+        root.copy_from_slice(&sha[..]);
+        Field::from(root)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -19,28 +83,78 @@ pub struct BlockHeader {
     pub previous_hash: BlockHash,
     pub previous_state_root: Field,
     pub transactions_root: Field,
+    /// See [`Block::coinbase_root`]
+    pub coinbase_root: Field,
+    /// See [`Block::rewards_root`]
+    pub rewards_root: Field,
     pub metadata: Metadata,
+    /// Address of the block producer, the only key whose signature is accepted for the header.
+    pub producer: Address,
     pub signature: Signature,
 }
 
 impl BlockHeader {
+    /// Creates a new header over the given contents, signed deterministically by the block producer.
+    pub fn new(
+        previous_hash: BlockHash,
+        previous_state_root: Field,
+        transactions_root: Field,
+        coinbase_root: Field,
+        rewards_root: Field,
+        metadata: Metadata,
+        private_key: &PrivateKey,
+    ) -> Self {
+        let compute_key = ComputeKey::from(private_key);
+        let mut header = Self {
+            block_hash: BlockHash::default(),
+            previous_hash,
+            previous_state_root,
+            transactions_root,
+            coinbase_root,
+            rewards_root,
+            metadata,
+            producer: compute_key.to_address(),
+            // the signature is not part of the hash, so a placeholder is replaced below
+            signature: Signature::new(Scalar::ZERO, Scalar::ZERO, compute_key),
+        };
+        header.block_hash = header.hash();
+        header.signature = private_key.sign_deterministic(&header.signature_message());
+        header
+    }
+
     pub fn hash(&self) -> BlockHash {
         let mut sha = Sha256::default();
         sha.update(&self.previous_hash[..]);
         sha.update(&self.previous_state_root[..]);
         sha.update(&self.transactions_root[..]);
+        sha.update(&self.coinbase_root[..]);
+        sha.update(&self.rewards_root[..]);
         sha.update(&self.metadata.network.to_le_bytes()[..]);
         sha.update(&self.metadata.round.to_le_bytes()[..]);
         sha.update(&self.metadata.height.to_le_bytes()[..]);
         sha.update(&self.metadata.coinbase_target.to_le_bytes()[..]);
         sha.update(&self.metadata.proof_target.to_le_bytes()[..]);
         sha.update(&self.metadata.timestamp.to_le_bytes()[..]);
+        sha.update(&self.producer.0.to_bytes(Compress::Yes)[..]);
         let output = sha.finalize();
         let sha = sha2::Sha256::digest(output);
         let mut hash = [0u8; 32];
         hash.copy_from_slice(&sha[..]);
         BlockHash::from(hash)
     }
+
+    /// Returns the message signed by the block producer, which is the header hash as a field element.
+    pub fn signature_message(&self) -> [Fp; 1] {
+        let mut bytes = [0u8; 48];
+        bytes[..32].copy_from_slice(&self.hash()[..]);
+        [Fp(Uint::from_le_bytes(bytes))]
+    }
+
+    /// Checks that the header is signed by its producer.
+    pub fn verify_signature(&self) -> bool {
+        self.signature
+            .verify(&self.producer, &self.signature_message())
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
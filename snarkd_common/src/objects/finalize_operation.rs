@@ -0,0 +1,63 @@
+use anyhow::{ensure, Result};
+use prost::Message;
+
+use super::Identifier;
+
+/// A change to a mapping of the transition's program, applied once the transition is finalized in a canon block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FinalizeOperation {
+    pub mapping: Identifier,
+    pub key: Vec<u8>,
+    /// New value of `key`, or `None` to remove it
+    pub value: Option<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct FinalizeOperationMessage {
+    #[prost(bytes = "vec", tag = "1")]
+    mapping: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    key: Vec<u8>,
+    #[prost(bytes = "vec", optional, tag = "3")]
+    value: Option<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct FinalizeOperationsMessage {
+    #[prost(message, repeated, tag = "1")]
+    operations: Vec<FinalizeOperationMessage>,
+}
+
+impl FinalizeOperation {
+    /// Encodes operations as carried by `Transition::finalize`.
+    pub fn encode_all(operations: &[FinalizeOperation]) -> Vec<u8> {
+        FinalizeOperationsMessage {
+            operations: operations
+                .iter()
+                .map(|x| FinalizeOperationMessage {
+                    mapping: x.mapping.to_string().into_bytes(),
+                    key: x.key.clone(),
+                    value: x.value.clone(),
+                })
+                .collect(),
+        }
+        .encode_to_vec()
+    }
+
+    /// Decodes the operations carried by `Transition::finalize`.
+    pub fn decode_all(bytes: &[u8]) -> Result<Vec<FinalizeOperation>> {
+        FinalizeOperationsMessage::decode(bytes)?
+            .operations
+            .into_iter()
+            .map(|x| {
+                let mapping: Identifier = std::str::from_utf8(&x.mapping)?.parse()?;
+                ensure!(!x.key.is_empty(), "empty key for mapping `{mapping}`");
+                Ok(FinalizeOperation {
+                    mapping,
+                    key: x.key,
+                    value: x.value,
+                })
+            })
+            .collect()
+    }
+}
@@ -1,3 +1,7 @@
+use std::{fmt, str::FromStr};
+
+use anyhow::{ensure, Error, Result};
+
 use super::Field;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -6,6 +10,44 @@ pub struct Identifier {
     pub length: u8,
 }
 
+impl Identifier {
+    /// Identifiers are packed into a single field element, leaving room for the length.
+    pub const MAX_LENGTH: usize = 31;
+}
+
+impl FromStr for Identifier {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        ensure!(
+            !name.is_empty() && name.len() <= Self::MAX_LENGTH,
+            "identifier must be 1 to {} characters long",
+            Self::MAX_LENGTH
+        );
+        ensure!(
+            name.starts_with(|x: char| x.is_ascii_alphabetic()),
+            "identifier `{name}` must start with a letter"
+        );
+        ensure!(
+            name.bytes().all(|x| x.is_ascii_alphanumeric() || x == b'_'),
+            "identifier `{name}` may only contain letters, digits and underscores"
+        );
+        let mut field = [0u8; 32];
+        field[..name.len()].copy_from_slice(name.as_bytes());
+        Ok(Identifier {
+            field: Field::from(field),
+            length: name.len() as u8,
+        })
+    }
+}
+
+impl fmt::Display for Identifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let length = (self.length as usize).min(self.field.len());
+        write!(f, "{}", String::from_utf8_lossy(&self.field[..length]))
+    }
+}
+
 #[cfg(feature = "rusqlite")]
 impl rusqlite::types::FromSql for Identifier {
     fn column_result(value: rusqlite::types::ValueRef<'_>) -> rusqlite::types::FromSqlResult<Self> {
@@ -4,8 +4,9 @@ use crate::{Digest32, Digest48};
 type Scalar = Digest32;
 type Group = Digest48;
 type Field = Digest32;
-type VerifyingKey = Digest32;
-type Certificate = Digest32;
+
+type VerifyingKey = snarkd_crypto::marlin::CircuitVerifyingKey;
+type Certificate = snarkd_crypto::marlin::Certificate;
 
 type Instruction = ();
 type LiteralType = ();
@@ -21,6 +22,8 @@ mod entry;
 pub use entry::*;
 mod entry_type;
 pub use entry_type::*;
+mod finalize_operation;
+pub use finalize_operation::*;
 mod finalize_type;
 pub use finalize_type::*;
 mod function;
@@ -1 +1,7 @@
+use crate::Digest32;
+
+/// Canonical encoding of a `snarkd_ir::Program`, see `snarkd_ir::Program::canonical_bytes`.
 pub type Program = Vec<u8>;
+
+/// Hash of the canonical encoding of a program, see `snarkd_ir::Program::fingerprint`.
+pub type ProgramFingerprint = Digest32;
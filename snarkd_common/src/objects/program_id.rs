@@ -1,7 +1,51 @@
-use super::Identifier;
+use std::{fmt, str::FromStr};
+
+use anyhow::{anyhow, Error, Result};
+use sha2::{Digest, Sha256};
+
+use super::{DeploymentID, Identifier, ProgramFingerprint};
+use crate::Digest32;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ProgramID {
     pub name: Identifier,
     pub network: Identifier,
 }
+
+impl ProgramID {
+    /// Identifies an edition of this program deployed with the given fingerprint.
+    ///
+    /// Hashes the ID as written, its edition and the fingerprint, each at a fixed or prefixed
+    /// length so that no two deployments share an input.
+    pub fn deployment_id(&self, edition: u16, fingerprint: &ProgramFingerprint) -> DeploymentID {
+        let id = self.to_string();
+        let mut sha = Sha256::default();
+        sha.update(b"snarkd.deployment.v1");
+        sha.update([id.len() as u8]);
+        sha.update(id.as_bytes());
+        sha.update(edition.to_le_bytes());
+        sha.update(&fingerprint[..]);
+        Digest32::from(&sha.finalize()[..])
+    }
+}
+
+impl FromStr for ProgramID {
+    type Err = Error;
+
+    /// Parses a program ID written as `name.network`, e.g. `token.aleo`.
+    fn from_str(id: &str) -> Result<Self> {
+        let (name, network) = id
+            .split_once('.')
+            .ok_or_else(|| anyhow!("program ID `{id}` must be written as `name.network`"))?;
+        Ok(ProgramID {
+            name: name.parse()?,
+            network: network.parse()?,
+        })
+    }
+}
+
+impl fmt::Display for ProgramID {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.name, self.network)
+    }
+}
@@ -1,10 +1,142 @@
-use super::{Field, Group, Identifier};
+use anyhow::{anyhow, ensure, Result};
 use indexmap::IndexMap;
+use ruint::Uint;
+use snarkd_crypto::{
+    bls12_377::{Fp, G1Affine, Scalar},
+    keys::{Address, RecordCiphertext, RecordPlaintext, ViewKey},
+};
+
+use super::{Field, Identifier};
+
+/// Bytes of an entry packed into each field element, which must stay below the modulus.
+const BYTES_PER_FIELD: usize = 31;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Record {
-    pub owner: Field,
-    pub gates: Field,
+    pub owner: Address,
+    pub gates: u64,
     pub data: IndexMap<Identifier, Vec<u8>>,
-    pub nonce: Group,
+    pub nonce: G1Affine,
+}
+
+fn to_field(bytes: &[u8]) -> Fp {
+    let mut limbs = [0u8; 48];
+    limbs[..bytes.len()].copy_from_slice(bytes);
+    Fp(Uint::from_le_bytes(limbs))
+}
+
+/// Returns the first `len` little endian bytes of `field`, failing if the others are not zero.
+fn from_field(field: &Fp, len: usize) -> Result<&[u8]> {
+    let bytes = field.0.as_le_slice();
+    ensure!(
+        bytes[len..].iter().all(|x| *x == 0),
+        "record entry does not fit in {len} bytes"
+    );
+    Ok(&bytes[..len])
+}
+
+impl Record {
+    /// Packs the record into field elements. Each entry is written as its identifier, with the
+    /// length of the identifier in its last byte, then the number of bytes of the entry, then
+    /// the bytes of the entry in chunks of 31.
+    pub fn to_plaintext(&self) -> RecordPlaintext {
+        let mut data = vec![];
+        for (identifier, value) in &self.data {
+            let field = &identifier.field[..identifier.field.len().min(31)];
+            let mut name = [0u8; 32];
+            name[..field.len()].copy_from_slice(field);
+            name[31] = identifier.length;
+            data.push(to_field(&name));
+            data.push(Fp::from(value.len() as u64));
+            data.extend(value.chunks(BYTES_PER_FIELD).map(to_field));
+        }
+        RecordPlaintext {
+            owner: self.owner,
+            gates: self.gates,
+            data,
+            nonce: self.nonce,
+        }
+    }
+
+    /// Unpacks a record written by [`Record::to_plaintext`].
+    pub fn from_plaintext(plaintext: &RecordPlaintext) -> Result<Self> {
+        let mut data = IndexMap::new();
+        let mut fields = plaintext.data.iter();
+        while let Some(name) = fields.next() {
+            let name = from_field(name, 32)?;
+            let identifier = Identifier {
+                field: Field::from(&[&name[..31], &[0]].concat()[..]),
+                length: name[31],
+            };
+            let len = fields
+                .next()
+                .ok_or_else(|| anyhow!("missing length of record entry `{identifier}`"))?;
+            let len = u64::try_from(len.0)? as usize;
+            let mut value = Vec::with_capacity(len);
+            while value.len() < len {
+                let field = fields
+                    .next()
+                    .ok_or_else(|| anyhow!("record entry `{identifier}` is truncated"))?;
+                value.extend(from_field(field, BYTES_PER_FIELD.min(len - value.len()))?);
+            }
+            ensure!(
+                data.insert(identifier.clone(), value).is_none(),
+                "duplicate record entry `{identifier}`"
+            );
+        }
+        Ok(Self {
+            owner: plaintext.owner,
+            gates: plaintext.gates,
+            data,
+            nonce: plaintext.nonce,
+        })
+    }
+
+    /// Encrypts the record to its owner, with the randomizer its nonce was created from.
+    pub fn encrypt(&self, randomizer: Scalar) -> Result<RecordCiphertext> {
+        self.to_plaintext().encrypt(randomizer)
+    }
+
+    /// Decrypts a record owned by the account of `view_key`, or returns `None` if it belongs to
+    /// another account.
+    pub fn decrypt(view_key: &ViewKey, record: &RecordCiphertext) -> Result<Option<Self>> {
+        view_key
+            .decrypt_record(record)
+            .map(|x| Self::from_plaintext(&x))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use snarkd_crypto::{bls12_377::Field as _, keys::PrivateKey};
+
+    use super::*;
+
+    #[test]
+    fn encrypt_and_decrypt() {
+        let view_key = ViewKey::from(&PrivateKey::rand());
+        let randomizer = Scalar::rand();
+        let nonce = RecordPlaintext::new(view_key.to_address(), 0, vec![], randomizer).nonce;
+        let record = Record {
+            owner: view_key.to_address(),
+            gates: 1000,
+            data: IndexMap::from([
+                ("empty".parse().unwrap(), vec![]),
+                ("short".parse().unwrap(), vec![1, 2, 3]),
+                ("a".repeat(31).parse().unwrap(), (0..=255).collect()),
+            ]),
+            nonce,
+        };
+        assert!(record.encrypt(Scalar::rand()).is_err());
+        let ciphertext = record.encrypt(randomizer).unwrap();
+        assert!(view_key.is_owner(&ciphertext));
+        assert_eq!(
+            Record::decrypt(&view_key, &ciphertext).unwrap(),
+            Some(record)
+        );
+
+        let other = ViewKey::from(&PrivateKey::rand());
+        assert_eq!(Record::decrypt(&other, &ciphertext).unwrap(), None);
+    }
 }
@@ -1,8 +1,19 @@
-use crate::Digest32;
+use anyhow::{anyhow, Result};
+use prost::Message;
+use snarkd_crypto::{
+    marlin::Proof,
+    serialization::{CanonicalDeserialize, CanonicalSerialize, Compress},
+};
 
-use super::{Certificate, Identifier, Program, Transition, VerifyingKey};
+use crate::{Digest32, Digest48};
+
+use super::{
+    Certificate, Identifier, Program, ProgramFingerprint, ProgramID, Transition, VerifyingKey,
+};
 
 type TransactionID = Digest32;
+/// Identity of a deployed edition of a program, see [`ProgramID::deployment_id`].
+pub type DeploymentID = Digest32;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Transaction {
@@ -10,6 +21,80 @@ pub enum Transaction {
     Execute(Box<ExecuteTransaction>),
 }
 
+impl Transaction {
+    pub fn id(&self) -> &TransactionID {
+        match self {
+            Transaction::Deploy(transaction) => &transaction.id,
+            Transaction::Execute(transaction) => &transaction.id,
+        }
+    }
+
+    /// Encodes the transaction as relayed between peers.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let message = match self {
+            Transaction::Deploy(transaction) => TransactionMessage {
+                id: transaction.id.to_vec(),
+                deployment: Some(DeploymentMessage::from(&transaction.deployment)),
+                edition: 0,
+                transitions: vec![],
+                fee: Some(TransitionMessage::from(&transaction.transition)),
+            },
+            Transaction::Execute(transaction) => TransactionMessage {
+                id: transaction.id.to_vec(),
+                deployment: None,
+                edition: transaction.execution.edition as u32,
+                transitions: transaction
+                    .execution
+                    .transitions
+                    .iter()
+                    .map(TransitionMessage::from)
+                    .collect(),
+                fee: transaction.transition.as_ref().map(TransitionMessage::from),
+            },
+        };
+        message.encode_to_vec()
+    }
+
+    /// Decodes a transaction written by [`Transaction::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let message = TransactionMessage::decode(bytes)?;
+        let id = Digest32::from(&message.id[..]);
+        let fee = message.fee.map(Transition::try_from).transpose()?;
+        Ok(match message.deployment {
+            Some(deployment) => {
+                let transition =
+                    fee.ok_or_else(|| anyhow!("deploy transaction {id} has no fee transition"))?;
+                Transaction::Deploy(Box::new(DeployTransaction {
+                    id,
+                    deployment: deployment.try_into()?,
+                    transition,
+                }))
+            }
+            None => Transaction::Execute(Box::new(ExecuteTransaction {
+                id,
+                execution: Execution {
+                    edition: message.edition.try_into()?,
+                    transitions: message
+                        .transitions
+                        .into_iter()
+                        .map(Transition::try_from)
+                        .collect::<Result<_>>()?,
+                },
+                transition: fee,
+            })),
+        })
+    }
+
+    /// Fee paid by the fee transition of this transaction, if any.
+    pub fn fee(&self) -> u64 {
+        let transition = match self {
+            Transaction::Deploy(transaction) => Some(&transaction.transition),
+            Transaction::Execute(transaction) => transaction.transition.as_ref(),
+        };
+        transition.map(|x| x.fee.max(0) as u64).unwrap_or_default()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DeployTransaction {
     pub id: TransactionID,
@@ -29,6 +114,7 @@ pub struct ExecuteTransaction {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Deployment {
+    pub program_id: ProgramID,
     pub edition: u16,
     pub program: Program,
     pub verifying_key_id: Identifier,
@@ -36,8 +122,157 @@ pub struct Deployment {
     pub certificate: Certificate,
 }
 
+impl Deployment {
+    /// Decodes the deployed program, which is only valid in its canonical encoding.
+    pub fn decode_program(&self) -> Result<snarkd_ir::Program> {
+        Ok(snarkd_ir::Program::from_canonical_bytes(&self.program)?)
+    }
+
+    /// Fingerprint of the deployed program.
+    pub fn fingerprint(&self) -> Result<ProgramFingerprint> {
+        let program = self.decode_program()?;
+        Ok(Digest32::from(&program.fingerprint()[..]))
+    }
+
+    /// Identity of this edition of the program. Fails if the program bytes are not canonical,
+    /// since another node may have received the same program encoded differently.
+    pub fn id(&self) -> Result<DeploymentID> {
+        Ok(self
+            .program_id
+            .deployment_id(self.edition, &self.fingerprint()?))
+    }
+
+    /// Checks that the deployed program is canonically encoded and passes
+    /// [`snarkd_ir::Program::validate`], returning the identity of the deployment.
+    pub fn verify(&self) -> Result<DeploymentID> {
+        let program = self.decode_program()?;
+        program.validate()?;
+        let fingerprint = Digest32::from(&program.fingerprint()[..]);
+        Ok(self.program_id.deployment_id(self.edition, &fingerprint))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Execution {
     pub edition: u16,
     pub transitions: Vec<Transition>,
 }
+
+#[derive(Clone, PartialEq, Message)]
+struct TransactionMessage {
+    #[prost(bytes = "vec", tag = "1")]
+    id: Vec<u8>,
+    /// Set for deployments only
+    #[prost(message, optional, tag = "2")]
+    deployment: Option<DeploymentMessage>,
+    #[prost(uint32, tag = "3")]
+    edition: u32,
+    #[prost(message, repeated, tag = "4")]
+    transitions: Vec<TransitionMessage>,
+    #[prost(message, optional, tag = "5")]
+    fee: Option<TransitionMessage>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct DeploymentMessage {
+    #[prost(string, tag = "1")]
+    program_id: String,
+    #[prost(uint32, tag = "2")]
+    edition: u32,
+    #[prost(bytes = "vec", tag = "3")]
+    program: Vec<u8>,
+    #[prost(string, tag = "4")]
+    verifying_key_id: String,
+    #[prost(bytes = "vec", tag = "5")]
+    verifying_key: Vec<u8>,
+    #[prost(bytes = "vec", tag = "6")]
+    certificate: Vec<u8>,
+}
+
+impl From<&Deployment> for DeploymentMessage {
+    fn from(deployment: &Deployment) -> Self {
+        Self {
+            program_id: deployment.program_id.to_string(),
+            edition: deployment.edition as u32,
+            program: deployment.program.clone(),
+            verifying_key_id: deployment.verifying_key_id.to_string(),
+            verifying_key: deployment.verifying_key.to_bytes(Compress::Yes),
+            certificate: deployment.certificate.to_bytes(Compress::Yes),
+        }
+    }
+}
+
+impl TryFrom<DeploymentMessage> for Deployment {
+    type Error = anyhow::Error;
+
+    fn try_from(message: DeploymentMessage) -> Result<Self> {
+        Ok(Self {
+            program_id: message.program_id.parse()?,
+            edition: message.edition.try_into()?,
+            program: message.program,
+            verifying_key_id: message.verifying_key_id.parse()?,
+            verifying_key: VerifyingKey::from_bytes(&message.verifying_key, Compress::Yes)?,
+            certificate: Certificate::from_bytes(&message.certificate, Compress::Yes)?,
+        })
+    }
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct TransitionMessage {
+    #[prost(bytes = "vec", tag = "1")]
+    id: Vec<u8>,
+    #[prost(string, tag = "2")]
+    program_id: String,
+    #[prost(string, tag = "3")]
+    function_name: String,
+    #[prost(bytes = "vec", tag = "4")]
+    inputs: Vec<u8>,
+    #[prost(bytes = "vec", tag = "5")]
+    outputs: Vec<u8>,
+    #[prost(bytes = "vec", optional, tag = "6")]
+    finalize: Option<Vec<u8>>,
+    #[prost(bytes = "vec", tag = "7")]
+    proof: Vec<u8>,
+    #[prost(bytes = "vec", tag = "8")]
+    tpk: Vec<u8>,
+    #[prost(bytes = "vec", tag = "9")]
+    tcm: Vec<u8>,
+    #[prost(int64, tag = "10")]
+    fee: i64,
+}
+
+impl From<&Transition> for TransitionMessage {
+    fn from(transition: &Transition) -> Self {
+        Self {
+            id: transition.id.to_vec(),
+            program_id: transition.program_id.to_string(),
+            function_name: transition.function_name.to_string(),
+            inputs: transition.inputs.clone(),
+            outputs: transition.outputs.clone(),
+            finalize: transition.finalize.clone(),
+            proof: transition.proof.to_bytes(Compress::Yes),
+            tpk: transition.tpk.to_vec(),
+            tcm: transition.tcm.to_vec(),
+            fee: transition.fee,
+        }
+    }
+}
+
+impl TryFrom<TransitionMessage> for Transition {
+    type Error = anyhow::Error;
+
+    fn try_from(message: TransitionMessage) -> Result<Self> {
+        Ok(Self {
+            id: Digest32::from(&message.id[..]),
+            program_id: message.program_id.parse()?,
+            function_name: message.function_name.parse()?,
+            inputs: message.inputs,
+            outputs: message.outputs,
+            finalize: message.finalize,
+            proof: Proof::from_bytes(&message.proof, Compress::Yes)?,
+            tpk: Digest48::from(&message.tpk[..]),
+            tcm: Digest32::from(&message.tcm[..]),
+            fee: message.fee,
+        })
+    }
+}
@@ -1,11 +1,12 @@
+use anyhow::Result;
+use snarkd_crypto::marlin::Proof;
+
 use crate::Digest32;
 
-use super::{Field, Group, Identifier, Input, Output, ProgramID};
+use super::{Field, FinalizeOperation, Group, Identifier, Input, Output, ProgramID};
 
 type TransitionID = Digest32;
 
-type Proof = Digest32;
-
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Transition {
     pub id: TransitionID,
@@ -13,9 +14,20 @@ pub struct Transition {
     pub function_name: Identifier,
     pub inputs: Input,
     pub outputs: Output,
+    /// Encoded `FinalizeOperation`s, see `FinalizeOperation::encode_all`
     pub finalize: Option<Vec<u8>>,
     pub proof: Proof,
     pub tpk: Group,
     pub tcm: Field,
     pub fee: i64,
 }
+
+impl Transition {
+    /// Mapping updates applied when this transition is finalized.
+    pub fn finalize_operations(&self) -> Result<Vec<FinalizeOperation>> {
+        match &self.finalize {
+            Some(finalize) => FinalizeOperation::decode_all(finalize),
+            None => Ok(vec![]),
+        }
+    }
+}
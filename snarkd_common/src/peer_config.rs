@@ -8,7 +8,7 @@ pub struct PeerConfig {
     pub peer_id: String,
     /// Not used at the moment as it's identical to the peer's port.
     pub client_port: u16,
-    /// Info Hash for finding peers
+    /// Info Hash for finding peers, defaults to the one of the configured network
     pub info_hash: String,
     /// List of trackers to find peers from. Leave empty to disable tracker based peer discovery
     pub trackers: Vec<url::Url>,
@@ -21,7 +21,7 @@ impl Default for PeerConfig {
         Self {
             peer_id: generate_peer_id(),
             client_port: 0,
-            info_hash: default_info_hash(),
+            info_hash: String::new(),
             trackers: default_trackers(),
             peers: vec![],
         }
@@ -34,10 +34,6 @@ fn generate_peer_id() -> String {
     format!("-MD0001-{bytes}")
 }
 
-fn default_info_hash() -> String {
-    "000000F214C636F3EBD358EC783C6E8A91BF81AE".to_string()
-}
-
 fn default_trackers() -> Vec<url::Url> {
     vec![
         "http://tracker.opentrackr.org:1337/announce
@@ -95,11 +91,13 @@ impl PeerConfig {
     }
 
     pub fn validate(&self) -> Result<(), PeerConfigError> {
-        // info_hash validation
-        if let Err(err) = validate_hash(&self.info_hash) {
-            return Err(PeerConfigError::InvalidInfoHash(format!(
-                "invalid peer info hash: {err}"
-            )));
+        // info_hash validation, it is left empty until the genesis block of the network is known
+        if !self.info_hash.is_empty() {
+            if let Err(err) = validate_hash(&self.info_hash) {
+                return Err(PeerConfigError::InvalidInfoHash(format!(
+                    "invalid peer info hash: {err}"
+                )));
+            }
         }
 
         // peer id validation
@@ -0,0 +1,49 @@
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use snarkd_crypto::keys::PrivateKey;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct ProducerConfig {
+    /// If true, the node proves the coinbase puzzle and produces blocks. Defaults to false.
+    pub enabled: bool,
+    /// Private key that signs produced blocks, as an `APrivateKey1…` string or hex encoded `sk_sig || r_sig` in little endian. Required when enabled.
+    pub private_key: Option<String>,
+    /// Number of threads proving the coinbase puzzle, 0 to only accumulate solutions from peers. Defaults to the number of cores.
+    pub prover_threads: usize,
+}
+
+impl Default for ProducerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            private_key: None,
+            prover_threads: std::thread::available_parallelism()
+                .map(|x| x.get())
+                .unwrap_or(1),
+        }
+    }
+}
+
+impl ProducerConfig {
+    /// Decodes the configured private key.
+    pub fn private_key(&self) -> Result<PrivateKey> {
+        let encoded = self
+            .private_key
+            .as_ref()
+            .ok_or_else(|| anyhow!("missing producer private key"))?;
+        let encoded = encoded.trim();
+        if encoded.starts_with("APrivateKey1") {
+            return encoded.parse();
+        }
+        let bytes = hex::decode(encoded).context("private key is not hex digits")?;
+        PrivateKey::from_bytes_le(&bytes)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.enabled {
+            self.private_key()?;
+        }
+        Ok(())
+    }
+}
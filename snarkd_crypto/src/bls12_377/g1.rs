@@ -21,8 +21,9 @@ impl Parameters for G1Parameters {
 
     /// COFACTOR_INV = COFACTOR^{-1} mod r
     ///              = 5285428838741532253824584287042945485047145357130994810877
-    const COFACTOR_INV: Scalar =
-        Scalar(uint!(5285428838741532253824584287042945485047145357130994810877_U256));
+    const COFACTOR_INV: Scalar = Scalar(uint!(
+        5285428838741532253824584287042945485047145357130994810877_U256
+    ));
 
     /// AFFINE_GENERATOR_COEFFS = (G1_GENERATOR_X, G1_GENERATOR_Y)
     const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
@@ -0,0 +1,67 @@
+mod sponge;
+pub use sponge::*;
+
+use std::sync::Arc;
+
+use crate::{
+    bls12_377::Fp,
+    circuit::{traits::Inject, types::Field},
+    utils::PoseidonParameters,
+};
+
+/// The Poseidon hash function over the field of the circuit, which evaluates to the same outputs
+/// as `utils::Poseidon`.
+#[derive(Clone)]
+pub struct Poseidon {
+    parameters: Arc<PoseidonParameters>,
+}
+
+impl Poseidon {
+    /// Initializes a new instance of the hash function with the default parameters.
+    pub fn setup() -> Self {
+        Self {
+            parameters: Arc::new(PoseidonParameters::default()),
+        }
+    }
+
+    /// Evaluates the hash function over a list of field elements.
+    pub fn evaluate(&self, input: &[Field]) -> Field {
+        self.evaluate_many(input, 1).swap_remove(0)
+    }
+
+    /// Evaluates the hash function over a list of field elements, and returns the given number of
+    /// field elements.
+    pub fn evaluate_many(&self, input: &[Field], num_outputs: usize) -> Vec<Field> {
+        let mut sponge = PoseidonSponge::new(self.parameters.clone());
+        sponge.absorb_native_field_elements(input);
+        sponge.squeeze_native_field_elements(num_outputs)
+    }
+
+    /// Evaluates the hash function over a list of field elements prefixed by its length.
+    pub fn evaluate_with_len(&self, input: &[Field]) -> Field {
+        let mut preimage = vec![Field::constant(Fp::from(input.len() as u64))];
+        preimage.extend_from_slice(input);
+        self.evaluate(&preimage)
+    }
+
+    pub fn parameters(&self) -> &Arc<PoseidonParameters> {
+        &self.parameters
+    }
+}
+
+impl From<&crate::utils::Poseidon> for Poseidon {
+    fn from(poseidon: &crate::utils::Poseidon) -> Self {
+        Self {
+            parameters: poseidon.parameters().clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = ""]
+mod test {
+    use super::*;
+
+    mod poseidon_tests;
+    mod sponge_tests;
+}
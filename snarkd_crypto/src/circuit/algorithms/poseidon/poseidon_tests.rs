@@ -0,0 +1,106 @@
+use crate::{
+    assert_scope,
+    bls12_377::Fp,
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        rng_test_struct::{TestRng, Uniform},
+        traits::Eject,
+        Environment,
+    },
+    utils,
+};
+
+use super::*;
+
+const ITERATIONS: usize = 10;
+
+fn sample_inputs(mode: Mode, num_inputs: usize, rng: &mut TestRng) -> (Vec<Fp>, Vec<Field>) {
+    let native = (0..num_inputs)
+        .map(|_| Uniform::rand(rng))
+        .collect::<Vec<Fp>>();
+    let circuit = native
+        .iter()
+        .map(|input| Field::new(mode, *input))
+        .collect();
+    (native, circuit)
+}
+
+#[test]
+fn test_evaluate() {
+    let mut rng = TestRng::default();
+    let native = utils::Poseidon::<2>::setup();
+    let poseidon = Poseidon::setup();
+
+    for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+        for num_inputs in 0..ITERATIONS {
+            let (expected_input, input) = sample_inputs(mode, num_inputs, &mut rng);
+            let expected = native.evaluate(&expected_input);
+            Circuit::scope(format!("Poseidon {mode} {num_inputs}"), || {
+                let candidate = poseidon.evaluate(&input);
+                assert_eq!(expected, candidate.eject_value());
+                if mode.is_constant() {
+                    assert!(candidate.is_constant());
+                }
+            });
+            Circuit::reset();
+        }
+    }
+}
+
+#[test]
+fn test_evaluate_many() {
+    let mut rng = TestRng::default();
+    let native = utils::Poseidon::<2>::setup();
+    let poseidon = Poseidon::setup();
+
+    for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+        for num_inputs in 0..ITERATIONS {
+            for num_outputs in 0..ITERATIONS {
+                let (expected_input, input) = sample_inputs(mode, num_inputs, &mut rng);
+                let expected = native.evaluate_many(&expected_input, num_outputs);
+                let candidate = poseidon.evaluate_many(&input, num_outputs);
+                assert_eq!(expected, candidate.eject_value());
+                Circuit::reset();
+            }
+        }
+    }
+}
+
+#[test]
+fn test_evaluate_with_len() {
+    let mut rng = TestRng::default();
+    let native = utils::Poseidon::<2>::setup();
+    let poseidon = Poseidon::from(&native);
+
+    for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+        for num_inputs in 0..ITERATIONS {
+            let (expected_input, input) = sample_inputs(mode, num_inputs, &mut rng);
+            let expected = native.evaluate_with_len(&expected_input);
+            let candidate = poseidon.evaluate_with_len(&input);
+            assert_eq!(expected, candidate.eject_value());
+            Circuit::reset();
+        }
+    }
+}
+
+#[test]
+fn test_evaluate_count() {
+    let mut rng = TestRng::default();
+    let poseidon = Poseidon::setup();
+
+    // Every S-box costs 5 multiplications, and the first round skips those of constant elements.
+    for (mode, num_inputs, count) in [
+        (Mode::Constant, 4, (0, 0, 0, 0)),
+        (Mode::Public, 1, (0, 0, 265, 265)),
+        (Mode::Private, 2, (0, 0, 270, 270)),
+        (Mode::Private, 4, (0, 0, 545, 545)),
+    ] {
+        let (_, input) = sample_inputs(mode, num_inputs, &mut rng);
+        Circuit::scope(format!("Poseidon {mode} {num_inputs}"), || {
+            poseidon.evaluate(&input);
+            assert_scope!(count.0, count.1, count.2, count.3);
+        });
+        Circuit::reset();
+    }
+}
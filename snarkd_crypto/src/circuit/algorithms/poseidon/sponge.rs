@@ -0,0 +1,261 @@
+use std::sync::Arc;
+
+use crate::{
+    bls12_377::{fp, scalar},
+    circuit::{
+        circuit::Circuit,
+        helpers::LinearCombination,
+        traits::ToBits,
+        types::{Boolean, Field, Scalar},
+        Environment,
+    },
+    utils::{DuplexSpongeMode, PoseidonParameters, CAPACITY, RATE},
+};
+
+/// A duplex sponge over the field of the circuit using the Poseidon permutation, which absorbs
+/// and squeezes the same elements as `utils::PoseidonSponge`.
+#[derive(Clone)]
+pub struct PoseidonSponge {
+    /// Sponge Parameters
+    parameters: Arc<PoseidonParameters>,
+    /// The capacity elements of the state, followed by the rate elements.
+    state: Vec<Field>,
+    /// Current mode (whether its absorbing or squeezing)
+    mode: DuplexSpongeMode,
+}
+
+impl Default for PoseidonSponge {
+    fn default() -> Self {
+        Self::new(Arc::new(PoseidonParameters::default()))
+    }
+}
+
+impl PoseidonSponge {
+    pub fn new(parameters: Arc<PoseidonParameters>) -> Self {
+        Self {
+            parameters,
+            state: vec![Field::zero(); CAPACITY + RATE],
+            mode: DuplexSpongeMode::Absorbing {
+                next_absorb_index: 0,
+            },
+        }
+    }
+
+    /// Takes in field elements.
+    pub fn absorb_native_field_elements(&mut self, elements: &[Field]) {
+        if !elements.is_empty() {
+            match self.mode {
+                DuplexSpongeMode::Absorbing {
+                    mut next_absorb_index,
+                } => {
+                    if next_absorb_index == RATE {
+                        self.permute();
+                        next_absorb_index = 0;
+                    }
+                    self.absorb_internal(next_absorb_index, elements);
+                }
+                DuplexSpongeMode::Squeezing { .. } => {
+                    self.permute();
+                    self.absorb_internal(0, elements);
+                }
+            }
+        }
+    }
+
+    pub fn squeeze_native_field_elements(&mut self, num_elements: usize) -> Vec<Field> {
+        if num_elements == 0 {
+            return Vec::new();
+        }
+        let mut output = vec![Field::zero(); num_elements];
+
+        match self.mode {
+            DuplexSpongeMode::Absorbing { .. } => {
+                self.permute();
+                self.squeeze_internal(0, &mut output);
+            }
+            DuplexSpongeMode::Squeezing {
+                mut next_squeeze_index,
+            } => {
+                if next_squeeze_index == RATE {
+                    self.permute();
+                    next_squeeze_index = 0;
+                }
+                self.squeeze_internal(next_squeeze_index, &mut output);
+            }
+        }
+
+        output
+    }
+
+    pub fn squeeze_nonnative_field_elements(&mut self, num: usize) -> Vec<Scalar> {
+        self.get_fe(num, false)
+    }
+
+    /// Takes out scalars of 168 bits.
+    pub fn squeeze_short_nonnative_field_elements(&mut self, num: usize) -> Vec<Scalar> {
+        self.get_fe(num, true)
+    }
+
+    /// Takes out a scalar of 168 bits.
+    pub fn squeeze_short_nonnative_field_element(&mut self) -> Scalar {
+        self.squeeze_short_nonnative_field_elements(1)
+            .swap_remove(0)
+    }
+}
+
+impl PoseidonSponge {
+    fn apply_ark(&mut self, round_number: usize) {
+        for (state_elem, ark_elem) in self
+            .state
+            .iter_mut()
+            .zip(&self.parameters.ark[round_number])
+        {
+            *state_elem += Field::from(Circuit::one() * *ark_elem);
+        }
+    }
+
+    fn apply_s_box(&mut self, is_full_round: bool) {
+        let alpha = self.parameters.alpha;
+        if is_full_round {
+            // Full rounds apply the S Box (x^alpha) to every element of state
+            for elem in self.state.iter_mut() {
+                *elem = pow(elem, alpha);
+            }
+        } else {
+            // Partial rounds apply the S Box (x^alpha) to just the first element of state
+            self.state[0] = pow(&self.state[0], alpha);
+        }
+    }
+
+    fn apply_mds(&mut self) {
+        self.state = self
+            .parameters
+            .mds
+            .iter()
+            .map(|mds_row| {
+                self.state
+                    .iter()
+                    .zip(mds_row)
+                    .fold(Field::zero(), |sum, (elem, coefficient)| {
+                        sum + Field::from(LinearCombination::from(elem) * *coefficient)
+                    })
+            })
+            .collect();
+    }
+
+    fn permute(&mut self) {
+        // Determine the partial rounds range bound.
+        let partial_rounds = self.parameters.partial_rounds;
+        let full_rounds = self.parameters.full_rounds;
+        let full_rounds_over_2 = full_rounds / 2;
+        let partial_round_range = full_rounds_over_2..(full_rounds_over_2 + partial_rounds);
+
+        // Iterate through all rounds to permute.
+        for i in 0..(partial_rounds + full_rounds) {
+            let is_full_round = !partial_round_range.contains(&i);
+            self.apply_ark(i);
+            self.apply_s_box(is_full_round);
+            self.apply_mds();
+        }
+    }
+
+    /// Absorbs everything in elements, this does not end in an absorption.
+    fn absorb_internal(&mut self, mut rate_start: usize, input: &[Field]) {
+        let first_chunk_size = std::cmp::min(RATE - rate_start, input.len());
+        let (first_chunk, rest_chunk) = input.split_at(first_chunk_size);
+        let num_chunks = 1 + rest_chunk.len().div_ceil(RATE);
+
+        // Absorb the input elements, `RATE` elements at a time, except for the first chunk, which
+        // is of size `RATE - rate_start`.
+        for (i, chunk) in std::iter::once(first_chunk)
+            .chain(rest_chunk.chunks(RATE))
+            .enumerate()
+        {
+            for (element, state_elem) in chunk.iter().zip(&mut self.state[CAPACITY + rate_start..])
+            {
+                *state_elem += element;
+            }
+            // Are we in the last chunk?
+            // If so, let's wrap up.
+            if i == num_chunks - 1 {
+                self.mode = DuplexSpongeMode::Absorbing {
+                    next_absorb_index: rate_start + chunk.len(),
+                };
+                return;
+            }
+            self.permute();
+            rate_start = 0;
+        }
+    }
+
+    /// Squeeze |output| many elements. This does not end in a squeeze
+    fn squeeze_internal(&mut self, mut rate_start: usize, output: &mut [Field]) {
+        let first_chunk_size = std::cmp::min(RATE - rate_start, output.len());
+        let (first_chunk, rest_chunk) = output.split_at_mut(first_chunk_size);
+        let num_chunks = 1 + rest_chunk.len().div_ceil(RATE);
+
+        for (i, chunk) in std::iter::once(first_chunk)
+            .chain(rest_chunk.chunks_mut(RATE))
+            .enumerate()
+        {
+            let start = CAPACITY + rate_start;
+            chunk.clone_from_slice(&self.state[start..start + chunk.len()]);
+            // Are we in the last chunk?
+            // If so, let's wrap up.
+            if i == num_chunks - 1 {
+                self.mode = DuplexSpongeMode::Squeezing {
+                    next_squeeze_index: rate_start + chunk.len(),
+                };
+                return;
+            }
+            self.permute();
+            rate_start = 0;
+        }
+    }
+
+    /// Obtains bits from the hashchain, most significant first, as `utils::PoseidonSponge`
+    /// does: every squeezed field element gives all of its bits but the highest.
+    fn get_bits(&mut self, num_bits: usize) -> Vec<Boolean> {
+        let bits_per_element = (fp::MODULUS_BITS - 1) as usize;
+        let num_elements = num_bits.div_ceil(bits_per_element);
+
+        let mut bits = self
+            .squeeze_native_field_elements(num_elements)
+            .iter()
+            .flat_map(|elem| {
+                let mut bits_le = elem.to_bits_le();
+                bits_le.truncate(bits_per_element);
+                bits_le.into_iter().rev()
+            })
+            .collect::<Vec<_>>();
+        bits.truncate(num_bits);
+        bits
+    }
+
+    /// Obtains scalars from the hashchain, whose bits are too few to reach the modulus.
+    fn get_fe(&mut self, num_elements: usize, outputs_short_elements: bool) -> Vec<Scalar> {
+        let num_bits_per_nonnative = if outputs_short_elements {
+            168
+        } else {
+            (scalar::MODULUS_BITS - 1) as usize // also omit the highest bit
+        };
+
+        self.get_bits(num_bits_per_nonnative * num_elements)
+            .chunks_exact(num_bits_per_nonnative)
+            .map(|bits_be| Scalar::from_short_bits_le(bits_be.iter().rev().cloned().collect()))
+            .collect()
+    }
+}
+
+/// Returns `base` raised to the power of the constant `exponent`, by squaring and multiplying from
+/// the most significant bit.
+fn pow(base: &Field, exponent: u64) -> Field {
+    let mut output = Field::one();
+    for i in (0..u64::BITS - exponent.leading_zeros()).rev() {
+        output = &output * &output;
+        if exponent >> i & 1 == 1 {
+            output *= base;
+        }
+    }
+    output
+}
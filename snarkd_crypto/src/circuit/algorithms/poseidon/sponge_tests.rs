@@ -0,0 +1,110 @@
+use crate::{
+    assert_scope,
+    bls12_377::Fp,
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        rng_test_struct::{TestRng, Uniform},
+        traits::Eject,
+        Environment,
+    },
+    utils,
+};
+
+use super::*;
+
+const ITERATIONS: usize = 10;
+
+/// Absorbs and squeezes random runs of elements, checking the circuit sponge against the native
+/// one after every squeeze.
+fn check_duplex(mode: Mode, rng: &mut TestRng) {
+    let mut native = utils::PoseidonSponge::default();
+    let mut sponge = PoseidonSponge::default();
+
+    for i in 0..ITERATIONS {
+        let input = (0..i).map(|_| Uniform::rand(rng)).collect::<Vec<Fp>>();
+        native.absorb_native_field_elements(&input);
+        sponge.absorb_native_field_elements(
+            &input
+                .iter()
+                .map(|input| Field::new(mode, *input))
+                .collect::<Vec<_>>(),
+        );
+
+        let num_outputs = ITERATIONS - i;
+        let expected = native.squeeze_native_field_elements(num_outputs);
+        let candidate = sponge.squeeze_native_field_elements(num_outputs);
+        assert_eq!(expected.to_vec(), candidate.eject_value());
+    }
+}
+
+#[test]
+fn test_duplex() {
+    let mut rng = TestRng::default();
+
+    for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+        check_duplex(mode, &mut rng);
+        Circuit::reset();
+    }
+}
+
+#[test]
+fn test_squeeze_nonnative() {
+    let mut rng = TestRng::default();
+
+    for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+        for num_inputs in 0..ITERATIONS {
+            let input = (0..num_inputs)
+                .map(|_| Uniform::rand(&mut rng))
+                .collect::<Vec<Fp>>();
+            let input_circuit = input
+                .iter()
+                .map(|input| Field::new(mode, *input))
+                .collect::<Vec<_>>();
+
+            let mut native = utils::PoseidonSponge::default();
+            native.absorb_native_field_elements(&input);
+            let mut sponge = PoseidonSponge::default();
+            sponge.absorb_native_field_elements(&input_circuit);
+            assert_eq!(
+                native.squeeze_nonnative_field_elements(3).to_vec(),
+                sponge.squeeze_nonnative_field_elements(3).eject_value()
+            );
+            assert_eq!(
+                native.squeeze_short_nonnative_field_elements(2).to_vec(),
+                sponge
+                    .squeeze_short_nonnative_field_elements(2)
+                    .eject_value()
+            );
+            assert_eq!(
+                native.squeeze_short_nonnative_field_element(),
+                sponge.squeeze_short_nonnative_field_element().eject_value()
+            );
+            Circuit::reset();
+        }
+    }
+}
+
+#[test]
+fn test_squeeze_short_nonnative_count() {
+    let mut rng = TestRng::default();
+
+    for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+        let input =
+            [Uniform::rand(&mut rng), Uniform::rand(&mut rng)].map(|input| Field::new(mode, input));
+        Circuit::scope(format!("Squeeze {mode}"), || {
+            let mut sponge = PoseidonSponge::default();
+            sponge.absorb_native_field_elements(&input);
+            let candidate = sponge.squeeze_short_nonnative_field_element();
+            // The permutation is followed by a strict decomposition of the squeezed element.
+            match mode {
+                Mode::Constant => {
+                    assert!(candidate.is_constant());
+                    assert_scope!(0, 0, 0, 0);
+                }
+                _ => assert_scope!(0, 0, 270 + 1021, 270 + 1023),
+            }
+        });
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,163 @@
+/// Binary operator for adding two values, enforcing that the sum does not overflow.
+pub trait AddChecked<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns the sum of `self` and `other`.
+    fn add_checked(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for adding two values, wrapping around on overflow.
+pub trait AddWrapped<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns the sum of `self` and `other`, modulo the bit width.
+    fn add_wrapped(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for subtracting two values, enforcing that the difference does not overflow.
+pub trait SubChecked<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns the difference of `self` and `other`.
+    fn sub_checked(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for subtracting two values, wrapping around on overflow.
+pub trait SubWrapped<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns the difference of `self` and `other`, modulo the bit width.
+    fn sub_wrapped(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for multiplying two values, enforcing that the product does not overflow.
+pub trait MulChecked<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns the product of `self` and `other`.
+    fn mul_checked(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for multiplying two values, wrapping around on overflow.
+pub trait MulWrapped<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns the product of `self` and `other`, modulo the bit width.
+    fn mul_wrapped(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for dividing two values, enforcing that the divisor is nonzero and the
+/// quotient does not overflow.
+pub trait DivChecked<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns the quotient of `self` and `other`, rounded towards zero.
+    fn div_checked(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for dividing two values, enforcing that the divisor is nonzero and wrapping
+/// around on overflow.
+pub trait DivWrapped<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns the quotient of `self` and `other`, rounded towards zero, modulo the bit width.
+    fn div_wrapped(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for the remainder of two values, enforcing that the divisor is nonzero and the
+/// matching quotient does not overflow.
+pub trait RemChecked<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns the remainder of `self` divided by `other`, with the sign of `self`.
+    fn rem_checked(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for the remainder of two values, enforcing that the divisor is nonzero.
+pub trait RemWrapped<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns the remainder of `self` divided by `other`, with the sign of `self`, or zero when
+    /// the matching quotient overflows.
+    fn rem_wrapped(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for exponentiation, enforcing that the power does not overflow.
+pub trait PowChecked<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `self` raised to the power of `other`.
+    fn pow_checked(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for exponentiation, wrapping around on overflow.
+pub trait PowWrapped<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `self` raised to the power of `other`, modulo the bit width.
+    fn pow_wrapped(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for exponentiation in a field, which cannot overflow.
+pub trait Pow<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `self` raised to the power of `other`.
+    fn pow(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for shifting left, enforcing that the shift is less than the bit width.
+pub trait ShlChecked<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `self` shifted left by `other` bits.
+    fn shl_checked(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for shifting left by the shift modulo the bit width.
+pub trait ShlWrapped<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `self` shifted left by `other` bits, modulo the bit width.
+    fn shl_wrapped(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for shifting right, enforcing that the shift is less than the bit width.
+pub trait ShrChecked<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `self` shifted right by `other` bits.
+    fn shr_checked(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for shifting right by the shift modulo the bit width.
+pub trait ShrWrapped<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `self` shifted right by `other` bits, modulo the bit width.
+    fn shr_wrapped(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Unary operator for the multiplicative inverse, enforcing that the value is nonzero.
+pub trait Inverse {
+    type Output;
+
+    /// Returns the multiplicative inverse of `self`.
+    fn inverse(&self) -> Self::Output;
+}
+
+/// Unary operator for squaring.
+pub trait Square {
+    type Output;
+
+    /// Returns the square of `self`.
+    fn square(&self) -> Self::Output;
+}
+
+/// Unary operator for a square root, enforcing that one exists.
+pub trait SquareRoot {
+    type Output;
+
+    /// Returns a square root of `self`.
+    fn square_root(&self) -> Self::Output;
+}
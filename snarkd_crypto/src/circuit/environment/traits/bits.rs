@@ -0,0 +1,35 @@
+/// Unary operator for converting to bits.
+pub trait ToBits {
+    type Boolean;
+
+    /// Returns the little-endian bits of the circuit.
+    fn to_bits_le(&self) -> Vec<Self::Boolean>;
+
+    /// Returns the big-endian bits of the circuit.
+    fn to_bits_be(&self) -> Vec<Self::Boolean> {
+        let mut bits = self.to_bits_le();
+        bits.reverse();
+        bits
+    }
+}
+
+/// Operator for initializing from bits.
+pub trait FromBits {
+    type Boolean;
+
+    /// Returns the circuit of the given little-endian bits.
+    fn from_bits_le(bits_le: &[Self::Boolean]) -> Self
+    where
+        Self: Sized;
+
+    /// Returns the circuit of the given big-endian bits.
+    fn from_bits_be(bits_be: &[Self::Boolean]) -> Self
+    where
+        Self: Sized,
+        Self::Boolean: Clone,
+    {
+        let mut bits_le = bits_be.to_vec();
+        bits_le.reverse();
+        Self::from_bits_le(&bits_le)
+    }
+}
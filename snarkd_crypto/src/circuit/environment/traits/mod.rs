@@ -1,3 +1,7 @@
+mod arithmetic;
+pub use arithmetic::*;
+mod bits;
+pub use bits::*;
 mod bitwise;
 pub use bitwise::*;
 mod eject;
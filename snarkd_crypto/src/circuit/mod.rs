@@ -1,3 +1,4 @@
+pub mod algorithms;
 mod environment;
 pub use environment::*;
 pub mod types;
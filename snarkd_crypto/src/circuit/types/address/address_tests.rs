@@ -0,0 +1,87 @@
+use crate::{
+    assert_count, assert_scope,
+    bls12_377::{Affine, G1Affine},
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        traits::{Eject, Equal, Inject, Ternary, ToBits},
+        types::{Boolean, Group},
+        Environment,
+    },
+    keys,
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 10;
+
+#[test]
+fn test_new() {
+    for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+        for _ in 0..ITERATIONS {
+            let expected = keys::Address::new(G1Affine::rand());
+            Circuit::scope(format!("New: {mode}"), || {
+                let candidate = Address::new(mode, expected);
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(mode, candidate.eject_mode());
+                assert_eq!(expected.0, candidate.to_group().eject_value());
+                match mode {
+                    Mode::Constant => assert_scope!(3, 0, 0, 0),
+                    Mode::Public => assert_scope!(0, 3, 1301, 1462),
+                    Mode::Private => assert_scope!(0, 0, 1304, 1462),
+                }
+            });
+            Circuit::reset();
+        }
+    }
+}
+
+#[test]
+fn test_from_group() {
+    let expected = G1Affine::rand();
+    let candidate = Address::from_group(Group::new(Mode::Private, expected));
+    assert_eq!(keys::Address::new(expected), candidate.eject_value());
+    assert_eq!(
+        candidate.to_group().to_bits_le().eject_value(),
+        candidate.to_bits_le().eject_value()
+    );
+    Circuit::reset();
+}
+
+#[test]
+fn test_equal() {
+    let modes = [Mode::Constant, Mode::Public, Mode::Private];
+
+    for mode_a in modes {
+        for mode_b in modes {
+            let first = keys::Address::new(G1Affine::rand());
+            for second in [first, keys::Address::new(G1Affine::rand())] {
+                let a = Address::new(mode_a, first);
+                let b = Address::new(mode_b, second);
+                Circuit::scope(format!("Equal: {mode_a} {mode_b}"), || {
+                    let candidate = a.is_equal(&b);
+                    assert_eq!(first == second, candidate.eject_value());
+                    assert_count!(Equal(Address, Address) => Boolean, &(mode_a, mode_b));
+                });
+                Circuit::reset();
+            }
+        }
+    }
+}
+
+#[test]
+fn test_ternary() {
+    let (first, second) = (
+        keys::Address::new(G1Affine::rand()),
+        keys::Address::new(G1Affine::ZERO),
+    );
+    for flag in [true, false] {
+        let condition = Boolean::new(Mode::Private, flag);
+        let a = Address::new(Mode::Public, first);
+        let b = Address::new(Mode::Private, second);
+        let candidate = Address::ternary(&condition, &a, &b);
+        assert_eq!(if flag { first } else { second }, candidate.eject_value());
+    }
+    assert!(Circuit::is_satisfied());
+    Circuit::reset();
+}
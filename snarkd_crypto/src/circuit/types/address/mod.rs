@@ -0,0 +1,114 @@
+use std::fmt;
+
+use crate::{
+    circuit::{
+        helpers::Mode,
+        traits::{Eject, Equal, Inject, Ternary, ToBits},
+    },
+    keys,
+};
+
+#[cfg(test)]
+use crate::circuit::{helpers::Count, traits::Metrics};
+
+use super::{Boolean, Group};
+
+/// An account address, which is a group element.
+#[derive(Clone)]
+pub struct Address(Group);
+
+impl Address {
+    /// Initializes an address from a group element.
+    pub fn from_group(group: Group) -> Self {
+        Self(group)
+    }
+
+    /// Returns the group element of the address.
+    pub fn to_group(&self) -> &Group {
+        &self.0
+    }
+}
+
+impl Inject for Address {
+    type Primitive = keys::Address;
+
+    /// Initializes an address circuit from a console address.
+    fn new(mode: Mode, address: Self::Primitive) -> Self {
+        Self(Group::new(mode, address.0))
+    }
+
+    /// Initializes a constant address circuit from a console address.
+    fn constant(address: Self::Primitive) -> Self {
+        Self(Group::constant(address.0))
+    }
+}
+
+impl Eject for Address {
+    type Primitive = keys::Address;
+
+    /// Ejects the mode of the address circuit.
+    fn eject_mode(&self) -> Mode {
+        self.0.eject_mode()
+    }
+
+    /// Ejects the address circuit as a console address.
+    fn eject_value(&self) -> Self::Primitive {
+        keys::Address::new(self.0.eject_value())
+    }
+}
+
+impl Equal<Self> for Address {
+    type Output = Boolean;
+
+    /// Returns `true` if `self` and `other` are equal.
+    fn is_equal(&self, other: &Self) -> Self::Output {
+        self.0.is_equal(&other.0)
+    }
+
+    /// Returns `true` if `self` and `other` are *not* equal.
+    fn is_not_equal(&self, other: &Self) -> Self::Output {
+        self.0.is_not_equal(&other.0)
+    }
+}
+
+impl Ternary for Address {
+    type Boolean = Boolean;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        Self(Group::ternary(condition, &first.0, &second.0))
+    }
+}
+
+impl ToBits for Address {
+    type Boolean = Boolean;
+
+    /// Returns the little-endian bits of the group element of the address.
+    fn to_bits_le(&self) -> Vec<Self::Boolean> {
+        self.0.to_bits_le()
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.eject_value(), self.eject_mode())
+    }
+}
+
+#[cfg(test)]
+impl Metrics<dyn Equal<Address, Output = Boolean>> for Address {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        <Group as Metrics<dyn Equal<Group, Output = Boolean>>>::count(case)
+    }
+}
+
+#[cfg(test)]
+#[path = ""]
+mod test {
+    use super::*;
+
+    mod address_tests;
+}
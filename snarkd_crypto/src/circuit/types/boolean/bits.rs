@@ -0,0 +1,41 @@
+use ruint::Uint;
+
+use crate::{
+    bls12_377::Fp,
+    circuit::{circuit::Circuit, helpers::LinearCombination, traits::Inject, Environment},
+};
+
+use super::Boolean;
+
+impl Boolean {
+    /// Returns the value of the given little-endian bits.
+    pub(crate) fn le_bits_to_lc(bits_le: &[Boolean]) -> LinearCombination {
+        bits_le
+            .iter()
+            .enumerate()
+            .fold(Circuit::zero(), |lc, (i, bit)| {
+                lc + &**bit * Fp(Uint::from(1u64) << i)
+            })
+    }
+
+    /// Ensures that the value of the given little-endian bits is less than the constant with the
+    /// given little-endian bits, which must be as many.
+    pub(crate) fn assert_less_than_constant(bits_le: &[Boolean], constant_le: &[bool]) {
+        debug_assert_eq!(bits_le.len(), constant_le.len());
+
+        // Scan from the most significant bit, tracking whether the bits so far are equal to those
+        // of the constant, and whether they are already less.
+        let mut is_less = Boolean::constant(false);
+        let mut is_equal = Boolean::constant(true);
+        for (bit, constant) in bits_le.iter().zip(constant_le).rev() {
+            match constant {
+                true => {
+                    is_less = &is_less | &(&is_equal & &!bit);
+                    is_equal = &is_equal & bit;
+                }
+                false => is_equal = &is_equal & &!bit,
+            }
+        }
+        Circuit::assert(is_less);
+    }
+}
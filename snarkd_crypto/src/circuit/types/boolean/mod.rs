@@ -1,4 +1,5 @@
 mod and;
+mod bits;
 mod equal;
 mod nand;
 mod nor;
@@ -0,0 +1,60 @@
+use crate::{
+    bls12_377::{Field as FieldTrait, Scalar as NativeScalar},
+    circuit::{circuit::Circuit, Environment},
+    keys::PrivateKey,
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 3;
+
+#[test]
+fn test_new() {
+    for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+        for _ in 0..ITERATIONS {
+            let expected = keys::ComputeKey::from(&PrivateKey::rand());
+            let candidate = ComputeKey::new(mode, expected);
+            assert_eq!(expected, candidate.eject_value());
+            assert_eq!(
+                expected.public_key_signature,
+                candidate.public_key_signature().eject_value()
+            );
+            assert_eq!(
+                expected.public_randomness_signature,
+                candidate.public_randomness_signature().eject_value()
+            );
+            assert_eq!(
+                expected.prf_secret_key,
+                candidate.prf_secret_key().eject_value()
+            );
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
+
+#[test]
+fn test_new_derives_prf_secret_key() {
+    let expected = keys::ComputeKey::from(&PrivateKey::rand());
+    let mut given = expected;
+    given.prf_secret_key += NativeScalar::ONE;
+
+    // The given PRF secret key is ignored in favor of the one derived from the public keys.
+    let candidate = ComputeKey::new(Mode::Private, given);
+    assert_eq!(expected, candidate.eject_value());
+    assert!(Circuit::is_satisfied());
+    Circuit::reset();
+}
+
+#[test]
+fn test_to_address() {
+    for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+        for _ in 0..ITERATIONS {
+            let compute_key = keys::ComputeKey::from(&PrivateKey::rand());
+            let candidate = ComputeKey::new(mode, compute_key).to_address();
+            assert_eq!(compute_key.to_address(), candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{
+    bls12_377::{Affine, G1Affine},
+    circuit::{
+        algorithms::PoseidonSponge,
+        helpers::Mode,
+        traits::{Eject, Inject},
+    },
+    keys,
+};
+
+use super::{Address, Group, Scalar};
+
+/// The compute key of an account. Its PRF secret key is always derived in the circuit from the
+/// signature public keys, so it cannot be chosen by whoever injects the compute key.
+#[derive(Clone)]
+pub struct ComputeKey {
+    public_key_signature: Group,
+    public_randomness_signature: Group,
+    prf_secret_key: Scalar,
+}
+
+impl ComputeKey {
+    /// Initializes a compute key from its signature public keys, deriving the PRF secret key from
+    /// them as `keys::ComputeKey::new` does.
+    pub fn from_public_keys(
+        public_key_signature: Group,
+        public_randomness_signature: Group,
+    ) -> Self {
+        let mut sponge = PoseidonSponge::default();
+        sponge.absorb_native_field_elements(&[
+            public_key_signature.to_xy_coordinates().0,
+            public_randomness_signature.to_xy_coordinates().0,
+        ]);
+        let prf_secret_key = sponge.squeeze_short_nonnative_field_element();
+        Self {
+            public_key_signature,
+            public_randomness_signature,
+            prf_secret_key,
+        }
+    }
+
+    /// Returns the signature public key `pk_sig`.
+    pub fn public_key_signature(&self) -> &Group {
+        &self.public_key_signature
+    }
+
+    /// Returns the signature public randomness `pr_sig`.
+    pub fn public_randomness_signature(&self) -> &Group {
+        &self.public_randomness_signature
+    }
+
+    /// Returns the PRF secret key `sk_prf`.
+    pub fn prf_secret_key(&self) -> &Scalar {
+        &self.prf_secret_key
+    }
+
+    /// Returns the address corresponding to the compute key, `pk_sig + pr_sig + G * sk_prf`.
+    pub fn to_address(&self) -> Address {
+        let generator = Group::constant(G1Affine::prime_subgroup_generator());
+        let pk_prf = generator * &self.prf_secret_key;
+        Address::from_group(&self.public_key_signature + &self.public_randomness_signature + pk_prf)
+    }
+}
+
+impl Inject for ComputeKey {
+    type Primitive = keys::ComputeKey;
+
+    /// Initializes a compute key circuit from the signature public keys of a console compute key.
+    fn new(mode: Mode, compute_key: Self::Primitive) -> Self {
+        Self::from_public_keys(
+            Group::new(mode, compute_key.public_key_signature),
+            Group::new(mode, compute_key.public_randomness_signature),
+        )
+    }
+}
+
+impl Eject for ComputeKey {
+    type Primitive = keys::ComputeKey;
+
+    /// Ejects the mode of the compute key circuit.
+    fn eject_mode(&self) -> Mode {
+        Mode::combine(
+            self.public_key_signature.eject_mode(),
+            [
+                self.public_randomness_signature.eject_mode(),
+                self.prf_secret_key.eject_mode(),
+            ],
+        )
+    }
+
+    /// Ejects the compute key circuit as a console compute key.
+    fn eject_value(&self) -> Self::Primitive {
+        keys::ComputeKey {
+            public_key_signature: self.public_key_signature.eject_value(),
+            public_randomness_signature: self.public_randomness_signature.eject_value(),
+            prf_secret_key: self.prf_secret_key.eject_value(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = ""]
+mod test {
+    use super::*;
+
+    mod compute_key_tests;
+}
@@ -1,8 +1,14 @@
 use crate::circuit::{
-    traits::{Compare, Eject, Inject, Ternary},
+    traits::{Compare, Eject, Inject, Ternary, ToBits},
     types::Boolean,
 };
 
+#[cfg(test)]
+use crate::circuit::{
+    helpers::{Count, Mode},
+    traits::Metrics,
+};
+
 use super::Field;
 
 impl Compare<Field> for Field {
@@ -17,34 +23,29 @@ impl Compare<Field> for Field {
         // Case 2: Constant < Variable
         else if self.is_constant() {
             // See the `else` case below for the truth table and description of the logic.
-            self.to_bits_le()
-                .into_iter()
-                .zip_eq(other.to_bits_le())
-                .fold(
-                    Boolean::constant(false),
-                    |is_less_than, (this, that)| match this.eject_value() {
-                        true => that.bitand(&is_less_than),
-                        false => that.bitor(&is_less_than),
-                    },
-                )
+            self.to_bits_le().into_iter().zip(other.to_bits_le()).fold(
+                Boolean::constant(false),
+                |is_less_than, (this, that)| match this.eject_value() {
+                    true => that & is_less_than,
+                    false => that | is_less_than,
+                },
+            )
         }
         // Case 3: Variable < Constant
         else if other.is_constant() {
             // See the `else` case below for the truth table and description of the logic.
-            self.to_bits_le()
-                .into_iter()
-                .zip_eq(other.to_bits_le())
-                .fold(
-                    Boolean::constant(false),
-                    |is_less_than, (this, that)| match that.eject_value() {
-                        true => (!this).bitor(is_less_than),
-                        false => (!this).bitand(&is_less_than),
-                    },
-                )
+            self.to_bits_le().into_iter().zip(other.to_bits_le()).fold(
+                Boolean::constant(false),
+                |is_less_than, (this, that)| match that.eject_value() {
+                    true => !this | is_less_than,
+                    false => !this & is_less_than,
+                },
+            )
         }
         // Case 4: Variable < Variable
         else {
-            // Check each bitwise pair of `(self, other)` from MSB to LSB as follows:
+            // Check each bitwise pair of `(self, other)` from LSB to MSB, so that the most
+            // significant differing pair decides the result:
             //   - If `this` != `that`, and if `this` is `true`, return `false`.
             //   - If `this` != `that`, and if `this` is `false`, return `true`.
             //   - If `this` == `that`, return `is_less_than`.
@@ -55,17 +56,18 @@ impl Compare<Field> for Field {
             // |---------+---------+--------------+--------|
             // | `true`  | `true`  | `true`       | `true` |
             // | `true`  | `true`  | `false`      | `false`|
-            // | `true`  | `false` | `true`       | `true` |
-            // | `true`  | `false` | `false`      | `true` |
-            // | `false` | `true`  | `true`       | `false`|
-            // | `false` | `true`  | `false`      | `false`|
+            // | `true`  | `false` | `true`       | `false`|
+            // | `true`  | `false` | `false`      | `false`|
+            // | `false` | `true`  | `true`       | `true` |
+            // | `false` | `true`  | `false`      | `true` |
             // | `false` | `false` | `true`       | `true` |
             // | `false` | `false` | `false`      | `false`|
             //
-            self.to_bits_le().iter().zip_eq(other.to_bits_le()).fold(
+            // The bits are strict, so that comparing them compares the values of the fields.
+            self.to_bits_le().into_iter().zip(other.to_bits_le()).fold(
                 Boolean::constant(false),
                 |is_less_than, (this, that)| {
-                    Boolean::ternary(&this.bitxor(&that), &that, &is_less_than)
+                    Boolean::ternary(&(&this ^ &that), &that, &is_less_than)
                 },
             )
         }
@@ -86,3 +88,20 @@ impl Compare<Field> for Field {
         !self.is_less_than(other)
     }
 }
+
+#[cfg(test)]
+impl Metrics<dyn Compare<Field, Output = Boolean>> for Field {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Count::is(0, 0, 0, 0),
+            // The variable is decomposed, and every bit costs at most a conjunction or a
+            // disjunction.
+            (Mode::Constant, _) | (_, Mode::Constant) => Count::less_than(0, 0, 1398, 1400),
+            // Both fields are decomposed, and every pair of bits costs an exclusive or and a
+            // ternary.
+            (_, _) => Count::is(0, 0, 2796, 2800),
+        }
+    }
+}
@@ -1,122 +1,108 @@
+use crate::{
+    assert_count,
+    bls12_377::{Field as FieldTrait, Fp},
+    circuit::{
+        rng_test_struct::{TestRng, Uniform},
+        traits::Compare,
+    },
+};
 
 use super::*;
-use snarkvm_circuit_environment::Circuit;
 
-const ITERATIONS: u64 = 100;
+const ITERATIONS: u64 = 10;
 
-fn check_is_less_than(
-    name: &str,
-    expected: bool,
-    a: &Field<Circuit>,
-    b: &Field<Circuit>,
-    num_constants: u64,
-    num_public: u64,
-    num_private: u64,
-    num_constraints: u64,
-) {
-    // Perform the less than comparison.
+fn check_compare(name: &str, first: Fp, second: Fp, mode_a: Mode, mode_b: Mode) {
+    let a = Field::new(mode_a, first);
+    let b = Field::new(mode_b, second);
     Circuit::scope(name, || {
-        let candidate = a.is_less_than(b);
-        assert_eq!(expected, candidate.eject_value());
-        match (a.eject_mode(), b.eject_mode()) {
-            (Mode::Constant, Mode::Constant) => {
-                assert_scope!(num_constants, num_public, num_private, num_constraints)
-            }
-            (_, Mode::Constant) | (Mode::Constant, _) => {
-                assert_scope!(<=num_constants, <=num_public, <=num_private, <=num_constraints)
-            }
-            _ => assert_scope!(num_constants, num_public, num_private, num_constraints),
-        }
+        let candidate = a.is_less_than(&b);
+        assert_eq!(
+            first < second,
+            candidate.eject_value(),
+            "({} < {})",
+            first,
+            second
+        );
+        assert_count!(Compare(Field, Field) => Boolean, &(mode_a, mode_b));
+    });
+    Circuit::scope(name, || {
+        assert_eq!(first > second, a.is_greater_than(&b).eject_value());
+        assert_eq!(first <= second, a.is_less_than_or_equal(&b).eject_value());
+        assert_eq!(
+            first >= second,
+            a.is_greater_than_or_equal(&b).eject_value()
+        );
+        assert!(Circuit::is_satisfied_in_scope());
     });
     Circuit::reset();
 }
 
-fn run_test(
-    mode_a: Mode,
-    mode_b: Mode,
-    num_constants: u64,
-    num_public: u64,
-    num_private: u64,
-    num_constraints: u64,
-) {
+fn run_test(mode_a: Mode, mode_b: Mode) {
     let mut rng = TestRng::default();
 
     for i in 0..ITERATIONS {
         let first = Uniform::rand(&mut rng);
         let second = Uniform::rand(&mut rng);
-
-        let a = Field::<Circuit>::new(mode_a, first);
-        let b = Field::<Circuit>::new(mode_b, second);
-        let expected = first < second;
-        let name = format!("{} {} {}", mode_a, mode_b, i);
-        check_is_less_than(
-            &name,
-            expected,
-            &a,
-            &b,
-            num_constants,
-            num_public,
-            num_private,
-            num_constraints,
-        );
-
-        // Check `first` is not less than `first`.
-        let a = Field::<Circuit>::new(mode_a, first);
-        let b = Field::<Circuit>::new(mode_b, first);
-        check_is_less_than(
-            "first !< first",
-            false,
-            &a,
-            &b,
-            num_constants,
-            num_public,
-            num_private,
-            num_constraints,
+        check_compare(
+            &format!("Compare: a < b {i}"),
+            first,
+            second,
+            mode_a,
+            mode_b,
         );
+        check_compare(&format!("Compare: a < a {i}"), first, first, mode_a, mode_b);
     }
+
+    // Check the values at the ends of the field, and neighbouring values.
+    let (zero, one, minus_one) = (Fp::ZERO, Fp::ONE, -Fp::ONE);
+    check_compare("Compare: 0 < 1", zero, one, mode_a, mode_b);
+    check_compare("Compare: 1 < 0", one, zero, mode_a, mode_b);
+    check_compare("Compare: 0 < -1", zero, minus_one, mode_a, mode_b);
+    check_compare("Compare: -1 < 0", minus_one, zero, mode_a, mode_b);
+    check_compare("Compare: -1 < -1", minus_one, minus_one, mode_a, mode_b);
 }
 
 #[test]
-fn test_constant_is_less_than_constant() {
-    run_test(Mode::Constant, Mode::Constant, 0, 0, 0, 0);
+fn test_constant_compare_constant() {
+    run_test(Mode::Constant, Mode::Constant);
 }
 
 #[test]
-fn test_constant_is_less_than_public() {
-    run_test(Mode::Constant, Mode::Public, 253, 0, 506, 507);
+fn test_constant_compare_public() {
+    run_test(Mode::Constant, Mode::Public);
 }
 
 #[test]
-fn test_constant_is_less_than_private() {
-    run_test(Mode::Constant, Mode::Private, 253, 0, 506, 507);
+fn test_constant_compare_private() {
+    run_test(Mode::Constant, Mode::Private);
 }
 
 #[test]
-fn test_public_is_less_than_constant() {
-    run_test(Mode::Public, Mode::Constant, 253, 0, 506, 507);
+fn test_public_compare_constant() {
+    run_test(Mode::Public, Mode::Constant);
 }
 
 #[test]
-fn test_public_is_less_than_public() {
-    run_test(Mode::Public, Mode::Public, 0, 0, 1012, 1014);
+fn test_public_compare_public() {
+    run_test(Mode::Public, Mode::Public);
 }
 
 #[test]
-fn test_public_is_less_than_private() {
-    run_test(Mode::Public, Mode::Private, 0, 0, 1012, 1014);
+fn test_public_compare_private() {
+    run_test(Mode::Public, Mode::Private);
 }
 
 #[test]
-fn test_private_is_less_than_constant() {
-    run_test(Mode::Private, Mode::Constant, 253, 0, 506, 507);
+fn test_private_compare_constant() {
+    run_test(Mode::Private, Mode::Constant);
 }
 
 #[test]
-fn test_private_is_less_than_public() {
-    run_test(Mode::Private, Mode::Public, 0, 0, 1012, 1014);
+fn test_private_compare_public() {
+    run_test(Mode::Private, Mode::Public);
 }
 
 #[test]
-fn test_private_is_less_than_private() {
-    run_test(Mode::Private, Mode::Private, 0, 0, 1012, 1014);
+fn test_private_compare_private() {
+    run_test(Mode::Private, Mode::Private);
 }
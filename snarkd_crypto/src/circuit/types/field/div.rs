@@ -0,0 +1,100 @@
+use std::ops::{Div, DivAssign};
+
+use crate::circuit::traits::Inverse;
+
+#[cfg(test)]
+use crate::{
+    bls12_377::Field as FieldTrait,
+    circuit::{
+        circuit::Circuit,
+        helpers::{CircuitType, Count, Mode},
+        traits::{Eject, Metrics, OutputMode},
+        Environment,
+    },
+};
+
+use super::Field;
+
+impl Div<Field> for Field {
+    type Output = Field;
+
+    fn div(self, other: Field) -> Self::Output {
+        self / &other
+    }
+}
+
+impl Div<&Field> for Field {
+    type Output = Field;
+
+    fn div(self, other: &Field) -> Self::Output {
+        let mut output = self;
+        output /= other;
+        output
+    }
+}
+
+impl Div<Field> for &Field {
+    type Output = Field;
+
+    fn div(self, other: Field) -> Self::Output {
+        self / &other
+    }
+}
+
+impl Div<&Field> for &Field {
+    type Output = Field;
+
+    fn div(self, other: &Field) -> Self::Output {
+        let mut output = self.clone();
+        output /= other;
+        output
+    }
+}
+
+impl DivAssign<Field> for Field {
+    fn div_assign(&mut self, other: Field) {
+        *self /= &other;
+    }
+}
+
+impl DivAssign<&Field> for Field {
+    /// Divides by `other` as multiplication by its inverse, which ensures that `other` is nonzero.
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn div_assign(&mut self, other: &Field) {
+        *self *= other.inverse();
+    }
+}
+
+#[cfg(test)]
+impl Metrics<dyn Div<Field, Output = Field>> for Field {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match (case.0, case.1) {
+            (_, Mode::Constant) => Count::is(1, 0, 0, 0),
+            (Mode::Constant, _) => Count::is(0, 0, 1, 1),
+            (_, _) => Count::is(0, 0, 2, 2),
+        }
+    }
+}
+
+#[cfg(test)]
+impl OutputMode<dyn Div<Field, Output = Field>> for Field {
+    type Case = (CircuitType<Field>, CircuitType<Field>);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0.mode(), case.1.mode()) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (Mode::Public, Mode::Constant) => match &case.1 {
+                CircuitType::Constant(constant) => match constant.eject_value() {
+                    value if value.is_one() => Mode::Public,
+                    _ => Mode::Private,
+                },
+                _ => Circuit::halt(
+                    "The constant is required to determine the output mode of Public / Constant",
+                ),
+            },
+            (_, _) => Mode::Private,
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use std::ops::Div;
+
+use crate::{
+    assert_count, assert_output_mode,
+    bls12_377::{Field as FieldTrait, Fp},
+    circuit::{
+        helpers::CircuitType,
+        rng_test_struct::{TestRng, Uniform},
+    },
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 100;
+
+fn check_div(name: &str, expected: &Fp, a: &Field, b: &Field) {
+    Circuit::scope(name, || {
+        let candidate = a / b;
+        assert_eq!(
+            *expected,
+            candidate.eject_value(),
+            "({} / {})",
+            a.eject_value(),
+            b.eject_value()
+        );
+        assert_count!(Div(Field, Field) => Field, &(a.eject_mode(), b.eject_mode()));
+        assert_output_mode!(Div(Field, Field) => Field, &(CircuitType::from(a), CircuitType::from(b)), candidate);
+    });
+    Circuit::scope(name, || {
+        let mut candidate = a.clone();
+        candidate /= b;
+        assert_eq!(*expected, candidate.eject_value());
+        assert_count!(Div(Field, Field) => Field, &(a.eject_mode(), b.eject_mode()));
+        assert_output_mode!(Div(Field, Field) => Field, &(CircuitType::from(a), CircuitType::from(b)), candidate);
+    });
+    Circuit::reset();
+}
+
+fn run_test(mode_a: Mode, mode_b: Mode) {
+    let mut rng = TestRng::default();
+
+    for i in 0..ITERATIONS {
+        let first: Fp = Uniform::rand(&mut rng);
+        let second: Fp = Uniform::rand(&mut rng);
+        if second.is_zero() {
+            continue;
+        }
+
+        let expected = first * second.inverse().unwrap();
+        let a = Field::new(mode_a, first);
+        let b = Field::new(mode_b, second);
+        check_div(&format!("Div: a / b {i}"), &expected, &a, &b);
+
+        // Test identity.
+        let one = Field::new(mode_b, Fp::ONE);
+        check_div(&format!("Div: a / 1 {i}"), &first, &a, &one);
+
+        // Test self-division.
+        let b = Field::new(mode_b, first);
+        check_div(&format!("Div: a / a {i}"), &Fp::ONE, &a, &b);
+
+        // Test zero.
+        let zero = Field::new(mode_a, Fp::ZERO);
+        let b = Field::new(mode_b, second);
+        check_div(&format!("Div: 0 / b {i}"), &Fp::ZERO, &zero, &b);
+    }
+}
+
+#[test]
+fn test_constant_div_constant() {
+    run_test(Mode::Constant, Mode::Constant);
+}
+
+#[test]
+fn test_constant_div_public() {
+    run_test(Mode::Constant, Mode::Public);
+}
+
+#[test]
+fn test_constant_div_private() {
+    run_test(Mode::Constant, Mode::Private);
+}
+
+#[test]
+fn test_public_div_constant() {
+    run_test(Mode::Public, Mode::Constant);
+}
+
+#[test]
+fn test_public_div_public() {
+    run_test(Mode::Public, Mode::Public);
+}
+
+#[test]
+fn test_public_div_private() {
+    run_test(Mode::Public, Mode::Private);
+}
+
+#[test]
+fn test_private_div_constant() {
+    run_test(Mode::Private, Mode::Constant);
+}
+
+#[test]
+fn test_private_div_public() {
+    run_test(Mode::Private, Mode::Public);
+}
+
+#[test]
+fn test_private_div_private() {
+    run_test(Mode::Private, Mode::Private);
+}
+
+#[test]
+fn test_div_by_zero_fails() {
+    let mut rng = TestRng::default();
+
+    for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+        for mode_b in [Mode::Public, Mode::Private] {
+            let a = Field::new(mode_a, Uniform::rand(&mut rng));
+            let b = Field::new(mode_b, Fp::ZERO);
+            Circuit::scope(format!("Div: {mode_a} / {mode_b} zero"), || {
+                let _ = &a / &b;
+                assert!(!Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_div_by_constant_zero_halts() {
+    let _ = Field::one() / Field::zero();
+}
@@ -0,0 +1,62 @@
+use crate::{
+    bls12_377::{Field as FieldTrait, Fp},
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        traits::{Eject, Equal, Inject},
+        types::Boolean,
+        Environment,
+    },
+};
+
+use super::Field;
+
+impl Field {
+    /// Returns `true` if `self` is zero.
+    pub fn is_zero(&self) -> Boolean {
+        self.is_equal(&Field::zero())
+    }
+}
+
+impl Equal<Self> for Field {
+    type Output = Boolean;
+
+    /// Returns `true` if `self` and `other` are equal.
+    fn is_equal(&self, other: &Self) -> Self::Output {
+        !self.is_not_equal(other)
+    }
+
+    /// Returns `true` if `self` and `other` are *not* equal.
+    ///
+    /// This method constructs a boolean that indicates if
+    /// `self` and `other ` are *not* equal to each other.
+    fn is_not_equal(&self, other: &Self) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            return Boolean::constant(self.eject_value() != other.eject_value());
+        }
+
+        // Compute `self - other`, which is zero exactly when the two are equal.
+        let delta = self - other;
+
+        // Witness whether `delta` is nonzero, along with its inverse, or one if it is zero.
+        let is_neq = Boolean::new(Mode::Private, !delta.eject_value().is_zero());
+        let multiplier = Field::new(
+            Mode::Private,
+            delta.eject_value().inverse().unwrap_or(Fp::ONE),
+        );
+
+        //
+        // Inequality Enforcement
+        // -------------------------------------------------------
+        // 1. delta * multiplier = is_neq
+        // 2. delta * (1 - is_neq) = 0
+        //
+        // If `delta` is zero, (1) forces `is_neq` to be `false`.
+        // If `delta` is nonzero, (2) forces `is_neq` to be `true`.
+        //
+        Circuit::enforce(|| (&delta, &multiplier, &is_neq));
+        Circuit::enforce(|| (&delta, Circuit::one() - &*is_neq, Circuit::zero()));
+
+        is_neq
+    }
+}
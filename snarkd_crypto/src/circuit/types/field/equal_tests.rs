@@ -0,0 +1,174 @@
+use crate::{
+    assert_scope,
+    bls12_377::{Field as FieldTrait, Fp},
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        rng_test_struct::{TestRng, Uniform},
+        traits::{Eject, Equal, Inject},
+        Environment,
+    },
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 100;
+
+#[allow(clippy::too_many_arguments)]
+fn check_is_equal(
+    name: &str,
+    expected: bool,
+    a: &Field,
+    b: &Field,
+    num_constants: u64,
+    num_public: u64,
+    num_private: u64,
+    num_constraints: u64,
+) {
+    Circuit::scope(name, || {
+        let candidate = a.is_equal(b);
+        assert_eq!(
+            expected,
+            candidate.eject_value(),
+            "({} == {})",
+            a.eject_value(),
+            b.eject_value()
+        );
+        assert_scope!(num_constants, num_public, num_private, num_constraints);
+    });
+    Circuit::scope(name, || {
+        let candidate = a.is_not_equal(b);
+        assert_eq!(
+            !expected,
+            candidate.eject_value(),
+            "({} != {})",
+            a.eject_value(),
+            b.eject_value()
+        );
+        assert_scope!(num_constants, num_public, num_private, num_constraints);
+    });
+}
+
+fn run_test(
+    mode_a: Mode,
+    mode_b: Mode,
+    num_constants: u64,
+    num_public: u64,
+    num_private: u64,
+    num_constraints: u64,
+) {
+    let mut rng = TestRng::default();
+
+    for i in 0..ITERATIONS {
+        let first: Fp = Uniform::rand(&mut rng);
+        let second: Fp = Uniform::rand(&mut rng);
+
+        let a = Field::new(mode_a, first);
+        let b = Field::new(mode_b, second);
+        let name = format!("Equal: a == b {i}");
+        check_is_equal(
+            &name,
+            first == second,
+            &a,
+            &b,
+            num_constants,
+            num_public,
+            num_private,
+            num_constraints,
+        );
+
+        let b = Field::new(mode_b, first);
+        let name = format!("Equal: a == a {i}");
+        check_is_equal(
+            &name,
+            true,
+            &a,
+            &b,
+            num_constants,
+            num_public,
+            num_private,
+            num_constraints,
+        );
+    }
+
+    // Check the zero case.
+    let a = Field::new(mode_a, Fp::ZERO);
+    let b = Field::new(mode_b, Fp::ONE);
+    check_is_equal(
+        "Equal: 0 == 1",
+        false,
+        &a,
+        &b,
+        num_constants,
+        num_public,
+        num_private,
+        num_constraints,
+    );
+}
+
+#[test]
+fn test_constant_equals_constant() {
+    run_test(Mode::Constant, Mode::Constant, 0, 0, 0, 0);
+}
+
+#[test]
+fn test_constant_equals_public() {
+    run_test(Mode::Constant, Mode::Public, 0, 0, 2, 3);
+}
+
+#[test]
+fn test_constant_equals_private() {
+    run_test(Mode::Constant, Mode::Private, 0, 0, 2, 3);
+}
+
+#[test]
+fn test_public_equals_constant() {
+    run_test(Mode::Public, Mode::Constant, 0, 0, 2, 3);
+}
+
+#[test]
+fn test_public_equals_public() {
+    run_test(Mode::Public, Mode::Public, 0, 0, 2, 3);
+}
+
+#[test]
+fn test_public_equals_private() {
+    run_test(Mode::Public, Mode::Private, 0, 0, 2, 3);
+}
+
+#[test]
+fn test_private_equals_constant() {
+    run_test(Mode::Private, Mode::Constant, 0, 0, 2, 3);
+}
+
+#[test]
+fn test_private_equals_public() {
+    run_test(Mode::Private, Mode::Public, 0, 0, 2, 3);
+}
+
+#[test]
+fn test_private_equals_private() {
+    run_test(Mode::Private, Mode::Private, 0, 0, 2, 3);
+}
+
+#[test]
+fn test_is_zero() {
+    let mut rng = TestRng::default();
+
+    for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+        for (i, value) in [Fp::ZERO, Fp::ONE, Uniform::rand(&mut rng)]
+            .into_iter()
+            .enumerate()
+        {
+            let candidate = Field::new(mode, value);
+            Circuit::scope(format!("IsZero: {mode} {i}"), || {
+                assert_eq!(value.is_zero(), candidate.is_zero().eject_value());
+                match mode {
+                    Mode::Constant => assert_scope!(0, 0, 0, 0),
+                    _ => assert_scope!(0, 0, 2, 3),
+                }
+            });
+            Circuit::reset();
+        }
+    }
+}
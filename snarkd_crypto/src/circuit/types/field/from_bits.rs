@@ -0,0 +1,44 @@
+use crate::{
+    bls12_377::fp::{MODULUS, MODULUS_BITS},
+    circuit::{
+        circuit::Circuit,
+        traits::{FromBits, Inject},
+        types::Boolean,
+        Environment,
+    },
+};
+
+use super::Field;
+
+impl FromBits for Field {
+    type Boolean = Boolean;
+
+    /// Initializes a field from little-endian bits, which must be those of a value less than the
+    /// modulus. Any bits past the size of the modulus must be zero.
+    fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
+        let num_bits = MODULUS_BITS as usize;
+
+        let mut bits_le = bits_le.to_vec();
+        if bits_le.len() > num_bits {
+            for bit in &bits_le[num_bits..] {
+                Circuit::assert_eq(bit, Circuit::zero());
+            }
+            bits_le.truncate(num_bits);
+        }
+
+        // Ensure the bits are less than the modulus, unless they are too few to reach it.
+        if bits_le.len() == num_bits {
+            let modulus_le = (0..num_bits).map(|i| MODULUS.bit(i)).collect::<Vec<_>>();
+            Boolean::assert_less_than_constant(&bits_le, &modulus_le);
+        }
+        bits_le.resize(num_bits, Boolean::constant(false));
+
+        // The bits are exactly those `ToBits` would decompose the field into, so they are cached.
+        let field = Field::from(Boolean::le_bits_to_lc(&bits_le));
+        field
+            .bits_le
+            .set(bits_le)
+            .unwrap_or_else(|_| unreachable!("The bits of a new field are unset"));
+        field
+    }
+}
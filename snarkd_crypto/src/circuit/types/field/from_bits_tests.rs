@@ -0,0 +1,108 @@
+use crate::{
+    assert_scope,
+    bls12_377::{
+        fp::{MODULUS, MODULUS_BITS},
+        Field as FieldTrait, Fp,
+    },
+    circuit::{
+        rng_test_struct::{TestRng, Uniform},
+        traits::{FromBits, ToBits},
+    },
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 100;
+
+fn check_from_bits_le(name: &str, expected: Fp, mode: Mode, num_bits: usize) {
+    let bits_le = (0..num_bits)
+        .map(|i| Boolean::new(mode, i < 384 && expected.0.bit(i)))
+        .collect::<Vec<_>>();
+    Circuit::scope(name, || {
+        let candidate = Field::from_bits_le(&bits_le);
+        assert_eq!(expected, candidate.eject_value());
+        assert_eq!(mode.is_constant(), candidate.is_constant());
+        // Only the bits of the modulus size are checked to be less than it, and the excess bits
+        // to be zero.
+        let num_excess_bits = num_bits.saturating_sub(MODULUS_BITS as usize) as u64;
+        match mode {
+            Mode::Constant => assert_scope!(0, 0, 0, 0),
+            _ if num_bits < MODULUS_BITS as usize => assert_scope!(0, 0, 0, 0),
+            _ => assert_scope!(0, 0, 644, 645 + num_excess_bits),
+        }
+
+        // The bits are cached, so decomposing the field again is free and gives them back.
+        let candidate_bits = candidate.to_bits_le();
+        assert_eq!(MODULUS_BITS as usize, candidate_bits.len());
+        for (i, bit) in candidate_bits.iter().enumerate() {
+            assert_eq!(expected.0.bit(i), bit.eject_value());
+        }
+        match mode {
+            Mode::Constant => assert_scope!(0, 0, 0, 0),
+            _ if num_bits < MODULUS_BITS as usize => assert_scope!(0, 0, 0, 0),
+            _ => assert_scope!(0, 0, 644, 645 + num_excess_bits),
+        }
+    });
+    Circuit::reset();
+}
+
+#[test]
+fn test_from_bits_le() {
+    let mut rng = TestRng::default();
+
+    for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+        for i in 0..ITERATIONS {
+            let given: Fp = Uniform::rand(&mut rng);
+            check_from_bits_le(&format!("FromBits: {mode} {i}"), given, mode, 377);
+            check_from_bits_le(&format!("FromBits: {mode} {i} padded"), given, mode, 400);
+        }
+        check_from_bits_le(&format!("FromBits: {mode} zero"), Fp::ZERO, mode, 377);
+        check_from_bits_le(&format!("FromBits: {mode} minus one"), -Fp::ONE, mode, 377);
+
+        // Fewer bits than the modulus are always less than it.
+        let given = Fp::from(u64::MAX);
+        check_from_bits_le(&format!("FromBits: {mode} u64"), given, mode, 64);
+    }
+}
+
+#[test]
+fn test_from_bits_be() {
+    let mut rng = TestRng::default();
+
+    for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+        let given: Fp = Uniform::rand(&mut rng);
+        let bits_be = Field::new(mode, given).to_bits_be();
+        assert_eq!(given, Field::from_bits_be(&bits_be).eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
+
+#[test]
+fn test_from_bits_le_rejects_modulus() {
+    for mode in [Mode::Public, Mode::Private] {
+        let bits_le = (0..MODULUS_BITS as usize)
+            .map(|i| Boolean::new(mode, MODULUS.bit(i)))
+            .collect::<Vec<_>>();
+        Circuit::scope(format!("FromBits: {mode} modulus"), || {
+            let _ = Field::from_bits_le(&bits_le);
+            assert!(!Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+    }
+}
+
+#[test]
+fn test_from_bits_le_rejects_excess_bits() {
+    for mode in [Mode::Public, Mode::Private] {
+        let mut bits_le = (0..MODULUS_BITS as usize)
+            .map(|_| Boolean::new(mode, false))
+            .collect::<Vec<_>>();
+        bits_le.push(Boolean::new(mode, true));
+        Circuit::scope(format!("FromBits: {mode} excess"), || {
+            let _ = Field::from_bits_le(&bits_le);
+            assert!(!Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+    }
+}
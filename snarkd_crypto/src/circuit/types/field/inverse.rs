@@ -0,0 +1,64 @@
+use crate::{
+    bls12_377::{Field as FieldTrait, Fp},
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        traits::{Eject, Inject, Inverse},
+        Environment,
+    },
+};
+
+#[cfg(test)]
+use crate::circuit::{
+    helpers::Count,
+    traits::{Metrics, OutputMode},
+};
+
+use super::Field;
+
+impl Inverse for Field {
+    type Output = Field;
+
+    /// Returns the multiplicative inverse of `self`, which does not exist for zero.
+    fn inverse(&self) -> Self::Output {
+        let inverse = self.eject_value().inverse();
+        if self.is_constant() {
+            return match inverse {
+                Some(inverse) => Field::new(Mode::Constant, inverse),
+                None => Circuit::halt("Attempted to invert zero"),
+            };
+        }
+
+        // Witness zero if there is no inverse, which leaves the constraint below unsatisfied.
+        let inverse = Field::new(Mode::Private, inverse.unwrap_or(Fp::ZERO));
+
+        // Ensure self * inverse == 1.
+        Circuit::enforce(|| (self, &inverse, Circuit::one()));
+
+        inverse
+    }
+}
+
+#[cfg(test)]
+impl Metrics<dyn Inverse<Output = Field>> for Field {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case.is_constant() {
+            true => Count::is(1, 0, 0, 0),
+            false => Count::is(0, 0, 1, 1),
+        }
+    }
+}
+
+#[cfg(test)]
+impl OutputMode<dyn Inverse<Output = Field>> for Field {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            Mode::Constant => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{
+    assert_count, assert_output_mode,
+    bls12_377::{Field as FieldTrait, Fp},
+    circuit::{
+        rng_test_struct::{TestRng, Uniform},
+        traits::Inverse,
+    },
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 1_000;
+
+fn check_inverse(name: &str, mode: Mode, rng: &mut TestRng) {
+    let check_inverse = |given: Fp| {
+        let expected = given.inverse().unwrap();
+        let candidate = Field::new(mode, given);
+
+        Circuit::scope(name, || {
+            let result = candidate.inverse();
+            assert_eq!(expected, result.eject_value());
+            assert_count!(Inverse(Field) => Field, &mode);
+            assert_output_mode!(Inverse(Field) => Field, &mode, result);
+        });
+        Circuit::reset();
+    };
+
+    for _ in 0..ITERATIONS {
+        let given: Fp = Uniform::rand(rng);
+        if !given.is_zero() {
+            check_inverse(given);
+        }
+    }
+    check_inverse(Fp::ONE);
+    check_inverse(-Fp::ONE);
+}
+
+#[test]
+fn test_inverse() {
+    let mut rng = TestRng::default();
+
+    check_inverse("Constant", Mode::Constant, &mut rng);
+    check_inverse("Public", Mode::Public, &mut rng);
+    check_inverse("Private", Mode::Private, &mut rng);
+}
+
+#[test]
+fn test_inverse_of_zero_fails() {
+    for mode in [Mode::Public, Mode::Private] {
+        let candidate = Field::new(mode, Fp::ZERO);
+        Circuit::scope(format!("Inverse: {mode} zero"), || {
+            let _ = candidate.inverse();
+            assert!(!Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_constant_inverse_of_zero_halts() {
+    let _ = Field::zero().inverse();
+}
@@ -1,8 +1,17 @@
 mod add;
-// mod compare;
+mod compare;
+mod div;
+mod equal;
+mod from_bits;
+mod inverse;
 mod mul;
 mod neg;
+mod pow;
+mod square;
+mod square_root;
 mod sub;
+mod ternary;
+mod to_bits;
 
 use std::fmt;
 
@@ -104,7 +113,17 @@ mod test {
     use super::*;
 
     mod add_tests;
+    mod compare_tests;
+    mod div_tests;
+    mod equal_tests;
+    mod from_bits_tests;
+    mod inverse_tests;
     mod mul_tests;
     mod neg_tests;
+    mod pow_tests;
+    mod square_root_tests;
+    mod square_tests;
     mod sub_tests;
+    mod ternary_tests;
+    mod to_bits_tests;
 }
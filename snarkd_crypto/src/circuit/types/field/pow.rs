@@ -0,0 +1,74 @@
+use crate::circuit::traits::{Eject, Pow, Square, Ternary, ToBits};
+
+#[cfg(test)]
+use crate::{
+    bls12_377::Field as FieldTrait,
+    circuit::{
+        circuit::Circuit,
+        helpers::{CircuitType, Count, Mode},
+        traits::{Metrics, OutputMode},
+        Environment,
+    },
+};
+
+use super::Field;
+
+impl Pow<Field> for Field {
+    type Output = Field;
+
+    /// Returns `self` raised to the power of `other`, by squaring and multiplying from the most
+    /// significant bit of `other`.
+    fn pow(&self, other: &Field) -> Self::Output {
+        let mut output = Field::one();
+        for bit in other.to_bits_be() {
+            output = output.square();
+            output = match bit.is_constant() {
+                true => match bit.eject_value() {
+                    true => &output * self,
+                    false => output,
+                },
+                false => Field::ternary(&bit, &(&output * self), &output),
+            };
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+impl Metrics<dyn Pow<Field, Output = Field>> for Field {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Count::is(0, 0, 0, 0),
+            // A square and a multiplication for each bit of the exponent after the first set one.
+            (_, Mode::Constant) => Count::less_than(0, 0, 752, 752),
+            // The exponent is decomposed, and every bit costs a square and a ternary but the
+            // first, which only costs a ternary.
+            (Mode::Constant, _) => Count::is(0, 0, 1774, 1776),
+            (_, _) => Count::is(0, 0, 2150, 2152),
+        }
+    }
+}
+
+#[cfg(test)]
+impl OutputMode<dyn Pow<Field, Output = Field>> for Field {
+    type Case = (CircuitType<Field>, CircuitType<Field>);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0.mode(), case.1.mode()) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (mode, Mode::Constant) => match &case.1 {
+                CircuitType::Constant(constant) => match constant.eject_value() {
+                    value if value.is_zero() => Mode::Constant,
+                    value if value.is_one() => mode,
+                    _ => Mode::Private,
+                },
+                _ => Circuit::halt(
+                    "The constant is required to determine the output mode of Variable ^ Constant",
+                ),
+            },
+            (_, _) => Mode::Private,
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{
+    assert_count, assert_output_mode,
+    bls12_377::{Field as FieldTrait, Fp},
+    circuit::{
+        helpers::CircuitType,
+        rng_test_struct::{TestRng, Uniform},
+        traits::Pow,
+    },
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 10;
+
+fn check_pow(name: &str, expected: &Fp, a: &Field, b: &Field) {
+    Circuit::scope(name, || {
+        let candidate = a.pow(b);
+        assert_eq!(
+            *expected,
+            candidate.eject_value(),
+            "({} ^ {})",
+            a.eject_value(),
+            b.eject_value()
+        );
+        assert_count!(Pow(Field, Field) => Field, &(a.eject_mode(), b.eject_mode()));
+        assert_output_mode!(Pow(Field, Field) => Field, &(CircuitType::from(a), CircuitType::from(b)), candidate);
+    });
+    Circuit::reset();
+}
+
+fn run_test(mode_a: Mode, mode_b: Mode) {
+    let mut rng = TestRng::default();
+
+    for i in 0..ITERATIONS {
+        let first: Fp = Uniform::rand(&mut rng);
+        let second: Fp = Uniform::rand(&mut rng);
+
+        let expected = first.pow(second.0.as_limbs());
+        let a = Field::new(mode_a, first);
+        let b = Field::new(mode_b, second);
+        check_pow(&format!("Pow: a ^ b {i}"), &expected, &a, &b);
+
+        // Test the exponents zero, one and two.
+        let b = Field::new(mode_b, Fp::ZERO);
+        check_pow(&format!("Pow: a ^ 0 {i}"), &Fp::ONE, &a, &b);
+        let b = Field::new(mode_b, Fp::ONE);
+        check_pow(&format!("Pow: a ^ 1 {i}"), &first, &a, &b);
+        let b = Field::new(mode_b, Fp::ONE + Fp::ONE);
+        check_pow(&format!("Pow: a ^ 2 {i}"), &first.square(), &a, &b);
+
+        // Test the bases zero and one.
+        let b = Field::new(mode_b, second);
+        let zero = Field::new(mode_a, Fp::ZERO);
+        check_pow(&format!("Pow: 0 ^ b {i}"), &Fp::ZERO, &zero, &b);
+        // The exponent caches its bits, so it is injected again to decompose it afresh.
+        let b = Field::new(mode_b, second);
+        let one = Field::new(mode_a, Fp::ONE);
+        check_pow(&format!("Pow: 1 ^ b {i}"), &Fp::ONE, &one, &b);
+    }
+}
+
+#[test]
+fn test_constant_pow_constant() {
+    run_test(Mode::Constant, Mode::Constant);
+}
+
+#[test]
+fn test_constant_pow_public() {
+    run_test(Mode::Constant, Mode::Public);
+}
+
+#[test]
+fn test_constant_pow_private() {
+    run_test(Mode::Constant, Mode::Private);
+}
+
+#[test]
+fn test_public_pow_constant() {
+    run_test(Mode::Public, Mode::Constant);
+}
+
+#[test]
+fn test_public_pow_public() {
+    run_test(Mode::Public, Mode::Public);
+}
+
+#[test]
+fn test_public_pow_private() {
+    run_test(Mode::Public, Mode::Private);
+}
+
+#[test]
+fn test_private_pow_constant() {
+    run_test(Mode::Private, Mode::Constant);
+}
+
+#[test]
+fn test_private_pow_public() {
+    run_test(Mode::Private, Mode::Public);
+}
+
+#[test]
+fn test_private_pow_private() {
+    run_test(Mode::Private, Mode::Private);
+}
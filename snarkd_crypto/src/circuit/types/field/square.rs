@@ -0,0 +1,42 @@
+use crate::circuit::traits::Square;
+
+#[cfg(test)]
+use crate::circuit::{
+    helpers::{Count, Mode},
+    traits::{Metrics, OutputMode},
+};
+
+use super::Field;
+
+impl Square for Field {
+    type Output = Field;
+
+    /// Returns the square of `self`.
+    fn square(&self) -> Self::Output {
+        self * self
+    }
+}
+
+#[cfg(test)]
+impl Metrics<dyn Square<Output = Field>> for Field {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case.is_constant() {
+            true => Count::is(0, 0, 0, 0),
+            false => Count::is(0, 0, 1, 1),
+        }
+    }
+}
+
+#[cfg(test)]
+impl OutputMode<dyn Square<Output = Field>> for Field {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            Mode::Constant => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{
+    bls12_377::{Field as FieldTrait, Fp},
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        traits::{Eject, Inject, SquareRoot},
+        Environment,
+    },
+};
+
+#[cfg(test)]
+use crate::circuit::{
+    helpers::Count,
+    traits::{Metrics, OutputMode},
+};
+
+use super::Field;
+
+impl SquareRoot for Field {
+    type Output = Field;
+
+    /// Returns a square root of `self`, which does not exist for a quadratic nonresidue. Either
+    /// root of `self` satisfies the constraint.
+    fn square_root(&self) -> Self::Output {
+        let root = self.eject_value().sqrt();
+        if self.is_constant() {
+            return match root {
+                Some(root) => Field::new(Mode::Constant, root),
+                None => Circuit::halt("Attempted to take the square root of a nonresidue"),
+            };
+        }
+
+        // Witness zero if there is no root, whose square is not the nonzero nonresidue.
+        let root = Field::new(Mode::Private, root.unwrap_or(Fp::ZERO));
+
+        // Ensure root * root == self.
+        Circuit::enforce(|| (&root, &root, self));
+
+        root
+    }
+}
+
+#[cfg(test)]
+impl Metrics<dyn SquareRoot<Output = Field>> for Field {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case.is_constant() {
+            true => Count::is(1, 0, 0, 0),
+            false => Count::is(0, 0, 1, 1),
+        }
+    }
+}
+
+#[cfg(test)]
+impl OutputMode<dyn SquareRoot<Output = Field>> for Field {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            Mode::Constant => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{
+    assert_count, assert_output_mode,
+    bls12_377::{Field as FieldTrait, Fp},
+    circuit::{
+        rng_test_struct::{TestRng, Uniform},
+        traits::SquareRoot,
+    },
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 100;
+
+fn check_square_root(name: &str, mode: Mode, rng: &mut TestRng) {
+    let check_square_root = |given: Fp| {
+        let candidate = Field::new(mode, given);
+
+        Circuit::scope(name, || {
+            let result = candidate.square_root();
+            // Either root may be returned, so check its square instead.
+            assert_eq!(given, result.eject_value().square());
+            assert_count!(SquareRoot(Field) => Field, &mode);
+            assert_output_mode!(SquareRoot(Field) => Field, &mode, result);
+        });
+        Circuit::reset();
+    };
+
+    for _ in 0..ITERATIONS {
+        // Sample a square, which has a root.
+        let given: Fp = Uniform::rand(rng);
+        check_square_root(given.square());
+    }
+    check_square_root(Fp::ZERO);
+    check_square_root(Fp::ONE);
+}
+
+#[test]
+fn test_square_root() {
+    let mut rng = TestRng::default();
+
+    check_square_root("Constant", Mode::Constant, &mut rng);
+    check_square_root("Public", Mode::Public, &mut rng);
+    check_square_root("Private", Mode::Private, &mut rng);
+}
+
+/// Returns a random element that is not a square.
+fn sample_nonresidue(rng: &mut TestRng) -> Fp {
+    loop {
+        let given: Fp = Uniform::rand(rng);
+        if given.sqrt().is_none() {
+            return given;
+        }
+    }
+}
+
+#[test]
+fn test_square_root_of_nonresidue_fails() {
+    let mut rng = TestRng::default();
+
+    for mode in [Mode::Public, Mode::Private] {
+        let candidate = Field::new(mode, sample_nonresidue(&mut rng));
+        Circuit::scope(format!("SquareRoot: {mode} nonresidue"), || {
+            let _ = candidate.square_root();
+            assert!(!Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_constant_square_root_of_nonresidue_halts() {
+    let mut rng = TestRng::default();
+    let _ = Field::constant(sample_nonresidue(&mut rng)).square_root();
+}
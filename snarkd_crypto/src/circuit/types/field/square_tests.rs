@@ -0,0 +1,42 @@
+use crate::{
+    assert_count, assert_output_mode,
+    bls12_377::{Field as FieldTrait, Fp},
+    circuit::{
+        rng_test_struct::{TestRng, Uniform},
+        traits::Square,
+    },
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 1_000;
+
+fn check_square(name: &str, mode: Mode, rng: &mut TestRng) {
+    let check_square = |given: Fp| {
+        let expected = given.square();
+        let candidate = Field::new(mode, given);
+
+        Circuit::scope(name, || {
+            let result = candidate.square();
+            assert_eq!(expected, result.eject_value());
+            assert_count!(Square(Field) => Field, &mode);
+            assert_output_mode!(Square(Field) => Field, &mode, result);
+        });
+        Circuit::reset();
+    };
+
+    for _ in 0..ITERATIONS {
+        check_square(Uniform::rand(rng));
+    }
+    check_square(Fp::ZERO);
+    check_square(Fp::ONE);
+}
+
+#[test]
+fn test_square() {
+    let mut rng = TestRng::default();
+
+    check_square("Constant", Mode::Constant, &mut rng);
+    check_square("Public", Mode::Public, &mut rng);
+    check_square("Private", Mode::Private, &mut rng);
+}
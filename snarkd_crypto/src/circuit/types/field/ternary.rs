@@ -0,0 +1,45 @@
+use crate::circuit::{
+    circuit::Circuit,
+    helpers::Mode,
+    traits::{Eject, Inject, Ternary},
+    types::Boolean,
+    Environment,
+};
+
+use super::Field;
+
+impl Ternary for Field {
+    type Boolean = Boolean;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        // Constant `condition`
+        if condition.is_constant() {
+            return match condition.eject_value() {
+                true => first.clone(),
+                false => second.clone(),
+            };
+        }
+
+        // Declare a new variable with the expected output as witness.
+        let output = Field::new(
+            Mode::Private,
+            match condition.eject_value() {
+                true => first.eject_value(),
+                false => second.eject_value(),
+            },
+        );
+
+        //
+        // Ternary Enforcement
+        // -------------------------------------------------------
+        //    output = condition * a + (1 - condition) * b
+        // => output = b + condition * (a - b)
+        // => condition * (a - b) = output - b
+        //
+        Circuit::enforce(|| (condition, first - second, &output - second));
+
+        output
+    }
+}
@@ -0,0 +1,44 @@
+use crate::{
+    bls12_377::fp::{MODULUS, MODULUS_BITS},
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        traits::{Eject, Inject, ToBits},
+        types::Boolean,
+        Environment,
+    },
+};
+
+use super::Field;
+
+impl ToBits for Field {
+    type Boolean = Boolean;
+
+    /// Returns the little-endian bits of the field, which are the unique bits of its value
+    /// less than the modulus. The decomposition is only constrained once.
+    fn to_bits_le(&self) -> Vec<Self::Boolean> {
+        self.bits_le
+            .get_or_init(|| {
+                let value = self.eject_value();
+                let num_bits = MODULUS_BITS as usize;
+                if self.is_constant() {
+                    return (0..num_bits)
+                        .map(|i| Boolean::constant(value.0.bit(i)))
+                        .collect();
+                }
+
+                let bits_le = (0..num_bits)
+                    .map(|i| Boolean::new(Mode::Private, value.0.bit(i)))
+                    .collect::<Vec<_>>();
+
+                // Ensure the bits make up the field, and that they are less than the modulus, as
+                // the field minus the modulus would otherwise make it up as well.
+                Circuit::assert_eq(Boolean::le_bits_to_lc(&bits_le), self);
+                let modulus_le = (0..num_bits).map(|i| MODULUS.bit(i)).collect::<Vec<_>>();
+                Boolean::assert_less_than_constant(&bits_le, &modulus_le);
+
+                bits_le
+            })
+            .clone()
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{
+    assert_scope,
+    bls12_377::{Field as FieldTrait, Fp},
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        rng_test_struct::{TestRng, Uniform},
+        traits::{Eject, Inject, ToBits},
+        Environment,
+    },
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 100;
+
+fn check_to_bits_le(name: &str, expected: Fp, mode: Mode) {
+    let candidate = Field::new(mode, expected);
+    Circuit::scope(name, || {
+        let bits_le = candidate.to_bits_le();
+        assert_eq!(377, bits_le.len());
+        for (i, bit) in bits_le.iter().enumerate() {
+            assert_eq!(expected.0.bit(i), bit.eject_value());
+        }
+        match mode {
+            Mode::Constant => assert_scope!(0, 0, 0, 0),
+            _ => assert_scope!(0, 0, 1021, 1023),
+        }
+
+        // The bits are cached, so asking for them again is free.
+        let bits_be = candidate.to_bits_be();
+        assert_eq!(
+            bits_le.into_iter().rev().collect::<Vec<_>>().eject_value(),
+            bits_be.eject_value()
+        );
+        match mode {
+            Mode::Constant => assert_scope!(0, 0, 0, 0),
+            _ => assert_scope!(0, 0, 1021, 1023),
+        }
+    });
+    Circuit::reset();
+}
+
+#[test]
+fn test_to_bits_le() {
+    let mut rng = TestRng::default();
+
+    for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+        for i in 0..ITERATIONS {
+            check_to_bits_le(
+                &format!("ToBits: {mode} {i}"),
+                Uniform::rand(&mut rng),
+                mode,
+            );
+        }
+        check_to_bits_le(&format!("ToBits: {mode} zero"), Fp::ZERO, mode);
+        check_to_bits_le(&format!("ToBits: {mode} one"), Fp::ONE, mode);
+        check_to_bits_le(&format!("ToBits: {mode} minus one"), -Fp::ONE, mode);
+    }
+}
+
+#[test]
+fn test_to_bits_le_rejects_modulus_offset() {
+    // Decompose zero into the bits of the modulus, which make up zero in the field but are not
+    // less than the modulus.
+    let candidate = Field::new(Mode::Private, Fp::ZERO);
+    Circuit::scope("ToBits: modulus", || {
+        let num_bits = 377;
+        let bits_le = (0..num_bits)
+            .map(|i| Boolean::new(Mode::Private, crate::bls12_377::fp::MODULUS.bit(i)))
+            .collect::<Vec<_>>();
+        Circuit::assert_eq(Boolean::le_bits_to_lc(&bits_le), &candidate);
+        assert!(Circuit::is_satisfied_in_scope());
+
+        let modulus_le = (0..num_bits)
+            .map(|i| crate::bls12_377::fp::MODULUS.bit(i))
+            .collect::<Vec<_>>();
+        Boolean::assert_less_than_constant(&bits_le, &modulus_le);
+        assert!(!Circuit::is_satisfied_in_scope());
+    });
+    Circuit::reset();
+}
@@ -0,0 +1,129 @@
+use std::ops::{Add, AddAssign};
+
+use crate::{
+    bls12_377::{Affine, Field as FieldTrait, Fp, G1Affine, Projective},
+    circuit::{
+        circuit::Circuit,
+        helpers::{LinearCombination, Mode},
+        traits::{Eject, Equal, Inject, Ternary},
+        types::Field,
+        Environment,
+    },
+};
+
+#[cfg(test)]
+use crate::circuit::{helpers::Count, traits::Metrics};
+
+use super::Group;
+
+impl Add<Group> for Group {
+    type Output = Group;
+
+    fn add(self, other: Group) -> Self::Output {
+        self + &other
+    }
+}
+
+impl Add<&Group> for Group {
+    type Output = Group;
+
+    fn add(self, other: &Group) -> Self::Output {
+        &self + other
+    }
+}
+
+impl Add<Group> for &Group {
+    type Output = Group;
+
+    fn add(self, other: Group) -> Self::Output {
+        self + &other
+    }
+}
+
+impl Add<&Group> for &Group {
+    type Output = Group;
+
+    fn add(self, other: &Group) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            let sum = self
+                .eject_value()
+                .to_projective()
+                .add_mixed(&other.eject_value());
+            return Group::constant(G1Affine::from(sum));
+        }
+        // Adding a constant point at infinity is free.
+        if self.is_constant() && self.infinity.eject_value() {
+            return other.clone();
+        }
+        if other.is_constant() && other.infinity.eject_value() {
+            return self.clone();
+        }
+
+        let (x1, y1) = (&self.x, &self.y);
+        let (x2, y2) = (&other.x, &other.y);
+
+        // When neither point is at infinity, the sum is at infinity exactly when the points have
+        // the same x-coordinate and opposite y-coordinates, which includes doubling a point whose
+        // y-coordinate is zero.
+        let same_x = x1.is_equal(x2);
+        let is_zero = &same_x & &(y1 + y2).is_equal(&Field::zero());
+
+        // The slope of the line through the points, or of the tangent if they are the same point.
+        // Its denominator is replaced by one when the sum is at infinity, where it may be zero.
+        let x1_squared = x1 * x1;
+        let numerator = Field::ternary(
+            &same_x,
+            &(&x1_squared + &x1_squared + &x1_squared),
+            &(y2 - y1),
+        );
+        let denominator = Field::ternary(&same_x, &(y1 + y1), &(x2 - x1));
+        let denominator = Field::ternary(&is_zero, &Field::one(), &denominator);
+        let slope = Field::new(
+            Mode::Private,
+            numerator.eject_value() * denominator.eject_value().inverse().unwrap_or(Fp::ZERO),
+        );
+        Circuit::enforce(|| (&slope, &denominator, &numerator));
+
+        // Zero the coordinates of the sum when it is at infinity.
+        let is_not_zero = Field::from(Circuit::one() - LinearCombination::from(&is_zero));
+        let x3 = &(&slope * &slope) - x1 - x2;
+        let y3 = &(&slope * &(x1 - &x3)) - y1;
+        let sum = Group {
+            x: &x3 * &is_not_zero,
+            y: &y3 * &is_not_zero,
+            infinity: is_zero,
+        };
+
+        // Either point at infinity is the identity.
+        let sum = Group::ternary(&other.infinity, self, &sum);
+        Group::ternary(&self.infinity, other, &sum)
+    }
+}
+
+impl AddAssign<Group> for Group {
+    fn add_assign(&mut self, other: Group) {
+        *self += &other;
+    }
+}
+
+impl AddAssign<&Group> for Group {
+    fn add_assign(&mut self, other: &Group) {
+        *self = &*self + other;
+    }
+}
+
+#[cfg(test)]
+impl Metrics<dyn Add<Group, Output = Group>> for Group {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Count::is(0, 0, 0, 0),
+            // A constant operand saves selecting by its infinity flag, and squaring its
+            // x-coordinate if it is the first operand. Adding a constant point at infinity is free.
+            (Mode::Constant, _) => Count::less_than(0, 0, 16, 18),
+            (_, Mode::Constant) => Count::less_than(0, 0, 17, 19),
+            (_, _) => Count::is(0, 0, 20, 22),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use std::ops::Add;
+
+use crate::{
+    assert_count,
+    bls12_377::{Affine, G1Affine, Projective},
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        traits::{Eject, Inject},
+        Environment,
+    },
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 10;
+
+fn check_add(name: &str, first: G1Affine, second: G1Affine, mode_a: Mode, mode_b: Mode) {
+    let expected = G1Affine::from(first.to_projective().add_mixed(&second));
+    let a = Group::new(mode_a, first);
+    let b = Group::new(mode_b, second);
+    Circuit::scope(name, || {
+        let candidate = &a + &b;
+        assert_eq!(expected, candidate.eject_value(), "({first} + {second})");
+        assert_count!(Add(Group, Group) => Group, &(mode_a, mode_b));
+    });
+    Circuit::reset();
+}
+
+#[test]
+fn test_add() {
+    let modes = [Mode::Constant, Mode::Public, Mode::Private];
+
+    for mode_a in modes {
+        for mode_b in modes {
+            for i in 0..ITERATIONS {
+                let name = format!("Add: {mode_a} + {mode_b} {i}");
+                check_add(&name, G1Affine::rand(), G1Affine::rand(), mode_a, mode_b);
+            }
+
+            let point = G1Affine::rand();
+            let name = format!("Add: {mode_a} + {mode_b}");
+            check_add(&format!("{name} same"), point, point, mode_a, mode_b);
+            check_add(&format!("{name} opposite"), point, -point, mode_a, mode_b);
+            check_add(
+                &format!("{name} zero first"),
+                G1Affine::ZERO,
+                point,
+                mode_a,
+                mode_b,
+            );
+            check_add(
+                &format!("{name} zero second"),
+                point,
+                G1Affine::ZERO,
+                mode_a,
+                mode_b,
+            );
+            check_add(
+                &format!("{name} zero both"),
+                G1Affine::ZERO,
+                G1Affine::ZERO,
+                mode_a,
+                mode_b,
+            );
+        }
+    }
+}
+
+#[test]
+fn test_add_assign() {
+    let (first, second) = (G1Affine::rand(), G1Affine::rand());
+    let mut candidate = Group::new(Mode::Private, first);
+    candidate += Group::new(Mode::Public, second);
+    assert_eq!(
+        G1Affine::from(first.to_projective().add_mixed(&second)),
+        candidate.eject_value()
+    );
+    assert!(Circuit::is_satisfied());
+    Circuit::reset();
+}
@@ -0,0 +1,46 @@
+use crate::{
+    bls12_377::{Affine, Field as FieldTrait, Fp, G1Affine, Projective},
+    circuit::{
+        circuit::Circuit,
+        helpers::{LinearCombination, Mode},
+        traits::{Eject, Equal, Inject},
+        types::Field,
+        Environment,
+    },
+};
+
+use super::Group;
+
+impl Group {
+    /// Returns `2 * self`.
+    pub fn double(&self) -> Self {
+        if self.is_constant() {
+            let double = self.eject_value().to_projective().double();
+            return Group::constant(G1Affine::from(double));
+        }
+
+        // Doubling a point whose y-coordinate is zero, which is either the point at infinity or a
+        // point of order two, gives the point at infinity.
+        let is_zero = self.y.is_equal(&Field::zero());
+
+        // The slope of the tangent. Its denominator is one instead of zero when `y` is zero.
+        let x_squared = &self.x * &self.x;
+        let numerator = &x_squared + &x_squared + &x_squared;
+        let denominator = &(&self.y + &self.y) + &Field::from(LinearCombination::from(&is_zero));
+        let slope = Field::new(
+            Mode::Private,
+            numerator.eject_value() * denominator.eject_value().inverse().unwrap_or(Fp::ZERO),
+        );
+        Circuit::enforce(|| (&slope, &denominator, &numerator));
+
+        // Zero the coordinates of the double when it is at infinity.
+        let is_not_zero = Field::from(Circuit::one() - LinearCombination::from(&is_zero));
+        let x = &(&slope * &slope) - &self.x - &self.x;
+        let y = &(&slope * &(&self.x - &x)) - &self.y;
+        Group {
+            x: &x * &is_not_zero,
+            y: &y * &is_not_zero,
+            infinity: is_zero,
+        }
+    }
+}
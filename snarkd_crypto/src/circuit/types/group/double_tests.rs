@@ -0,0 +1,50 @@
+use crate::{
+    assert_scope,
+    bls12_377::{Affine, G1Affine, Projective},
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        traits::{Eject, Inject},
+        Environment,
+    },
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 100;
+
+fn check_double(name: &str, given: G1Affine, mode: Mode) {
+    let expected = G1Affine::from(given.to_projective().double());
+    let candidate = Group::new(mode, given);
+    Circuit::scope(name, || {
+        let result = candidate.double();
+        assert_eq!(expected, result.eject_value(), "(2 * {given})");
+        match mode {
+            Mode::Constant => assert_scope!(0, 0, 0, 0),
+            _ => assert_scope!(0, 0, 8, 9),
+        }
+    });
+    Circuit::reset();
+}
+
+#[test]
+fn test_double() {
+    for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+        for i in 0..ITERATIONS {
+            check_double(&format!("Double: {mode} {i}"), G1Affine::rand(), mode);
+        }
+        check_double(&format!("Double: {mode} zero"), G1Affine::ZERO, mode);
+    }
+}
+
+#[test]
+fn test_double_matches_add() {
+    let point = G1Affine::rand();
+    let candidate = Group::new(Mode::Private, point);
+    assert_eq!(
+        (&candidate + &candidate).eject_value(),
+        candidate.double().eject_value()
+    );
+    assert!(Circuit::is_satisfied());
+    Circuit::reset();
+}
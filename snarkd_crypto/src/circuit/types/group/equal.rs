@@ -0,0 +1,44 @@
+use crate::circuit::{
+    traits::{Eject, Equal, Inject},
+    types::Boolean,
+};
+
+#[cfg(test)]
+use crate::circuit::{
+    helpers::{Count, Mode},
+    traits::Metrics,
+};
+
+use super::Group;
+
+impl Equal<Self> for Group {
+    type Output = Boolean;
+
+    /// Returns `true` if `self` and `other` are equal.
+    fn is_equal(&self, other: &Self) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            return Boolean::constant(self.eject_value() == other.eject_value());
+        }
+
+        // Points are equal exactly when their coordinates are, as only the point at infinity has
+        // zero coordinates.
+        &self.x.is_equal(&other.x) & &self.y.is_equal(&other.y)
+    }
+
+    /// Returns `true` if `self` and `other` are *not* equal.
+    fn is_not_equal(&self, other: &Self) -> Self::Output {
+        !self.is_equal(other)
+    }
+}
+
+#[cfg(test)]
+impl Metrics<dyn Equal<Group, Output = Boolean>> for Group {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match case.0.is_constant() && case.1.is_constant() {
+            true => Count::is(0, 0, 0, 0),
+            false => Count::is(0, 0, 5, 7),
+        }
+    }
+}
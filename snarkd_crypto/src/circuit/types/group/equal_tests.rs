@@ -0,0 +1,59 @@
+use crate::{
+    assert_count,
+    bls12_377::{Affine, G1Affine},
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        traits::{Eject, Equal, Inject},
+        types::Boolean,
+        Environment,
+    },
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 10;
+
+fn check_equal(name: &str, first: G1Affine, second: G1Affine, mode_a: Mode, mode_b: Mode) {
+    let a = Group::new(mode_a, first);
+    let b = Group::new(mode_b, second);
+    Circuit::scope(format!("{name} is_equal"), || {
+        let candidate = a.is_equal(&b);
+        assert_eq!(
+            first == second,
+            candidate.eject_value(),
+            "({first} == {second})"
+        );
+        assert_count!(Equal(Group, Group) => Boolean, &(mode_a, mode_b));
+    });
+    Circuit::scope(format!("{name} is_not_equal"), || {
+        let candidate = a.is_not_equal(&b);
+        assert_eq!(
+            first != second,
+            candidate.eject_value(),
+            "({first} != {second})"
+        );
+        assert_count!(Equal(Group, Group) => Boolean, &(mode_a, mode_b));
+    });
+    Circuit::reset();
+}
+
+#[test]
+fn test_equal() {
+    let modes = [Mode::Constant, Mode::Public, Mode::Private];
+
+    for mode_a in modes {
+        for mode_b in modes {
+            for i in 0..ITERATIONS {
+                let name = format!("Equal: {mode_a} {mode_b} {i}");
+                let first = G1Affine::rand();
+                check_equal(&name, first, G1Affine::rand(), mode_a, mode_b);
+                check_equal(&name, first, first, mode_a, mode_b);
+                check_equal(&name, first, -first, mode_a, mode_b);
+                check_equal(&name, first, G1Affine::ZERO, mode_a, mode_b);
+            }
+            let name = format!("Equal: {mode_a} {mode_b} zero");
+            check_equal(&name, G1Affine::ZERO, G1Affine::ZERO, mode_a, mode_b);
+        }
+    }
+}
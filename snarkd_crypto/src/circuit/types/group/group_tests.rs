@@ -0,0 +1,136 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use crate::{
+    assert_scope,
+    bls12_377::{Affine, Field as FieldTrait, Fp, G1Affine},
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        traits::{Eject, Inject, ToBits},
+        Environment,
+    },
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 10;
+
+/// Returns a point on the curve that is not in the prime order subgroup.
+fn point_outside_subgroup() -> G1Affine {
+    loop {
+        if let Some(point) = G1Affine::from_x_coordinate(Fp::rand(), false) {
+            if !point.is_in_correct_subgroup_assuming_on_curve() {
+                return point;
+            }
+        }
+    }
+}
+
+fn check_new(name: &str, expected: G1Affine, mode: Mode) {
+    Circuit::scope(name, || {
+        let candidate = Group::new(mode, expected);
+        assert_eq!(expected, candidate.eject_value());
+        assert_eq!(mode, candidate.eject_mode());
+        match mode {
+            Mode::Constant => assert_scope!(3, 0, 0, 0),
+            Mode::Public => assert_scope!(0, 3, 1301, 1462),
+            Mode::Private => assert_scope!(0, 0, 1304, 1462),
+        }
+    });
+    Circuit::reset();
+}
+
+#[test]
+fn test_new() {
+    for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+        for i in 0..ITERATIONS {
+            check_new(&format!("New: {mode} {i}"), G1Affine::rand(), mode);
+        }
+        check_new(&format!("New: {mode} zero"), G1Affine::ZERO, mode);
+        check_new(
+            &format!("New: {mode} generator"),
+            G1Affine::prime_subgroup_generator(),
+            mode,
+        );
+    }
+}
+
+#[test]
+fn test_constant() {
+    for expected in [G1Affine::rand(), G1Affine::ZERO] {
+        Circuit::scope("Constant", || {
+            let candidate = Group::constant(expected);
+            assert_eq!(expected, candidate.eject_value());
+            assert!(candidate.is_constant());
+            assert_scope!(0, 0, 0, 0);
+        });
+    }
+    Circuit::reset();
+}
+
+#[test]
+fn test_zero() {
+    let candidate = Group::zero();
+    assert_eq!(G1Affine::ZERO, candidate.eject_value());
+    assert!(candidate.is_zero().eject_value());
+    assert!(!Group::new(Mode::Private, G1Affine::rand())
+        .is_zero()
+        .eject_value());
+    Circuit::reset();
+}
+
+#[test]
+fn test_new_rejects_point_outside_subgroup() {
+    let point = point_outside_subgroup();
+    for mode in [Mode::Public, Mode::Private] {
+        Circuit::scope(format!("Outside subgroup: {mode}"), || {
+            Group::new(mode, point);
+            assert!(!Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+    }
+    let result = catch_unwind(AssertUnwindSafe(|| Group::new(Mode::Constant, point)));
+    assert!(result.is_err());
+    Circuit::reset();
+}
+
+#[test]
+fn test_new_rejects_point_off_curve() {
+    let point = G1Affine::rand();
+    let point = G1Affine::new(point.x, point.y + Fp::ONE, false);
+    for mode in [Mode::Public, Mode::Private] {
+        Circuit::scope(format!("Off curve: {mode}"), || {
+            Group::new(mode, point);
+            assert!(!Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+    }
+}
+
+#[test]
+fn test_to_bits_le() {
+    for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+        for expected in [G1Affine::rand(), G1Affine::ZERO] {
+            let candidate = Group::new(mode, expected);
+            Circuit::scope(format!("ToBits: {mode}"), || {
+                let bits_le = candidate.to_bits_le();
+                assert_eq!(754, bits_le.len());
+                let (x, y) = match expected.infinity {
+                    true => (Fp::ZERO, Fp::ZERO),
+                    false => (expected.x, expected.y),
+                };
+                for (i, bit) in bits_le.iter().enumerate() {
+                    match i < 377 {
+                        true => assert_eq!(x.0.bit(i), bit.eject_value()),
+                        false => assert_eq!(y.0.bit(i - 377), bit.eject_value()),
+                    }
+                }
+                match mode {
+                    Mode::Constant => assert_scope!(0, 0, 0, 0),
+                    _ => assert_scope!(0, 0, 2042, 2046),
+                }
+            });
+            Circuit::reset();
+        }
+    }
+}
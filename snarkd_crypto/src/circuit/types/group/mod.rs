@@ -0,0 +1,166 @@
+mod add;
+mod double;
+mod equal;
+mod mul;
+mod neg;
+mod sub;
+mod ternary;
+
+use std::fmt;
+
+use crate::{
+    bls12_377::{Affine, Field as FieldTrait, Fp, G1Affine},
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        traits::{Eject, Inject, ToBits},
+        Environment,
+    },
+};
+
+use super::{Boolean, Field};
+
+/// A point of the BLS12-377 G1 curve `y^2 = x^3 + 1`, whose coordinates are in the field of the
+/// circuit. The point at infinity has zero coordinates, which are not those of any other point.
+#[derive(Clone)]
+pub struct Group {
+    x: Field,
+    y: Field,
+    infinity: Boolean,
+}
+
+impl Group {
+    /// Returns the point at infinity.
+    pub fn zero() -> Self {
+        Self {
+            x: Field::zero(),
+            y: Field::zero(),
+            infinity: Boolean::constant(true),
+        }
+    }
+
+    /// Returns the coordinates of the point, which are both zero at infinity.
+    pub fn to_xy_coordinates(&self) -> (Field, Field) {
+        (self.x.clone(), self.y.clone())
+    }
+
+    /// Returns `true` if the point is the point at infinity.
+    pub fn is_zero(&self) -> Boolean {
+        self.infinity.clone()
+    }
+
+    /// Initializes a point on the curve, which is not necessarily in the prime order subgroup.
+    fn witness(mode: Mode, point: G1Affine) -> Self {
+        let (x, y) = match point.infinity {
+            true => (Fp::ZERO, Fp::ZERO),
+            false => (point.x, point.y),
+        };
+        let x = Field::new(mode, x);
+        let y = Field::new(mode, y);
+        let infinity = Boolean::new(mode, point.infinity);
+
+        // Ensure the coordinates are zero at infinity, and that `y^2 = x^3 + 1` otherwise.
+        Circuit::enforce(|| (&infinity, &x, Circuit::zero()));
+        Circuit::enforce(|| (&infinity, &y, Circuit::zero()));
+        let x_cubed = &(&x * &x) * &x;
+        Circuit::enforce(|| {
+            (
+                Circuit::one() - &*infinity,
+                &(&y * &y) - &x_cubed - Field::one(),
+                Circuit::zero(),
+            )
+        });
+
+        Self { x, y, infinity }
+    }
+}
+
+impl Inject for Group {
+    type Primitive = G1Affine;
+
+    /// Initializes a group circuit from a console group element in the prime order subgroup.
+    fn new(mode: Mode, point: Self::Primitive) -> Self {
+        let group = Self::witness(mode, point);
+
+        if group.is_constant() {
+            if !point.is_in_correct_subgroup_assuming_on_curve() {
+                Circuit::halt("Group element is not in the prime order subgroup")
+            }
+            return group;
+        }
+
+        // Ensure the point is in the prime order subgroup, by witnessing a point that it is the
+        // cofactor multiple of. Every cofactor multiple of a point on the curve is in the subgroup.
+        let quotient = Self::witness(Mode::Private, point.mul_by_cofactor_inv());
+        let product = quotient.mul_by_cofactor();
+        Circuit::assert_eq(&group.x, &product.x);
+        Circuit::assert_eq(&group.y, &product.y);
+
+        group
+    }
+
+    /// Initializes a constant group circuit from a console group element, which is trusted to be
+    /// in the prime order subgroup.
+    fn constant(point: Self::Primitive) -> Self {
+        match point.infinity {
+            true => Self::zero(),
+            false => Self {
+                x: Field::from(Circuit::one() * point.x),
+                y: Field::from(Circuit::one() * point.y),
+                infinity: Boolean::constant(false),
+            },
+        }
+    }
+}
+
+impl Eject for Group {
+    type Primitive = G1Affine;
+
+    /// Ejects the mode of the group circuit.
+    fn eject_mode(&self) -> Mode {
+        Mode::combine(
+            self.x.eject_mode(),
+            [self.y.eject_mode(), self.infinity.eject_mode()],
+        )
+    }
+
+    /// Ejects the group circuit as a console group element.
+    fn eject_value(&self) -> Self::Primitive {
+        match self.infinity.eject_value() {
+            true => G1Affine::ZERO,
+            false => G1Affine::new(self.x.eject_value(), self.y.eject_value(), false),
+        }
+    }
+}
+
+impl ToBits for Group {
+    type Boolean = Boolean;
+
+    /// Returns the little-endian bits of the x-coordinate followed by those of the y-coordinate.
+    fn to_bits_le(&self) -> Vec<Self::Boolean> {
+        let mut bits_le = self.x.to_bits_le();
+        bits_le.extend(self.y.to_bits_le());
+        bits_le
+    }
+}
+
+impl fmt::Display for Group {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.eject_value(), self.eject_mode())
+    }
+}
+
+#[cfg(test)]
+#[path = ""]
+mod test {
+    use super::*;
+
+    mod add_tests;
+    mod double_tests;
+    mod equal_tests;
+    mod group_tests;
+    mod mul_tests;
+    mod neg_tests;
+    mod sub_tests;
+    mod ternary_tests;
+}
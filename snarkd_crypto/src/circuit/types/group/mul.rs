@@ -0,0 +1,105 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{
+    bls12_377::{G1Parameters, Parameters},
+    circuit::{
+        traits::{Eject, Inject, Ternary, ToBits},
+        types::{Boolean, Scalar},
+    },
+};
+
+#[cfg(test)]
+use crate::circuit::{
+    helpers::{Count, Mode},
+    traits::Metrics,
+};
+
+use super::Group;
+
+impl Group {
+    /// Returns `self` multiplied by the integer with the given little-endian bits, by doubling and
+    /// adding from the most significant bit.
+    pub fn mul_bits_le(&self, bits_le: &[Boolean]) -> Self {
+        let mut output = Group::zero();
+        for bit in bits_le.iter().rev() {
+            output = output.double();
+            output = match bit.is_constant() {
+                true => match bit.eject_value() {
+                    true => &output + self,
+                    false => output,
+                },
+                false => Group::ternary(bit, &(&output + self), &output),
+            };
+        }
+        output
+    }
+
+    /// Returns `self` multiplied by the cofactor of the curve.
+    pub(super) fn mul_by_cofactor(&self) -> Self {
+        let cofactor_le = G1Parameters::COFACTOR
+            .iter()
+            .flat_map(|limb| (0..64).map(move |i| Boolean::constant(limb >> i & 1 == 1)))
+            .collect::<Vec<_>>();
+        self.mul_bits_le(&cofactor_le)
+    }
+}
+
+impl Mul<Scalar> for Group {
+    type Output = Group;
+
+    fn mul(self, other: Scalar) -> Self::Output {
+        self * &other
+    }
+}
+
+impl Mul<&Scalar> for Group {
+    type Output = Group;
+
+    fn mul(self, other: &Scalar) -> Self::Output {
+        &self * other
+    }
+}
+
+impl Mul<Scalar> for &Group {
+    type Output = Group;
+
+    fn mul(self, other: Scalar) -> Self::Output {
+        self * &other
+    }
+}
+
+impl Mul<&Scalar> for &Group {
+    type Output = Group;
+
+    fn mul(self, other: &Scalar) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            return Group::constant((self.eject_value() * other.eject_value()).into());
+        }
+        self.mul_bits_le(&other.to_bits_le())
+    }
+}
+
+impl MulAssign<Scalar> for Group {
+    fn mul_assign(&mut self, other: Scalar) {
+        *self *= &other;
+    }
+}
+
+impl MulAssign<&Scalar> for Group {
+    fn mul_assign(&mut self, other: &Scalar) {
+        *self = &*self * other;
+    }
+}
+
+#[cfg(test)]
+impl Metrics<dyn Mul<Scalar, Output = Group>> for Group {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Count::is(0, 0, 0, 0),
+            // At most one doubling, addition and selection for every bit of the scalar.
+            (_, _) => Count::less_than(0, 0, 253 * 31, 253 * 34),
+        }
+    }
+}
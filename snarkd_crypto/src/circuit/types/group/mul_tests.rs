@@ -0,0 +1,101 @@
+use std::ops::Mul;
+
+use crate::{
+    assert_count, assert_scope,
+    bls12_377::{self, Affine, Field as FieldTrait, G1Affine},
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        traits::{Eject, Inject},
+        types::{Boolean, Scalar},
+        Environment,
+    },
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 2;
+
+fn check_mul(name: &str, point: G1Affine, scalar: bls12_377::Scalar, mode_a: Mode, mode_b: Mode) {
+    let expected = G1Affine::from(point * scalar);
+    let a = Group::new(mode_a, point);
+    let b = Scalar::new(mode_b, scalar);
+    Circuit::scope(name, || {
+        let candidate = &a * &b;
+        assert_eq!(expected, candidate.eject_value(), "({point} * {scalar})");
+        assert_count!(Mul(Group, Scalar) => Group, &(mode_a, mode_b));
+    });
+    Circuit::reset();
+}
+
+#[test]
+fn test_mul() {
+    let modes = [Mode::Constant, Mode::Public, Mode::Private];
+
+    for mode_a in modes {
+        for mode_b in modes {
+            for i in 0..ITERATIONS {
+                let name = format!("Mul: {mode_a} * {mode_b} {i}");
+                check_mul(
+                    &name,
+                    G1Affine::rand(),
+                    bls12_377::Scalar::rand(),
+                    mode_a,
+                    mode_b,
+                );
+            }
+
+            let name = format!("Mul: {mode_a} * {mode_b}");
+            let point = G1Affine::rand();
+            check_mul(
+                &format!("{name} by zero"),
+                point,
+                bls12_377::Scalar::ZERO,
+                mode_a,
+                mode_b,
+            );
+            check_mul(
+                &format!("{name} by one"),
+                point,
+                bls12_377::Scalar::ONE,
+                mode_a,
+                mode_b,
+            );
+            check_mul(
+                &format!("{name} by minus one"),
+                point,
+                -bls12_377::Scalar::ONE,
+                mode_a,
+                mode_b,
+            );
+            check_mul(
+                &format!("{name} zero"),
+                G1Affine::ZERO,
+                bls12_377::Scalar::rand(),
+                mode_a,
+                mode_b,
+            );
+        }
+    }
+}
+
+#[test]
+fn test_mul_bits_le() {
+    let point = G1Affine::rand();
+    let candidate = Group::new(Mode::Private, point);
+    for value in [0u64, 1, 2, 3, 5, 255] {
+        let bits_le = (0..8)
+            .map(|i| Boolean::new(Mode::Private, value >> i & 1 == 1))
+            .collect::<Vec<_>>();
+        Circuit::scope(format!("MulBits: {value}"), || {
+            let result = candidate.mul_bits_le(&bits_le);
+            assert_eq!(
+                G1Affine::from(point * bls12_377::Scalar::from(value)),
+                result.eject_value()
+            );
+            // The first bit selects the point without an addition.
+            assert_scope!(<=0, 0, 3 + 7 * 31, 3 + 7 * 34);
+        });
+    }
+    Circuit::reset();
+}
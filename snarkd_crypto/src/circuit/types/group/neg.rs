@@ -0,0 +1,40 @@
+use std::ops::Neg;
+
+#[cfg(test)]
+use crate::circuit::{
+    helpers::{Count, Mode},
+    traits::Metrics,
+};
+
+use super::Group;
+
+impl Neg for Group {
+    type Output = Group;
+
+    /// Returns the negation of the point, which is free to compute.
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl Neg for &Group {
+    type Output = Group;
+
+    /// Returns the negation of the point, which is free to compute.
+    fn neg(self) -> Self::Output {
+        Group {
+            x: self.x.clone(),
+            y: -&self.y,
+            infinity: self.infinity.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+impl Metrics<dyn Neg<Output = Group>> for Group {
+    type Case = Mode;
+
+    fn count(_case: &Self::Case) -> Count {
+        Count::is(0, 0, 0, 0)
+    }
+}
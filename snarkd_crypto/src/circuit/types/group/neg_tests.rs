@@ -0,0 +1,36 @@
+use std::ops::Neg;
+
+use crate::{
+    assert_count,
+    bls12_377::{Affine, G1Affine},
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        traits::{Eject, Inject},
+        Environment,
+    },
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 100;
+
+fn check_neg(name: &str, given: G1Affine, mode: Mode) {
+    let candidate = Group::new(mode, given);
+    Circuit::scope(name, || {
+        let result = -&candidate;
+        assert_eq!(-given, result.eject_value(), "(-{given})");
+        assert_count!(Neg(Group) => Group, &mode);
+    });
+    Circuit::reset();
+}
+
+#[test]
+fn test_neg() {
+    for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+        for i in 0..ITERATIONS {
+            check_neg(&format!("Neg: {mode} {i}"), G1Affine::rand(), mode);
+        }
+        check_neg(&format!("Neg: {mode} zero"), G1Affine::ZERO, mode);
+    }
+}
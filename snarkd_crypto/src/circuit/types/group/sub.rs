@@ -0,0 +1,63 @@
+use std::ops::{Sub, SubAssign};
+
+#[cfg(test)]
+use crate::circuit::{
+    helpers::{Count, Mode},
+    traits::Metrics,
+};
+
+use super::Group;
+
+impl Sub<Group> for Group {
+    type Output = Group;
+
+    fn sub(self, other: Group) -> Self::Output {
+        self - &other
+    }
+}
+
+impl Sub<&Group> for Group {
+    type Output = Group;
+
+    fn sub(self, other: &Group) -> Self::Output {
+        &self - other
+    }
+}
+
+impl Sub<Group> for &Group {
+    type Output = Group;
+
+    fn sub(self, other: Group) -> Self::Output {
+        self - &other
+    }
+}
+
+impl Sub<&Group> for &Group {
+    type Output = Group;
+
+    fn sub(self, other: &Group) -> Self::Output {
+        self + &-other
+    }
+}
+
+impl SubAssign<Group> for Group {
+    fn sub_assign(&mut self, other: Group) {
+        *self -= &other;
+    }
+}
+
+impl SubAssign<&Group> for Group {
+    fn sub_assign(&mut self, other: &Group) {
+        *self = &*self - other;
+    }
+}
+
+#[cfg(test)]
+impl Metrics<dyn Sub<Group, Output = Group>> for Group {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        // Negation is free, so subtraction costs as much as addition.
+        <Group as Metrics<dyn std::ops::Add<Group, Output = Group>>>::count(case)
+    }
+}
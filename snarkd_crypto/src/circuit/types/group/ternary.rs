@@ -0,0 +1,48 @@
+use crate::circuit::{
+    traits::{Eject, Ternary},
+    types::{Boolean, Field},
+};
+
+#[cfg(test)]
+use crate::circuit::{
+    helpers::{Count, Mode},
+    traits::Metrics,
+};
+
+use super::Group;
+
+impl Ternary for Group {
+    type Boolean = Boolean;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        // Constant `condition`
+        if condition.is_constant() {
+            return match condition.eject_value() {
+                true => first.clone(),
+                false => second.clone(),
+            };
+        }
+
+        Group {
+            x: Field::ternary(condition, &first.x, &second.x),
+            y: Field::ternary(condition, &first.y, &second.y),
+            infinity: Boolean::ternary(condition, &first.infinity, &second.infinity),
+        }
+    }
+}
+
+#[cfg(test)]
+impl Metrics<dyn Ternary<Boolean = Boolean, Output = Group>> for Group {
+    type Case = (Mode, Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            (Mode::Constant, _, _) => Count::is(0, 0, 0, 0),
+            // The infinity flags of two constant points select without a constraint.
+            (_, Mode::Constant, Mode::Constant) => Count::is(0, 0, 2, 2),
+            _ => Count::is(0, 0, 3, 3),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{
+    assert_count,
+    bls12_377::{Affine, G1Affine},
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        traits::{Eject, Inject, Ternary},
+        types::Boolean,
+        Environment,
+    },
+};
+
+use super::*;
+
+#[test]
+fn test_ternary() {
+    let modes = [Mode::Constant, Mode::Public, Mode::Private];
+
+    for mode_condition in modes {
+        for mode_a in modes {
+            for mode_b in modes {
+                for flag in [true, false] {
+                    for (first, second) in [
+                        (G1Affine::rand(), G1Affine::rand()),
+                        (G1Affine::ZERO, G1Affine::rand()),
+                        (G1Affine::rand(), G1Affine::ZERO),
+                    ] {
+                        let condition = Boolean::new(mode_condition, flag);
+                        let a = Group::new(mode_a, first);
+                        let b = Group::new(mode_b, second);
+
+                        let name = format!("Ternary: {mode_condition} ? {mode_a} : {mode_b}");
+                        Circuit::scope(name, || {
+                            let candidate = Group::ternary(&condition, &a, &b);
+                            let expected = if flag { first } else { second };
+                            assert_eq!(expected, candidate.eject_value());
+                            assert_count!(
+                                Ternary(Boolean, Group, Group) => Group,
+                                &(mode_condition, mode_a, mode_b)
+                            );
+                        });
+                        Circuit::reset();
+                    }
+                }
+            }
+        }
+    }
+}
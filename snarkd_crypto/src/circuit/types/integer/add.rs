@@ -0,0 +1,125 @@
+use std::ops::{Add, AddAssign};
+
+use crate::circuit::{
+    circuit::Circuit,
+    helpers::Mode,
+    traits::{AddChecked, AddWrapped, Eject, Inject},
+    Environment,
+};
+
+#[cfg(test)]
+use crate::circuit::{helpers::Count, traits::Metrics};
+
+use super::{decompose_le, Integer, IntegerType};
+
+impl<I: IntegerType> Add<Integer<I>> for Integer<I> {
+    type Output = Integer<I>;
+
+    fn add(self, other: Integer<I>) -> Self::Output {
+        self + &other
+    }
+}
+
+impl<I: IntegerType> Add<&Integer<I>> for Integer<I> {
+    type Output = Integer<I>;
+
+    fn add(self, other: &Integer<I>) -> Self::Output {
+        &self + other
+    }
+}
+
+impl<I: IntegerType> Add<Integer<I>> for &Integer<I> {
+    type Output = Integer<I>;
+
+    fn add(self, other: Integer<I>) -> Self::Output {
+        self + &other
+    }
+}
+
+impl<I: IntegerType> Add<&Integer<I>> for &Integer<I> {
+    type Output = Integer<I>;
+
+    fn add(self, other: &Integer<I>) -> Self::Output {
+        self.add_checked(other)
+    }
+}
+
+impl<I: IntegerType> AddAssign<Integer<I>> for Integer<I> {
+    fn add_assign(&mut self, other: Integer<I>) {
+        *self += &other;
+    }
+}
+
+impl<I: IntegerType> AddAssign<&Integer<I>> for Integer<I> {
+    fn add_assign(&mut self, other: &Integer<I>) {
+        *self = self.add_checked(other);
+    }
+}
+
+impl<I: IntegerType> AddChecked<Self> for Integer<I> {
+    type Output = Self;
+
+    fn add_checked(&self, other: &Self) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            return match self.eject_value().checked_add(other.eject_value()) {
+                Some(sum) => Integer::constant(sum),
+                None => Circuit::halt("Integer overflow on addition of two constants"),
+            };
+        }
+
+        // On overflow, the witness is the wrapped sum, which the constraint below rejects.
+        let sum = Integer::new(
+            Mode::Private,
+            self.eject_value().wrapping_add(other.eject_value()),
+        );
+
+        // Ensure `self + other == sum`. Both sides are far smaller than the modulus,
+        // so this only holds if the sum is in range.
+        Circuit::assert_eq(self.to_lc() + other.to_lc(), sum.to_lc());
+
+        sum
+    }
+}
+
+impl<I: IntegerType> AddWrapped<Self> for Integer<I> {
+    type Output = Self;
+
+    fn add_wrapped(&self, other: &Self) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            return Integer::constant(self.eject_value().wrapping_add(other.eject_value()));
+        }
+
+        // The sum of the unsigned values has one more bit, the carry, which is dropped.
+        // Two's complement makes this correct for signed integers as well.
+        let sum = self.to_unsigned_lc() + other.to_unsigned_lc();
+        let bits_le = decompose_le(&sum, I::BITS + 1);
+
+        Integer::from_bits_le(&bits_le[..I::BITS])
+    }
+}
+
+#[cfg(test)]
+impl<I: IntegerType> Metrics<dyn AddChecked<Integer<I>, Output = Integer<I>>> for Integer<I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let n = I::BITS as u64;
+        match case.0.is_constant() && case.1.is_constant() {
+            true => Count::is(0, 0, 0, 0),
+            false => Count::is(0, 0, n, n + 1),
+        }
+    }
+}
+
+#[cfg(test)]
+impl<I: IntegerType> Metrics<dyn AddWrapped<Integer<I>, Output = Integer<I>>> for Integer<I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let n = I::BITS as u64;
+        match case.0.is_constant() && case.1.is_constant() {
+            true => Count::is(0, 0, 0, 0),
+            false => Count::is(0, 0, n + 1, n + 2),
+        }
+    }
+}
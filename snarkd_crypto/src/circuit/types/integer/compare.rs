@@ -0,0 +1,60 @@
+use crate::circuit::{
+    circuit::Circuit,
+    traits::{Compare, Eject, Inject},
+    types::Boolean,
+    Environment,
+};
+
+#[cfg(test)]
+use crate::circuit::{
+    helpers::{Count, Mode},
+    traits::Metrics,
+};
+
+use super::{decompose_le, two_pow, Integer, IntegerType};
+
+impl<I: IntegerType> Compare<Self> for Integer<I> {
+    type Output = Boolean;
+
+    /// Returns `true` if `self` is less than `other`.
+    fn is_less_than(&self, other: &Self) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            return Boolean::constant(self.eject_value() < other.eject_value());
+        }
+
+        // `self - other + 2^BITS` is never negative and has `BITS + 1` bits, the last of which is
+        // set exactly when `self >= other`.
+        let difference = self.to_lc() - other.to_lc() + Circuit::one() * two_pow(I::BITS);
+        let bits_le = decompose_le(&difference, I::BITS + 1);
+
+        !&bits_le[I::BITS]
+    }
+
+    /// Returns `true` if `self` is greater than `other`.
+    fn is_greater_than(&self, other: &Self) -> Self::Output {
+        other.is_less_than(self)
+    }
+
+    /// Returns `true` if `self` is less than or equal to `other`.
+    fn is_less_than_or_equal(&self, other: &Self) -> Self::Output {
+        other.is_greater_than_or_equal(self)
+    }
+
+    /// Returns `true` if `self` is greater than or equal to `other`.
+    fn is_greater_than_or_equal(&self, other: &Self) -> Self::Output {
+        !self.is_less_than(other)
+    }
+}
+
+#[cfg(test)]
+impl<I: IntegerType> Metrics<dyn Compare<Integer<I>, Output = Boolean>> for Integer<I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let n = I::BITS as u64;
+        match case.0.is_constant() && case.1.is_constant() {
+            true => Count::is(0, 0, 0, 0),
+            false => Count::is(0, 0, n + 1, n + 2),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    assert_count,
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        rng_test_struct::{TestRng, Uniform},
+        traits::{Compare, Eject, Inject},
+        types::Boolean,
+        Environment,
+    },
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 32;
+
+fn check_compare<I: IntegerType>(name: &str, first: I, second: I, mode_a: Mode, mode_b: Mode) {
+    let a = Integer::<I>::new(mode_a, first);
+    let b = Integer::<I>::new(mode_b, second);
+    let case = (mode_a, mode_b);
+
+    Circuit::scope(format!("{name} is_less_than"), || {
+        let candidate = a.is_less_than(&b);
+        assert_eq!(
+            first < second,
+            candidate.eject_value(),
+            "({first} < {second})"
+        );
+        assert_count!(Integer<I>, Compare<Integer<I>, Output = Boolean>, &case);
+    });
+    Circuit::scope(format!("{name} is_greater_than"), || {
+        let candidate = a.is_greater_than(&b);
+        assert_eq!(
+            first > second,
+            candidate.eject_value(),
+            "({first} > {second})"
+        );
+        assert_count!(Integer<I>, Compare<Integer<I>, Output = Boolean>, &case);
+    });
+    Circuit::scope(format!("{name} is_less_than_or_equal"), || {
+        let candidate = a.is_less_than_or_equal(&b);
+        assert_eq!(
+            first <= second,
+            candidate.eject_value(),
+            "({first} <= {second})"
+        );
+        assert_count!(Integer<I>, Compare<Integer<I>, Output = Boolean>, &case);
+    });
+    Circuit::scope(format!("{name} is_greater_than_or_equal"), || {
+        let candidate = a.is_greater_than_or_equal(&b);
+        assert_eq!(
+            first >= second,
+            candidate.eject_value(),
+            "({first} >= {second})"
+        );
+        assert_count!(Integer<I>, Compare<Integer<I>, Output = Boolean>, &case);
+    });
+    Circuit::reset();
+}
+
+fn run_test<I: IntegerType + Uniform>() {
+    let mut rng = TestRng::default();
+
+    for mode_a in MODES {
+        for mode_b in MODES {
+            for i in 0..ITERATIONS {
+                let name = format!("Compare: {mode_a} {mode_b} {i}");
+                check_compare(&name, I::rand(&mut rng), I::rand(&mut rng), mode_a, mode_b);
+            }
+
+            for first in edge_cases::<I>() {
+                for second in edge_cases::<I>() {
+                    let name = format!("Compare: {first} {second}");
+                    check_compare(&name, first, second, mode_a, mode_b);
+                }
+            }
+        }
+    }
+}
+
+test_integer_types!(run_test);
@@ -0,0 +1,285 @@
+use std::ops::{Div, DivAssign};
+
+use crate::{
+    bls12_377::Fp,
+    circuit::{
+        circuit::Circuit,
+        helpers::{LinearCombination, Mode},
+        traits::{DivChecked, DivWrapped, Eject, Inject},
+        types::Boolean,
+        Environment,
+    },
+};
+
+#[cfg(test)]
+use crate::circuit::{helpers::Count, traits::Metrics};
+
+use super::{bits_to_lc, decompose_le, two_pow, witness_bits_le, Integer, IntegerType};
+
+impl<I: IntegerType> Div<Integer<I>> for Integer<I> {
+    type Output = Integer<I>;
+
+    fn div(self, other: Integer<I>) -> Self::Output {
+        self / &other
+    }
+}
+
+impl<I: IntegerType> Div<&Integer<I>> for Integer<I> {
+    type Output = Integer<I>;
+
+    fn div(self, other: &Integer<I>) -> Self::Output {
+        &self / other
+    }
+}
+
+impl<I: IntegerType> Div<Integer<I>> for &Integer<I> {
+    type Output = Integer<I>;
+
+    fn div(self, other: Integer<I>) -> Self::Output {
+        self / &other
+    }
+}
+
+impl<I: IntegerType> Div<&Integer<I>> for &Integer<I> {
+    type Output = Integer<I>;
+
+    fn div(self, other: &Integer<I>) -> Self::Output {
+        self.div_checked(other)
+    }
+}
+
+impl<I: IntegerType> DivAssign<Integer<I>> for Integer<I> {
+    fn div_assign(&mut self, other: Integer<I>) {
+        *self /= &other;
+    }
+}
+
+impl<I: IntegerType> DivAssign<&Integer<I>> for Integer<I> {
+    fn div_assign(&mut self, other: &Integer<I>) {
+        *self = self.div_checked(other);
+    }
+}
+
+impl<I: IntegerType> Integer<I> {
+    /// Returns the magnitude of the integer as unsigned bits. The magnitude of the minimum signed
+    /// integer is `2^(BITS - 1)`, which only fits as an unsigned integer.
+    fn abs_unsigned(&self) -> Vec<Boolean> {
+        if !I::IS_SIGNED {
+            return self.bits_le.clone();
+        }
+
+        let value = self.to_unsigned_lc();
+        let msb = self.msb();
+        let abs = match msb.eject_value() {
+            true => two_pow(I::BITS) - value.value(),
+            false => value.value(),
+        };
+        if self.is_constant() {
+            return (0..I::BITS)
+                .map(|i| Boolean::constant(abs.0.bit(i)))
+                .collect();
+        }
+        let bits_le = witness_bits_le(abs, I::BITS);
+
+        //
+        // Magnitude Enforcement
+        // -------------------------------------------------------
+        //    abs = msb * (2^BITS - value) + (1 - msb) * value
+        // => msb * (2^BITS - 2 * value) = abs - value
+        //
+        Circuit::enforce(|| {
+            (
+                msb,
+                Circuit::one() * two_pow(I::BITS) - &value - &value,
+                bits_to_lc(&bits_le, false) - &value,
+            )
+        });
+
+        bits_le
+    }
+
+    /// Returns the quotient and remainder of the magnitudes of `self` and `other`, as unsigned bits.
+    /// The circuit is not satisfied if `other` is zero.
+    pub(super) fn div_rem_unsigned(&self, other: &Self) -> (Vec<Boolean>, Vec<Boolean>) {
+        let dividend = bits_to_lc(&self.abs_unsigned(), false);
+        let divisor = bits_to_lc(&other.abs_unsigned(), false);
+
+        let (quotient, remainder) = match (to_u128(dividend.value()), to_u128(divisor.value())) {
+            (_, 0) => (0, 0),
+            (dividend, divisor) => (dividend / divisor, dividend % divisor),
+        };
+        let quotient = witness_bits_le(Fp::from(quotient), I::BITS);
+        let remainder = witness_bits_le(Fp::from(remainder), I::BITS);
+        let remainder_lc = bits_to_lc(&remainder, false);
+
+        // Ensure `divisor * quotient == dividend - remainder`.
+        Circuit::enforce(|| {
+            (
+                divisor.clone(),
+                bits_to_lc(&quotient, false),
+                &dividend - &remainder_lc,
+            )
+        });
+        // Ensure `remainder < divisor`, which also rules out a zero divisor, by decomposing
+        // `divisor - remainder - 1`, which is negative otherwise.
+        decompose_le(&(divisor - remainder_lc - Circuit::one()), I::BITS);
+
+        (quotient, remainder)
+    }
+}
+
+/// Returns the low 128 bits of `value`.
+fn to_u128(value: Fp) -> u128 {
+    let limbs = value.0.as_limbs();
+    limbs[0] as u128 | (limbs[1] as u128) << 64
+}
+
+/// Returns `1 - 2 * bit`, which is `-1` if `bit` is set and `1` otherwise.
+pub(super) fn sign_lc(bit: &Boolean) -> LinearCombination {
+    Circuit::one() - &**bit - &**bit
+}
+
+impl<I: IntegerType> DivChecked<Self> for Integer<I> {
+    type Output = Self;
+
+    fn div_checked(&self, other: &Self) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            return match self.eject_value().checked_div(other.eject_value()) {
+                Some(quotient) => Integer::constant(quotient),
+                None => Circuit::halt("Division by zero or overflow on two constants"),
+            };
+        }
+
+        let (quotient, _) = self.div_rem_unsigned(other);
+        if !I::IS_SIGNED {
+            return Integer::from_bits_le(&quotient);
+        }
+
+        // The quotient is negative if exactly one of the operands is.
+        let is_negative = self.msb() ^ other.msb();
+        let (this, that) = (self.eject_value(), other.eject_value());
+        let signed_quotient = Integer::new(
+            Mode::Private,
+            match that == I::ZERO {
+                true => I::ZERO,
+                false => this.wrapping_div(that),
+            },
+        );
+
+        // Ensure `(1 - 2 * is_negative) * quotient == signed_quotient`. Dividing the minimum
+        // integer by `-1` gives `2^(BITS - 1)`, which no signed integer is equal to.
+        Circuit::enforce(|| {
+            (
+                sign_lc(&is_negative),
+                bits_to_lc(&quotient, false),
+                signed_quotient.to_lc(),
+            )
+        });
+
+        signed_quotient
+    }
+}
+
+impl<I: IntegerType> DivWrapped<Self> for Integer<I> {
+    type Output = Self;
+
+    fn div_wrapped(&self, other: &Self) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            return match other.eject_value() == I::ZERO {
+                true => Circuit::halt("Division by zero on two constants"),
+                false => Integer::constant(self.eject_value().wrapping_div(other.eject_value())),
+            };
+        }
+
+        let (quotient, _) = self.div_rem_unsigned(other);
+        if !I::IS_SIGNED {
+            return Integer::from_bits_le(&quotient);
+        }
+
+        // Decompose the signed quotient offset by `2^BITS`, which is never negative, and drop that
+        // bit, so that dividing the minimum integer by `-1` wraps around to the minimum integer.
+        let is_negative = self.msb() ^ other.msb();
+        let quotient = bits_to_lc(&quotient, false);
+        let offset = two_pow(I::BITS);
+        let bits_le = witness_bits_le(
+            match is_negative.eject_value() {
+                true => offset - quotient.value(),
+                false => offset + quotient.value(),
+            },
+            I::BITS + 1,
+        );
+
+        // Ensure `(1 - 2 * is_negative) * quotient == bits - 2^BITS`.
+        Circuit::enforce(|| {
+            (
+                sign_lc(&is_negative),
+                quotient,
+                bits_to_lc(&bits_le, false) - Circuit::one() * offset,
+            )
+        });
+
+        Integer::from_bits_le(&bits_le[..I::BITS])
+    }
+}
+
+/// Returns the count of the division of the magnitudes of two integers with the given modes,
+/// which is shared by division and remainder.
+#[cfg(test)]
+pub(super) fn div_rem_unsigned_count<I: IntegerType>(case: &(Mode, Mode)) -> Count {
+    let n = I::BITS as u64;
+    // The quotient, the remainder, and the range check of the remainder.
+    let mut count = Count::is(0, 0, 3 * n, 3 * n + 2);
+    if I::IS_SIGNED {
+        for mode in [case.0, case.1] {
+            if !mode.is_constant() {
+                count = count + Count::is(0, 0, n, n + 1);
+            }
+        }
+    }
+    count
+}
+
+/// Returns the count of the sign of the quotient of two signed integers with the given modes.
+#[cfg(test)]
+pub(super) fn quotient_sign_count(case: &(Mode, Mode)) -> Count {
+    match case.0.is_constant() || case.1.is_constant() {
+        true => Count::is(0, 0, 0, 0),
+        false => Count::is(0, 0, 1, 1),
+    }
+}
+
+#[cfg(test)]
+impl<I: IntegerType> Metrics<dyn DivChecked<Integer<I>, Output = Integer<I>>> for Integer<I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let n = I::BITS as u64;
+        match (case.0.is_constant() && case.1.is_constant(), I::IS_SIGNED) {
+            (true, _) => Count::is(0, 0, 0, 0),
+            (false, false) => div_rem_unsigned_count::<I>(case),
+            (false, true) => {
+                div_rem_unsigned_count::<I>(case)
+                    + quotient_sign_count(case)
+                    + Count::is(0, 0, n, n + 1)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+impl<I: IntegerType> Metrics<dyn DivWrapped<Integer<I>, Output = Integer<I>>> for Integer<I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let n = I::BITS as u64;
+        match (case.0.is_constant() && case.1.is_constant(), I::IS_SIGNED) {
+            (true, _) => Count::is(0, 0, 0, 0),
+            (false, false) => div_rem_unsigned_count::<I>(case),
+            (false, true) => {
+                div_rem_unsigned_count::<I>(case)
+                    + quotient_sign_count(case)
+                    + Count::is(0, 0, n + 1, n + 2)
+            }
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{
+    assert_scope,
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        rng_test_struct::{TestRng, Uniform},
+        traits::{Eject, Inject, ToBits},
+        types::Boolean,
+        Environment,
+    },
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 32;
+
+fn check_new<I: IntegerType>(mode: Mode, value: I) {
+    let n = I::BITS as u64;
+    Circuit::scope(format!("New: {mode} {value}"), || {
+        let candidate = Integer::<I>::new(mode, value);
+        assert_eq!(value, candidate.eject_value());
+        assert_eq!(mode, candidate.eject_mode());
+        match mode {
+            Mode::Constant => assert_scope!(n, 0, 0, 0),
+            Mode::Public => assert_scope!(0, n, 0, n),
+            Mode::Private => assert_scope!(0, 0, n, n),
+        }
+    });
+    Circuit::reset();
+}
+
+fn run_test<I: IntegerType + Uniform>() {
+    let mut rng = TestRng::default();
+
+    for mode in MODES {
+        for _ in 0..ITERATIONS {
+            check_new(mode, I::rand(&mut rng));
+        }
+        for value in edge_cases::<I>() {
+            check_new(mode, value);
+        }
+    }
+
+    for value in edge_cases::<I>() {
+        Circuit::scope(format!("Constant: {value}"), || {
+            let candidate = Integer::<I>::constant(value);
+            assert_eq!(value, candidate.eject_value());
+            assert!(candidate.is_constant());
+            assert_scope!(0, 0, 0, 0);
+        });
+
+        Circuit::scope(format!("Bits: {value}"), || {
+            let candidate = Integer::<I>::new(Mode::Private, value);
+            let bits_le = candidate.to_bits_le();
+            assert_eq!(I::BITS, bits_le.len());
+            assert_eq!(value, Integer::<I>::from_bits_le(&bits_le).eject_value());
+        });
+        Circuit::reset();
+    }
+}
+
+#[test]
+fn test_from_bits_le_wrong_length() {
+    let bits_le = vec![Boolean::constant(false); 7];
+    check_halts(|| U8::from_bits_le(&bits_le));
+}
+
+test_integer_types!(run_test);
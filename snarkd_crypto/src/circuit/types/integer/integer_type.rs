@@ -0,0 +1,147 @@
+use std::fmt;
+
+/// A primitive integer type, with the native operations its circuit counterpart is checked
+/// against.
+pub trait IntegerType: 'static + Copy + fmt::Debug + fmt::Display + Eq + Ord {
+    /// The number of bits in the integer.
+    const BITS: usize;
+    /// Whether the integer is signed, in two's complement.
+    const IS_SIGNED: bool;
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Returns the bits of the integer, sign-extended to 128 bits.
+    fn to_u128(self) -> u128;
+
+    /// Returns the integer made of the low `BITS` bits of `value`.
+    fn from_u128(value: u128) -> Self;
+
+    /// Returns the little-endian bits of the integer.
+    fn to_bits_le(self) -> Vec<bool> {
+        let value = self.to_u128();
+        (0..Self::BITS).map(|i| (value >> i) & 1 == 1).collect()
+    }
+
+    /// Returns the integer with the given little-endian bits.
+    fn from_bits_le(bits_le: &[bool]) -> Self {
+        Self::from_u128(
+            bits_le
+                .iter()
+                .rev()
+                .fold(0, |value, bit| (value << 1) | *bit as u128),
+        )
+    }
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn wrapping_add(self, other: Self) -> Self;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn wrapping_sub(self, other: Self) -> Self;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn wrapping_mul(self, other: Self) -> Self;
+    fn checked_div(self, other: Self) -> Option<Self>;
+    fn wrapping_div(self, other: Self) -> Self;
+    fn checked_rem(self, other: Self) -> Option<Self>;
+    fn wrapping_rem(self, other: Self) -> Self;
+    fn checked_pow(self, exponent: u32) -> Option<Self>;
+    fn wrapping_pow(self, exponent: u32) -> Self;
+    fn checked_shl(self, shift: u32) -> Option<Self>;
+    fn wrapping_shl(self, shift: u32) -> Self;
+    fn checked_shr(self, shift: u32) -> Option<Self>;
+    fn wrapping_shr(self, shift: u32) -> Self;
+}
+
+macro_rules! impl_integer_type {
+    ($($type_:ty => $is_signed:expr),* $(,)?) => {$(
+        impl IntegerType for $type_ {
+            const BITS: usize = <$type_>::BITS as usize;
+            const IS_SIGNED: bool = $is_signed;
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn to_u128(self) -> u128 {
+                self as u128
+            }
+
+            fn from_u128(value: u128) -> Self {
+                value as Self
+            }
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$type_>::checked_add(self, other)
+            }
+
+            fn wrapping_add(self, other: Self) -> Self {
+                <$type_>::wrapping_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$type_>::checked_sub(self, other)
+            }
+
+            fn wrapping_sub(self, other: Self) -> Self {
+                <$type_>::wrapping_sub(self, other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$type_>::checked_mul(self, other)
+            }
+
+            fn wrapping_mul(self, other: Self) -> Self {
+                <$type_>::wrapping_mul(self, other)
+            }
+
+            fn checked_div(self, other: Self) -> Option<Self> {
+                <$type_>::checked_div(self, other)
+            }
+
+            fn wrapping_div(self, other: Self) -> Self {
+                <$type_>::wrapping_div(self, other)
+            }
+
+            fn checked_rem(self, other: Self) -> Option<Self> {
+                <$type_>::checked_rem(self, other)
+            }
+
+            fn wrapping_rem(self, other: Self) -> Self {
+                <$type_>::wrapping_rem(self, other)
+            }
+
+            fn checked_pow(self, exponent: u32) -> Option<Self> {
+                <$type_>::checked_pow(self, exponent)
+            }
+
+            fn wrapping_pow(self, exponent: u32) -> Self {
+                <$type_>::wrapping_pow(self, exponent)
+            }
+
+            fn checked_shl(self, shift: u32) -> Option<Self> {
+                <$type_>::checked_shl(self, shift)
+            }
+
+            fn wrapping_shl(self, shift: u32) -> Self {
+                <$type_>::wrapping_shl(self, shift)
+            }
+
+            fn checked_shr(self, shift: u32) -> Option<Self> {
+                <$type_>::checked_shr(self, shift)
+            }
+
+            fn wrapping_shr(self, shift: u32) -> Self {
+                <$type_>::wrapping_shr(self, shift)
+            }
+        }
+    )*};
+}
+
+impl_integer_type!(
+    u8 => false,
+    u16 => false,
+    u32 => false,
+    u64 => false,
+    u128 => false,
+    i8 => true,
+    i16 => true,
+    i32 => true,
+    i64 => true,
+    i128 => true,
+);
@@ -0,0 +1,304 @@
+mod add;
+mod and;
+mod compare;
+mod div;
+mod equal;
+mod integer_type;
+pub use integer_type::*;
+mod mul;
+mod not;
+mod or;
+mod pow;
+mod rem;
+mod shl;
+mod shr;
+mod sub;
+mod ternary;
+mod xor;
+
+use std::{fmt, marker::PhantomData};
+
+use ruint::Uint;
+
+use crate::{
+    bls12_377::Fp,
+    circuit::{
+        circuit::Circuit,
+        helpers::{LinearCombination, Mode},
+        traits::{Eject, Inject, ToBits},
+        Environment,
+    },
+};
+
+use super::Boolean;
+
+pub type I8 = Integer<i8>;
+pub type I16 = Integer<i16>;
+pub type I32 = Integer<i32>;
+pub type I64 = Integer<i64>;
+pub type I128 = Integer<i128>;
+pub type U8 = Integer<u8>;
+pub type U16 = Integer<u16>;
+pub type U32 = Integer<u32>;
+pub type U64 = Integer<u64>;
+pub type U128 = Integer<u128>;
+
+#[derive(Clone)]
+pub struct Integer<I: IntegerType> {
+    /// The little-endian bits of the integer, in two's complement if it is signed.
+    bits_le: Vec<Boolean>,
+    phantom: PhantomData<I>,
+}
+
+impl<I: IntegerType> Integer<I> {
+    /// Initializes an integer from its little-endian bits.
+    pub fn from_bits_le(bits_le: &[Boolean]) -> Self {
+        if bits_le.len() != I::BITS {
+            Circuit::halt(format!(
+                "Expected {} bits for an integer, found {}",
+                I::BITS,
+                bits_le.len()
+            ))
+        }
+        Self {
+            bits_le: bits_le.to_vec(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the most significant bit, which is the sign bit of a signed integer.
+    fn msb(&self) -> &Boolean {
+        &self.bits_le[I::BITS - 1]
+    }
+
+    /// Returns the value of the integer, which is negative for negative signed integers.
+    fn to_lc(&self) -> LinearCombination {
+        bits_to_lc(&self.bits_le, I::IS_SIGNED)
+    }
+
+    /// Returns the value of the bits of the integer, read as an unsigned integer.
+    fn to_unsigned_lc(&self) -> LinearCombination {
+        bits_to_lc(&self.bits_le, false)
+    }
+}
+
+/// Returns `2^exponent`.
+fn two_pow(exponent: usize) -> Fp {
+    Fp(Uint::from(1u64) << exponent)
+}
+
+/// Returns the value of little-endian bits, where the most significant bit is negative if `signed`.
+fn bits_to_lc(bits_le: &[Boolean], signed: bool) -> LinearCombination {
+    bits_le
+        .iter()
+        .enumerate()
+        .fold(Circuit::zero(), |lc, (i, bit)| {
+            let coefficient = match signed && i + 1 == bits_le.len() {
+                true => -two_pow(i),
+                false => two_pow(i),
+            };
+            lc + &**bit * coefficient
+        })
+}
+
+/// Witnesses the low `num_bits` bits of `value` as private booleans. The caller enforces that they
+/// make up `value`.
+fn witness_bits_le(value: Fp, num_bits: usize) -> Vec<Boolean> {
+    (0..num_bits)
+        .map(|i| Boolean::new(Mode::Private, value.0.bit(i)))
+        .collect()
+}
+
+/// Returns the low `num_bits` bits of `value`, decomposed in the circuit. `value` must be less than
+/// `2^num_bits`, or the circuit is not satisfied.
+fn decompose_le(value: &LinearCombination, num_bits: usize) -> Vec<Boolean> {
+    let bits_le = witness_bits_le(value.value(), num_bits);
+    Circuit::assert_eq(value.clone(), bits_to_lc(&bits_le, false));
+    bits_le
+}
+
+impl<I: IntegerType> Inject for Integer<I> {
+    type Primitive = I;
+
+    /// Initializes an integer circuit from a primitive integer.
+    fn new(mode: Mode, value: Self::Primitive) -> Self {
+        Self {
+            bits_le: value
+                .to_bits_le()
+                .into_iter()
+                .map(|bit| Boolean::new(mode, bit))
+                .collect(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Initializes a constant integer circuit from a primitive integer.
+    fn constant(value: Self::Primitive) -> Self {
+        Self {
+            bits_le: value
+                .to_bits_le()
+                .into_iter()
+                .map(Boolean::constant)
+                .collect(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I: IntegerType> Eject for Integer<I> {
+    type Primitive = I;
+
+    /// Ejects the mode of the integer circuit.
+    fn eject_mode(&self) -> Mode {
+        self.bits_le.eject_mode()
+    }
+
+    /// Ejects the integer circuit as a primitive integer.
+    fn eject_value(&self) -> Self::Primitive {
+        I::from_bits_le(&self.bits_le.eject_value())
+    }
+}
+
+impl<I: IntegerType> ToBits for Integer<I> {
+    type Boolean = Boolean;
+
+    /// Returns the little-endian bits of the integer.
+    fn to_bits_le(&self) -> Vec<Self::Boolean> {
+        self.bits_le.clone()
+    }
+}
+
+impl<I: IntegerType> fmt::Debug for Integer<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<I: IntegerType> fmt::Display for Integer<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}{}.{}",
+            self.eject_value(),
+            std::any::type_name::<I>(),
+            self.eject_mode()
+        )
+    }
+}
+
+/// Expands to a test for every integer type, each calling `$run::<I>()`.
+#[cfg(test)]
+macro_rules! test_integer_types {
+    ($run:ident) => {
+        #[test]
+        fn test_u8() {
+            $run::<u8>();
+        }
+
+        #[test]
+        fn test_u16() {
+            $run::<u16>();
+        }
+
+        #[test]
+        fn test_u32() {
+            $run::<u32>();
+        }
+
+        #[test]
+        fn test_u64() {
+            $run::<u64>();
+        }
+
+        #[test]
+        fn test_u128() {
+            $run::<u128>();
+        }
+
+        #[test]
+        fn test_i8() {
+            $run::<i8>();
+        }
+
+        #[test]
+        fn test_i16() {
+            $run::<i16>();
+        }
+
+        #[test]
+        fn test_i32() {
+            $run::<i32>();
+        }
+
+        #[test]
+        fn test_i64() {
+            $run::<i64>();
+        }
+
+        #[test]
+        fn test_i128() {
+            $run::<i128>();
+        }
+    };
+}
+
+#[cfg(test)]
+#[path = ""]
+mod test {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use crate::circuit::rng_test_struct::{TestRng, Uniform};
+
+    use super::*;
+
+    /// Every mode an integer can be injected with.
+    const MODES: [Mode; 3] = [Mode::Constant, Mode::Public, Mode::Private];
+
+    /// Returns a random integer of at most `BITS / 2 - 1` bits, negated at random if it is signed,
+    /// so that the product of two of them does not overflow.
+    fn small<I: IntegerType>(rng: &mut TestRng) -> I {
+        let magnitude = I::from_u128(u128::rand(rng) >> (129 - I::BITS / 2));
+        match I::IS_SIGNED && bool::rand(rng) {
+            true => I::ZERO.wrapping_sub(magnitude),
+            false => magnitude,
+        }
+    }
+
+    /// Returns the integers at the edges of the range of `I`, and `-1` if it is signed.
+    fn edge_cases<I: IntegerType>() -> [I; 6] {
+        let min = match I::IS_SIGNED {
+            true => I::from_u128(1 << (I::BITS - 1)),
+            false => I::ZERO,
+        };
+        [
+            min,
+            min.wrapping_add(I::ONE),
+            I::ZERO,
+            I::ONE,
+            I::from_u128(u128::MAX),
+            min.wrapping_sub(I::ONE),
+        ]
+    }
+
+    /// Asserts that `operation` halts the circuit.
+    fn check_halts<T>(operation: impl FnOnce() -> T) {
+        assert!(catch_unwind(AssertUnwindSafe(operation)).is_err());
+    }
+
+    mod add_tests;
+    mod and_tests;
+    mod compare_tests;
+    mod div_tests;
+    mod equal_tests;
+    mod integer_tests;
+    mod mul_tests;
+    mod not_tests;
+    mod or_tests;
+    mod pow_tests;
+    mod rem_tests;
+    mod shl_tests;
+    mod shr_tests;
+    mod sub_tests;
+    mod ternary_tests;
+    mod xor_tests;
+}
@@ -0,0 +1,126 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::circuit::{
+    circuit::Circuit,
+    helpers::Mode,
+    traits::{Eject, Inject, MulChecked, MulWrapped},
+    Environment,
+};
+
+#[cfg(test)]
+use crate::circuit::{helpers::Count, traits::Metrics};
+
+use super::{bits_to_lc, witness_bits_le, Integer, IntegerType};
+
+impl<I: IntegerType> Mul<Integer<I>> for Integer<I> {
+    type Output = Integer<I>;
+
+    fn mul(self, other: Integer<I>) -> Self::Output {
+        self * &other
+    }
+}
+
+impl<I: IntegerType> Mul<&Integer<I>> for Integer<I> {
+    type Output = Integer<I>;
+
+    fn mul(self, other: &Integer<I>) -> Self::Output {
+        &self * other
+    }
+}
+
+impl<I: IntegerType> Mul<Integer<I>> for &Integer<I> {
+    type Output = Integer<I>;
+
+    fn mul(self, other: Integer<I>) -> Self::Output {
+        self * &other
+    }
+}
+
+impl<I: IntegerType> Mul<&Integer<I>> for &Integer<I> {
+    type Output = Integer<I>;
+
+    fn mul(self, other: &Integer<I>) -> Self::Output {
+        self.mul_checked(other)
+    }
+}
+
+impl<I: IntegerType> MulAssign<Integer<I>> for Integer<I> {
+    fn mul_assign(&mut self, other: Integer<I>) {
+        *self *= &other;
+    }
+}
+
+impl<I: IntegerType> MulAssign<&Integer<I>> for Integer<I> {
+    fn mul_assign(&mut self, other: &Integer<I>) {
+        *self = self.mul_checked(other);
+    }
+}
+
+impl<I: IntegerType> MulChecked<Self> for Integer<I> {
+    type Output = Self;
+
+    fn mul_checked(&self, other: &Self) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            return match self.eject_value().checked_mul(other.eject_value()) {
+                Some(product) => Integer::constant(product),
+                None => Circuit::halt("Integer overflow on multiplication of two constants"),
+            };
+        }
+
+        // On overflow, the witness is the wrapped product, which the constraint below rejects.
+        let product = Integer::new(
+            Mode::Private,
+            self.eject_value().wrapping_mul(other.eject_value()),
+        );
+
+        // Ensure `self * other == product`. The product of two integers has at most twice as many
+        // bits, which is still far smaller than the modulus, so this only holds if it is in range.
+        Circuit::enforce(|| (self.to_lc(), other.to_lc(), product.to_lc()));
+
+        product
+    }
+}
+
+impl<I: IntegerType> MulWrapped<Self> for Integer<I> {
+    type Output = Self;
+
+    fn mul_wrapped(&self, other: &Self) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            return Integer::constant(self.eject_value().wrapping_mul(other.eject_value()));
+        }
+
+        // Decompose the product of the unsigned values, which has twice as many bits, and keep
+        // the low half. Two's complement makes this correct for signed integers as well.
+        let (this, that) = (self.to_unsigned_lc(), other.to_unsigned_lc());
+        let bits_le = witness_bits_le(this.value() * that.value(), 2 * I::BITS);
+        Circuit::enforce(|| (this, that, bits_to_lc(&bits_le, false)));
+
+        Integer::from_bits_le(&bits_le[..I::BITS])
+    }
+}
+
+#[cfg(test)]
+impl<I: IntegerType> Metrics<dyn MulChecked<Integer<I>, Output = Integer<I>>> for Integer<I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let n = I::BITS as u64;
+        match case.0.is_constant() && case.1.is_constant() {
+            true => Count::is(0, 0, 0, 0),
+            false => Count::is(0, 0, n, n + 1),
+        }
+    }
+}
+
+#[cfg(test)]
+impl<I: IntegerType> Metrics<dyn MulWrapped<Integer<I>, Output = Integer<I>>> for Integer<I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let n = I::BITS as u64;
+        match case.0.is_constant() && case.1.is_constant() {
+            true => Count::is(0, 0, 0, 0),
+            false => Count::is(0, 0, 2 * n, 2 * n + 1),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{
+    assert_count, assert_count_fails,
+    circuit::{
+        circuit::Circuit,
+        helpers::{Count, Mode},
+        rng_test_struct::{TestRng, Uniform},
+        traits::{Eject, Inject, MulChecked, MulWrapped},
+        Environment,
+    },
+    count,
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 32;
+
+fn check_mul<I: IntegerType>(name: &str, first: I, second: I, mode_a: Mode, mode_b: Mode) {
+    let a = Integer::<I>::new(mode_a, first);
+    let b = Integer::<I>::new(mode_b, second);
+    let case = (mode_a, mode_b);
+
+    match first.checked_mul(second) {
+        Some(expected) => Circuit::scope(format!("{name} checked"), || {
+            let candidate = a.mul_checked(&b);
+            assert_eq!(expected, candidate.eject_value(), "({first} * {second})");
+            assert_count!(
+                Integer<I>,
+                MulChecked<Integer<I>, Output = Integer<I>>,
+                &case
+            );
+        }),
+        None if a.is_constant() && b.is_constant() => check_halts(|| a.mul_checked(&b)),
+        None => Circuit::scope(format!("{name} checked"), || {
+            let _candidate = a.mul_checked(&b);
+            assert_count_fails!(
+                Integer<I>,
+                MulChecked<Integer<I>, Output = Integer<I>>,
+                &case
+            );
+        }),
+    }
+
+    Circuit::scope(format!("{name} wrapped"), || {
+        let candidate = a.mul_wrapped(&b);
+        assert_eq!(
+            first.wrapping_mul(second),
+            candidate.eject_value(),
+            "({first} * {second})"
+        );
+        assert_count!(
+            Integer<I>,
+            MulWrapped<Integer<I>, Output = Integer<I>>,
+            &case
+        );
+    });
+    Circuit::reset();
+}
+
+fn run_test<I: IntegerType + Uniform>() {
+    let mut rng = TestRng::default();
+
+    for mode_a in MODES {
+        for mode_b in MODES {
+            for i in 0..ITERATIONS {
+                let name = format!("Mul: {mode_a} * {mode_b} {i}");
+                check_mul(&name, I::rand(&mut rng), I::rand(&mut rng), mode_a, mode_b);
+
+                let name = format!("Mul: small {mode_a} * {mode_b} {i}");
+                check_mul(&name, small::<I>(&mut rng), small(&mut rng), mode_a, mode_b);
+            }
+
+            for first in edge_cases::<I>() {
+                for second in edge_cases::<I>() {
+                    let name = format!("Mul: {first} * {second}");
+                    check_mul(&name, first, second, mode_a, mode_b);
+                }
+            }
+        }
+    }
+}
+
+test_integer_types!(run_test);
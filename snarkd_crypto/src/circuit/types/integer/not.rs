@@ -0,0 +1,36 @@
+use std::ops::Not;
+
+#[cfg(test)]
+use crate::circuit::{
+    helpers::{Count, Mode},
+    traits::Metrics,
+};
+
+use super::{Integer, IntegerType};
+
+impl<I: IntegerType> Not for Integer<I> {
+    type Output = Integer<I>;
+
+    /// Returns `(NOT a)`.
+    fn not(self) -> Self::Output {
+        (&self).not()
+    }
+}
+
+impl<I: IntegerType> Not for &Integer<I> {
+    type Output = Integer<I>;
+
+    /// Returns `(NOT a)`.
+    fn not(self) -> Self::Output {
+        Integer::from_bits_le(&self.bits_le.iter().map(Not::not).collect::<Vec<_>>())
+    }
+}
+
+#[cfg(test)]
+impl<I: IntegerType> Metrics<dyn Not<Output = Integer<I>>> for Integer<I> {
+    type Case = Mode;
+
+    fn count(_case: &Self::Case) -> Count {
+        Count::is(0, 0, 0, 0)
+    }
+}
@@ -0,0 +1,44 @@
+use std::ops::Not;
+
+use crate::{
+    assert_count,
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        rng_test_struct::{TestRng, Uniform},
+        traits::{Eject, Inject},
+        Environment,
+    },
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 32;
+
+fn check_not<I: IntegerType>(name: &str, value: I, mode: Mode) {
+    let a = Integer::<I>::new(mode, value);
+    let expected = I::from_u128(!value.to_u128());
+
+    Circuit::scope(name, || {
+        let candidate = !&a;
+        assert_eq!(expected, candidate.eject_value(), "(NOT {value})");
+        assert_count!(Integer<I>, Not<Output = Integer<I>>, &mode);
+    });
+    Circuit::reset();
+}
+
+fn run_test<I: IntegerType + Uniform>() {
+    let mut rng = TestRng::default();
+
+    for mode in MODES {
+        for i in 0..ITERATIONS {
+            check_not(&format!("Not: {mode} {i}"), I::rand(&mut rng), mode);
+        }
+
+        for value in edge_cases::<I>() {
+            check_not(&format!("Not: {value}"), value, mode);
+        }
+    }
+}
+
+test_integer_types!(run_test);
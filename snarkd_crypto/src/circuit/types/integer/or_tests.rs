@@ -0,0 +1,55 @@
+use std::ops::BitOr;
+
+use crate::{
+    assert_count,
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        rng_test_struct::{TestRng, Uniform},
+        traits::{Eject, Inject},
+        Environment,
+    },
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 32;
+
+fn check_or<I: IntegerType>(name: &str, first: I, second: I, mode_a: Mode, mode_b: Mode) {
+    let a = Integer::<I>::new(mode_a, first);
+    let b = Integer::<I>::new(mode_b, second);
+    let expected = I::from_u128(first.to_u128() | second.to_u128());
+
+    Circuit::scope(name, || {
+        let candidate = &a | &b;
+        assert_eq!(expected, candidate.eject_value(), "({first} OR {second})");
+        assert_count!(
+            Integer<I>,
+            BitOr<Integer<I>, Output = Integer<I>>,
+            &(mode_a, mode_b)
+        );
+    });
+    Circuit::reset();
+}
+
+fn run_test<I: IntegerType + Uniform>() {
+    let mut rng = TestRng::default();
+
+    for mode_a in MODES {
+        for mode_b in MODES {
+            for i in 0..ITERATIONS {
+                let name = format!("Or: {mode_a} | {mode_b} {i}");
+                check_or(&name, I::rand(&mut rng), I::rand(&mut rng), mode_a, mode_b);
+            }
+
+            for first in edge_cases::<I>() {
+                for second in edge_cases::<I>() {
+                    let name = format!("Or: {first} | {second}");
+                    check_or(&name, first, second, mode_a, mode_b);
+                }
+            }
+        }
+    }
+}
+
+test_integer_types!(run_test);
@@ -0,0 +1,86 @@
+use crate::circuit::{
+    circuit::Circuit,
+    traits::{Eject, Inject, MulChecked, MulWrapped, PowChecked, PowWrapped, Ternary},
+    Environment,
+};
+
+#[cfg(test)]
+use crate::circuit::{helpers::Count, helpers::Mode, traits::Metrics};
+
+use super::{Integer, IntegerType, U32};
+
+impl<I: IntegerType> PowChecked<U32> for Integer<I> {
+    type Output = Self;
+
+    fn pow_checked(&self, other: &U32) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            return match self.eject_value().checked_pow(other.eject_value()) {
+                Some(power) => Integer::constant(power),
+                None => Circuit::halt("Integer overflow on exponentiation of two constants"),
+            };
+        }
+
+        // Square and multiply, from the most significant bit of the exponent. Every intermediate
+        // power divides the result, so none of them overflows unless the result does.
+        let one = Integer::constant(I::ONE);
+        let mut power = one.clone();
+        for bit in other.bits_le.iter().rev() {
+            power = power.mul_checked(&power);
+            power = power.mul_checked(&Integer::ternary(bit, self, &one));
+        }
+        power
+    }
+}
+
+impl<I: IntegerType> PowWrapped<U32> for Integer<I> {
+    type Output = Self;
+
+    fn pow_wrapped(&self, other: &U32) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            return Integer::constant(self.eject_value().wrapping_pow(other.eject_value()));
+        }
+
+        // Square and multiply, from the most significant bit of the exponent.
+        let one = Integer::constant(I::ONE);
+        let mut power = one.clone();
+        for bit in other.bits_le.iter().rev() {
+            power = power.mul_wrapped(&power);
+            power = power.mul_wrapped(&Integer::ternary(bit, self, &one));
+        }
+        power
+    }
+}
+
+#[cfg(test)]
+impl<I: IntegerType> Metrics<dyn PowChecked<U32, Output = Integer<I>>> for Integer<I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let n = I::BITS as u64;
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Count::is(0, 0, 0, 0),
+            (Mode::Constant, _) | (_, Mode::Constant) => {
+                Count::less_than(0, 0, 95 * n, 95 * n + 63)
+            }
+            // Each of the 32 bits of the exponent costs a square, a selection and a
+            // multiplication, except that the first square is of the constant one.
+            (_, _) => Count::is(0, 0, 95 * n, 95 * n + 63),
+        }
+    }
+}
+
+#[cfg(test)]
+impl<I: IntegerType> Metrics<dyn PowWrapped<U32, Output = Integer<I>>> for Integer<I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let n = I::BITS as u64;
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Count::is(0, 0, 0, 0),
+            (Mode::Constant, _) | (_, Mode::Constant) => {
+                Count::less_than(0, 0, 158 * n, 158 * n + 63)
+            }
+            (_, _) => Count::is(0, 0, 158 * n, 158 * n + 63),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{
+    assert_count, assert_count_fails,
+    circuit::{
+        circuit::Circuit,
+        helpers::{Count, Mode},
+        rng_test_struct::{TestRng, Uniform},
+        traits::{Eject, Inject, PowChecked, PowWrapped},
+        types::U32,
+        Environment,
+    },
+    count,
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 8;
+
+fn check_pow<I: IntegerType>(name: &str, first: I, second: u32, mode_a: Mode, mode_b: Mode) {
+    let a = Integer::<I>::new(mode_a, first);
+    let b = U32::new(mode_b, second);
+    let case = (mode_a, mode_b);
+
+    match first.checked_pow(second) {
+        Some(expected) => Circuit::scope(format!("{name} checked"), || {
+            let candidate = a.pow_checked(&b);
+            assert_eq!(expected, candidate.eject_value(), "({first} ** {second})");
+            assert_count!(Integer<I>, PowChecked<U32, Output = Integer<I>>, &case);
+        }),
+        None if a.is_constant() && b.is_constant() => check_halts(|| a.pow_checked(&b)),
+        None => Circuit::scope(format!("{name} checked"), || {
+            let _candidate = a.pow_checked(&b);
+            assert_count_fails!(Integer<I>, PowChecked<U32, Output = Integer<I>>, &case);
+        }),
+    }
+
+    Circuit::scope(format!("{name} wrapped"), || {
+        let candidate = a.pow_wrapped(&b);
+        assert_eq!(
+            first.wrapping_pow(second),
+            candidate.eject_value(),
+            "({first} ** {second})"
+        );
+        assert_count!(Integer<I>, PowWrapped<U32, Output = Integer<I>>, &case);
+    });
+    Circuit::reset();
+}
+
+fn run_test<I: IntegerType + Uniform>() {
+    let mut rng = TestRng::default();
+
+    for mode_a in MODES {
+        for mode_b in MODES {
+            for i in 0..ITERATIONS {
+                let name = format!("Pow: {mode_a} ** {mode_b} {i}");
+                check_pow(
+                    &name,
+                    I::rand(&mut rng),
+                    u32::rand(&mut rng),
+                    mode_a,
+                    mode_b,
+                );
+
+                // Small powers, which only overflow for some exponents.
+                let base = I::from_u128(u128::rand(&mut rng) % 4);
+                let base = match I::IS_SIGNED && bool::rand(&mut rng) {
+                    true => I::ZERO.wrapping_sub(base),
+                    false => base,
+                };
+                let exponent = u32::rand(&mut rng) % I::BITS as u32;
+                let name = format!("Pow: small {mode_a} ** {mode_b} {i}");
+                check_pow(&name, base, exponent, mode_a, mode_b);
+            }
+
+            for first in edge_cases::<I>() {
+                for second in [0, 1, 2, u32::MAX] {
+                    let name = format!("Pow: {first} ** {second}");
+                    check_pow(&name, first, second, mode_a, mode_b);
+                }
+            }
+        }
+    }
+}
+
+test_integer_types!(run_test);
@@ -0,0 +1,171 @@
+use std::ops::{Rem, RemAssign};
+
+use crate::circuit::{
+    circuit::Circuit,
+    helpers::Mode,
+    traits::{Eject, Inject, RemChecked, RemWrapped},
+    types::Boolean,
+    Environment,
+};
+
+#[cfg(test)]
+use crate::circuit::{helpers::Count, traits::Metrics};
+
+use super::{bits_to_lc, div::sign_lc, Integer, IntegerType};
+
+#[cfg(test)]
+use super::div::{div_rem_unsigned_count, quotient_sign_count};
+
+impl<I: IntegerType> Rem<Integer<I>> for Integer<I> {
+    type Output = Integer<I>;
+
+    fn rem(self, other: Integer<I>) -> Self::Output {
+        self % &other
+    }
+}
+
+impl<I: IntegerType> Rem<&Integer<I>> for Integer<I> {
+    type Output = Integer<I>;
+
+    fn rem(self, other: &Integer<I>) -> Self::Output {
+        &self % other
+    }
+}
+
+impl<I: IntegerType> Rem<Integer<I>> for &Integer<I> {
+    type Output = Integer<I>;
+
+    fn rem(self, other: Integer<I>) -> Self::Output {
+        self % &other
+    }
+}
+
+impl<I: IntegerType> Rem<&Integer<I>> for &Integer<I> {
+    type Output = Integer<I>;
+
+    fn rem(self, other: &Integer<I>) -> Self::Output {
+        self.rem_checked(other)
+    }
+}
+
+impl<I: IntegerType> RemAssign<Integer<I>> for Integer<I> {
+    fn rem_assign(&mut self, other: Integer<I>) {
+        *self %= &other;
+    }
+}
+
+impl<I: IntegerType> RemAssign<&Integer<I>> for Integer<I> {
+    fn rem_assign(&mut self, other: &Integer<I>) {
+        *self = self.rem_checked(other);
+    }
+}
+
+impl<I: IntegerType> RemChecked<Self> for Integer<I> {
+    type Output = Self;
+
+    fn rem_checked(&self, other: &Self) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            return match self.eject_value().checked_rem(other.eject_value()) {
+                Some(remainder) => Integer::constant(remainder),
+                None => Circuit::halt("Division by zero or overflow on two constants"),
+            };
+        }
+
+        let (quotient, remainder) = self.div_rem_unsigned(other);
+        if !I::IS_SIGNED {
+            return Integer::from_bits_le(&remainder);
+        }
+
+        // Dividing the minimum integer by `-1` overflows, which is the only way for the quotient
+        // of the magnitudes to reach `2^(BITS - 1)` while the operands have the same sign.
+        // Ensure `quotient_msb * (1 - is_negative) == 0`.
+        let is_negative = self.msb() ^ other.msb();
+        Circuit::enforce(|| {
+            (
+                &quotient[I::BITS - 1],
+                Circuit::one() - &*is_negative,
+                Circuit::zero(),
+            )
+        });
+
+        self.signed_remainder(other, &remainder)
+    }
+}
+
+impl<I: IntegerType> RemWrapped<Self> for Integer<I> {
+    type Output = Self;
+
+    fn rem_wrapped(&self, other: &Self) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            return match other.eject_value() == I::ZERO {
+                true => Circuit::halt("Division by zero on two constants"),
+                false => Integer::constant(self.eject_value().wrapping_rem(other.eject_value())),
+            };
+        }
+
+        let (_, remainder) = self.div_rem_unsigned(other);
+        match I::IS_SIGNED {
+            true => self.signed_remainder(other, &remainder),
+            false => Integer::from_bits_le(&remainder),
+        }
+    }
+}
+
+impl<I: IntegerType> Integer<I> {
+    /// Returns the remainder of `self` divided by `other`, given the remainder of their magnitudes.
+    /// It always fits, as it is smaller than the magnitude of `other`.
+    fn signed_remainder(&self, other: &Self, remainder: &[Boolean]) -> Self {
+        let (this, that) = (self.eject_value(), other.eject_value());
+        let signed_remainder = Integer::new(
+            Mode::Private,
+            match that == I::ZERO {
+                true => I::ZERO,
+                false => this.wrapping_rem(that),
+            },
+        );
+
+        // Ensure `(1 - 2 * msb) * remainder == signed_remainder`, as the remainder has the sign
+        // of the dividend.
+        Circuit::enforce(|| {
+            (
+                sign_lc(self.msb()),
+                bits_to_lc(remainder, false),
+                signed_remainder.to_lc(),
+            )
+        });
+
+        signed_remainder
+    }
+}
+
+#[cfg(test)]
+impl<I: IntegerType> Metrics<dyn RemChecked<Integer<I>, Output = Integer<I>>> for Integer<I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let n = I::BITS as u64;
+        match (case.0.is_constant() && case.1.is_constant(), I::IS_SIGNED) {
+            (true, _) => Count::is(0, 0, 0, 0),
+            (false, false) => div_rem_unsigned_count::<I>(case),
+            (false, true) => {
+                div_rem_unsigned_count::<I>(case)
+                    + quotient_sign_count(case)
+                    + Count::is(0, 0, n, n + 2)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+impl<I: IntegerType> Metrics<dyn RemWrapped<Integer<I>, Output = Integer<I>>> for Integer<I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let n = I::BITS as u64;
+        match (case.0.is_constant() && case.1.is_constant(), I::IS_SIGNED) {
+            (true, _) => Count::is(0, 0, 0, 0),
+            (false, false) => div_rem_unsigned_count::<I>(case),
+            (false, true) => div_rem_unsigned_count::<I>(case) + Count::is(0, 0, n, n + 1),
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use std::ops::{Shl, ShlAssign};
+
+use crate::circuit::{
+    circuit::Circuit,
+    traits::{Eject, Inject, ShlChecked, ShlWrapped, Ternary},
+    types::Boolean,
+    Environment,
+};
+
+#[cfg(test)]
+use crate::circuit::{
+    helpers::{Count, Mode},
+    traits::Metrics,
+};
+
+use super::{Integer, IntegerType, U32};
+
+impl<I: IntegerType> Shl<U32> for Integer<I> {
+    type Output = Integer<I>;
+
+    fn shl(self, other: U32) -> Self::Output {
+        self << &other
+    }
+}
+
+impl<I: IntegerType> Shl<&U32> for Integer<I> {
+    type Output = Integer<I>;
+
+    fn shl(self, other: &U32) -> Self::Output {
+        &self << other
+    }
+}
+
+impl<I: IntegerType> Shl<U32> for &Integer<I> {
+    type Output = Integer<I>;
+
+    fn shl(self, other: U32) -> Self::Output {
+        self << &other
+    }
+}
+
+impl<I: IntegerType> Shl<&U32> for &Integer<I> {
+    type Output = Integer<I>;
+
+    fn shl(self, other: &U32) -> Self::Output {
+        self.shl_checked(other)
+    }
+}
+
+impl<I: IntegerType> ShlAssign<U32> for Integer<I> {
+    fn shl_assign(&mut self, other: U32) {
+        *self <<= &other;
+    }
+}
+
+impl<I: IntegerType> ShlAssign<&U32> for Integer<I> {
+    fn shl_assign(&mut self, other: &U32) {
+        *self = self.shl_checked(other);
+    }
+}
+
+/// Returns the number of low bits of a shift that are used to shift an integer of type `I`.
+fn shift_bits<I: IntegerType>() -> usize {
+    I::BITS.trailing_zeros() as usize
+}
+
+impl<I: IntegerType> Integer<I> {
+    /// Returns `self` shifted by the low bits of `shift`, one stage per bit. The bits shifted in
+    /// are zero, or copies of the sign bit when a signed integer is shifted right.
+    pub(super) fn barrel_shift(&self, shift: &U32, left: bool) -> Self {
+        let mut bits_le = self.bits_le.clone();
+        for (i, bit) in shift.bits_le[..shift_bits::<I>()].iter().enumerate() {
+            let distance = 1 << i;
+            let fill = match !left && I::IS_SIGNED {
+                true => bits_le[I::BITS - 1].clone(),
+                false => Boolean::constant(false),
+            };
+            let shifted = (0..I::BITS).map(|j| match left {
+                true => j
+                    .checked_sub(distance)
+                    .map_or_else(|| fill.clone(), |k| bits_le[k].clone()),
+                false => bits_le
+                    .get(j + distance)
+                    .cloned()
+                    .unwrap_or_else(|| fill.clone()),
+            });
+            bits_le = shifted
+                .zip(&bits_le)
+                .map(|(shifted, unshifted)| Boolean::ternary(bit, &shifted, unshifted))
+                .collect();
+        }
+        Integer::from_bits_le(&bits_le)
+    }
+
+    /// Ensures that `shift` is less than the number of bits, so that no bit of it is ignored.
+    pub(super) fn assert_shift_in_range(shift: &U32) {
+        if shift.is_constant() {
+            if shift.eject_value() as usize >= I::BITS {
+                Circuit::halt(format!("Shift of a {}-bit integer by {shift}", I::BITS))
+            }
+            return;
+        }
+
+        // Ensure the unused bits of the shift sum to zero, which is only the case if they are all
+        // zero, as there are too few of them for the sum to wrap around.
+        let unused = shift.bits_le[shift_bits::<I>()..]
+            .iter()
+            .fold(Circuit::zero(), |sum, bit| sum + &**bit);
+        Circuit::assert_eq(unused, Circuit::zero());
+    }
+}
+
+impl<I: IntegerType> ShlChecked<U32> for Integer<I> {
+    type Output = Self;
+
+    fn shl_checked(&self, other: &U32) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            return match self.eject_value().checked_shl(other.eject_value()) {
+                Some(shifted) => Integer::constant(shifted),
+                None => Circuit::halt("Shift of a constant integer by too many bits"),
+            };
+        }
+
+        Self::assert_shift_in_range(other);
+        self.barrel_shift(other, true)
+    }
+}
+
+impl<I: IntegerType> ShlWrapped<U32> for Integer<I> {
+    type Output = Self;
+
+    fn shl_wrapped(&self, other: &U32) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            return Integer::constant(self.eject_value().wrapping_shl(other.eject_value()));
+        }
+
+        self.barrel_shift(other, true)
+    }
+}
+
+/// Returns the count of a barrel shift of an integer of type `I`, which is the same both ways.
+#[cfg(test)]
+pub(super) fn barrel_shift_count<I: IntegerType>(case: &(Mode, Mode)) -> Count {
+    let num_selections = (shift_bits::<I>() * I::BITS) as u64;
+    match (case.0, case.1) {
+        (_, Mode::Constant) => Count::is(0, 0, 0, 0),
+        (Mode::Constant, _) => Count::less_than(0, 0, num_selections, num_selections),
+        (_, _) => Count::is(0, 0, num_selections, num_selections),
+    }
+}
+
+/// Returns the count of the range check of a shift.
+#[cfg(test)]
+pub(super) fn shift_range_count(case: &(Mode, Mode)) -> Count {
+    match case.1.is_constant() {
+        true => Count::is(0, 0, 0, 0),
+        false => Count::is(0, 0, 0, 1),
+    }
+}
+
+#[cfg(test)]
+impl<I: IntegerType> Metrics<dyn ShlChecked<U32, Output = Integer<I>>> for Integer<I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        barrel_shift_count::<I>(case) + shift_range_count(case)
+    }
+}
+
+#[cfg(test)]
+impl<I: IntegerType> Metrics<dyn ShlWrapped<U32, Output = Integer<I>>> for Integer<I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        barrel_shift_count::<I>(case)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{
+    assert_count, assert_count_fails,
+    circuit::{
+        circuit::Circuit,
+        helpers::{Count, Mode},
+        rng_test_struct::{TestRng, Uniform},
+        traits::{Eject, Inject, ShlChecked, ShlWrapped},
+        types::U32,
+        Environment,
+    },
+    count,
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 32;
+
+fn check_shl<I: IntegerType>(name: &str, first: I, second: u32, mode_a: Mode, mode_b: Mode) {
+    let a = Integer::<I>::new(mode_a, first);
+    let b = U32::new(mode_b, second);
+    let case = (mode_a, mode_b);
+
+    match first.checked_shl(second) {
+        Some(expected) => Circuit::scope(format!("{name} checked"), || {
+            let candidate = a.shl_checked(&b);
+            assert_eq!(expected, candidate.eject_value(), "({first} << {second})");
+            assert_count!(Integer<I>, ShlChecked<U32, Output = Integer<I>>, &case);
+        }),
+        None if b.is_constant() => check_halts(|| a.shl_checked(&b)),
+        None => Circuit::scope(format!("{name} checked"), || {
+            let _candidate = a.shl_checked(&b);
+            assert_count_fails!(Integer<I>, ShlChecked<U32, Output = Integer<I>>, &case);
+        }),
+    }
+
+    Circuit::scope(format!("{name} wrapped"), || {
+        let candidate = a.shl_wrapped(&b);
+        assert_eq!(
+            first.wrapping_shl(second),
+            candidate.eject_value(),
+            "({first} << {second})"
+        );
+        assert_count!(Integer<I>, ShlWrapped<U32, Output = Integer<I>>, &case);
+    });
+    Circuit::reset();
+}
+
+fn run_test<I: IntegerType + Uniform>() {
+    let mut rng = TestRng::default();
+
+    for mode_a in MODES {
+        for mode_b in MODES {
+            for i in 0..ITERATIONS {
+                let name = format!("Shl: {mode_a} << {mode_b} {i}");
+                check_shl(
+                    &name,
+                    I::rand(&mut rng),
+                    u32::rand(&mut rng),
+                    mode_a,
+                    mode_b,
+                );
+
+                let shift = u32::rand(&mut rng) % I::BITS as u32;
+                let name = format!("Shl: {mode_a} << small {mode_b} {i}");
+                check_shl(&name, I::rand(&mut rng), shift, mode_a, mode_b);
+            }
+
+            for first in edge_cases::<I>() {
+                for second in [0, 1, I::BITS as u32 - 1, I::BITS as u32, u32::MAX] {
+                    let name = format!("Shl: {first} << {second}");
+                    check_shl(&name, first, second, mode_a, mode_b);
+                }
+            }
+        }
+    }
+}
+
+test_integer_types!(run_test);
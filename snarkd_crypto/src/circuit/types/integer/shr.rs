@@ -0,0 +1,108 @@
+use std::ops::{Shr, ShrAssign};
+
+use crate::circuit::{
+    circuit::Circuit,
+    traits::{Eject, Inject, ShrChecked, ShrWrapped},
+    Environment,
+};
+
+#[cfg(test)]
+use crate::circuit::{
+    helpers::{Count, Mode},
+    traits::Metrics,
+};
+
+use super::{Integer, IntegerType, U32};
+
+#[cfg(test)]
+use super::shl::{barrel_shift_count, shift_range_count};
+
+impl<I: IntegerType> Shr<U32> for Integer<I> {
+    type Output = Integer<I>;
+
+    fn shr(self, other: U32) -> Self::Output {
+        self >> &other
+    }
+}
+
+impl<I: IntegerType> Shr<&U32> for Integer<I> {
+    type Output = Integer<I>;
+
+    fn shr(self, other: &U32) -> Self::Output {
+        &self >> other
+    }
+}
+
+impl<I: IntegerType> Shr<U32> for &Integer<I> {
+    type Output = Integer<I>;
+
+    fn shr(self, other: U32) -> Self::Output {
+        self >> &other
+    }
+}
+
+impl<I: IntegerType> Shr<&U32> for &Integer<I> {
+    type Output = Integer<I>;
+
+    fn shr(self, other: &U32) -> Self::Output {
+        self.shr_checked(other)
+    }
+}
+
+impl<I: IntegerType> ShrAssign<U32> for Integer<I> {
+    fn shr_assign(&mut self, other: U32) {
+        *self >>= &other;
+    }
+}
+
+impl<I: IntegerType> ShrAssign<&U32> for Integer<I> {
+    fn shr_assign(&mut self, other: &U32) {
+        *self = self.shr_checked(other);
+    }
+}
+
+impl<I: IntegerType> ShrChecked<U32> for Integer<I> {
+    type Output = Self;
+
+    fn shr_checked(&self, other: &U32) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            return match self.eject_value().checked_shr(other.eject_value()) {
+                Some(shifted) => Integer::constant(shifted),
+                None => Circuit::halt("Shift of a constant integer by too many bits"),
+            };
+        }
+
+        Self::assert_shift_in_range(other);
+        self.barrel_shift(other, false)
+    }
+}
+
+impl<I: IntegerType> ShrWrapped<U32> for Integer<I> {
+    type Output = Self;
+
+    fn shr_wrapped(&self, other: &U32) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            return Integer::constant(self.eject_value().wrapping_shr(other.eject_value()));
+        }
+
+        self.barrel_shift(other, false)
+    }
+}
+
+#[cfg(test)]
+impl<I: IntegerType> Metrics<dyn ShrChecked<U32, Output = Integer<I>>> for Integer<I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        barrel_shift_count::<I>(case) + shift_range_count(case)
+    }
+}
+
+#[cfg(test)]
+impl<I: IntegerType> Metrics<dyn ShrWrapped<U32, Output = Integer<I>>> for Integer<I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        barrel_shift_count::<I>(case)
+    }
+}
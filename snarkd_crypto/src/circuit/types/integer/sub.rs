@@ -0,0 +1,126 @@
+use std::ops::{Sub, SubAssign};
+
+use crate::circuit::{
+    circuit::Circuit,
+    helpers::Mode,
+    traits::{Eject, Inject, SubChecked, SubWrapped},
+    Environment,
+};
+
+#[cfg(test)]
+use crate::circuit::{helpers::Count, traits::Metrics};
+
+use super::{decompose_le, two_pow, Integer, IntegerType};
+
+impl<I: IntegerType> Sub<Integer<I>> for Integer<I> {
+    type Output = Integer<I>;
+
+    fn sub(self, other: Integer<I>) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<I: IntegerType> Sub<&Integer<I>> for Integer<I> {
+    type Output = Integer<I>;
+
+    fn sub(self, other: &Integer<I>) -> Self::Output {
+        &self - other
+    }
+}
+
+impl<I: IntegerType> Sub<Integer<I>> for &Integer<I> {
+    type Output = Integer<I>;
+
+    fn sub(self, other: Integer<I>) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<I: IntegerType> Sub<&Integer<I>> for &Integer<I> {
+    type Output = Integer<I>;
+
+    fn sub(self, other: &Integer<I>) -> Self::Output {
+        self.sub_checked(other)
+    }
+}
+
+impl<I: IntegerType> SubAssign<Integer<I>> for Integer<I> {
+    fn sub_assign(&mut self, other: Integer<I>) {
+        *self -= &other;
+    }
+}
+
+impl<I: IntegerType> SubAssign<&Integer<I>> for Integer<I> {
+    fn sub_assign(&mut self, other: &Integer<I>) {
+        *self = self.sub_checked(other);
+    }
+}
+
+impl<I: IntegerType> SubChecked<Self> for Integer<I> {
+    type Output = Self;
+
+    fn sub_checked(&self, other: &Self) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            return match self.eject_value().checked_sub(other.eject_value()) {
+                Some(difference) => Integer::constant(difference),
+                None => Circuit::halt("Integer overflow on subtraction of two constants"),
+            };
+        }
+
+        // On overflow, the witness is the wrapped difference, which the constraint below rejects.
+        let difference = Integer::new(
+            Mode::Private,
+            self.eject_value().wrapping_sub(other.eject_value()),
+        );
+
+        // Ensure `self - other == difference`. Both sides are far smaller than the modulus,
+        // so this only holds if the difference is in range.
+        Circuit::assert_eq(self.to_lc() - other.to_lc(), difference.to_lc());
+
+        difference
+    }
+}
+
+impl<I: IntegerType> SubWrapped<Self> for Integer<I> {
+    type Output = Self;
+
+    fn sub_wrapped(&self, other: &Self) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            return Integer::constant(self.eject_value().wrapping_sub(other.eject_value()));
+        }
+
+        // Offset the difference of the unsigned values by `2^BITS` so that it is never negative,
+        // then drop that bit. Two's complement makes this correct for signed integers as well.
+        let difference =
+            self.to_unsigned_lc() - other.to_unsigned_lc() + Circuit::one() * two_pow(I::BITS);
+        let bits_le = decompose_le(&difference, I::BITS + 1);
+
+        Integer::from_bits_le(&bits_le[..I::BITS])
+    }
+}
+
+#[cfg(test)]
+impl<I: IntegerType> Metrics<dyn SubChecked<Integer<I>, Output = Integer<I>>> for Integer<I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let n = I::BITS as u64;
+        match case.0.is_constant() && case.1.is_constant() {
+            true => Count::is(0, 0, 0, 0),
+            false => Count::is(0, 0, n, n + 1),
+        }
+    }
+}
+
+#[cfg(test)]
+impl<I: IntegerType> Metrics<dyn SubWrapped<Integer<I>, Output = Integer<I>>> for Integer<I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let n = I::BITS as u64;
+        match case.0.is_constant() && case.1.is_constant() {
+            true => Count::is(0, 0, 0, 0),
+            false => Count::is(0, 0, n + 1, n + 2),
+        }
+    }
+}
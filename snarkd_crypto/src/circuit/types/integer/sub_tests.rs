@@ -0,0 +1,79 @@
+use crate::{
+    assert_count, assert_count_fails,
+    circuit::{
+        circuit::Circuit,
+        helpers::{Count, Mode},
+        rng_test_struct::{TestRng, Uniform},
+        traits::{Eject, Inject, SubChecked, SubWrapped},
+        Environment,
+    },
+    count,
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 32;
+
+fn check_sub<I: IntegerType>(name: &str, first: I, second: I, mode_a: Mode, mode_b: Mode) {
+    let a = Integer::<I>::new(mode_a, first);
+    let b = Integer::<I>::new(mode_b, second);
+    let case = (mode_a, mode_b);
+
+    match first.checked_sub(second) {
+        Some(expected) => Circuit::scope(format!("{name} checked"), || {
+            let candidate = a.sub_checked(&b);
+            assert_eq!(expected, candidate.eject_value(), "({first} - {second})");
+            assert_count!(
+                Integer<I>,
+                SubChecked<Integer<I>, Output = Integer<I>>,
+                &case
+            );
+        }),
+        None if a.is_constant() && b.is_constant() => check_halts(|| a.sub_checked(&b)),
+        None => Circuit::scope(format!("{name} checked"), || {
+            let _candidate = a.sub_checked(&b);
+            assert_count_fails!(
+                Integer<I>,
+                SubChecked<Integer<I>, Output = Integer<I>>,
+                &case
+            );
+        }),
+    }
+
+    Circuit::scope(format!("{name} wrapped"), || {
+        let candidate = a.sub_wrapped(&b);
+        assert_eq!(
+            first.wrapping_sub(second),
+            candidate.eject_value(),
+            "({first} - {second})"
+        );
+        assert_count!(
+            Integer<I>,
+            SubWrapped<Integer<I>, Output = Integer<I>>,
+            &case
+        );
+    });
+    Circuit::reset();
+}
+
+fn run_test<I: IntegerType + Uniform>() {
+    let mut rng = TestRng::default();
+
+    for mode_a in MODES {
+        for mode_b in MODES {
+            for i in 0..ITERATIONS {
+                let name = format!("Sub: {mode_a} - {mode_b} {i}");
+                check_sub(&name, I::rand(&mut rng), I::rand(&mut rng), mode_a, mode_b);
+            }
+
+            for first in edge_cases::<I>() {
+                for second in edge_cases::<I>() {
+                    let name = format!("Sub: {first} - {second}");
+                    check_sub(&name, first, second, mode_a, mode_b);
+                }
+            }
+        }
+    }
+}
+
+test_integer_types!(run_test);
@@ -0,0 +1,50 @@
+use crate::circuit::{
+    traits::{Eject, Ternary},
+    types::Boolean,
+};
+
+#[cfg(test)]
+use crate::circuit::{
+    helpers::{Count, Mode},
+    traits::Metrics,
+};
+
+use super::{Integer, IntegerType};
+
+impl<I: IntegerType> Ternary for Integer<I> {
+    type Boolean = Boolean;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        // Constant `condition`
+        if condition.is_constant() {
+            return match condition.eject_value() {
+                true => first.clone(),
+                false => second.clone(),
+            };
+        }
+
+        // Select each bit, which is cheaper than selecting the value and decomposing it.
+        let bits_le = first
+            .bits_le
+            .iter()
+            .zip(&second.bits_le)
+            .map(|(first, second)| Boolean::ternary(condition, first, second))
+            .collect::<Vec<_>>();
+        Integer::from_bits_le(&bits_le)
+    }
+}
+
+#[cfg(test)]
+impl<I: IntegerType> Metrics<dyn Ternary<Boolean = Boolean, Output = Integer<I>>> for Integer<I> {
+    type Case = (Mode, Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let n = I::BITS as u64;
+        match case {
+            (Mode::Constant, _, _) | (_, Mode::Constant, Mode::Constant) => Count::is(0, 0, 0, 0),
+            _ => Count::is(0, 0, n, n),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{
+    assert_count,
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        rng_test_struct::{TestRng, Uniform},
+        traits::{Eject, Inject, Ternary},
+        types::Boolean,
+        Environment,
+    },
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 16;
+
+fn check_ternary<I: IntegerType>(
+    name: &str,
+    flag: bool,
+    first: I,
+    second: I,
+    case: (Mode, Mode, Mode),
+) {
+    let condition = Boolean::new(case.0, flag);
+    let a = Integer::<I>::new(case.1, first);
+    let b = Integer::<I>::new(case.2, second);
+    let expected = match flag {
+        true => first,
+        false => second,
+    };
+
+    Circuit::scope(name, || {
+        let candidate = Integer::ternary(&condition, &a, &b);
+        assert_eq!(
+            expected,
+            candidate.eject_value(),
+            "({flag} ? {first} : {second})"
+        );
+        assert_count!(Integer<I>, Ternary<Boolean = Boolean, Output = Integer<I>>, &case);
+    });
+    Circuit::reset();
+}
+
+fn run_test<I: IntegerType + Uniform>() {
+    let mut rng = TestRng::default();
+
+    for mode_condition in MODES {
+        for mode_a in MODES {
+            for mode_b in MODES {
+                let case = (mode_condition, mode_a, mode_b);
+                for flag in [true, false] {
+                    for i in 0..ITERATIONS {
+                        let name = format!("Ternary: {mode_condition} ? {mode_a} : {mode_b} {i}");
+                        check_ternary(&name, flag, I::rand(&mut rng), I::rand(&mut rng), case);
+                    }
+                }
+            }
+        }
+    }
+}
+
+test_integer_types!(run_test);
@@ -0,0 +1,76 @@
+use std::ops::{BitXor, BitXorAssign};
+
+#[cfg(test)]
+use crate::circuit::{
+    helpers::{Count, Mode},
+    traits::Metrics,
+};
+
+use super::{Integer, IntegerType};
+
+impl<I: IntegerType> BitXor<Integer<I>> for Integer<I> {
+    type Output = Integer<I>;
+
+    /// Returns `(self XOR other)`.
+    fn bitxor(self, other: Integer<I>) -> Self::Output {
+        self ^ &other
+    }
+}
+
+impl<I: IntegerType> BitXor<Integer<I>> for &Integer<I> {
+    type Output = Integer<I>;
+
+    /// Returns `(self XOR other)`.
+    fn bitxor(self, other: Integer<I>) -> Self::Output {
+        self ^ &other
+    }
+}
+
+impl<I: IntegerType> BitXor<&Integer<I>> for Integer<I> {
+    type Output = Integer<I>;
+
+    /// Returns `(self XOR other)`.
+    fn bitxor(self, other: &Integer<I>) -> Self::Output {
+        &self ^ other
+    }
+}
+
+impl<I: IntegerType> BitXor<&Integer<I>> for &Integer<I> {
+    type Output = Integer<I>;
+
+    /// Returns `(self XOR other)`.
+    fn bitxor(self, other: &Integer<I>) -> Self::Output {
+        let mut output = self.clone();
+        output ^= other;
+        output
+    }
+}
+
+impl<I: IntegerType> BitXorAssign<Integer<I>> for Integer<I> {
+    /// Sets `self` as `(self XOR other)`.
+    fn bitxor_assign(&mut self, other: Integer<I>) {
+        *self ^= &other;
+    }
+}
+
+impl<I: IntegerType> BitXorAssign<&Integer<I>> for Integer<I> {
+    /// Sets `self` as `(self XOR other)`.
+    fn bitxor_assign(&mut self, other: &Integer<I>) {
+        for (this, that) in self.bits_le.iter_mut().zip(&other.bits_le) {
+            *this ^= that;
+        }
+    }
+}
+
+#[cfg(test)]
+impl<I: IntegerType> Metrics<dyn BitXor<Integer<I>, Output = Integer<I>>> for Integer<I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let n = I::BITS as u64;
+        match case.0.is_constant() || case.1.is_constant() {
+            true => Count::is(0, 0, 0, 0),
+            false => Count::is(0, 0, n, n),
+        }
+    }
+}
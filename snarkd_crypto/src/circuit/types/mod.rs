@@ -1,4 +1,16 @@
+mod address;
+pub use address::*;
 mod boolean;
 pub use boolean::*;
+mod compute_key;
+pub use compute_key::*;
 mod field;
 pub use field::*;
+mod group;
+pub use group::*;
+mod integer;
+pub use integer::*;
+mod scalar;
+pub use scalar::*;
+mod signature;
+pub use signature::*;
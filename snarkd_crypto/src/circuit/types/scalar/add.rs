@@ -0,0 +1,92 @@
+use std::ops::{Add, AddAssign};
+
+use ruint::Uint;
+
+use crate::{
+    bls12_377::{scalar::MODULUS, Fp},
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        traits::{Eject, Inject},
+        types::Boolean,
+        Environment,
+    },
+};
+
+#[cfg(test)]
+use crate::circuit::{helpers::Count, traits::Metrics};
+
+use super::Scalar;
+
+impl Add<Scalar> for Scalar {
+    type Output = Scalar;
+
+    fn add(self, other: Scalar) -> Self::Output {
+        self + &other
+    }
+}
+
+impl Add<&Scalar> for Scalar {
+    type Output = Scalar;
+
+    fn add(self, other: &Scalar) -> Self::Output {
+        &self + other
+    }
+}
+
+impl Add<Scalar> for &Scalar {
+    type Output = Scalar;
+
+    fn add(self, other: Scalar) -> Self::Output {
+        self + &other
+    }
+}
+
+impl Add<&Scalar> for &Scalar {
+    type Output = Scalar;
+
+    fn add(self, other: &Scalar) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            return Scalar::constant(self.eject_value() + other.eject_value());
+        }
+
+        // The sum of the two values is less than twice the modulus, so it wraps around at most once.
+        let (this, that) = (self.eject_value(), other.eject_value());
+        let carry = Boolean::new(Mode::Private, this.0 + that.0 >= MODULUS);
+        let sum = Scalar::new(Mode::Private, this + that);
+
+        // Ensure `self + other == sum + carry * modulus`.
+        let modulus = Fp(Uint::from_limbs_slice(MODULUS.as_limbs()));
+        Circuit::assert_eq(
+            self.to_lc() + other.to_lc(),
+            sum.to_lc() + &*carry * modulus,
+        );
+
+        sum
+    }
+}
+
+impl AddAssign<Scalar> for Scalar {
+    fn add_assign(&mut self, other: Scalar) {
+        *self += &other;
+    }
+}
+
+impl AddAssign<&Scalar> for Scalar {
+    fn add_assign(&mut self, other: &Scalar) {
+        *self = &*self + other;
+    }
+}
+
+#[cfg(test)]
+impl Metrics<dyn Add<Scalar, Output = Scalar>> for Scalar {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match case.0.is_constant() && case.1.is_constant() {
+            true => Count::is(0, 0, 0, 0),
+            // The carry, the sum with its range check, and the equality.
+            false => Count::is(0, 0, 680, 682),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::ops::Add;
+
+use crate::{
+    assert_count,
+    bls12_377::{self, Field as FieldTrait},
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        traits::{Eject, Inject},
+        Environment,
+    },
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 20;
+
+fn check_add(
+    name: &str,
+    first: bls12_377::Scalar,
+    second: bls12_377::Scalar,
+    mode_a: Mode,
+    mode_b: Mode,
+) {
+    let a = Scalar::new(mode_a, first);
+    let b = Scalar::new(mode_b, second);
+    Circuit::scope(name, || {
+        let candidate = &a + &b;
+        assert_eq!(
+            first + second,
+            candidate.eject_value(),
+            "({first} + {second})"
+        );
+        assert_count!(Add(Scalar, Scalar) => Scalar, &(mode_a, mode_b));
+    });
+    Circuit::reset();
+}
+
+#[test]
+fn test_add() {
+    let modes = [Mode::Constant, Mode::Public, Mode::Private];
+    let minus_one = -bls12_377::Scalar::ONE;
+
+    for mode_a in modes {
+        for mode_b in modes {
+            for i in 0..ITERATIONS {
+                let name = format!("Add: {mode_a} + {mode_b} {i}");
+                check_add(
+                    &name,
+                    bls12_377::Scalar::rand(),
+                    bls12_377::Scalar::rand(),
+                    mode_a,
+                    mode_b,
+                );
+            }
+            let name = format!("Add: {mode_a} + {mode_b} wraps around");
+            check_add(&name, minus_one, minus_one, mode_a, mode_b);
+            let name = format!("Add: {mode_a} + {mode_b} to zero");
+            check_add(&name, minus_one, bls12_377::Scalar::ONE, mode_a, mode_b);
+            let name = format!("Add: {mode_a} + {mode_b} zero");
+            check_add(
+                &name,
+                bls12_377::Scalar::ZERO,
+                bls12_377::Scalar::ZERO,
+                mode_a,
+                mode_b,
+            );
+        }
+    }
+}
+
+#[test]
+fn test_add_assign() {
+    let (first, second) = (bls12_377::Scalar::rand(), bls12_377::Scalar::rand());
+    let mut candidate = Scalar::new(Mode::Private, first);
+    candidate += Scalar::new(Mode::Public, second);
+    assert_eq!(first + second, candidate.eject_value());
+    assert!(Circuit::is_satisfied());
+    Circuit::reset();
+}
@@ -0,0 +1,44 @@
+use crate::circuit::{
+    traits::{Eject, Equal, Inject},
+    types::{Boolean, Field},
+};
+
+#[cfg(test)]
+use crate::circuit::{
+    helpers::{Count, Mode},
+    traits::Metrics,
+};
+
+use super::Scalar;
+
+impl Equal<Self> for Scalar {
+    type Output = Boolean;
+
+    /// Returns `true` if `self` and `other` are equal.
+    fn is_equal(&self, other: &Self) -> Self::Output {
+        !self.is_not_equal(other)
+    }
+
+    /// Returns `true` if `self` and `other` are *not* equal.
+    fn is_not_equal(&self, other: &Self) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            return Boolean::constant(self.eject_value() != other.eject_value());
+        }
+
+        // Scalars are less than the base field modulus, so they are equal exactly when their
+        // values are as field elements.
+        Field::from(self.to_lc()).is_not_equal(&Field::from(other.to_lc()))
+    }
+}
+
+#[cfg(test)]
+impl Metrics<dyn Equal<Scalar, Output = Boolean>> for Scalar {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match case.0.is_constant() && case.1.is_constant() {
+            true => Count::is(0, 0, 0, 0),
+            false => Count::is(0, 0, 2, 3),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{
+    assert_count,
+    bls12_377::{self, Field as FieldTrait},
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        traits::{Eject, Equal, Inject},
+        types::Boolean,
+        Environment,
+    },
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 20;
+
+fn check_equal(
+    name: &str,
+    first: bls12_377::Scalar,
+    second: bls12_377::Scalar,
+    mode_a: Mode,
+    mode_b: Mode,
+) {
+    let a = Scalar::new(mode_a, first);
+    let b = Scalar::new(mode_b, second);
+    Circuit::scope(format!("{name} is_equal"), || {
+        let candidate = a.is_equal(&b);
+        assert_eq!(
+            first == second,
+            candidate.eject_value(),
+            "({first} == {second})"
+        );
+        assert_count!(Equal(Scalar, Scalar) => Boolean, &(mode_a, mode_b));
+    });
+    Circuit::scope(format!("{name} is_not_equal"), || {
+        let candidate = a.is_not_equal(&b);
+        assert_eq!(
+            first != second,
+            candidate.eject_value(),
+            "({first} != {second})"
+        );
+        assert_count!(Equal(Scalar, Scalar) => Boolean, &(mode_a, mode_b));
+    });
+    Circuit::reset();
+}
+
+#[test]
+fn test_equal() {
+    let modes = [Mode::Constant, Mode::Public, Mode::Private];
+
+    for mode_a in modes {
+        for mode_b in modes {
+            for i in 0..ITERATIONS {
+                let name = format!("Equal: {mode_a} {mode_b} {i}");
+                let first = bls12_377::Scalar::rand();
+                check_equal(&name, first, bls12_377::Scalar::rand(), mode_a, mode_b);
+                check_equal(&name, first, first, mode_a, mode_b);
+            }
+        }
+    }
+}
@@ -0,0 +1,118 @@
+mod add;
+mod equal;
+mod ternary;
+
+use std::fmt;
+
+use ruint::Uint;
+
+use crate::{
+    bls12_377::{
+        self,
+        scalar::{MODULUS, MODULUS_BITS},
+    },
+    circuit::{
+        helpers::{LinearCombination, Mode},
+        traits::{Eject, Inject, ToBits},
+    },
+};
+
+use super::Boolean;
+
+#[derive(Clone)]
+pub struct Scalar {
+    /// The little-endian bits of the scalar, whose value is less than the scalar field modulus.
+    bits_le: Vec<Boolean>,
+}
+
+impl Scalar {
+    /// Initializes a scalar from little-endian bits that are fewer than those of the modulus, so
+    /// that their value is always a scalar.
+    pub(crate) fn from_short_bits_le(mut bits_le: Vec<Boolean>) -> Self {
+        let num_bits = MODULUS_BITS as usize;
+        assert!(bits_le.len() < num_bits, "Too many bits for a short scalar");
+        bits_le.resize(num_bits, Boolean::constant(false));
+        Self { bits_le }
+    }
+
+    /// Returns the value of the scalar.
+    fn to_lc(&self) -> LinearCombination {
+        Boolean::le_bits_to_lc(&self.bits_le)
+    }
+}
+
+/// Returns the little-endian bits of the scalar field modulus.
+fn modulus_le() -> Vec<bool> {
+    (0..MODULUS_BITS as usize).map(|i| MODULUS.bit(i)).collect()
+}
+
+impl Inject for Scalar {
+    type Primitive = bls12_377::Scalar;
+
+    /// Initializes a scalar circuit from a console scalar.
+    fn new(mode: Mode, scalar: Self::Primitive) -> Self {
+        let bits_le = (0..MODULUS_BITS as usize)
+            .map(|i| Boolean::new(mode, scalar.0.bit(i)))
+            .collect::<Vec<_>>();
+
+        // Ensure the bits are those of a scalar, as they could otherwise exceed the modulus.
+        if !mode.is_constant() {
+            Boolean::assert_less_than_constant(&bits_le, &modulus_le());
+        }
+
+        Self { bits_le }
+    }
+
+    /// Initializes a constant scalar circuit from a console scalar.
+    fn constant(scalar: Self::Primitive) -> Self {
+        Self {
+            bits_le: (0..MODULUS_BITS as usize)
+                .map(|i| Boolean::constant(scalar.0.bit(i)))
+                .collect(),
+        }
+    }
+}
+
+impl Eject for Scalar {
+    type Primitive = bls12_377::Scalar;
+
+    /// Ejects the mode of the scalar circuit.
+    fn eject_mode(&self) -> Mode {
+        self.bits_le.eject_mode()
+    }
+
+    /// Ejects the scalar circuit as a console scalar.
+    fn eject_value(&self) -> Self::Primitive {
+        let mut value = Uint::ZERO;
+        for (i, bit) in self.bits_le.eject_value().into_iter().enumerate() {
+            value.set_bit(i, bit);
+        }
+        bls12_377::Scalar(value)
+    }
+}
+
+impl ToBits for Scalar {
+    type Boolean = Boolean;
+
+    /// Returns the little-endian bits of the scalar.
+    fn to_bits_le(&self) -> Vec<Self::Boolean> {
+        self.bits_le.clone()
+    }
+}
+
+impl fmt::Display for Scalar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.eject_value(), self.eject_mode())
+    }
+}
+
+#[cfg(test)]
+#[path = ""]
+mod test {
+    use super::*;
+
+    mod add_tests;
+    mod equal_tests;
+    mod scalar_tests;
+    mod ternary_tests;
+}
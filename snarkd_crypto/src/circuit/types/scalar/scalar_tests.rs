@@ -0,0 +1,88 @@
+use crate::{
+    assert_scope,
+    bls12_377::{self, Field as FieldTrait},
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        traits::{Eject, Inject, ToBits},
+        types::Boolean,
+        Environment,
+    },
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 100;
+
+fn check_new(name: &str, expected: bls12_377::Scalar, mode: Mode) {
+    Circuit::scope(name, || {
+        let candidate = Scalar::new(mode, expected);
+        assert_eq!(expected, candidate.eject_value());
+        assert_eq!(mode, candidate.eject_mode());
+        match mode {
+            Mode::Constant => assert_scope!(253, 0, 0, 0),
+            Mode::Public => assert_scope!(0, 253, 426, 680),
+            Mode::Private => assert_scope!(0, 0, 679, 680),
+        }
+
+        let bits_le = candidate.to_bits_le();
+        assert_eq!(253, bits_le.len());
+        for (i, bit) in bits_le.iter().enumerate() {
+            assert_eq!(expected.0.bit(i), bit.eject_value());
+        }
+    });
+    Circuit::reset();
+}
+
+#[test]
+fn test_new() {
+    for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+        for i in 0..ITERATIONS {
+            check_new(&format!("New: {mode} {i}"), bls12_377::Scalar::rand(), mode);
+        }
+        check_new(&format!("New: {mode} zero"), bls12_377::Scalar::ZERO, mode);
+        check_new(&format!("New: {mode} one"), bls12_377::Scalar::ONE, mode);
+        check_new(
+            &format!("New: {mode} minus one"),
+            -bls12_377::Scalar::ONE,
+            mode,
+        );
+    }
+}
+
+#[test]
+fn test_constant() {
+    let expected = bls12_377::Scalar::rand();
+    Circuit::scope("Constant", || {
+        let candidate = Scalar::constant(expected);
+        assert_eq!(expected, candidate.eject_value());
+        assert_scope!(0, 0, 0, 0);
+    });
+    Circuit::reset();
+}
+
+#[test]
+fn test_range_check() {
+    // The bits of the modulus, which is not a scalar, and those of the largest scalar.
+    let modulus_le = modulus_le();
+    let mut largest_le = modulus_le.clone();
+    largest_le[0] = false;
+
+    Circuit::scope("Largest", || {
+        let bits_le = largest_le
+            .iter()
+            .map(|bit| Boolean::new(Mode::Private, *bit))
+            .collect::<Vec<_>>();
+        Boolean::assert_less_than_constant(&bits_le, &modulus_le);
+        assert!(Circuit::is_satisfied_in_scope());
+    });
+    Circuit::scope("Modulus", || {
+        let bits_le = modulus_le
+            .iter()
+            .map(|bit| Boolean::new(Mode::Private, *bit))
+            .collect::<Vec<_>>();
+        Boolean::assert_less_than_constant(&bits_le, &modulus_le);
+        assert!(!Circuit::is_satisfied_in_scope());
+    });
+    Circuit::reset();
+}
@@ -0,0 +1,50 @@
+use crate::circuit::{
+    traits::{Eject, Ternary},
+    types::Boolean,
+};
+
+#[cfg(test)]
+use crate::circuit::{
+    helpers::{Count, Mode},
+    traits::Metrics,
+};
+
+use super::Scalar;
+
+impl Ternary for Scalar {
+    type Boolean = Boolean;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        // Constant `condition`
+        if condition.is_constant() {
+            return match condition.eject_value() {
+                true => first.clone(),
+                false => second.clone(),
+            };
+        }
+
+        // Select each bit, so that the selected scalar needs no range check of its own.
+        Scalar {
+            bits_le: first
+                .bits_le
+                .iter()
+                .zip(&second.bits_le)
+                .map(|(first, second)| Boolean::ternary(condition, first, second))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+impl Metrics<dyn Ternary<Boolean = Boolean, Output = Scalar>> for Scalar {
+    type Case = (Mode, Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            (Mode::Constant, _, _) | (_, Mode::Constant, Mode::Constant) => Count::is(0, 0, 0, 0),
+            _ => Count::is(0, 0, 253, 253),
+        }
+    }
+}
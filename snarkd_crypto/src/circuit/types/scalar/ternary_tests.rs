@@ -0,0 +1,48 @@
+use crate::{
+    assert_count,
+    bls12_377::{self, Field as FieldTrait},
+    circuit::{
+        circuit::Circuit,
+        helpers::Mode,
+        traits::{Eject, Inject, Ternary},
+        types::Boolean,
+        Environment,
+    },
+};
+
+use super::*;
+
+const ITERATIONS: u64 = 5;
+
+#[test]
+fn test_ternary() {
+    let modes = [Mode::Constant, Mode::Public, Mode::Private];
+
+    for mode_condition in modes {
+        for mode_a in modes {
+            for mode_b in modes {
+                for flag in [true, false] {
+                    for i in 0..ITERATIONS {
+                        let (first, second) =
+                            (bls12_377::Scalar::rand(), bls12_377::Scalar::rand());
+                        let condition = Boolean::new(mode_condition, flag);
+                        let a = Scalar::new(mode_a, first);
+                        let b = Scalar::new(mode_b, second);
+
+                        let name = format!("Ternary: {mode_condition} ? {mode_a} : {mode_b} {i}");
+                        Circuit::scope(name, || {
+                            let candidate = Scalar::ternary(&condition, &a, &b);
+                            let expected = if flag { first } else { second };
+                            assert_eq!(expected, candidate.eject_value());
+                            assert_count!(
+                                Ternary(Boolean, Scalar, Scalar) => Scalar,
+                                &(mode_condition, mode_a, mode_b)
+                            );
+                        });
+                        Circuit::reset();
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{
+    bls12_377::{Affine, G1Affine},
+    circuit::{
+        algorithms::PoseidonSponge,
+        helpers::Mode,
+        traits::{Eject, Equal, Inject},
+    },
+    keys,
+};
+
+use super::{Address, Boolean, ComputeKey, Field, Group, Scalar};
+
+/// A Schnorr signature, which is verified in the circuit as `keys::Signature::verify` does.
+#[derive(Clone)]
+pub struct Signature {
+    /// The verifier challenge to check against.
+    challenge: Scalar,
+    /// The prover response to the challenge.
+    response: Scalar,
+    /// The compute key of the prover.
+    compute_key: ComputeKey,
+}
+
+impl Signature {
+    /// Returns the verifier challenge.
+    pub fn challenge(&self) -> &Scalar {
+        &self.challenge
+    }
+
+    /// Returns the prover response.
+    pub fn response(&self) -> &Scalar {
+        &self.response
+    }
+
+    /// Returns the compute key of the prover.
+    pub fn compute_key(&self) -> &ComputeKey {
+        &self.compute_key
+    }
+
+    /// Returns `true` if the signature was produced by the private key of `address` over
+    /// `message`.
+    pub fn verify(&self, address: &Address, message: &[Field]) -> Boolean {
+        let pk_sig = self.compute_key.public_key_signature();
+        let pr_sig = self.compute_key.public_randomness_signature();
+
+        // Recompute `g_r` as `response * G + challenge * pk_sig`, which is `nonce * G` for a valid
+        // signature.
+        let generator = Group::constant(G1Affine::prime_subgroup_generator());
+        let g_r = generator * &self.response + pk_sig * &self.challenge;
+
+        // Compute the challenge as a Poseidon hash of (g_r, pk_sig, pr_sig, address, message).
+        let mut preimage = [&g_r, pk_sig, pr_sig, address.to_group()]
+            .map(|point| point.to_xy_coordinates().0)
+            .to_vec();
+        preimage.extend_from_slice(message);
+        let mut sponge = PoseidonSponge::default();
+        sponge.absorb_native_field_elements(&preimage);
+        let candidate_challenge = sponge.squeeze_short_nonnative_field_element();
+
+        // The PRF secret key of the compute key is derived in the circuit, so it remains to check
+        // the address and the challenge.
+        self.compute_key.to_address().is_equal(address)
+            & candidate_challenge.is_equal(&self.challenge)
+    }
+}
+
+impl Inject for Signature {
+    type Primitive = keys::Signature;
+
+    /// Initializes a signature circuit from a console signature.
+    fn new(mode: Mode, signature: Self::Primitive) -> Self {
+        Self {
+            challenge: Scalar::new(mode, signature.challenge),
+            response: Scalar::new(mode, signature.response),
+            compute_key: ComputeKey::new(mode, signature.compute_key),
+        }
+    }
+}
+
+impl Eject for Signature {
+    type Primitive = keys::Signature;
+
+    /// Ejects the mode of the signature circuit.
+    fn eject_mode(&self) -> Mode {
+        Mode::combine(
+            self.challenge.eject_mode(),
+            [self.response.eject_mode(), self.compute_key.eject_mode()],
+        )
+    }
+
+    /// Ejects the signature circuit as a console signature.
+    fn eject_value(&self) -> Self::Primitive {
+        keys::Signature::new(
+            self.challenge.eject_value(),
+            self.response.eject_value(),
+            self.compute_key.eject_value(),
+        )
+    }
+}
+
+#[cfg(test)]
+#[path = ""]
+mod test {
+    use super::*;
+
+    mod signature_tests;
+}
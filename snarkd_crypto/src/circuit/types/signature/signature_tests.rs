@@ -0,0 +1,77 @@
+use crate::{
+    bls12_377::Fp,
+    circuit::{
+        circuit::Circuit,
+        rng_test_struct::{TestRng, Uniform},
+        Environment,
+    },
+    keys::{self, PrivateKey},
+};
+
+use super::*;
+
+const ITERATIONS: usize = 2;
+
+fn sample_message(num_elements: usize, rng: &mut TestRng) -> Vec<Fp> {
+    (0..num_elements).map(|_| Uniform::rand(rng)).collect()
+}
+
+/// Checks the in-circuit verification of `signature` against the native one.
+fn check_verify(mode: Mode, signature: keys::Signature, address: keys::Address, message: &[Fp]) {
+    let expected = signature.verify(&address, message);
+
+    Circuit::scope(format!("Verify: {mode}"), || {
+        let signature = Signature::new(mode, signature);
+        let address = Address::new(mode, address);
+        let message = message
+            .iter()
+            .map(|element| Field::new(mode, *element))
+            .collect::<Vec<_>>();
+
+        let candidate = signature.verify(&address, &message);
+        assert_eq!(expected, candidate.eject_value());
+        if mode.is_constant() {
+            assert!(candidate.is_constant());
+        }
+        assert!(Circuit::is_satisfied_in_scope());
+    });
+    Circuit::reset();
+}
+
+#[test]
+fn test_verify() {
+    let mut rng = TestRng::default();
+
+    for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+        for num_elements in 0..ITERATIONS {
+            let private_key = PrivateKey::rand();
+            let address = keys::ComputeKey::from(&private_key).to_address();
+            let message = sample_message(num_elements + 1, &mut rng);
+            let signature = private_key.sign(&message);
+            assert!(signature.verify(&address, &message));
+            check_verify(mode, signature, address, &message);
+
+            // A signature does not verify for another message or another address.
+            let other_message = sample_message(num_elements + 1, &mut rng);
+            check_verify(mode, signature, address, &other_message);
+            let other_address = keys::ComputeKey::from(&PrivateKey::rand()).to_address();
+            check_verify(mode, signature, other_address, &message);
+        }
+    }
+}
+
+#[test]
+fn test_eject() {
+    let mut rng = TestRng::default();
+
+    for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+        let message = sample_message(2, &mut rng);
+        let expected = PrivateKey::rand().sign(&message);
+        let candidate = Signature::new(mode, expected);
+        assert_eq!(expected, candidate.eject_value());
+        assert_eq!(expected.challenge, candidate.challenge().eject_value());
+        assert_eq!(expected.response, candidate.response().eject_value());
+        assert_eq!(expected.compute_key, candidate.compute_key().eject_value());
+        Circuit::reset();
+    }
+}
@@ -2,7 +2,7 @@ use super::*;
 use anyhow::{anyhow, Result};
 
 /// The coinbase puzzle solution constructed by accumulating the individual prover solutions.
-#[derive(Clone, Eq, PartialEq, Hash)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct CoinbaseSolution {
     /// The partial solutions of the coinbase puzzle, which are aggregated into a single solution.
     partial_solutions: Vec<PartialSolution>,
@@ -19,6 +19,7 @@ use crate::{
     fft::{domain::FFTPrecomputation, DensePolynomial, EvaluationDomain},
     keys::Address,
     polycommit::kzg10::{Commitment, LagrangeBasis, Proof, VerifierKey, KZG10},
+    serialization::impl_canonical_serialization,
 };
 use anyhow::Result;
 use std::borrow::Cow;
@@ -16,7 +16,7 @@ pub fn sha256d_to_u64(data: &[u8]) -> u64 {
 }
 
 /// The partial solution for the coinbase puzzle from a prover.
-#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub struct PartialSolution {
     /// The address of the prover.
     address: Address,
@@ -26,6 +26,12 @@ pub struct PartialSolution {
     commitment: PuzzleCommitment,
 }
 
+impl_canonical_serialization!(PartialSolution {
+    address,
+    nonce,
+    commitment
+});
+
 impl PartialSolution {
     /// Initializes a new instance of the partial solution.
     pub fn new<C: Into<PuzzleCommitment>>(address: Address, nonce: u64, commitment: C) -> Self {
@@ -2,7 +2,7 @@ use super::*;
 use anyhow::{anyhow, Result};
 
 /// The prover solution for the coinbase puzzle from a prover.
-#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub struct ProverSolution {
     /// The core data of the prover solution.
     partial_solution: PartialSolution,
@@ -10,6 +10,11 @@ pub struct ProverSolution {
     proof: PuzzleProof,
 }
 
+impl_canonical_serialization!(ProverSolution {
+    partial_solution,
+    proof
+});
+
 impl ProverSolution {
     /// Initializes a new instance of the prover solution.
     pub const fn new(partial_solution: PartialSolution, proof: PuzzleProof) -> Self {
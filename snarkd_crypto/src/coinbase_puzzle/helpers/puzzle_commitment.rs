@@ -2,12 +2,14 @@ use super::*;
 use std::ops::Deref;
 
 /// A coinbase puzzle commitment to a polynomial.
-#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub struct PuzzleCommitment {
     /// The commitment for the solution.
     commitment: Commitment,
 }
 
+impl_canonical_serialization!(PuzzleCommitment { commitment });
+
 impl PuzzleCommitment {
     /// Initializes a new instance of the puzzle commitment.
     pub const fn new(commitment: Commitment) -> Self {
@@ -12,9 +12,12 @@ use crate::{
     fft::{DensePolynomial, EvaluationDomain},
     keys::Address,
     msm::VariableBase,
-    polycommit::kzg10::{Commitment, UniversalParams as SRS, KZG10},
+    polycommit::{
+        kzg10::{Commitment, UniversalParams as SRS, KZG10},
+        parameters,
+    },
 };
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, ensure, Result};
 use rayon::prelude::*;
 use std::{collections::HashSet, hash::Hash, sync::Arc};
 
@@ -53,9 +56,9 @@ impl CoinbasePuzzle {
     /// Initializes a new `SRS` for the coinbase puzzle.
     #[cfg(any(test, feature = "setup"))]
     pub fn setup(config: PuzzleConfig) -> Result<SRS> {
-        // The SRS must support committing to the product of two degree `n` polynomials.
-        // Thus, the SRS must support committing to a polynomial of degree `2n - 1`.
-        let total_degree = (2 * config.degree - 1).try_into()?;
+        // The SRS must support committing to the product of two degree `n` polynomials,
+        // over the whole product domain, see `srs_degree`.
+        let total_degree = Self::srs_degree(config)?;
         let srs = KZG10::setup(
             total_degree,
             &crate::kzg10::KZG10DegreeBoundsConfig::NONE,
@@ -64,18 +67,26 @@ impl CoinbasePuzzle {
         Ok(srs)
     }
 
-    /// Load the coinbase puzzle proving and verifying keys.
-    pub fn load() -> Result<Self> {
-        let max_degree = COINBASE_PUZZLE_DEGREE;
-        // Load the universal SRS.
-        // TODO: This needs to be loaded from disk.
-        let universal_srs = KZG10::setup(
-            max_degree as usize,
-            &crate::kzg10::KZG10DegreeBoundsConfig::NONE,
-            true,
-        )?;
+    /// Load the coinbase puzzle proving and verifying keys for `config`, trimmed from the universal SRS
+    /// of the parameters directory.
+    pub fn load(config: PuzzleConfig) -> Result<Self> {
+        let universal_srs = parameters::universal_srs()?
+            .ok_or_else(|| anyhow!("the parameters directory is not set"))?;
+        let srs_degree = Self::srs_degree(config)?;
+        ensure!(
+            universal_srs.max_degree() >= srs_degree,
+            "the universal SRS supports degree {}, but the coinbase puzzle requires {srs_degree}",
+            universal_srs.max_degree()
+        );
         // Trim the universal SRS to the maximum degree.
-        Self::trim(&universal_srs, PuzzleConfig { degree: max_degree })
+        Self::trim(&universal_srs, config)
+    }
+
+    /// Returns the degree the universal SRS must support to trim a puzzle with the given config,
+    /// which is that of the product of two polynomials of degree `config.degree`, rounded up to
+    /// a power of two minus one.
+    pub fn srs_degree(config: PuzzleConfig) -> Result<usize> {
+        Ok(Self::product_domain(config.degree)?.size() - 1)
     }
 
     pub fn trim(srs: &SRS, config: PuzzleConfig) -> Result<Self> {
@@ -1,5 +1,8 @@
 use super::*;
-use crate::keys::{Address, ComputeKey, PrivateKey};
+use crate::{
+    keys::{Address, ComputeKey, PrivateKey},
+    serialization::{CanonicalDeserialize, CanonicalSerialize, Compress},
+};
 
 use rand::{Rng, RngCore};
 
@@ -69,6 +72,14 @@ fn test_prover_solution_minimum_target() {
             .unwrap();
         let proof_target = solution.to_target().unwrap();
 
+        // Assert that the solution survives its canonical encoding, as relayed between peers.
+        let bytes = solution.to_bytes(Compress::Yes);
+        assert_eq!(
+            ProverSolution::from_bytes(&bytes, Compress::Yes).unwrap(),
+            solution
+        );
+        assert!(ProverSolution::from_bytes(&bytes[1..], Compress::Yes).is_err());
+
         // Assert that the operation will pass if the minimum target is low enough.
         puzzle
             .prove(
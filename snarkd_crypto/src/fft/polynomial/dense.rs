@@ -3,6 +3,7 @@
 use crate::{
     bls12_377::{Field, Scalar},
     fft::{EvaluationDomain, Evaluations, Polynomial},
+    serialization::impl_canonical_serialization,
 };
 use std::{
     fmt,
@@ -21,6 +22,8 @@ pub struct DensePolynomial {
     pub coeffs: Vec<Scalar>,
 }
 
+impl_canonical_serialization!(DensePolynomial { coeffs });
+
 impl fmt::Debug for DensePolynomial {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         for (i, coeff) in self.coeffs.iter().enumerate().filter(|(_, c)| !c.is_zero()) {
@@ -1,6 +1,21 @@
-use crate::bls12_377::G1Affine;
+use std::{
+    fmt,
+    io::{self, Read, Write},
+    str::FromStr,
+};
 
-#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+use anyhow::{Error, Result};
+
+use super::encoding::*;
+use crate::{
+    bls12_377::G1Affine,
+    serialization::{CanonicalDeserialize, CanonicalSerialize, Compress},
+};
+
+/// The human readable part of address strings.
+const ADDRESS_PREFIX: &str = "aleo";
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Address(pub G1Affine);
 
 impl Address {
@@ -8,4 +23,45 @@ impl Address {
     pub fn new(group: G1Affine) -> Self {
         Self(group)
     }
+
+    /// Returns the compressed group element.
+    pub fn to_bytes_le(&self) -> [u8; 48] {
+        self.0
+            .to_bytes(Compress::Yes)
+            .try_into()
+            .expect("a compressed point is 48 bytes")
+    }
+
+    /// Reads an address written by [`Address::to_bytes_le`].
+    pub fn from_bytes_le(bytes: &[u8]) -> Result<Self> {
+        Ok(Self(read_point(bytes)?))
+    }
 }
+
+impl CanonicalSerialize for Address {
+    fn serialize_with_mode<W: Write>(&self, writer: &mut W, compress: Compress) -> io::Result<()> {
+        self.0.serialize_with_mode(writer, compress)
+    }
+}
+
+impl CanonicalDeserialize for Address {
+    fn deserialize_with_mode<R: Read>(reader: &mut R, compress: Compress) -> io::Result<Self> {
+        Ok(Self(G1Affine::deserialize_with_mode(reader, compress)?))
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", encode_bech32(ADDRESS_PREFIX, &self.to_bytes_le()))
+    }
+}
+
+impl FromStr for Address {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_bytes_le(&decode_bech32(ADDRESS_PREFIX, s, "address")?)
+    }
+}
+
+serde_with_str!(Address);
@@ -1,12 +1,16 @@
-use super::{Address, PrivateKey};
+use std::{fmt, str::FromStr};
+
+use anyhow::{Error, Result};
+
+use super::{encoding::*, Address, PrivateKey};
 use crate::{
     bls12_377::{Affine, G1Affine, Projective, Scalar},
+    serialization::{CanonicalSerialize, Compress},
     utils::*,
 };
 
-//@jds: what is this?
-// It's a constant prefix used for key identification
-static _COMPUTE_KEY_PREFIX: [u8; 10] = [109, 249, 98, 224, 36, 15, 213, 187, 79, 190]; // AComputeKey1
+/// Prefix of compute key strings, which start with `AComputeKey1`.
+static COMPUTE_KEY_PREFIX: [u8; 9] = [72, 172, 178, 8, 180, 18, 42, 116, 23];
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct ComputeKey {
@@ -16,6 +20,20 @@ pub struct ComputeKey {
 }
 
 impl ComputeKey {
+    /// Returns the compute key of the given signature public keys, deriving the PRF secret key
+    /// from them.
+    pub fn new(public_key_signature: G1Affine, public_randomness_signature: G1Affine) -> Self {
+        let mut sponge = PoseidonSponge::default();
+        sponge
+            .absorb_native_field_elements(&[public_key_signature.x, public_randomness_signature.x]);
+        let prf_secret_key = sponge.squeeze_short_nonnative_field_element();
+        Self {
+            public_key_signature,
+            public_randomness_signature,
+            prf_secret_key,
+        }
+    }
+
     /// Returns the address corresponding to the compute key.
     pub fn to_address(&self) -> Address {
         // Compute pk_prf := G^sk_prf.
@@ -28,6 +46,23 @@ impl ComputeKey {
                 .to_affine(),
         )
     }
+
+    /// Returns the compressed `pk_sig` and `pr_sig`, which the PRF secret key is derived from.
+    pub fn to_bytes_le(&self) -> [u8; 96] {
+        let mut bytes = [0u8; 96];
+        bytes[..48].copy_from_slice(&self.public_key_signature.to_bytes(Compress::Yes));
+        bytes[48..].copy_from_slice(&self.public_randomness_signature.to_bytes(Compress::Yes));
+        bytes
+    }
+
+    /// Reads a compute key written by [`ComputeKey::to_bytes_le`].
+    pub fn from_bytes_le(bytes: &[u8]) -> Result<Self> {
+        ensure_len(bytes, 96, "compute key")?;
+        Ok(Self::new(
+            read_point(&bytes[..48])?,
+            read_point(&bytes[48..])?,
+        ))
+    }
 }
 
 impl From<&PrivateKey> for ComputeKey {
@@ -36,14 +71,26 @@ impl From<&PrivateKey> for ComputeKey {
             (G1Affine::prime_subgroup_generator() * value.sk_sig).to_affine();
         let public_randomness_signature =
             (G1Affine::prime_subgroup_generator() * value.r_sig).to_affine();
-        let mut sponge = PoseidonSponge::default();
-        sponge
-            .absorb_native_field_elements(&[public_key_signature.x, public_randomness_signature.x]);
-        let prf_secret_key = sponge.squeeze_short_nonnative_field_element();
-        Self {
-            public_key_signature,
-            public_randomness_signature,
-            prf_secret_key,
-        }
+        Self::new(public_key_signature, public_randomness_signature)
     }
 }
+
+impl fmt::Display for ComputeKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            encode_base58(&COMPUTE_KEY_PREFIX, &self.to_bytes_le())
+        )
+    }
+}
+
+impl FromStr for ComputeKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_bytes_le(&decode_base58(&COMPUTE_KEY_PREFIX, s, "compute key")?)
+    }
+}
+
+serde_with_str!(ComputeKey);
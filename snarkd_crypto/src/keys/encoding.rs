@@ -0,0 +1,171 @@
+//! Helpers for the canonical bytes and string encodings of account keys.
+//!
+//! Secret keys are written in base58 with a 4-byte double SHA-256 checksum, after a prefix
+//! chosen so that every key of a type starts with the same readable text, like `APrivateKey1`.
+//! Addresses and signatures are written in bech32, as `aleo1…` and `sign1…`.
+
+use anyhow::{anyhow, ensure, Result};
+use bech32::{FromBase32, ToBase32, Variant};
+use ruint::Uint;
+
+use crate::{
+    bls12_377::{scalar, G1Affine, Scalar},
+    serialization::{CanonicalDeserialize, Compress},
+};
+
+pub(crate) fn encode_base58(prefix: &[u8], payload: &[u8]) -> String {
+    bs58::encode([prefix, payload].concat())
+        .with_check()
+        .into_string()
+}
+
+/// Decodes a string written by [`encode_base58`], returning the payload after `prefix`.
+pub(crate) fn decode_base58(prefix: &[u8], text: &str, name: &str) -> Result<Vec<u8>> {
+    let bytes = bs58::decode(text)
+        .with_check(None)
+        .into_vec()
+        .map_err(|e| anyhow!("invalid {name}: {e}"))?;
+    let payload = bytes
+        .strip_prefix(prefix)
+        .ok_or_else(|| anyhow!("invalid {name}: wrong prefix"))?;
+    Ok(payload.to_vec())
+}
+
+pub(crate) fn encode_bech32(hrp: &str, payload: &[u8]) -> String {
+    bech32::encode(hrp, payload.to_base32(), Variant::Bech32).expect("invalid bech32 prefix")
+}
+
+/// Decodes a string written by [`encode_bech32`] with the human readable part `hrp`.
+pub(crate) fn decode_bech32(hrp: &str, text: &str, name: &str) -> Result<Vec<u8>> {
+    let (found, data, variant) =
+        bech32::decode(text).map_err(|e| anyhow!("invalid {name}: {e}"))?;
+    ensure!(
+        found == hrp && variant == Variant::Bech32,
+        "invalid {name}: expected a bech32 string starting with `{hrp}1`"
+    );
+    Ok(Vec::from_base32(&data)?)
+}
+
+/// Reads a scalar from 32 little endian bytes, rejecting values outside of the field.
+pub(crate) fn read_scalar(bytes: &[u8]) -> Result<Scalar> {
+    let bytes: [u8; 32] = bytes
+        .try_into()
+        .map_err(|_| anyhow!("a scalar must be 32 bytes, found {}", bytes.len()))?;
+    let scalar = Uint::from_le_bytes(bytes);
+    ensure!(scalar < scalar::MODULUS, "scalar is not canonical");
+    Ok(Scalar(scalar))
+}
+
+/// Reads a point in its compressed canonical encoding.
+pub(crate) fn read_point(bytes: &[u8]) -> Result<G1Affine> {
+    G1Affine::from_bytes(bytes, Compress::Yes).map_err(|e| anyhow!("invalid group element: {e}"))
+}
+
+/// Checks that `bytes` has the encoded size of `name` before it is split up.
+pub(crate) fn ensure_len(bytes: &[u8], len: usize, name: &str) -> Result<()> {
+    ensure!(
+        bytes.len() == len,
+        "{name} must be {len} bytes, found {}",
+        bytes.len()
+    );
+    Ok(())
+}
+
+/// Implements `Serialize` and `Deserialize` with the `Display` and `FromStr` string encoding.
+macro_rules! serde_with_str {
+    ($type:ty) => {
+        impl serde::Serialize for $type {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $type {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                String::deserialize(deserializer)?
+                    .parse()
+                    .map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+pub(crate) use serde_with_str;
+
+#[cfg(test)]
+mod tests {
+    use std::{fmt::Display, str::FromStr};
+
+    use crate::{
+        bls12_377::{Field, Scalar},
+        keys::*,
+    };
+
+    fn round_trip<T>(value: &T, prefix: &str)
+    where
+        T: Display + FromStr<Err = anyhow::Error> + PartialEq + serde::Serialize,
+        T: serde::de::DeserializeOwned,
+    {
+        let text = value.to_string();
+        assert!(text.starts_with(prefix), "{text}");
+        assert!(text.parse::<T>().unwrap() == *value);
+        let json = serde_json::to_string(value).unwrap();
+        assert_eq!(json, format!("\"{text}\""));
+        assert!(serde_json::from_str::<T>(&json).unwrap() == *value);
+
+        // Changing any character breaks the checksum.
+        let mut corrupted = text.into_bytes();
+        let last = corrupted.len() - 1;
+        corrupted[last] = if corrupted[last] == b'q' { b'p' } else { b'q' };
+        assert!(String::from_utf8(corrupted).unwrap().parse::<T>().is_err());
+    }
+
+    #[test]
+    fn string_encodings() {
+        for _ in 0..4 {
+            let private_key = PrivateKey::rand();
+            let view_key = ViewKey::from(&private_key);
+            let compute_key = ComputeKey::from(&private_key);
+            let signature = private_key.sign(&[]);
+            assert_eq!(view_key.to_address(), compute_key.to_address());
+
+            round_trip(&private_key, "APrivateKey1");
+            round_trip(&view_key, "AViewKey1");
+            round_trip(&compute_key, "AComputeKey1");
+            round_trip(&compute_key.to_address(), "aleo1");
+            round_trip(&signature, "sign1");
+        }
+        // The prefixes hold for the smallest and largest payloads.
+        let zero = PrivateKey::new(Scalar::ZERO, Scalar::ZERO);
+        let max = PrivateKey::new(-Scalar::ONE, -Scalar::ONE);
+        assert!(zero.to_string().starts_with("APrivateKey1"));
+        assert!(max.to_string().starts_with("APrivateKey1"));
+        assert!(ViewKey(Scalar::ZERO).to_string().starts_with("AViewKey1"));
+        assert!(ViewKey(-Scalar::ONE).to_string().starts_with("AViewKey1"));
+    }
+
+    #[test]
+    fn rejects_invalid_bytes() {
+        let private_key = PrivateKey::rand();
+        let mut bytes = private_key.to_bytes_le();
+        assert!(PrivateKey::from_bytes_le(&bytes).unwrap() == private_key);
+        assert!(PrivateKey::from_bytes_le(&bytes[1..]).is_err());
+        bytes[31] = 0xff;
+        assert!(PrivateKey::from_bytes_le(&bytes).is_err());
+
+        let address = ComputeKey::from(&private_key).to_address();
+        let mut bytes = address.to_bytes_le();
+        assert_eq!(Address::from_bytes_le(&bytes).unwrap(), address);
+        bytes[0] ^= 1;
+        // Most x-coordinates are not on the curve, and those that are need not be in the subgroup.
+        if let Ok(other) = Address::from_bytes_le(&bytes) {
+            assert_ne!(other, address);
+        }
+        bytes[47] = 0xff;
+        assert!(Address::from_bytes_le(&bytes).is_err());
+
+        // A view key string is not a private key, even with a valid checksum.
+        let view_key = ViewKey::from(&private_key).to_string();
+        assert!(view_key.parse::<PrivateKey>().is_err());
+        assert!(address.to_string().parse::<Signature>().is_err());
+    }
+}
@@ -1,6 +1,34 @@
-use crate::bls12_377::Fp;
+use ruint::Uint;
 
+use super::{PrivateKey, ViewKey};
+use crate::{bls12_377::Fp, utils::*};
+
+/// Domain separator absorbed with the view key when deriving the graph key.
+const GRAPH_KEY_DOMAIN: &[u8] = b"snarkd.graph_key.v1";
+
+/// The key that finds the serial numbers of the records of an account, and so which of them are
+/// spent, without being able to decrypt them.
 #[derive(Clone, PartialEq, Eq)]
 pub struct GraphKey {
     pub sk_tag: Fp,
 }
+
+impl From<&ViewKey> for GraphKey {
+    /// `sk_tag` is a Poseidon hash of the view key, so the view key can't be recovered from it.
+    fn from(view_key: &ViewKey) -> Self {
+        let mut sponge = PoseidonSponge::default();
+        sponge.absorb_bytes(GRAPH_KEY_DOMAIN);
+        // The scalar field is smaller than the base field.
+        sponge
+            .absorb_native_field_elements(&[Fp(Uint::from_limbs_slice(view_key.0 .0.as_limbs()))]);
+        Self {
+            sk_tag: sponge.squeeze_native_field_elements(1)[0],
+        }
+    }
+}
+
+impl From<&PrivateKey> for GraphKey {
+    fn from(private_key: &PrivateKey) -> Self {
+        Self::from(&ViewKey::from(private_key))
+    }
+}
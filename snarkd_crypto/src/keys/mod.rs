@@ -1,4 +1,5 @@
 pub mod address;
+mod encoding;
 pub use address::*;
 pub mod compute_key;
 pub use compute_key::*;
@@ -6,6 +7,8 @@ pub mod graph_key;
 pub use graph_key::*;
 pub mod private_key;
 pub use private_key::*;
+pub mod record;
+pub use record::*;
 pub mod signature;
 pub use signature::*;
 pub mod view_key;
@@ -1,9 +1,24 @@
-use super::{ComputeKey, Signature};
+use std::{fmt, str::FromStr};
+
+use anyhow::{Error, Result};
+use rand::{distributions::Standard, CryptoRng, Rng};
+use ruint::Uint;
+
+use super::{encoding::*, ComputeKey, Signature};
 use crate::{
-    bls12_377::{Affine, Field, Fp, G1Affine, Projective, Scalar},
-    utils::*,
+    bls12_377::{fp, scalar, Affine, Fp, G1Affine, Projective, Scalar},
+    utils::{
+        sha256::{hmac_sha256, sha256},
+        PoseidonSponge,
+    },
 };
 
+/// Domain separator absorbed with the seed when deriving the keys of an account.
+const ACCOUNT_DOMAIN: &[u8] = b"snarkd.account.v1";
+
+/// Prefix of private key strings, which start with `APrivateKey1`.
+static PRIVATE_KEY_PREFIX: [u8; 10] = [4, 254, 19, 127, 1, 198, 118, 207, 32, 157];
+
 #[derive(Clone, PartialEq, Eq)]
 pub struct PrivateKey {
     /// The derived signature secret key.
@@ -18,40 +33,137 @@ impl PrivateKey {
         Self { sk_sig, r_sig }
     }
 
-    /// Samples a new random private key.
-    pub fn rand() -> Self {
+    /// Returns `sk_sig || r_sig` in little endian.
+    pub fn to_bytes_le(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(self.sk_sig.0.as_le_slice());
+        bytes[32..].copy_from_slice(self.r_sig.0.as_le_slice());
+        bytes
+    }
+
+    /// Reads a private key written by [`PrivateKey::to_bytes_le`].
+    pub fn from_bytes_le(bytes: &[u8]) -> Result<Self> {
+        ensure_len(bytes, 64, "private key")?;
+        Ok(Self::new(
+            read_scalar(&bytes[..32])?,
+            read_scalar(&bytes[32..])?,
+        ))
+    }
+
+    /// Derives the private key of an account from its seed.
+    ///
+    /// `sk_sig` and `r_sig` are Poseidon hashes of the account domain, the seed and a counter of
+    /// 0 and 1 respectively, so the same seed always gives the same account.
+    pub fn from_seed(seed: Fp) -> Self {
+        let mut domain = [0u8; 48];
+        domain[..ACCOUNT_DOMAIN.len()].copy_from_slice(ACCOUNT_DOMAIN);
+        let domain = Fp(Uint::from_le_bytes(domain));
+        let derive = |counter: u64| {
+            let mut sponge = PoseidonSponge::default();
+            sponge.absorb_native_field_elements(&[domain, seed, Fp::from(counter)]);
+            sponge.squeeze_nonnative_field_elements(1)[0]
+        };
         Self {
-            sk_sig: Scalar::rand(),
-            r_sig: Scalar::rand(),
+            sk_sig: derive(0),
+            r_sig: derive(1),
         }
     }
 
+    /// Samples a new random private key.
+    pub fn rand() -> Self {
+        Self::rand_with_rng(&mut rand::thread_rng())
+    }
+
+    /// Samples a new private key from a seed drawn from `rng`.
+    pub fn rand_with_rng<R: Rng + CryptoRng + ?Sized>(rng: &mut R) -> Self {
+        // Rejection sampling keeps the seed uniform over the base field.
+        let seed = loop {
+            let seed = rng.sample::<Uint<384, 6>, _>(Standard) >> (384 - fp::MODULUS_BITS as usize);
+            if seed < fp::MODULUS {
+                break Fp(seed);
+            }
+        };
+        Self::from_seed(seed)
+    }
+
     /// Returns a signature for the given message (as field elements) using the private key.
     pub fn sign(&self, message: &[Fp]) -> Signature {
+        self.sign_with_rng(message, &mut rand::thread_rng())
+    }
+
+    /// Returns a signature for the given message using a nonce drawn from `rng`.
+    pub fn sign_with_rng<R: Rng + CryptoRng + ?Sized>(
+        &self,
+        message: &[Fp],
+        rng: &mut R,
+    ) -> Signature {
         // Sample a random nonce from the scalar field.
-        let nonce = Scalar::rand();
+        let nonce = loop {
+            let nonce =
+                rng.sample::<Uint<256, 4>, _>(Standard) >> (256 - scalar::MODULUS_BITS as usize);
+            if nonce != Uint::ZERO && nonce < scalar::MODULUS {
+                break Scalar(nonce);
+            }
+        };
+        self.sign_with_nonce(message, nonce)
+    }
+
+    /// Returns a signature for the given message whose nonce is derived from the private key and
+    /// the message, so signing the same message twice gives the same signature.
+    pub fn sign_deterministic(&self, message: &[Fp]) -> Signature {
+        self.sign_with_nonce(message, self.deterministic_nonce(message))
+    }
+
+    /// Derives a signing nonce as in RFC 6979, section 3.2, with HMAC-SHA256 over the scalar
+    /// field. The message hash is the SHA-256 hash of its field elements in little endian.
+    fn deterministic_nonce(&self, message: &[Fp]) -> Scalar {
+        // bits2int keeps the leftmost `qlen` bits of a 256-bit big endian string.
+        let bits2int = |bytes: [u8; 32]| {
+            Uint::<256, 4>::from_be_bytes(bytes) >> (256 - scalar::MODULUS_BITS as usize)
+        };
+
+        let h1 = sha256(
+            &message
+                .iter()
+                .flat_map(|element| element.0.to_le_bytes::<48>())
+                .collect::<Vec<_>>(),
+        );
+        // bits2octets reduces the hash once, as it has fewer bits than twice the modulus.
+        let mut h1 = bits2int(h1);
+        if h1 >= scalar::MODULUS {
+            h1 -= scalar::MODULUS;
+        }
+        let h1 = h1.to_be_bytes::<32>();
+        let x = self.sk_sig.0.to_be_bytes::<32>();
+
+        let mut v = [0x01u8; 32];
+        let mut k = [0x00u8; 32];
+        k = hmac_sha256(&k, &[&v, &[0x00], &x, &h1]);
+        v = hmac_sha256(&k, &[&v]);
+        k = hmac_sha256(&k, &[&v, &[0x01], &x, &h1]);
+        v = hmac_sha256(&k, &[&v]);
+        loop {
+            v = hmac_sha256(&k, &[&v]);
+            let nonce = bits2int(v);
+            if nonce != Uint::ZERO && nonce < scalar::MODULUS {
+                return Scalar(nonce);
+            }
+            k = hmac_sha256(&k, &[&v, &[0x00]]);
+            v = hmac_sha256(&k, &[&v]);
+        }
+    }
+
+    fn sign_with_nonce(&self, message: &[Fp], nonce: Scalar) -> Signature {
         // Compute `g_r` as `nonce * G`.
         let g_r = (G1Affine::prime_subgroup_generator() * nonce).to_affine();
 
         // Derive the compute key from the private key.
         let compute_key = ComputeKey::from(self);
-        // Retrieve pk_sig.
-        let pk_sig = compute_key.public_key_signature;
-        // Retrieve pr_sig.
-        let pr_sig = compute_key.public_randomness_signature;
-
         // Derive the address from the compute key.
         let address = compute_key.to_address();
 
-        // Construct the hash input as (r * G, pk_sig, pr_sig, address, message).
-        let mut preimage = Vec::with_capacity(4 + message.len());
-        preimage.extend([g_r, pk_sig, pr_sig, address.0].map(|point| point.x));
-        preimage.extend(message);
-
         // Compute the verifier challenge.
-        let mut sponge = PoseidonSponge::default();
-        sponge.absorb_native_field_elements(&preimage);
-        let challenge = sponge.squeeze_short_nonnative_field_element();
+        let challenge = Signature::challenge(g_r, &compute_key, &address, message);
         // Compute the prover response.
         let response = nonce - (challenge * self.sk_sig);
 
@@ -59,3 +171,66 @@ impl PrivateKey {
         Signature::new(challenge, response, compute_key)
     }
 }
+
+impl fmt::Display for PrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            encode_base58(&PRIVATE_KEY_PREFIX, &self.to_bytes_le())
+        )
+    }
+}
+
+impl FromStr for PrivateKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_bytes_le(&decode_base58(&PRIVATE_KEY_PREFIX, s, "private key")?)
+    }
+}
+
+serde_with_str!(PrivateKey);
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn from_seed() {
+        let key = PrivateKey::from_seed(Fp::from(7u64));
+        assert!(key == PrivateKey::from_seed(Fp::from(7u64)));
+        assert!(key != PrivateKey::from_seed(Fp::from(8u64)));
+        assert!(key.sk_sig != key.r_sig);
+
+        let key = PrivateKey::rand_with_rng(&mut StdRng::seed_from_u64(1));
+        assert!(key == PrivateKey::rand_with_rng(&mut StdRng::seed_from_u64(1)));
+        assert!(key != PrivateKey::rand_with_rng(&mut StdRng::seed_from_u64(2)));
+    }
+
+    #[test]
+    fn sign_deterministic() {
+        let key = PrivateKey::from_seed(Fp::from(7u64));
+        let address = ComputeKey::from(&key).to_address();
+        let message = [Fp::from(1u64), Fp::from(2u64)];
+
+        let signature = key.sign_deterministic(&message);
+        assert!(signature.verify(&address, &message));
+        assert_eq!(signature, key.sign_deterministic(&message));
+        assert_ne!(signature, key.sign_deterministic(&message[..1]));
+        assert_ne!(
+            signature,
+            PrivateKey::from_seed(Fp::from(8u64)).sign_deterministic(&message)
+        );
+
+        let mut rng = StdRng::seed_from_u64(1);
+        let signature = key.sign_with_rng(&message, &mut rng);
+        assert!(signature.verify(&address, &message));
+        assert_eq!(
+            signature,
+            key.sign_with_rng(&message, &mut StdRng::seed_from_u64(1))
+        );
+    }
+}
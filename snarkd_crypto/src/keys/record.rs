@@ -0,0 +1,210 @@
+//! Records encrypted to their owner.
+//!
+//! A record is created with a random scalar `r`, and its nonce `r * G` is public. The sender
+//! knows `r` and the owner knows `view_key`, so both can compute the record view key
+//! `(r * address).x = (view_key * nonce).x`, which seeds the Poseidon pads added to the owner,
+//! gates and data of the record.
+
+use anyhow::{ensure, Result};
+use smallvec::SmallVec;
+
+use super::{Address, GraphKey, ViewKey};
+use crate::{
+    bls12_377::{Affine, Fp, G1Affine, Projective, Scalar},
+    utils::*,
+};
+
+/// Domain separator of the pads that encrypt records.
+const ENCRYPTION_DOMAIN: &[u8] = b"snarkd.record.encryption.v1";
+
+/// Domain separator of record commitments.
+const COMMITMENT_DOMAIN: &[u8] = b"snarkd.record.commitment.v1";
+
+/// The contents of a record, as seen by its owner.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordPlaintext {
+    pub owner: Address,
+    pub gates: u64,
+    pub data: Vec<Fp>,
+    /// `r * G`, for the scalar `r` that encrypts the record.
+    pub nonce: G1Affine,
+}
+
+/// A record as published on chain, readable only with the view key of its owner.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordCiphertext {
+    /// The x-coordinate of the owner address, encrypted.
+    pub owner: Fp,
+    pub gates: Fp,
+    pub data: Vec<Fp>,
+    pub nonce: G1Affine,
+}
+
+/// Returns the first `count` pads of the record view key, the first of which encrypts the owner.
+fn pads(record_view_key: Fp, count: usize) -> SmallVec<[Fp; 10]> {
+    let mut sponge = PoseidonSponge::default();
+    sponge.absorb_bytes(ENCRYPTION_DOMAIN);
+    sponge.absorb_native_field_elements(&[record_view_key]);
+    sponge.squeeze_native_field_elements(count)
+}
+
+impl RecordPlaintext {
+    /// Creates a record owned by `owner`, to be encrypted with `randomizer`.
+    pub fn new(owner: Address, gates: u64, data: Vec<Fp>, randomizer: Scalar) -> Self {
+        Self {
+            owner,
+            gates,
+            data,
+            nonce: (G1Affine::prime_subgroup_generator() * randomizer).to_affine(),
+        }
+    }
+
+    /// Encrypts the record to its owner. Fails unless `randomizer` is the scalar the record was
+    /// created with, as the owner could not find the record view key from the nonce otherwise.
+    pub fn encrypt(&self, randomizer: Scalar) -> Result<RecordCiphertext> {
+        ensure!(
+            (G1Affine::prime_subgroup_generator() * randomizer).to_affine() == self.nonce,
+            "the randomizer does not match the nonce of the record"
+        );
+        let record_view_key = (self.owner.0 * randomizer).to_affine().x;
+        let pads = pads(record_view_key, 2 + self.data.len());
+        Ok(RecordCiphertext {
+            owner: self.owner.0.x + pads[0],
+            gates: Fp::from(self.gates) + pads[1],
+            data: self
+                .data
+                .iter()
+                .zip(&pads[2..])
+                .map(|(x, pad)| *x + pad)
+                .collect(),
+            nonce: self.nonce,
+        })
+    }
+}
+
+impl RecordCiphertext {
+    /// Returns the commitment to the record, which the serial number of the record is derived
+    /// from.
+    pub fn commitment(&self) -> Fp {
+        let mut preimage = Vec::with_capacity(3 + self.data.len());
+        preimage.extend([self.nonce.x, self.owner, self.gates]);
+        preimage.extend(&self.data);
+
+        let mut sponge = PoseidonSponge::default();
+        sponge.absorb_bytes(COMMITMENT_DOMAIN);
+        sponge.absorb_native_field_elements(&preimage);
+        sponge.squeeze_native_field_elements(1)[0]
+    }
+}
+
+impl ViewKey {
+    /// Returns the record view key of the record with the given nonce, `(view_key * nonce).x`.
+    fn record_view_key(&self, nonce: G1Affine) -> Fp {
+        (nonce * self.0).to_affine().x
+    }
+
+    /// Returns `true` if the record is owned by the account of this view key. This decrypts
+    /// only the owner, so it is cheaper than [`ViewKey::decrypt_record`] when scanning blocks.
+    pub fn is_owner(&self, record: &RecordCiphertext) -> bool {
+        let pad = pads(self.record_view_key(record.nonce), 1)[0];
+        record.owner - pad == self.to_address().0.x
+    }
+
+    /// Decrypts a record owned by the account of this view key, or returns `None` if it belongs
+    /// to another account.
+    pub fn decrypt_record(&self, record: &RecordCiphertext) -> Option<RecordPlaintext> {
+        let owner = self.to_address();
+        let pads = pads(self.record_view_key(record.nonce), 2 + record.data.len());
+        if record.owner - pads[0] != owner.0.x {
+            return None;
+        }
+        let gates = (record.gates - pads[1]).0.try_into().ok()?;
+        Some(RecordPlaintext {
+            owner,
+            gates,
+            data: record
+                .data
+                .iter()
+                .zip(&pads[2..])
+                .map(|(x, pad)| *x - pad)
+                .collect(),
+            nonce: record.nonce,
+        })
+    }
+}
+
+impl GraphKey {
+    /// Returns the tag of the record with the given commitment, which is published as its serial
+    /// number when the record is spent.
+    pub fn tag(&self, commitment: Fp) -> Fp {
+        let mut sponge = PoseidonSponge::default();
+        sponge.absorb_native_field_elements(&[self.sk_tag, commitment]);
+        sponge.squeeze_native_field_elements(1)[0]
+    }
+
+    /// Returns the serial number of a record owned by the account of this graph key.
+    pub fn serial_number(&self, record: &RecordCiphertext) -> Fp {
+        self.tag(record.commitment())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bls12_377::Field, keys::PrivateKey};
+
+    fn record(owner: Address) -> (RecordPlaintext, RecordCiphertext) {
+        let randomizer = Scalar::rand();
+        let data = (0..12).map(|_| Fp::rand()).collect();
+        let plaintext = RecordPlaintext::new(owner, u64::MAX - 1, data, randomizer);
+        let ciphertext = plaintext.encrypt(randomizer).unwrap();
+        (plaintext, ciphertext)
+    }
+
+    #[test]
+    fn encrypt_and_decrypt() {
+        let private_key = PrivateKey::rand();
+        let view_key = ViewKey::from(&private_key);
+        let (plaintext, ciphertext) = record(view_key.to_address());
+        assert_ne!(ciphertext.owner, plaintext.owner.0.x);
+        assert!(view_key.is_owner(&ciphertext));
+        assert_eq!(
+            view_key.decrypt_record(&ciphertext).as_ref(),
+            Some(&plaintext)
+        );
+
+        let other = ViewKey::from(&PrivateKey::rand());
+        assert!(!other.is_owner(&ciphertext));
+        assert_eq!(other.decrypt_record(&ciphertext), None);
+
+        // Only the randomizer of the nonce encrypts the record.
+        assert!(plaintext.encrypt(Scalar::rand()).is_err());
+
+        // Tampering with the nonce changes the record view key.
+        let mut tampered = ciphertext;
+        tampered.nonce = G1Affine::prime_subgroup_generator();
+        assert!(!view_key.is_owner(&tampered));
+    }
+
+    #[test]
+    fn serial_numbers() {
+        let private_key = PrivateKey::rand();
+        let view_key = ViewKey::from(&private_key);
+        let graph_key = GraphKey::from(&private_key);
+        assert!(graph_key == GraphKey::from(&view_key));
+
+        let (_, first) = record(view_key.to_address());
+        let (_, second) = record(view_key.to_address());
+        assert_eq!(
+            graph_key.serial_number(&first),
+            graph_key.tag(first.commitment())
+        );
+        assert_ne!(first.commitment(), second.commitment());
+        assert_ne!(
+            graph_key.serial_number(&first),
+            graph_key.serial_number(&second)
+        );
+        let other = GraphKey::from(&PrivateKey::rand());
+        assert_ne!(graph_key.serial_number(&first), other.serial_number(&first));
+    }
+}
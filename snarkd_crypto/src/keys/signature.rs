@@ -1,5 +1,16 @@
-use super::{Address, ComputeKey};
-use crate::bls12_377::Scalar;
+use std::{fmt, str::FromStr};
+
+use anyhow::{Error, Result};
+use rayon::prelude::*;
+
+use super::{encoding::*, Address, ComputeKey};
+use crate::{
+    bls12_377::{Affine, Fp, G1Affine, Projective, Scalar},
+    utils::*,
+};
+
+/// The human readable part of signature strings.
+const SIGNATURE_PREFIX: &str = "sign";
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Signature {
@@ -25,4 +36,166 @@ impl Signature {
     pub fn signer_address(&self) -> Address {
         self.compute_key.to_address()
     }
+
+    /// Returns the challenge and response in little endian, followed by the compute key.
+    pub fn to_bytes_le(&self) -> [u8; 160] {
+        let mut bytes = [0u8; 160];
+        bytes[..32].copy_from_slice(self.challenge.0.as_le_slice());
+        bytes[32..64].copy_from_slice(self.response.0.as_le_slice());
+        bytes[64..].copy_from_slice(&self.compute_key.to_bytes_le());
+        bytes
+    }
+
+    /// Reads a signature written by [`Signature::to_bytes_le`].
+    pub fn from_bytes_le(bytes: &[u8]) -> Result<Self> {
+        ensure_len(bytes, 160, "signature")?;
+        Ok(Self::new(
+            read_scalar(&bytes[..32])?,
+            read_scalar(&bytes[32..64])?,
+            ComputeKey::from_bytes_le(&bytes[64..])?,
+        ))
+    }
+
+    /// Computes the verifier challenge for the nonce commitment `g_r` and the given signer and
+    /// message, as a Poseidon hash of (g_r, pk_sig, pr_sig, address, message).
+    pub(crate) fn challenge(
+        g_r: G1Affine,
+        compute_key: &ComputeKey,
+        address: &Address,
+        message: &[Fp],
+    ) -> Scalar {
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage.extend(
+            [
+                g_r,
+                compute_key.public_key_signature,
+                compute_key.public_randomness_signature,
+                address.0,
+            ]
+            .map(|point| point.x),
+        );
+        preimage.extend(message);
+
+        let mut sponge = PoseidonSponge::default();
+        sponge.absorb_native_field_elements(&preimage);
+        sponge.squeeze_short_nonnative_field_element()
+    }
+
+    /// Recomputes `g_r` as `response * G + challenge * pk_sig`, which is `nonce * G` for a valid
+    /// signature.
+    fn g_r(&self) -> G1Affine {
+        let g = G1Affine::prime_subgroup_generator();
+        (g * self.response + self.compute_key.public_key_signature * self.challenge).to_affine()
+    }
+
+    /// Returns `true` if the compute key was derived from its own public keys, so that its PRF
+    /// secret key was not chosen by the signer.
+    fn has_derived_prf_key(&self) -> bool {
+        let compute_key = &self.compute_key;
+        ComputeKey::new(
+            compute_key.public_key_signature,
+            compute_key.public_randomness_signature,
+        ) == *compute_key
+    }
+
+    /// Returns `true` if the signature was produced by the private key of `address` over
+    /// `message`.
+    pub fn verify(&self, address: &Address, message: &[Fp]) -> bool {
+        self.has_derived_prf_key()
+            && self.compute_key.to_address() == *address
+            && Self::challenge(self.g_r(), &self.compute_key, address, message) == self.challenge
+    }
+
+    /// Returns `true` if every signature is valid for its address and message.
+    ///
+    /// Each challenge hashes its own `g_r`, which is not part of the signature, so signatures
+    /// can't be folded into one multi-scalar check and are verified one by one, in parallel.
+    pub fn verify_batch(signatures: &[(Signature, Address, &[Fp])]) -> bool {
+        signatures
+            .par_iter()
+            .all(|(signature, address, message)| signature.verify(address, message))
+    }
+}
+
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            encode_bech32(SIGNATURE_PREFIX, &self.to_bytes_le())
+        )
+    }
+}
+
+impl FromStr for Signature {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_bytes_le(&decode_bech32(SIGNATURE_PREFIX, s, "signature")?)
+    }
+}
+
+serde_with_str!(Signature);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bls12_377::Field, keys::PrivateKey};
+
+    fn message() -> Vec<Fp> {
+        (0..4).map(|_| Fp::rand()).collect()
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let private_key = PrivateKey::rand();
+        let address = ComputeKey::from(&private_key).to_address();
+        let message = message();
+        let signature = private_key.sign(&message);
+
+        assert!(signature.verify(&address, &message));
+        assert!(!signature.verify(&address, &message[1..]));
+        let other = ComputeKey::from(&PrivateKey::rand()).to_address();
+        assert!(!signature.verify(&other, &message));
+
+        let mut forged = signature;
+        forged.response += Scalar::ONE;
+        assert!(!forged.verify(&address, &message));
+
+        // A PRF secret key that does not come from the public keys can't redirect the address.
+        let mut forged = signature;
+        forged.compute_key.prf_secret_key += Scalar::ONE;
+        assert!(!forged.verify(&forged.signer_address(), &message));
+    }
+
+    #[test]
+    fn verify_batch() {
+        let messages = (0..8).map(|_| message()).collect::<Vec<_>>();
+        let mut signatures = messages
+            .iter()
+            .map(|message| {
+                let private_key = PrivateKey::rand();
+                let signature = private_key.sign(message);
+                (signature, signature.signer_address(), message.as_slice())
+            })
+            .collect::<Vec<_>>();
+        assert!(Signature::verify_batch(&[]));
+        assert!(Signature::verify_batch(&signatures));
+
+        signatures[3].1 = signatures[4].1;
+        assert!(!Signature::verify_batch(&signatures));
+        signatures[3].1 = signatures[3].0.signer_address();
+        signatures[5].2 = &messages[6];
+        assert!(!Signature::verify_batch(&signatures));
+        signatures[5].2 = &messages[5];
+        assert!(Signature::verify_batch(&signatures));
+
+        // A single forged signature fails the whole batch.
+        signatures[7].0.response += Scalar::ONE;
+        assert!(!Signature::verify_batch(&signatures));
+        signatures[7].0.response -= Scalar::ONE;
+        signatures[0].0.compute_key.prf_secret_key += Scalar::ONE;
+        signatures[0].1 = signatures[0].0.signer_address();
+        assert!(!Signature::verify_batch(&signatures));
+    }
 }
@@ -1,4 +1,57 @@
-use crate::bls12_377::Scalar;
+use std::{fmt, str::FromStr};
+
+use anyhow::{Error, Result};
+
+use super::{encoding::*, Address, ComputeKey, PrivateKey};
+use crate::bls12_377::{Affine, G1Affine, Projective, Scalar};
+
+/// Prefix of view key strings, which start with `AViewKey1`.
+static VIEW_KEY_PREFIX: [u8; 7] = [128, 229, 233, 139, 173, 90, 85];
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct ViewKey(pub Scalar);
+
+impl ViewKey {
+    /// Returns the address of the account, `view_key * G`.
+    pub fn to_address(&self) -> Address {
+        Address::new((G1Affine::prime_subgroup_generator() * self.0).to_affine())
+    }
+
+    /// Returns the scalar in little endian.
+    pub fn to_bytes_le(&self) -> [u8; 32] {
+        self.0 .0.to_le_bytes()
+    }
+
+    /// Reads a view key written by [`ViewKey::to_bytes_le`].
+    pub fn from_bytes_le(bytes: &[u8]) -> Result<Self> {
+        Ok(Self(read_scalar(bytes)?))
+    }
+}
+
+impl From<&PrivateKey> for ViewKey {
+    /// The view key is `sk_sig + r_sig + sk_prf`, whose multiple of the generator is the address.
+    fn from(private_key: &PrivateKey) -> Self {
+        let compute_key = ComputeKey::from(private_key);
+        Self(private_key.sk_sig + private_key.r_sig + compute_key.prf_secret_key)
+    }
+}
+
+impl fmt::Display for ViewKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            encode_base58(&VIEW_KEY_PREFIX, &self.to_bytes_le())
+        )
+    }
+}
+
+impl FromStr for ViewKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_bytes_le(&decode_base58(&VIEW_KEY_PREFIX, s, "view key")?)
+    }
+}
+
+serde_with_str!(ViewKey);
@@ -18,4 +18,5 @@ mod msm;
 pub mod polycommit;
 pub use polycommit::*;
 pub mod r1cs;
+pub mod serialization;
 mod test;
@@ -1,4 +1,4 @@
-use crate::marlin::ahp::AHPForR1CS;
+use crate::{marlin::ahp::AHPForR1CS, serialization::impl_canonical_serialization};
 
 /// Information about the circuit, including the field of definition, the number of
 /// variables, the number of constraints, and the maximum number of non-zero
@@ -19,6 +19,15 @@ pub struct CircuitInfo {
     pub num_non_zero_c: usize,
 }
 
+impl_canonical_serialization!(CircuitInfo {
+    num_public_inputs,
+    num_variables,
+    num_constraints,
+    num_non_zero_a,
+    num_non_zero_b,
+    num_non_zero_c,
+});
+
 impl CircuitInfo {
     /// The maximum degree of polynomial required to represent this index in the AHP.
     pub fn max_degree(&self, zk: bool) -> usize {
@@ -24,6 +24,45 @@ use super::Matrix;
 impl AHPForR1CS {
     /// Generate the index for this constraint system.
     pub fn index<C: ConstraintSynthesizer<Scalar>>(c: &C, mode: bool) -> Result<Circuit, AHPError> {
+        let (index_info, a, b, c) = Self::matrices(c)?;
+        Self::circuit(Self::index_state(index_info, a, b, c)?, mode)
+    }
+
+    /// Rebuilds the index of a circuit from its matrices, checking that they match `index_info`.
+    pub(crate) fn index_from_matrices(
+        index_info: CircuitInfo,
+        a: Matrix,
+        b: Matrix,
+        c: Matrix,
+        mode: bool,
+    ) -> Result<Circuit, AHPError> {
+        if index_info.num_constraints != index_info.num_variables {
+            return Err(AHPError::NonSquareMatrix);
+        }
+        Self::num_formatted_public_inputs_is_admissible(index_info.num_public_inputs)?;
+        if index_info.num_public_inputs > index_info.num_variables {
+            return Err(anyhow!("more public inputs than variables").into());
+        }
+        for (label, matrix, non_zero) in [
+            ("a", &a, index_info.num_non_zero_a),
+            ("b", &b, index_info.num_non_zero_b),
+            ("c", &c, index_info.num_non_zero_c),
+        ] {
+            let in_bounds = matrix
+                .iter()
+                .flatten()
+                .all(|(_, column)| *column < index_info.num_variables);
+            if matrix.len() != index_info.num_constraints
+                || num_non_zero(matrix) != non_zero
+                || !in_bounds
+            {
+                return Err(anyhow!("matrix {label} does not match the circuit info").into());
+            }
+        }
+        Self::circuit(Self::index_state(index_info, a, b, c)?, mode)
+    }
+
+    fn circuit(state: IndexerState, mode: bool) -> Result<Circuit, AHPError> {
         let IndexerState {
             constraint_domain,
 
@@ -40,7 +79,7 @@ impl AHPForR1CS {
             c_evals,
 
             index_info,
-        } = Self::index_helper(c)?;
+        } = state;
 
         let [a_arith, b_arith, c_arith]: [_; 3] = [("a", a_evals), ("b", b_evals), ("c", c_evals)]
             .into_iter()
@@ -105,7 +144,10 @@ impl AHPForR1CS {
         })
     }
 
-    fn index_helper<C: ConstraintSynthesizer<Scalar>>(c: &C) -> Result<IndexerState, AHPError> {
+    /// Synthesizes the constraint system and returns its padded, square matrices.
+    fn matrices<C: ConstraintSynthesizer<Scalar>>(
+        c: &C,
+    ) -> Result<(CircuitInfo, Matrix, Matrix, Matrix), AHPError> {
         let mut ics = IndexerConstraintSystem::new();
         c.generate_constraints(&mut ics)?;
 
@@ -161,17 +203,26 @@ impl AHPForR1CS {
             num_non_zero_b,
             num_non_zero_c,
         };
+        Ok((index_info, a, b, c))
+    }
 
-        let constraint_domain = EvaluationDomain::new(num_constraints)
+    /// Evaluates the matrices over their domains.
+    fn index_state(
+        index_info: CircuitInfo,
+        a: Matrix,
+        b: Matrix,
+        c: Matrix,
+    ) -> Result<IndexerState, AHPError> {
+        let constraint_domain = EvaluationDomain::new(index_info.num_constraints)
             .ok_or_else(|| anyhow!("polynomial degree too large"))?;
-        let input_domain = EvaluationDomain::new(num_padded_public_variables)
+        let input_domain = EvaluationDomain::new(index_info.num_public_inputs)
             .ok_or_else(|| anyhow!("polynomial degree too large"))?;
 
-        let non_zero_a_domain = EvaluationDomain::new(num_non_zero_a)
+        let non_zero_a_domain = EvaluationDomain::new(index_info.num_non_zero_a)
             .ok_or_else(|| anyhow!("polynomial degree too large"))?;
-        let non_zero_b_domain = EvaluationDomain::new(num_non_zero_b)
+        let non_zero_b_domain = EvaluationDomain::new(index_info.num_non_zero_b)
             .ok_or_else(|| anyhow!("polynomial degree too large"))?;
-        let non_zero_c_domain = EvaluationDomain::new(num_non_zero_c)
+        let non_zero_c_domain = EvaluationDomain::new(index_info.num_non_zero_c)
             .ok_or_else(|| anyhow!("polynomial degree too large"))?;
 
         let (constraint_domain_elements, constraint_domain_eq_poly_vals) =
@@ -219,7 +270,8 @@ impl AHPForR1CS {
         c: &C,
         point: Scalar,
     ) -> Result<impl Iterator<Item = Scalar>, AHPError> {
-        let state = Self::index_helper(c)?;
+        let (index_info, a, b, c) = Self::matrices(c)?;
+        let state = Self::index_state(index_info, a, b, c)?;
         let mut evals = [
             ("a", state.a_evals, state.non_zero_a_domain),
             ("b", state.b_evals, state.non_zero_b_domain),
@@ -1,4 +1,4 @@
-use crate::bls12_377::Scalar;
+use crate::{bls12_377::Scalar, serialization::impl_canonical_serialization};
 
 /// The prover message in the third round.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
@@ -7,3 +7,9 @@ pub struct ThirdMessage {
     pub sum_b: Scalar,
     pub sum_c: Scalar,
 }
+
+impl_canonical_serialization!(ThirdMessage {
+    sum_a,
+    sum_b,
+    sum_c
+});
@@ -1,4 +1,4 @@
-use crate::polycommit::sonic_pc;
+use crate::{polycommit::sonic_pc, serialization::impl_canonical_serialization};
 
 /// A certificate for the verifying key.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -7,6 +7,8 @@ pub struct Certificate {
     pub pc_proof: sonic_pc::BatchLCProof,
 }
 
+impl_canonical_serialization!(Certificate { pc_proof });
+
 impl Certificate {
     /// Construct a new certificate.
     pub fn new(pc_proof: sonic_pc::BatchLCProof) -> Self {
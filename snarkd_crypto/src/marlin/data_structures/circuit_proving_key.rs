@@ -1,9 +1,13 @@
 use crate::{
-    marlin::{ahp::indexer::*, CircuitVerifyingKey},
+    marlin::{ahp::indexer::*, AHPForR1CS, CircuitVerifyingKey},
     polycommit::sonic_pc,
+    serialization::*,
 };
 
-use std::sync::Arc;
+use std::{
+    io::{self, Read, Write},
+    sync::Arc,
+};
 
 /// Proving key for a specific circuit (i.e., R1CS matrices).
 #[derive(Clone, Debug)]
@@ -17,3 +21,41 @@ pub struct CircuitProvingKey {
     /// The committer key for this index, trimmed from the universal SRS.
     pub committer_key: Arc<sonic_pc::CommitterKey>,
 }
+
+impl CanonicalSerialize for CircuitProvingKey {
+    /// Only the matrices of the circuit are written, its arithmetization is recomputed on read.
+    fn serialize_with_mode<W: Write>(&self, writer: &mut W, compress: Compress) -> io::Result<()> {
+        self.circuit_verifying_key
+            .serialize_with_mode(writer, compress)?;
+        self.circuit_commitment_randomness
+            .serialize_with_mode(writer, compress)?;
+        self.circuit.a.serialize_with_mode(writer, compress)?;
+        self.circuit.b.serialize_with_mode(writer, compress)?;
+        self.circuit.c.serialize_with_mode(writer, compress)?;
+        self.circuit.zk.serialize_with_mode(writer, compress)?;
+        self.committer_key.serialize_with_mode(writer, compress)
+    }
+}
+
+impl CanonicalDeserialize for CircuitProvingKey {
+    fn deserialize_with_mode<R: Read>(reader: &mut R, compress: Compress) -> io::Result<Self> {
+        let circuit_verifying_key = CircuitVerifyingKey::deserialize_with_mode(reader, compress)?;
+        let circuit_commitment_randomness =
+            CanonicalDeserialize::deserialize_with_mode(reader, compress)?;
+        let a = Matrix::deserialize_with_mode(reader, compress)?;
+        let b = Matrix::deserialize_with_mode(reader, compress)?;
+        let c = Matrix::deserialize_with_mode(reader, compress)?;
+        let zk = bool::deserialize_with_mode(reader, compress)?;
+        let circuit =
+            AHPForR1CS::index_from_matrices(circuit_verifying_key.circuit_info, a, b, c, zk)
+                .map_err(|e| invalid_data(&format!("invalid circuit: {e:?}")))?;
+        Ok(Self {
+            circuit_verifying_key,
+            circuit_commitment_randomness,
+            circuit: Arc::new(circuit),
+            committer_key: Arc::new(CanonicalDeserialize::deserialize_with_mode(
+                reader, compress,
+            )?),
+        })
+    }
+}
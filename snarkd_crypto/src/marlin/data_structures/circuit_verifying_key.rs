@@ -2,6 +2,7 @@ use crate::{
     fft::EvaluationDomain,
     marlin::{ahp::indexer::*, CircuitProvingKey, PreparedCircuitVerifyingKey},
     polycommit::sonic_pc,
+    serialization::impl_canonical_serialization,
     Prepare,
 };
 
@@ -16,6 +17,12 @@ pub struct CircuitVerifyingKey {
     pub verifier_key: sonic_pc::VerifierKey,
 }
 
+impl_canonical_serialization!(CircuitVerifyingKey {
+    circuit_info,
+    circuit_commitments,
+    verifier_key,
+});
+
 impl Prepare for CircuitVerifyingKey {
     type Prepared = PreparedCircuitVerifyingKey;
 
@@ -1,4 +1,5 @@
-use crate::{bls12_377::Scalar, marlin::ahp, polycommit::sonic_pc};
+use crate::{bls12_377::Scalar, marlin::ahp, polycommit::sonic_pc, serialization::*};
+use std::io::{self, Read, Write};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Commitments {
@@ -19,6 +20,17 @@ pub struct Commitments {
     pub h_2: sonic_pc::Commitment,
 }
 
+impl_canonical_serialization!(Commitments {
+    witness_commitments,
+    mask_poly,
+    g_1,
+    h_1,
+    g_a,
+    g_b,
+    g_c,
+    h_2,
+});
+
 /// Commitments to the `w`, `z_a`, and `z_b` polynomials.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct WitnessCommitments {
@@ -30,6 +42,8 @@ pub struct WitnessCommitments {
     pub z_b: sonic_pc::Commitment,
 }
 
+impl_canonical_serialization!(WitnessCommitments { w, z_a, z_b });
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Evaluations {
     /// Evaluation of `z_b_i`'s at `beta`.
@@ -44,6 +58,14 @@ pub struct Evaluations {
     pub g_c_eval: Scalar,
 }
 
+impl_canonical_serialization!(Evaluations {
+    z_b_evals,
+    g_1_eval,
+    g_a_eval,
+    g_b_eval,
+    g_c_eval,
+});
+
 impl Evaluations {
     pub(crate) fn from_map(
         map: &std::collections::BTreeMap<String, Scalar>,
@@ -105,6 +127,35 @@ pub struct Proof {
     pub pc_proof: sonic_pc::BatchLCProof,
 }
 
+impl CanonicalSerialize for Proof {
+    fn serialize_with_mode<W: Write>(&self, writer: &mut W, compress: Compress) -> io::Result<()> {
+        self.batch_size.serialize_with_mode(writer, compress)?;
+        self.commitments.serialize_with_mode(writer, compress)?;
+        self.evaluations.serialize_with_mode(writer, compress)?;
+        self.msg.serialize_with_mode(writer, compress)?;
+        self.pc_proof.serialize_with_mode(writer, compress)
+    }
+}
+
+impl CanonicalDeserialize for Proof {
+    fn deserialize_with_mode<R: Read>(reader: &mut R, compress: Compress) -> io::Result<Self> {
+        let proof = Self::new(
+            CanonicalDeserialize::deserialize_with_mode(reader, compress)?,
+            CanonicalDeserialize::deserialize_with_mode(reader, compress)?,
+            CanonicalDeserialize::deserialize_with_mode(reader, compress)?,
+            CanonicalDeserialize::deserialize_with_mode(reader, compress)?,
+            CanonicalDeserialize::deserialize_with_mode(reader, compress)?,
+        );
+        // The verifier indexes the witness commitments and evaluations by instance.
+        if proof.commitments.witness_commitments.len() != proof.batch_size
+            || proof.evaluations.z_b_evals.len() != proof.batch_size
+        {
+            return Err(invalid_data("proof does not match its batch size"));
+        }
+        Ok(proof)
+    }
+}
+
 impl Proof {
     /// Construct a new proof.
     pub fn new(
@@ -368,3 +368,74 @@ mod marlin_recursion {
     //     println!("Called verifier");
     // }
 }
+
+mod serialization {
+    use super::*;
+    use crate::{
+        marlin::{
+            ahp::AHPForR1CS, Certificate, CircuitProvingKey, CircuitVerifyingKey, MarlinSNARK,
+            Proof,
+        },
+        polycommit::sonic_pc::UniversalParams,
+        serialization::*,
+    };
+
+    fn round_trip<T: CanonicalSerialize + CanonicalDeserialize>(
+        value: &T,
+        compress: Compress,
+    ) -> T {
+        let bytes = value.to_bytes(compress);
+        let read = T::from_bytes(&bytes, compress).unwrap();
+        assert_eq!(read.to_bytes(compress), bytes);
+        read
+    }
+
+    #[test]
+    fn prove_and_verify_with_deserialized_keys() {
+        let ahp = AHPForR1CS { mode: true };
+        let max_degree = ahp.max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinSNARK::universal_setup(max_degree).unwrap();
+        let fs_parameters = PoseidonParameters::default();
+
+        let a = Scalar::rand();
+        let b = Scalar::rand();
+        let c = a * b;
+        let d = c * b;
+        let circuit = Circuit {
+            a: Some(a),
+            b: Some(b),
+            num_constraints: 25,
+            num_variables: 25,
+        };
+
+        for compress in [Compress::Yes, Compress::No] {
+            let universal_srs = round_trip::<UniversalParams>(&universal_srs, compress);
+            let (index_pk, index_vk) =
+                MarlinSNARK::circuit_setup(&universal_srs, &circuit, true).unwrap();
+            let index_pk = round_trip::<CircuitProvingKey>(&index_pk, compress);
+            let read_vk = round_trip::<CircuitVerifyingKey>(&index_vk, compress);
+            assert_eq!(read_vk, index_vk);
+
+            let certificate = MarlinSNARK::prove_vk(&fs_parameters, &index_vk, &index_pk).unwrap();
+            let certificate = round_trip::<Certificate>(&certificate, compress);
+            assert!(
+                MarlinSNARK::verify_vk(&fs_parameters, &circuit, &read_vk, &certificate).unwrap()
+            );
+
+            let snark = MarlinSNARK { mode: true };
+            let proof = snark
+                .prove(&fs_parameters, &index_pk, &circuit, &mut rand::thread_rng())
+                .unwrap();
+            let read_proof = round_trip::<Proof>(&proof, compress);
+            assert_eq!(read_proof, proof);
+            assert!(snark
+                .verify::<[Scalar], _>(&fs_parameters, &read_vk, [c, d], &read_proof)
+                .unwrap());
+
+            // Truncated or padded encodings are rejected.
+            let bytes = proof.to_bytes(compress);
+            assert!(Proof::from_bytes(&bytes[..bytes.len() - 1], compress).is_err());
+            assert!(Proof::from_bytes(&[&bytes[..], &[0]].concat(), compress).is_err());
+        }
+    }
+}
@@ -1,13 +1,19 @@
 use crate::{
     bls12_377::{scalar, Affine, G1Affine, G1Projective, G2Affine, G2Prepared, Projective, Scalar},
     fft::{DensePolynomial, EvaluationDomain},
-    polycommit::powers::PowersOfG,
+    polycommit::{parameters, powers::PowersOfG},
+    serialization::*,
     utils::PoseidonSponge,
 };
 use anyhow::Result;
 use core::ops::{Add, AddAssign};
 use parking_lot::RwLock;
-use std::{borrow::Cow, collections::BTreeMap, sync::Arc};
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    io::{self, Read, Write},
+    sync::Arc,
+};
 
 /// `UniversalParams` are the universal parameters for the KZG10 scheme.
 #[derive(Clone, Debug)]
@@ -33,16 +39,21 @@ pub struct UniversalParams {
 }
 
 impl UniversalParams {
+    /// Returns the powers of beta G in the lagrange basis of `domain`, which are cached if the
+    /// parameters were read from disk.
     pub fn lagrange_basis(&self, domain: EvaluationDomain) -> Result<Vec<G1Affine>> {
-        let mut basis = domain.ifft_projective(
-            self.powers_of_beta_g(0, domain.size())?
-                .iter()
-                .map(|e| (*e).to_projective())
-                .collect::<Vec<_>>()
-                .as_slice(),
-        );
-        G1Projective::batch_normalization(&mut basis);
-        Ok(basis.iter().map(|e| (*e).into()).collect())
+        let name = format!("lagrange_basis_{}", domain.size());
+        parameters::trimmed(self, &name, || {
+            let mut basis = domain.ifft_projective(
+                self.powers_of_beta_g(0, domain.size())?
+                    .iter()
+                    .map(|e| (*e).to_projective())
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+            );
+            G1Projective::batch_normalization(&mut basis);
+            Ok(basis.iter().map(|e| (*e).into()).collect())
+        })
     }
 
     pub fn power_of_beta_g(&self, which_power: usize) -> Result<G1Affine> {
@@ -62,6 +73,37 @@ impl UniversalParams {
     }
 }
 
+impl CanonicalSerialize for UniversalParams {
+    fn serialize_with_mode<W: Write>(&self, writer: &mut W, compress: Compress) -> io::Result<()> {
+        self.powers.read().serialize_with_mode(writer, compress)?;
+        self.h.serialize_with_mode(writer, compress)?;
+        self.beta_h.serialize_with_mode(writer, compress)?;
+        self.supported_degree_bounds
+            .serialize_with_mode(writer, compress)?;
+        self.inverse_neg_powers_of_beta_h
+            .serialize_with_mode(writer, compress)
+    }
+}
+
+impl CanonicalDeserialize for UniversalParams {
+    fn deserialize_with_mode<R: Read>(reader: &mut R, compress: Compress) -> io::Result<Self> {
+        let powers = PowersOfG::deserialize_with_mode(reader, compress)?;
+        let h = G2Affine::deserialize_with_mode(reader, compress)?;
+        let beta_h = G2Affine::deserialize_with_mode(reader, compress)?;
+        Ok(Self {
+            powers: Arc::new(RwLock::new(powers)),
+            h,
+            beta_h,
+            supported_degree_bounds: CanonicalDeserialize::deserialize_with_mode(reader, compress)?,
+            inverse_neg_powers_of_beta_h: CanonicalDeserialize::deserialize_with_mode(
+                reader, compress,
+            )?,
+            prepared_h: G2Prepared::from_affine(h),
+            prepared_beta_h: G2Prepared::from_affine(beta_h),
+        })
+    }
+}
+
 impl UniversalParams {
     pub fn max_degree(&self) -> usize {
         self.powers.read().degree() - 1
@@ -127,6 +169,33 @@ pub struct VerifierKey {
     pub prepared_beta_h: G2Prepared,
 }
 
+impl CanonicalSerialize for VerifierKey {
+    fn serialize_with_mode<W: Write>(&self, writer: &mut W, compress: Compress) -> io::Result<()> {
+        self.g.serialize_with_mode(writer, compress)?;
+        self.gamma_g.serialize_with_mode(writer, compress)?;
+        self.h.serialize_with_mode(writer, compress)?;
+        self.beta_h.serialize_with_mode(writer, compress)
+    }
+}
+
+impl CanonicalDeserialize for VerifierKey {
+    /// The prepared points are recomputed rather than read.
+    fn deserialize_with_mode<R: Read>(reader: &mut R, compress: Compress) -> io::Result<Self> {
+        let g = G1Affine::deserialize_with_mode(reader, compress)?;
+        let gamma_g = G1Affine::deserialize_with_mode(reader, compress)?;
+        let h = G2Affine::deserialize_with_mode(reader, compress)?;
+        let beta_h = G2Affine::deserialize_with_mode(reader, compress)?;
+        Ok(Self {
+            g,
+            gamma_g,
+            h,
+            beta_h,
+            prepared_h: G2Prepared::from_affine(h),
+            prepared_beta_h: G2Prepared::from_affine(beta_h),
+        })
+    }
+}
+
 /// `PreparedVerifierKey` is the fully prepared version for checking evaluation proofs for a given commitment.
 /// We omit gamma here for simplicity.
 #[derive(Clone, Debug, Default)]
@@ -176,6 +245,18 @@ pub struct Commitment(
     pub G1Affine,
 );
 
+impl CanonicalSerialize for Commitment {
+    fn serialize_with_mode<W: Write>(&self, writer: &mut W, compress: Compress) -> io::Result<()> {
+        self.0.serialize_with_mode(writer, compress)
+    }
+}
+
+impl CanonicalDeserialize for Commitment {
+    fn deserialize_with_mode<R: Read>(reader: &mut R, compress: Compress) -> io::Result<Self> {
+        Ok(Self(G1Affine::deserialize_with_mode(reader, compress)?))
+    }
+}
+
 impl Commitment {
     #[inline]
     pub fn empty() -> Self {
@@ -222,6 +303,10 @@ pub struct Randomness {
     pub blinding_polynomial: DensePolynomial,
 }
 
+impl_canonical_serialization!(Randomness {
+    blinding_polynomial
+});
+
 impl Randomness {
     /// Does `self` provide any hiding properties to the corresponding commitment?
     /// `self.is_hiding() == true` only if the underlying polynomial is non-zero.
@@ -298,6 +383,8 @@ pub struct Proof {
     pub random_v: Option<Scalar>,
 }
 
+impl_canonical_serialization!(Proof { w, random_v });
+
 impl Proof {
     pub fn absorb_into_sponge(&self, sponge: &mut PoseidonSponge) {
         sponge.absorb_native_field_elements(&[self.w.x, self.w.y]);
@@ -32,4 +32,7 @@ pub mod optional_rng;
 #[cfg(test)]
 pub mod test_templates;
 
+/// Universal parameters read from disk, and the cache of parameters trimmed from them.
+pub mod parameters;
+
 pub mod powers;
@@ -0,0 +1,220 @@
+//! Universal parameters stored on disk.
+//!
+//! The universal SRS is generated once with [`setup`] and written to the parameters directory
+//! next to the SHA-256 checksum of its encoding, which is checked whenever it is read. Networks
+//! sharing published parameters pin their checksum, which the SRS must then have. Parameters
+//! trimmed from it are cached under `trimmed/`, keyed by that checksum, so that they are computed
+//! again only when the SRS changes.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use anyhow::{anyhow, ensure, Context, Result};
+use once_cell::sync::OnceCell;
+
+use crate::{
+    polycommit::kzg10::{KZG10DegreeBoundsConfig, UniversalParams, KZG10},
+    serialization::*,
+    utils::sha256::sha256,
+};
+
+/// File name of the universal SRS in the parameters directory.
+pub const UNIVERSAL_SRS_FILE: &str = "universal.srs";
+
+/// Directory of the cached trimmed parameters, inside the parameters directory.
+const TRIMMED_DIR: &str = "trimmed";
+
+static PARAMETERS_DIR: OnceCell<ParametersDir> = OnceCell::new();
+
+static UNIVERSAL_SRS: OnceCell<LoadedParams> = OnceCell::new();
+
+#[derive(Debug, PartialEq, Eq)]
+struct ParametersDir {
+    path: PathBuf,
+    /// Checksum the universal SRS must have, if pinned by the network.
+    checksum: Option<&'static str>,
+}
+
+struct LoadedParams {
+    srs: UniversalParams,
+    checksum: String,
+}
+
+/// Sets the directory that the universal SRS is read from, and the checksum it must have if the
+/// network pins one. It can only be set once, before the SRS is first used.
+pub fn set_parameters_dir(dir: impl Into<PathBuf>, checksum: Option<&'static str>) -> Result<()> {
+    let dir = ParametersDir {
+        path: dir.into(),
+        checksum,
+    };
+    let current = PARAMETERS_DIR.get_or_init(|| ParametersDir {
+        path: dir.path.clone(),
+        checksum,
+    });
+    ensure!(
+        *current == dir,
+        "the parameters directory is already set to {}",
+        current.path.display()
+    );
+    Ok(())
+}
+
+fn checksum_path(path: &Path) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(".sha256");
+    path.into()
+}
+
+/// Writes `bytes` to `path` with their checksum, through temporary files so that an interrupted
+/// write leaves no partial file behind.
+fn write_with_checksum(path: &Path, bytes: &[u8]) -> Result<String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let checksum = hex::encode(sha256(bytes));
+    for (path, contents) in [
+        (path.to_path_buf(), bytes),
+        (checksum_path(path), checksum.as_bytes()),
+    ] {
+        let mut temporary = path.as_os_str().to_owned();
+        temporary.push(".tmp");
+        fs::write(&temporary, contents)
+            .with_context(|| format!("cannot write {}", path.display()))?;
+        fs::rename(&temporary, &path)?;
+    }
+    Ok(checksum)
+}
+
+/// Reads a file written by [`write_with_checksum`], returning its contents and checksum.
+fn read_with_checksum(path: &Path) -> Result<(Vec<u8>, String)> {
+    let bytes = fs::read(path).with_context(|| format!("cannot read {}", path.display()))?;
+    let checksum_path = checksum_path(path);
+    let expected = fs::read_to_string(&checksum_path)
+        .with_context(|| format!("cannot read {}", checksum_path.display()))?;
+    let checksum = hex::encode(sha256(&bytes));
+    ensure!(
+        checksum == expected.trim(),
+        "checksum of {} is {checksum}, expected {}",
+        path.display(),
+        expected.trim()
+    );
+    Ok((bytes, checksum))
+}
+
+/// Writes universal parameters to `path`, returning the checksum of the file.
+pub fn write_universal_params(path: &Path, params: &UniversalParams) -> Result<String> {
+    write_with_checksum(path, &params.to_bytes(Compress::Yes))
+}
+
+/// Reads universal parameters written by [`write_universal_params`], returning them with the
+/// checksum of the file.
+pub fn read_universal_params(path: &Path) -> Result<(UniversalParams, String)> {
+    let (bytes, checksum) = read_with_checksum(path)?;
+    let params = UniversalParams::from_bytes(&bytes, Compress::Yes)
+        .with_context(|| format!("invalid universal parameters in {}", path.display()))?;
+    Ok((params, checksum))
+}
+
+/// Generates universal parameters supporting polynomials of degree up to `max_degree`, and writes
+/// them to `dir`, returning their checksum.
+///
+/// Whoever runs this could keep the secret the parameters are derived from, so they are only fit
+/// for devnets.
+pub fn setup(dir: &Path, max_degree: usize) -> Result<String> {
+    let params = KZG10::setup(max_degree, &KZG10DegreeBoundsConfig::MARLIN, true)
+        .map_err(|e| anyhow!("cannot generate universal parameters: {e}"))?;
+    write_universal_params(&dir.join(UNIVERSAL_SRS_FILE), &params)
+}
+
+/// Reads the universal SRS of `dir`, checking it against the pinned checksum if there is one.
+fn load_universal_srs(dir: &ParametersDir) -> Result<LoadedParams> {
+    let path = dir.path.join(UNIVERSAL_SRS_FILE);
+    let (srs, checksum) = read_universal_params(&path).context(match dir.checksum {
+        Some(_) => "the universal parameters of the network must be downloaded",
+        None => "universal parameters can be generated with `snarkd setup`",
+    })?;
+    if let Some(expected) = dir.checksum {
+        ensure!(
+            checksum == expected,
+            "checksum of {} is {checksum}, but the network requires {expected}",
+            path.display()
+        );
+    }
+    Ok(LoadedParams { srs, checksum })
+}
+
+/// Returns the universal SRS of the parameters directory, or `None` if no directory is set. The
+/// SRS is read once, then shared by every caller.
+pub fn universal_srs() -> Result<Option<UniversalParams>> {
+    let Some(dir) = PARAMETERS_DIR.get() else {
+        return Ok(None);
+    };
+    let loaded = UNIVERSAL_SRS.get_or_try_init(|| load_universal_srs(dir))?;
+    Ok(Some(loaded.srs.clone()))
+}
+
+/// Returns the parameters called `name` trimmed from `srs` by `trim`. If `srs` is the SRS of the
+/// parameters directory, they are read from the cache, or written to it after being trimmed.
+pub(crate) fn trimmed<T, E>(
+    srs: &UniversalParams,
+    name: &str,
+    trim: impl FnOnce() -> Result<T, E>,
+) -> Result<T, E>
+where
+    T: CanonicalSerialize + CanonicalDeserialize,
+{
+    let loaded = UNIVERSAL_SRS
+        .get()
+        .filter(|loaded| Arc::ptr_eq(&loaded.srs.powers, &srs.powers));
+    let (Some(loaded), Some(dir)) = (loaded, PARAMETERS_DIR.get()) else {
+        return trim();
+    };
+    let path = dir.path.join(TRIMMED_DIR).join(&loaded.checksum).join(name);
+
+    // The cache only saves time, so it is trimmed again if the cache can't be read, and isn't
+    // an error if it can't be written.
+    let cached =
+        read_with_checksum(&path).and_then(|(bytes, _)| T::from_bytes(&bytes, Compress::No));
+    if let Ok(value) = cached {
+        return Ok(value);
+    }
+    let value = trim()?;
+    let _ = write_with_checksum(&path, &value.to_bytes(Compress::No));
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_and_read() {
+        let dir = std::env::temp_dir().join(format!("snarkd-parameters-{}", std::process::id()));
+        let checksum = setup(&dir, 31).unwrap();
+        let path = dir.join(UNIVERSAL_SRS_FILE);
+        let (params, read_checksum) = read_universal_params(&path).unwrap();
+        assert_eq!(read_checksum, checksum);
+        assert_eq!(params.max_degree(), 31);
+
+        // Rewriting the same parameters gives the same file.
+        assert_eq!(write_universal_params(&path, &params).unwrap(), checksum);
+
+        // A network pinning another checksum rejects the parameters.
+        let pinned = |checksum: String| ParametersDir {
+            path: dir.clone(),
+            checksum: Some(checksum.leak()),
+        };
+        assert!(load_universal_srs(&pinned(checksum.clone())).is_ok());
+        assert!(load_universal_srs(&pinned("0".repeat(64))).is_err());
+
+        let mut bytes = fs::read(&path).unwrap();
+        bytes[100] ^= 1;
+        fs::write(&path, bytes).unwrap();
+        assert!(read_universal_params(&path).is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,6 +1,9 @@
-use crate::bls12_377::G1Affine;
+use crate::{bls12_377::G1Affine, serialization::*};
 use anyhow::{anyhow, Result};
-use std::collections::BTreeMap;
+use std::{
+    collections::BTreeMap,
+    io::{self, Read, Write},
+};
 
 /*
 const DEGREE_15: usize = 1 << 15;
@@ -100,6 +103,25 @@ impl PowersOfG {
     }
 }
 
+impl CanonicalSerialize for PowersOfG {
+    fn serialize_with_mode<W: Write>(&self, writer: &mut W, compress: Compress) -> io::Result<()> {
+        self.powers_of_beta_g
+            .serialize_with_mode(writer, compress)?;
+        self.powers_of_beta_times_gamma_g
+            .serialize_with_mode(writer, compress)
+    }
+}
+
+impl CanonicalDeserialize for PowersOfG {
+    fn deserialize_with_mode<R: Read>(reader: &mut R, compress: Compress) -> io::Result<Self> {
+        Self::setup(
+            CanonicalDeserialize::deserialize_with_mode(reader, compress)?,
+            CanonicalDeserialize::deserialize_with_mode(reader, compress)?,
+        )
+        .map_err(|e| invalid_data(&e.to_string()))
+    }
+}
+
 impl PowersOfG {
     #[allow(dead_code)]
     fn regenerate_powers_of_beta_times_gamma_g(
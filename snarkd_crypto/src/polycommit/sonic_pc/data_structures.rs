@@ -3,12 +3,14 @@ use crate::{
     bls12_377::{Field, G1Affine, G1Projective, G2Affine, G2Prepared, Projective, Scalar},
     fft::EvaluationDomain,
     polycommit::kzg10,
+    serialization::*,
 };
 use hashbrown::HashMap;
 use std::{
     borrow::{Borrow, Cow},
     collections::{BTreeMap, BTreeSet},
     fmt,
+    io::{self, Read, Write},
     ops::{AddAssign, MulAssign, SubAssign},
 };
 
@@ -68,6 +70,16 @@ pub struct CommitterKey {
     pub max_degree: usize,
 }
 
+impl_canonical_serialization!(CommitterKey {
+    powers_of_beta_g,
+    lagrange_bases_at_beta_g,
+    powers_of_beta_times_gamma_g,
+    shifted_powers_of_beta_g,
+    shifted_powers_of_beta_times_gamma_g,
+    enforced_degree_bounds,
+    max_degree,
+});
+
 impl CommitterKey {
     /// Obtain powers for the underlying KZG10 construction
     pub fn powers(&self) -> kzg10::Powers {
@@ -163,6 +175,46 @@ pub struct VerifierKey {
     pub max_degree: usize,
 }
 
+impl CanonicalSerialize for VerifierKey {
+    fn serialize_with_mode<W: Write>(&self, writer: &mut W, compress: Compress) -> io::Result<()> {
+        self.vk.serialize_with_mode(writer, compress)?;
+        self.degree_bounds_and_neg_powers_of_h
+            .serialize_with_mode(writer, compress)?;
+        self.supported_degree
+            .serialize_with_mode(writer, compress)?;
+        self.max_degree.serialize_with_mode(writer, compress)
+    }
+}
+
+impl CanonicalDeserialize for VerifierKey {
+    /// The prepared shifts are recomputed rather than read.
+    fn deserialize_with_mode<R: Read>(reader: &mut R, compress: Compress) -> io::Result<Self> {
+        let vk = kzg10::VerifierKey::deserialize_with_mode(reader, compress)?;
+        let degree_bounds_and_neg_powers_of_h =
+            Option::<Vec<(usize, G2Affine)>>::deserialize_with_mode(reader, compress)?;
+        // The shifts are looked up with a binary search.
+        if let Some(shifts) = &degree_bounds_and_neg_powers_of_h {
+            if shifts.windows(2).any(|x| x[0].0 >= x[1].0) {
+                return Err(invalid_data("degree bounds are not in ascending order"));
+            }
+        }
+        let degree_bounds_and_prepared_neg_powers_of_h =
+            degree_bounds_and_neg_powers_of_h.as_ref().map(|shifts| {
+                shifts
+                    .iter()
+                    .map(|(bound, power)| (*bound, G2Prepared::from_affine(*power)))
+                    .collect()
+            });
+        Ok(Self {
+            vk,
+            degree_bounds_and_neg_powers_of_h,
+            degree_bounds_and_prepared_neg_powers_of_h,
+            supported_degree: CanonicalDeserialize::deserialize_with_mode(reader, compress)?,
+            max_degree: CanonicalDeserialize::deserialize_with_mode(reader, compress)?,
+        })
+    }
+}
+
 impl VerifierKey {
     /// Find the appropriate shift for the degree bound.
     pub fn get_shift_power(&self, degree_bound: usize) -> Option<G2Affine> {
@@ -247,6 +299,18 @@ impl VerifierKey {
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct BatchProof(pub(crate) Vec<kzg10::Proof>);
 
+impl CanonicalSerialize for BatchProof {
+    fn serialize_with_mode<W: Write>(&self, writer: &mut W, compress: Compress) -> io::Result<()> {
+        self.0.serialize_with_mode(writer, compress)
+    }
+}
+
+impl CanonicalDeserialize for BatchProof {
+    fn deserialize_with_mode<R: Read>(reader: &mut R, compress: Compress) -> io::Result<Self> {
+        Ok(Self(Vec::deserialize_with_mode(reader, compress)?))
+    }
+}
+
 impl BatchProof {
     pub fn is_hiding(&self) -> bool {
         self.0.iter().any(|c| c.is_hiding())
@@ -528,6 +592,8 @@ pub struct BatchLCProof {
     pub evaluations: Option<Vec<Scalar>>,
 }
 
+impl_canonical_serialization!(BatchLCProof { proof, evaluations });
+
 impl BatchLCProof {
     pub fn is_hiding(&self) -> bool {
         self.proof.is_hiding()
@@ -5,7 +5,7 @@ use crate::{
     },
     fft::DensePolynomial,
     msm::variable_base::VariableBase,
-    polycommit::{kzg10, PCError},
+    polycommit::{kzg10, parameters, PCError},
     utils::*,
 };
 use core::{
@@ -37,9 +37,22 @@ pub use polynomial::*;
 pub struct SonicKZG10;
 
 impl SonicKZG10 {
+    /// Returns the universal SRS of the parameters directory, which must support `max_degree`.
+    /// If no directory is set, a new SRS is generated in tests and with the `setup` feature, and
+    /// this fails otherwise.
     pub fn setup(max_degree: usize) -> Result<UniversalParams, PCError> {
-        kzg10::KZG10::setup(max_degree, &kzg10::KZG10DegreeBoundsConfig::MARLIN, true)
-            .map_err(Into::into)
+        match parameters::universal_srs()? {
+            Some(srs) if srs.max_degree() >= max_degree => Ok(srs),
+            Some(srs) => Err(anyhow::anyhow!(
+                "the universal SRS supports degree {}, but degree {max_degree} is required",
+                srs.max_degree()
+            )
+            .into()),
+            #[cfg(any(test, feature = "setup"))]
+            None => kzg10::KZG10::setup(max_degree, &kzg10::KZG10DegreeBoundsConfig::MARLIN, true),
+            #[cfg(not(any(test, feature = "setup")))]
+            None => Err(anyhow::anyhow!("the parameters directory is not set").into()),
+        }
     }
 
     pub fn trim(
@@ -0,0 +1,512 @@
+//! Canonical binary encoding of field elements, curve points, and the keys, commitments and
+//! proofs built from them.
+//!
+//! Integers and field elements are little endian, and lengths are written as `u64`. Points are
+//! either compressed to their x-coordinate, with the sign of y and the point at infinity as flags
+//! in the two high bits of the last byte, or written uncompressed as x then y, with the infinity
+//! flag in the last byte of y. Reading always checks that field elements are canonical and that
+//! points are on the curve and in the prime order subgroup, so a decoded value is as trusted as a
+//! computed one. Values derived from others, like prepared pairing inputs, are not written and
+//! are recomputed when read.
+
+use std::{
+    collections::BTreeMap,
+    io::{self, Read, Write},
+};
+
+use anyhow::{ensure, Result};
+use ruint::Uint;
+
+use crate::bls12_377::{scalar, Affine, Field, Fp, Fp2, G1Affine, G2Affine, Scalar};
+
+/// Set in the last byte of a compressed point if y is the greater of its two roots.
+const GREATEST_FLAG: u8 = 1 << 7;
+/// Set in the last byte of an encoded point at infinity.
+const INFINITY_FLAG: u8 = 1 << 6;
+
+/// How points are written.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Compress {
+    /// Only the x-coordinate and flags, which halves the size but makes reading recover y with
+    /// a square root.
+    Yes,
+    /// Both coordinates.
+    No,
+}
+
+pub trait CanonicalSerialize {
+    fn serialize_with_mode<W: Write>(&self, writer: &mut W, compress: Compress) -> io::Result<()>;
+
+    fn to_bytes(&self, compress: Compress) -> Vec<u8> {
+        let mut bytes = vec![];
+        self.serialize_with_mode(&mut bytes, compress)
+            .expect("writing to a vector can't fail");
+        bytes
+    }
+}
+
+pub trait CanonicalDeserialize: Sized {
+    /// Reads a value written by [`CanonicalSerialize::serialize_with_mode`] with the same mode,
+    /// failing with [`io::ErrorKind::InvalidData`] if it is not a canonical encoding.
+    fn deserialize_with_mode<R: Read>(reader: &mut R, compress: Compress) -> io::Result<Self>;
+
+    /// Reads a value that takes up all of `bytes`.
+    fn from_bytes(mut bytes: &[u8], compress: Compress) -> Result<Self> {
+        let value = Self::deserialize_with_mode(&mut bytes, compress)?;
+        ensure!(
+            bytes.is_empty(),
+            "{} trailing bytes after the encoded value",
+            bytes.len()
+        );
+        Ok(value)
+    }
+}
+
+pub(crate) fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn read_array<R: Read, const N: usize>(reader: &mut R) -> io::Result<[u8; N]> {
+    let mut bytes = [0u8; N];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Implements both traits for a struct by writing its fields in order.
+macro_rules! impl_canonical_serialization {
+    ($type:ident { $($field:ident),* $(,)? }) => {
+        impl $crate::serialization::CanonicalSerialize for $type {
+            fn serialize_with_mode<W: std::io::Write>(
+                &self,
+                writer: &mut W,
+                compress: $crate::serialization::Compress,
+            ) -> std::io::Result<()> {
+                $($crate::serialization::CanonicalSerialize::serialize_with_mode(
+                    &self.$field,
+                    writer,
+                    compress,
+                )?;)*
+                Ok(())
+            }
+        }
+
+        impl $crate::serialization::CanonicalDeserialize for $type {
+            fn deserialize_with_mode<R: std::io::Read>(
+                reader: &mut R,
+                compress: $crate::serialization::Compress,
+            ) -> std::io::Result<Self> {
+                Ok(Self {
+                    $($field: $crate::serialization::CanonicalDeserialize::deserialize_with_mode(
+                        reader,
+                        compress,
+                    )?,)*
+                })
+            }
+        }
+    };
+}
+pub(crate) use impl_canonical_serialization;
+
+impl CanonicalSerialize for u8 {
+    fn serialize_with_mode<W: Write>(&self, writer: &mut W, _: Compress) -> io::Result<()> {
+        writer.write_all(&[*self])
+    }
+}
+
+impl CanonicalDeserialize for u8 {
+    fn deserialize_with_mode<R: Read>(reader: &mut R, _: Compress) -> io::Result<Self> {
+        Ok(read_array::<_, 1>(reader)?[0])
+    }
+}
+
+impl CanonicalSerialize for bool {
+    fn serialize_with_mode<W: Write>(&self, writer: &mut W, compress: Compress) -> io::Result<()> {
+        (*self as u8).serialize_with_mode(writer, compress)
+    }
+}
+
+impl CanonicalDeserialize for bool {
+    fn deserialize_with_mode<R: Read>(reader: &mut R, compress: Compress) -> io::Result<Self> {
+        match u8::deserialize_with_mode(reader, compress)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(invalid_data("boolean is not 0 or 1")),
+        }
+    }
+}
+
+impl CanonicalSerialize for u64 {
+    fn serialize_with_mode<W: Write>(&self, writer: &mut W, _: Compress) -> io::Result<()> {
+        writer.write_all(&self.to_le_bytes())
+    }
+}
+
+impl CanonicalDeserialize for u64 {
+    fn deserialize_with_mode<R: Read>(reader: &mut R, _: Compress) -> io::Result<Self> {
+        Ok(u64::from_le_bytes(read_array(reader)?))
+    }
+}
+
+impl CanonicalSerialize for usize {
+    fn serialize_with_mode<W: Write>(&self, writer: &mut W, compress: Compress) -> io::Result<()> {
+        (*self as u64).serialize_with_mode(writer, compress)
+    }
+}
+
+impl CanonicalDeserialize for usize {
+    fn deserialize_with_mode<R: Read>(reader: &mut R, compress: Compress) -> io::Result<Self> {
+        u64::deserialize_with_mode(reader, compress)?
+            .try_into()
+            .map_err(|_| invalid_data("length does not fit in usize"))
+    }
+}
+
+impl CanonicalSerialize for String {
+    fn serialize_with_mode<W: Write>(&self, writer: &mut W, compress: Compress) -> io::Result<()> {
+        self.as_bytes()
+            .to_vec()
+            .serialize_with_mode(writer, compress)
+    }
+}
+
+impl CanonicalDeserialize for String {
+    fn deserialize_with_mode<R: Read>(reader: &mut R, compress: Compress) -> io::Result<Self> {
+        String::from_utf8(Vec::deserialize_with_mode(reader, compress)?)
+            .map_err(|_| invalid_data("string is not UTF-8"))
+    }
+}
+
+impl<T: CanonicalSerialize> CanonicalSerialize for Vec<T> {
+    fn serialize_with_mode<W: Write>(&self, writer: &mut W, compress: Compress) -> io::Result<()> {
+        self.len().serialize_with_mode(writer, compress)?;
+        for item in self {
+            item.serialize_with_mode(writer, compress)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: CanonicalDeserialize> CanonicalDeserialize for Vec<T> {
+    fn deserialize_with_mode<R: Read>(reader: &mut R, compress: Compress) -> io::Result<Self> {
+        let len = usize::deserialize_with_mode(reader, compress)?;
+        // The length is not trusted until the items are read.
+        let mut items = Vec::with_capacity(len.min(1 << 16));
+        for _ in 0..len {
+            items.push(T::deserialize_with_mode(reader, compress)?);
+        }
+        Ok(items)
+    }
+}
+
+impl<T: CanonicalSerialize> CanonicalSerialize for Option<T> {
+    fn serialize_with_mode<W: Write>(&self, writer: &mut W, compress: Compress) -> io::Result<()> {
+        self.is_some().serialize_with_mode(writer, compress)?;
+        match self {
+            Some(value) => value.serialize_with_mode(writer, compress),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: CanonicalDeserialize> CanonicalDeserialize for Option<T> {
+    fn deserialize_with_mode<R: Read>(reader: &mut R, compress: Compress) -> io::Result<Self> {
+        match bool::deserialize_with_mode(reader, compress)? {
+            true => Ok(Some(T::deserialize_with_mode(reader, compress)?)),
+            false => Ok(None),
+        }
+    }
+}
+
+impl<A: CanonicalSerialize, B: CanonicalSerialize> CanonicalSerialize for (A, B) {
+    fn serialize_with_mode<W: Write>(&self, writer: &mut W, compress: Compress) -> io::Result<()> {
+        self.0.serialize_with_mode(writer, compress)?;
+        self.1.serialize_with_mode(writer, compress)
+    }
+}
+
+impl<A: CanonicalDeserialize, B: CanonicalDeserialize> CanonicalDeserialize for (A, B) {
+    fn deserialize_with_mode<R: Read>(reader: &mut R, compress: Compress) -> io::Result<Self> {
+        Ok((
+            A::deserialize_with_mode(reader, compress)?,
+            B::deserialize_with_mode(reader, compress)?,
+        ))
+    }
+}
+
+impl<K: CanonicalSerialize, V: CanonicalSerialize> CanonicalSerialize for BTreeMap<K, V> {
+    fn serialize_with_mode<W: Write>(&self, writer: &mut W, compress: Compress) -> io::Result<()> {
+        self.len().serialize_with_mode(writer, compress)?;
+        for (key, value) in self {
+            key.serialize_with_mode(writer, compress)?;
+            value.serialize_with_mode(writer, compress)?;
+        }
+        Ok(())
+    }
+}
+
+impl<K: CanonicalDeserialize + Ord, V: CanonicalDeserialize> CanonicalDeserialize
+    for BTreeMap<K, V>
+{
+    /// Keys must be written in ascending order, as they are by [`CanonicalSerialize`].
+    fn deserialize_with_mode<R: Read>(reader: &mut R, compress: Compress) -> io::Result<Self> {
+        let mut map = BTreeMap::new();
+        for (key, value) in Vec::<(K, V)>::deserialize_with_mode(reader, compress)? {
+            if map.last_key_value().is_some_and(|(last, _)| *last >= key) {
+                return Err(invalid_data("map keys are not in ascending order"));
+            }
+            map.insert(key, value);
+        }
+        Ok(map)
+    }
+}
+
+impl CanonicalSerialize for Scalar {
+    fn serialize_with_mode<W: Write>(&self, writer: &mut W, _: Compress) -> io::Result<()> {
+        writer.write_all(self.0.as_le_slice())
+    }
+}
+
+impl CanonicalDeserialize for Scalar {
+    fn deserialize_with_mode<R: Read>(reader: &mut R, _: Compress) -> io::Result<Self> {
+        let value = Uint::from_le_bytes(read_array::<_, 32>(reader)?);
+        if value >= scalar::MODULUS {
+            return Err(invalid_data("scalar is not canonical"));
+        }
+        Ok(Scalar(value))
+    }
+}
+
+impl CanonicalSerialize for Fp {
+    fn serialize_with_mode<W: Write>(&self, writer: &mut W, _: Compress) -> io::Result<()> {
+        writer.write_all(self.0.as_le_slice())
+    }
+}
+
+impl CanonicalDeserialize for Fp {
+    fn deserialize_with_mode<R: Read>(reader: &mut R, _: Compress) -> io::Result<Self> {
+        let value = Fp(Uint::from_le_bytes(read_array::<_, 48>(reader)?));
+        if !value.is_valid() {
+            return Err(invalid_data("field element is not canonical"));
+        }
+        Ok(value)
+    }
+}
+
+impl CanonicalSerialize for Fp2 {
+    fn serialize_with_mode<W: Write>(&self, writer: &mut W, compress: Compress) -> io::Result<()> {
+        self.c0.serialize_with_mode(writer, compress)?;
+        self.c1.serialize_with_mode(writer, compress)
+    }
+}
+
+impl CanonicalDeserialize for Fp2 {
+    fn deserialize_with_mode<R: Read>(reader: &mut R, compress: Compress) -> io::Result<Self> {
+        Ok(Fp2::new(
+            Fp::deserialize_with_mode(reader, compress)?,
+            Fp::deserialize_with_mode(reader, compress)?,
+        ))
+    }
+}
+
+/// Writes `coordinate`, setting `flags` in its last byte.
+fn write_with_flags<W: Write, T: CanonicalSerialize>(
+    writer: &mut W,
+    coordinate: &T,
+    flags: u8,
+) -> io::Result<()> {
+    let mut bytes = coordinate.to_bytes(Compress::No);
+    *bytes.last_mut().unwrap() |= flags;
+    writer.write_all(&bytes)
+}
+
+/// Reads a coordinate written by [`write_with_flags`] and the flags set with it.
+fn read_with_flags<R: Read, T: CanonicalDeserialize, const N: usize>(
+    reader: &mut R,
+) -> io::Result<(T, u8)> {
+    let mut bytes = read_array::<_, N>(reader)?;
+    let flags = bytes[N - 1] & (GREATEST_FLAG | INFINITY_FLAG);
+    bytes[N - 1] &= !flags;
+    Ok((
+        T::deserialize_with_mode(&mut &bytes[..], Compress::No)?,
+        flags,
+    ))
+}
+
+/// Implements both traits for the affine points of a curve whose base field is `$field`, of
+/// `$size` bytes.
+macro_rules! impl_point_serialization {
+    ($point:ty, $field:ty, $size:literal) => {
+        impl CanonicalSerialize for $point {
+            fn serialize_with_mode<W: Write>(
+                &self,
+                writer: &mut W,
+                compress: Compress,
+            ) -> io::Result<()> {
+                match (compress, self.infinity) {
+                    (Compress::Yes, true) => {
+                        write_with_flags(writer, &<$field>::ZERO, INFINITY_FLAG)
+                    }
+                    (Compress::Yes, false) => {
+                        let flags = if self.y > -self.y { GREATEST_FLAG } else { 0 };
+                        write_with_flags(writer, &self.x, flags)
+                    }
+                    (Compress::No, true) => {
+                        <$field>::ZERO.serialize_with_mode(writer, compress)?;
+                        write_with_flags(writer, &<$field>::ZERO, INFINITY_FLAG)
+                    }
+                    (Compress::No, false) => {
+                        self.x.serialize_with_mode(writer, compress)?;
+                        self.y.serialize_with_mode(writer, compress)
+                    }
+                }
+            }
+        }
+
+        impl CanonicalDeserialize for $point {
+            fn deserialize_with_mode<R: Read>(
+                reader: &mut R,
+                compress: Compress,
+            ) -> io::Result<Self> {
+                let (point, flags) = match compress {
+                    Compress::Yes => {
+                        let (x, flags) = read_with_flags::<_, $field, $size>(reader)?;
+                        let point = match flags & INFINITY_FLAG {
+                            0 => Self::from_x_coordinate(x, flags & GREATEST_FLAG != 0)
+                                .ok_or_else(|| invalid_data("x-coordinate is not on the curve"))?,
+                            _ if x.is_zero() => Self::ZERO,
+                            _ => return Err(invalid_data("point at infinity has a coordinate")),
+                        };
+                        (point, flags)
+                    }
+                    Compress::No => {
+                        let x = <$field>::deserialize_with_mode(reader, compress)?;
+                        let (y, flags) = read_with_flags::<_, $field, $size>(reader)?;
+                        let point = match flags {
+                            0 => Self::new(x, y, false),
+                            _ if x.is_zero() && y.is_zero() => Self::ZERO,
+                            _ => return Err(invalid_data("point at infinity has a coordinate")),
+                        };
+                        (point, flags & GREATEST_FLAG)
+                    }
+                };
+                if flags & GREATEST_FLAG != 0 && point.is_zero() {
+                    return Err(invalid_data("point at infinity has the sign flag set"));
+                }
+                if !point.is_zero()
+                    && !(point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve())
+                {
+                    return Err(invalid_data("point is not in the prime order subgroup"));
+                }
+                Ok(point)
+            }
+        }
+    };
+}
+
+impl_point_serialization!(G1Affine, Fp, 48);
+impl_point_serialization!(G2Affine, Fp2, 96);
+
+/// Stores values in SQLite as compressed blobs.
+macro_rules! impl_sql_blob {
+    ($($type:ty),*) => {$(
+        impl rusqlite::types::FromSql for $type {
+            fn column_result(
+                value: rusqlite::types::ValueRef<'_>,
+            ) -> rusqlite::types::FromSqlResult<Self> {
+                match value {
+                    rusqlite::types::ValueRef::Blob(blob) => Self::from_bytes(blob, Compress::Yes)
+                        .map_err(|e| rusqlite::types::FromSqlError::Other(e.into())),
+                    _ => Err(rusqlite::types::FromSqlError::InvalidType),
+                }
+            }
+        }
+
+        impl rusqlite::types::ToSql for $type {
+            fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+                Ok(rusqlite::types::ToSqlOutput::Owned(
+                    rusqlite::types::Value::Blob(self.to_bytes(Compress::Yes)),
+                ))
+            }
+        }
+    )*};
+}
+
+impl_sql_blob!(
+    crate::marlin::Proof,
+    crate::marlin::CircuitVerifyingKey,
+    crate::marlin::Certificate
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip<T>(value: &T)
+    where
+        T: CanonicalSerialize + CanonicalDeserialize + PartialEq + std::fmt::Debug,
+    {
+        for compress in [Compress::Yes, Compress::No] {
+            let bytes = value.to_bytes(compress);
+            assert_eq!(T::from_bytes(&bytes, compress).unwrap(), *value);
+            assert!(T::from_bytes(&bytes[1..], compress).is_err());
+            assert!(T::from_bytes(&[&bytes[..], &[0]].concat(), compress).is_err());
+        }
+    }
+
+    #[test]
+    fn points() {
+        for _ in 0..4 {
+            round_trip(&G1Affine::rand());
+            round_trip(&G2Affine::rand());
+            round_trip(&-G1Affine::rand());
+        }
+        round_trip(&G1Affine::ZERO);
+        round_trip(&G2Affine::ZERO);
+        assert_eq!(G1Affine::rand().to_bytes(Compress::Yes).len(), 48);
+        assert_eq!(G1Affine::rand().to_bytes(Compress::No).len(), 96);
+        assert_eq!(G2Affine::rand().to_bytes(Compress::Yes).len(), 96);
+        assert_eq!(G2Affine::rand().to_bytes(Compress::No).len(), 192);
+    }
+
+    #[test]
+    fn rejects_invalid_points() {
+        // A point on the curve, but outside of the prime order subgroup.
+        let outside = (0u64..)
+            .filter_map(|x| G1Affine::from_x_coordinate(Fp::from(x), false))
+            .find(|x| !x.is_in_correct_subgroup_assuming_on_curve())
+            .unwrap();
+        for compress in [Compress::Yes, Compress::No] {
+            assert!(G1Affine::from_bytes(&outside.to_bytes(compress), compress).is_err());
+        }
+
+        let mut bytes = G1Affine::rand().to_bytes(Compress::No);
+        bytes[0] ^= 1;
+        assert!(G1Affine::from_bytes(&bytes, Compress::No).is_err());
+
+        // Flags are only allowed where they mean something.
+        let mut bytes = G1Affine::ZERO.to_bytes(Compress::Yes);
+        bytes[47] |= GREATEST_FLAG;
+        assert!(G1Affine::from_bytes(&bytes, Compress::Yes).is_err());
+        let mut bytes = G1Affine::ZERO.to_bytes(Compress::Yes);
+        bytes[0] = 1;
+        assert!(G1Affine::from_bytes(&bytes, Compress::Yes).is_err());
+
+        // Field elements must be reduced.
+        let bytes = [0xff; 32];
+        assert!(Scalar::from_bytes(&bytes, Compress::Yes).is_err());
+    }
+
+    #[test]
+    fn collections() {
+        round_trip(&vec![Scalar::rand(); 3]);
+        round_trip(&Some((7usize, String::from("label"))));
+        round_trip(&None::<Scalar>);
+        round_trip(&BTreeMap::from([(1usize, Fp::rand()), (5, Fp::rand())]));
+
+        // Maps are written in key order, so another order is not canonical.
+        let pairs = vec![(5usize, true), (1, false)];
+        let bytes = pairs.to_bytes(Compress::Yes);
+        assert!(BTreeMap::<usize, bool>::from_bytes(&bytes, Compress::Yes).is_err());
+        assert!(bool::from_bytes(&[2], Compress::Yes).is_err());
+    }
+}
@@ -0,0 +1,163 @@
+use crate::{
+    bls12_377::{fp, Affine, Fp, G1Affine, G1Projective, Projective, Scalar},
+    utils::pedersen::hash_to_g1,
+};
+
+/// Separates the generators of BHP schemes from the bases of Pedersen schemes of the same domain.
+const BHP_SEED: u64 = u64::MAX;
+
+/// Number of input bits selecting the multiple of a base.
+const CHUNK_BITS: usize = 3;
+
+/// A Bowe-Hopwood-Pedersen hash and commitment scheme over G1.
+///
+/// Each 3 bit chunk of a block selects `±1`, `±2`, `±3` or `±4` times the base of its position.
+/// Chunks are grouped in `num_windows` windows of `window_size` chunks, each with its own
+/// generator, and the base of a chunk is 16 times that of the previous chunk in its window.
+/// Inputs longer than a block are chained: the first block starts with the input length, and each
+/// following block with the truncated x-coordinate of the previous hash.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bhp {
+    /// Multiples of the base of each chunk, indexed by the chunk bits.
+    lookups: Vec<[G1Projective; 8]>,
+    random_base: G1Affine,
+}
+
+impl Bhp {
+    /// Derives the generators of the scheme. Schemes set up with different `domain`s share no generators.
+    pub fn setup(domain: u64, num_windows: usize, window_size: usize) -> Self {
+        assert!(
+            num_windows * window_size * CHUNK_BITS > fp::CAPACITY as usize,
+            "a block must have room for input bits after the chaining value"
+        );
+        let lookups = (0..num_windows as u64)
+            .flat_map(|window| {
+                let generator =
+                    hash_to_g1(&[Fp::from(BHP_SEED), Fp::from(domain), Fp::from(window)]);
+                let mut base = generator.to_projective();
+                (0..window_size).map(move |_| {
+                    let multiples = [
+                        base,
+                        base.double(),
+                        base.double() + base,
+                        base.double().double(),
+                    ];
+                    let lookup = std::array::from_fn(|index| {
+                        let multiple = multiples[index & 0b11];
+                        if index & 0b100 == 0 {
+                            multiple
+                        } else {
+                            -multiple
+                        }
+                    });
+                    for _ in 0..4 {
+                        base.double_in_place();
+                    }
+                    lookup
+                })
+            })
+            .collect();
+        Self {
+            lookups,
+            random_base: hash_to_g1(&[Fp::from(BHP_SEED), Fp::from(domain), Fp::from(u64::MAX)]),
+        }
+    }
+
+    /// Returns the number of bits hashed at once, including the chaining value.
+    pub fn block_bits(&self) -> usize {
+        self.lookups.len() * CHUNK_BITS
+    }
+
+    /// Hashes a block of up to `block_bits` bits.
+    fn hash_block(&self, block: &[bool]) -> G1Projective {
+        block
+            .chunks(CHUNK_BITS)
+            .zip(&self.lookups)
+            .map(|(chunk, lookup)| {
+                let index = chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0, |acc, (i, bit)| acc | (*bit as usize) << i);
+                lookup[index]
+            })
+            .sum()
+    }
+
+    /// Returns the hash of the last block of `input`.
+    pub fn hash_uncompressed(&self, input: &[bool]) -> G1Projective {
+        let data_bits = fp::CAPACITY as usize;
+        let mut chaining = (0..64)
+            .map(|i| (input.len() as u64) >> i & 1 == 1)
+            .chain(std::iter::repeat(false))
+            .take(data_bits)
+            .collect::<Vec<_>>();
+        let mut blocks = input.chunks(self.block_bits() - data_bits).peekable();
+        let mut block = chaining.clone();
+        loop {
+            block.extend(blocks.next().unwrap_or_default());
+            let hash = self.hash_block(&block);
+            if blocks.peek().is_none() {
+                return hash;
+            }
+            let x = hash.to_affine().x;
+            chaining.clear();
+            chaining.extend((0..data_bits).map(|i| x.0.bit(i)));
+            block.clone_from(&chaining);
+        }
+    }
+
+    /// Returns the x-coordinate of the hash of `input`.
+    pub fn hash(&self, input: &[bool]) -> Fp {
+        self.hash_uncompressed(input).to_affine().x
+    }
+
+    /// Returns the x-coordinate of the commitment to `input` with `randomness`.
+    pub fn commit(&self, input: &[bool], randomness: Scalar) -> Fp {
+        (self.hash_uncompressed(input) + self.random_base * randomness)
+            .to_affine()
+            .x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bls12_377::Field;
+
+    #[test]
+    fn test_hash_and_commit() {
+        let bhp = Bhp::setup(0, 3, 57);
+        assert_eq!(bhp.block_bits(), 513);
+        let input = [true, false, true, true];
+        assert_eq!(bhp.hash(&input), bhp.hash(&input));
+        assert_ne!(
+            bhp.hash(&input),
+            bhp.hash(&[true, false, true, true, false])
+        );
+        assert_ne!(bhp.hash(&[]), bhp.hash(&[false]));
+        assert_ne!(bhp.hash(&input), Bhp::setup(1, 3, 57).hash(&input));
+        assert_eq!(bhp.commit(&input, Scalar::ZERO), bhp.hash(&input));
+        assert_ne!(bhp.commit(&input, Scalar::ONE), bhp.hash(&input));
+
+        // inputs spanning several blocks
+        let long = (0..1000).map(|i| i % 3 == 0).collect::<Vec<_>>();
+        let mut flipped = long.clone();
+        flipped[999] = !flipped[999];
+        assert_ne!(bhp.hash(&long), bhp.hash(&flipped));
+        flipped[999] = !flipped[999];
+        flipped[0] = !flipped[0];
+        assert_ne!(bhp.hash(&long), bhp.hash(&flipped));
+    }
+
+    #[test]
+    fn test_lookups() {
+        let bhp = Bhp::setup(0, 3, 57);
+        let base = bhp.lookups[0][0];
+        for (index, multiple) in [1i64, 2, 3, 4, -1, -2, -3, -4].into_iter().enumerate() {
+            let expected = base * Scalar::from(multiple.unsigned_abs());
+            let expected = if multiple < 0 { -expected } else { expected };
+            assert_eq!(bhp.lookups[0][index], expected);
+            assert_eq!(bhp.lookups[1][index], expected * Scalar::from(16u64));
+        }
+    }
+}
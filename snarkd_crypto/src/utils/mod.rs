@@ -1,5 +1,9 @@
+pub mod bhp;
+pub mod pedersen;
 pub mod poseidon;
 pub mod sha256;
+pub use bhp::*;
+pub use pedersen::*;
 pub use poseidon::*;
 
 pub struct ExecutionPool<'a, T> {
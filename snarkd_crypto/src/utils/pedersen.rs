@@ -0,0 +1,134 @@
+use crate::{
+    bls12_377::{Affine, Fp, G1Affine, G1Projective, Projective, Scalar},
+    utils::PoseidonSponge,
+};
+use anyhow::{ensure, Result};
+use bitvec::prelude::*;
+
+/// A Pedersen hash and commitment scheme over G1 for inputs of up to `num_bits` bits.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pedersen {
+    bases: Vec<G1Affine>,
+    length_base: G1Affine,
+    random_base: G1Affine,
+}
+
+impl Pedersen {
+    /// Derives the bases of the scheme for inputs of up to `num_bits` bits.
+    /// Schemes set up with different `domain`s share no bases.
+    pub fn setup(domain: u64, num_bits: usize) -> Self {
+        Self {
+            bases: (0..num_bits as u64)
+                .map(|index| hash_to_g1(&[Fp::from(domain), Fp::from(index)]))
+                .collect(),
+            length_base: hash_to_g1(&[Fp::from(domain), Fp::from(u64::MAX - 1)]),
+            random_base: hash_to_g1(&[Fp::from(domain), Fp::from(u64::MAX)]),
+        }
+    }
+
+    /// Returns the maximum number of input bits.
+    pub fn num_bits(&self) -> usize {
+        self.bases.len()
+    }
+
+    /// Returns the sum of the bases selected by `input`, plus the length base times the length of
+    /// `input`, so that inputs differing only in trailing zero bits have different hashes.
+    pub fn hash_uncompressed(&self, input: &[bool]) -> Result<G1Projective> {
+        ensure!(
+            input.len() <= self.bases.len(),
+            "input of {} bits exceeds the {} bits of the scheme",
+            input.len(),
+            self.bases.len()
+        );
+        Ok(input.iter().zip(&self.bases).filter(|(bit, _)| **bit).fold(
+            self.length_base * Scalar::from(input.len() as u64),
+            |acc, (_, base)| acc.add_mixed(base),
+        ))
+    }
+
+    /// Returns the x-coordinate of the hash of `input`.
+    pub fn hash(&self, input: &[bool]) -> Result<Fp> {
+        Ok(self.hash_uncompressed(input)?.to_affine().x)
+    }
+
+    /// Returns the x-coordinate of the commitment to `input` with `randomness`.
+    pub fn commit(&self, input: &[bool], randomness: Scalar) -> Result<Fp> {
+        let commitment = self.hash_uncompressed(input)? + self.random_base * randomness;
+        Ok(commitment.to_affine().x)
+    }
+}
+
+/// Maps `seed` to a point of the prime order subgroup by try-and-increment on x-coordinates.
+pub(crate) fn hash_to_g1(seed: &[Fp]) -> G1Affine {
+    let cofactor = G1Affine::cofactor()
+        .iter()
+        .flat_map(|limb| limb.view_bits::<Lsb0>())
+        .map(|bit| *bit)
+        .rev()
+        .collect::<Vec<_>>();
+    (0u64..)
+        .find_map(|counter| {
+            let mut sponge = PoseidonSponge::default();
+            sponge.absorb_native_field_elements(seed);
+            sponge.absorb_native_field_elements(&[Fp::from(counter)]);
+            let x = sponge.squeeze_native_field_elements(1)[0];
+            let point = G1Affine::from_x_coordinate(x, true)?
+                .mul_bits(cofactor.clone())
+                .to_affine();
+            (!point.is_zero()).then_some(point)
+        })
+        .expect("the counter space cannot be exhausted")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bls12_377::Field;
+
+    #[test]
+    fn test_bases_in_subgroup() {
+        let pedersen = Pedersen::setup(0, 8);
+        for base in pedersen
+            .bases
+            .iter()
+            .chain([&pedersen.length_base, &pedersen.random_base])
+        {
+            assert!(base.is_on_curve());
+            assert!(base.is_in_correct_subgroup_assuming_on_curve());
+        }
+        assert_ne!(pedersen.bases, Pedersen::setup(1, 8).bases);
+    }
+
+    #[test]
+    fn test_hash_and_commit() {
+        let pedersen = Pedersen::setup(0, 8);
+        let input = [true, false, true, true];
+        assert_eq!(
+            pedersen.hash(&input).unwrap(),
+            (pedersen.bases[0].to_projective()
+                + pedersen.bases[2].to_projective()
+                + pedersen.bases[3].to_projective()
+                + pedersen.length_base * Scalar::from(4u64))
+            .to_affine()
+            .x
+        );
+        // trailing zero bits select no base, but change the length
+        assert_ne!(
+            pedersen.hash(&input).unwrap(),
+            pedersen.hash(&[true, false, true, true, false]).unwrap()
+        );
+        assert_ne!(
+            pedersen.hash(&[]).unwrap(),
+            pedersen.hash(&[false]).unwrap()
+        );
+        assert_eq!(
+            pedersen.commit(&input, Scalar::ZERO).unwrap(),
+            pedersen.hash(&input).unwrap()
+        );
+        assert_ne!(
+            pedersen.commit(&input, Scalar::ONE).unwrap(),
+            pedersen.hash(&input).unwrap()
+        );
+        assert!(pedersen.hash(&[false; 9]).is_err());
+    }
+}
@@ -23,8 +23,8 @@ use std::{
     sync::Arc,
 };
 
-const RATE: usize = 2;
-const CAPACITY: usize = 1;
+pub(crate) const RATE: usize = 2;
+pub(crate) const CAPACITY: usize = 1;
 
 /// A function for computing ceil(log2(x))+1 for a field element x
 fn overhead(num: Fp) -> usize {
@@ -652,13 +652,129 @@ impl Default for PoseidonParameters {
     }
 }
 
+impl PoseidonParameters {
+    /// Parameters of a sponge absorbing `rate` elements per permutation, with a capacity of one element.
+    /// All rates use 8 full rounds, 31 partial rounds and an S-box of `x^17`, with round keys and MDS
+    /// matrix derived from the Grain LFSR as in the reference implementation of Poseidon. Rate 2 has
+    /// the default parameters.
+    pub fn for_rate(rate: usize) -> Self {
+        if rate == RATE {
+            return Self::default();
+        }
+        Self::generate(rate)
+    }
+
+    fn generate(rate: usize) -> Self {
+        let (full_rounds, partial_rounds, alpha) = (8, 31, 17);
+        let width = rate + CAPACITY;
+        let mut lfsr = GrainLfsr::new(width, full_rounds, partial_rounds);
+        let ark = (0..full_rounds + partial_rounds)
+            .map(|_| (0..width).map(|_| lfsr.field_element()).collect())
+            .collect();
+        let xs = (0..width)
+            .map(|_| lfsr.field_element_mod_p())
+            .collect::<Vec<_>>();
+        let ys = (0..width)
+            .map(|_| lfsr.field_element_mod_p())
+            .collect::<Vec<_>>();
+        let mds = xs
+            .iter()
+            .map(|x| {
+                ys.iter()
+                    .map(|y| {
+                        (*x + y)
+                            .inverse()
+                            .expect("the MDS matrix is a Cauchy matrix")
+                    })
+                    .collect()
+            })
+            .collect();
+        Self {
+            full_rounds,
+            partial_rounds,
+            alpha,
+            ark,
+            mds,
+        }
+    }
+}
+
+/// The Grain LFSR from which Poseidon parameters are derived, seeded with the field and round counts.
+struct GrainLfsr {
+    state: [bool; 80],
+    head: usize,
+}
+
+impl GrainLfsr {
+    fn new(width: usize, full_rounds: usize, partial_rounds: usize) -> Self {
+        let mut state = [false; 80];
+        // a prime field, with an S-box of `x^alpha` rather than `x^-1`
+        state[1] = true;
+        for (range, value) in [
+            (6..18, fp::MODULUS_BITS as usize),
+            (18..30, width),
+            (30..40, full_rounds),
+            (40..50, partial_rounds),
+        ] {
+            for (i, bit) in range.rev().enumerate() {
+                state[bit] = value >> i & 1 == 1;
+            }
+        }
+        state[50..].fill(true);
+        let mut lfsr = Self { state, head: 0 };
+        for _ in 0..160 {
+            lfsr.update();
+        }
+        lfsr
+    }
+
+    fn update(&mut self) -> bool {
+        let bit = [62, 51, 38, 23, 13, 0]
+            .iter()
+            .fold(false, |acc, i| acc ^ self.state[(self.head + i) % 80]);
+        self.state[self.head] = bit;
+        self.head = (self.head + 1) % 80;
+        bit
+    }
+
+    /// Returns the next output bit. Bits are output in pairs, and the second bit is kept only if the first is set.
+    fn bit(&mut self) -> bool {
+        while !self.update() {
+            self.update();
+        }
+        self.update()
+    }
+
+    /// Returns the next `MODULUS_BITS` output bits as an integer, most significant bit first.
+    fn integer(&mut self) -> Uint<384, 6> {
+        (0..fp::MODULUS_BITS).fold(Uint::ZERO, |acc, _| {
+            (acc << 1) | Uint::from(self.bit() as u64)
+        })
+    }
+
+    /// Samples a field element by rejecting integers outside of the field.
+    fn field_element(&mut self) -> Fp {
+        loop {
+            let value = self.integer();
+            if value < fp::MODULUS {
+                return Fp(value);
+            }
+        }
+    }
+
+    /// Samples a field element by reducing an integer modulo the field.
+    fn field_element_mod_p(&mut self) -> Fp {
+        Fp(self.integer() % fp::MODULUS)
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
-pub struct State {
+pub struct State<const RATE: usize> {
     capacity_state: [Fp; CAPACITY],
     rate_state: [Fp; RATE],
 }
 
-impl Default for State {
+impl<const RATE: usize> Default for State<RATE> {
     fn default() -> Self {
         Self {
             capacity_state: [Fp::ZERO; CAPACITY],
@@ -667,7 +783,7 @@ impl Default for State {
     }
 }
 
-impl State {
+impl<const RATE: usize> State<RATE> {
     /// Returns an immutable iterator over the state.
     pub fn iter(&self) -> impl Iterator<Item = &Fp> + Clone {
         self.capacity_state.iter().chain(self.rate_state.iter())
@@ -688,7 +804,7 @@ impl State {
     }
 }
 
-impl Index<usize> for State {
+impl<const RATE: usize> Index<usize> for State<RATE> {
     type Output = Fp;
 
     fn index(&self, index: usize) -> &Self::Output {
@@ -706,7 +822,7 @@ impl Index<usize> for State {
     }
 }
 
-impl IndexMut<usize> for State {
+impl<const RATE: usize> IndexMut<usize> for State<RATE> {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         assert!(
             index < RATE + CAPACITY,
@@ -722,16 +838,17 @@ impl IndexMut<usize> for State {
     }
 }
 
+/// The Poseidon hash function, absorbing `RATE` field elements per permutation.
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct Poseidon {
+pub struct Poseidon<const RATE: usize = 2> {
     parameters: Arc<PoseidonParameters>,
 }
 
-impl Poseidon {
+impl<const RATE: usize> Poseidon<RATE> {
     /// Initializes a new instance of the cryptographic hash function.
     pub fn setup() -> Self {
         Self {
-            parameters: Arc::new(PoseidonParameters::default()),
+            parameters: Arc::new(PoseidonParameters::for_rate(RATE)),
         }
     }
 
@@ -743,7 +860,7 @@ impl Poseidon {
     /// Evaluate the cryptographic hash function over a list of field elements as input,
     /// and returns the specified number of field elements as output.
     pub fn evaluate_many(&self, input: &[Fp], num_outputs: usize) -> Vec<Fp> {
-        let mut sponge = PoseidonSponge::new(self.parameters.clone());
+        let mut sponge = PoseidonSponge::<RATE>::new(self.parameters.clone());
         sponge.absorb_native_field_elements(input);
         sponge.squeeze_native_field_elements(num_outputs).to_vec()
     }
@@ -766,11 +883,11 @@ impl Poseidon {
 ///
 /// [cos]: https://eprint.iacr.org/2019/1076
 #[derive(Clone, Debug)]
-pub struct PoseidonSponge {
+pub struct PoseidonSponge<const RATE: usize = 2> {
     /// Sponge Parameters
     parameters: Arc<PoseidonParameters>,
     /// Current sponge's state (current elements in the permutation block)
-    state: State,
+    state: State<RATE>,
     /// Current mode (whether its absorbing or squeezing)
     pub mode: DuplexSpongeMode,
 }
@@ -781,7 +898,7 @@ impl Default for PoseidonSponge {
     }
 }
 
-impl PoseidonSponge {
+impl<const RATE: usize> PoseidonSponge<RATE> {
     pub fn new(parameters: Arc<PoseidonParameters>) -> Self {
         Self {
             parameters,
@@ -878,7 +995,7 @@ impl PoseidonSponge {
     }
 }
 
-impl PoseidonSponge {
+impl<const RATE: usize> PoseidonSponge<RATE> {
     #[inline]
     fn apply_ark(&mut self, round_number: usize) {
         for (state_elem, ark_elem) in self
@@ -1202,3 +1319,35 @@ impl PoseidonSponge {
         dest_elements
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_parameters() {
+        assert_eq!(
+            PoseidonParameters::generate(2),
+            PoseidonParameters::default()
+        );
+        for rate in [4, 8] {
+            let parameters = PoseidonParameters::for_rate(rate);
+            assert_eq!(parameters.ark.len(), 39);
+            assert!(parameters.ark.iter().all(|x| x.len() == rate + 1));
+            assert_eq!(parameters.mds.len(), rate + 1);
+        }
+    }
+
+    #[test]
+    fn test_rates() {
+        let input = (0..5).map(Fp::from).collect::<Vec<_>>();
+        let hashes = [
+            Poseidon::<2>::setup().evaluate(&input),
+            Poseidon::<4>::setup().evaluate(&input),
+            Poseidon::<8>::setup().evaluate(&input),
+        ];
+        assert_ne!(hashes[0], hashes[1]);
+        assert_ne!(hashes[1], hashes[2]);
+        assert_eq!(hashes[1], Poseidon::<4>::setup().evaluate(&input));
+    }
+}
@@ -59,6 +59,16 @@ CreateErrorType! {
     suggestions: [],
   }
 
+  unwrapped non_canonical_encoding {
+    args: (),
+    error_msgs: [
+        "The program bytes are not its canonical encoding.",
+    ],
+    suggestions: [
+        "Decode the program and encode it again with `Program::canonical_bytes`.",
+    ],
+  }
+
   from_error invalid_ir {
     args: (),
     error_msgs: [
@@ -106,4 +116,388 @@ CreateErrorType! {
     ],
     suggestions: [],
   }
+
+  unwrapped unknown_register {
+    args: (register),
+    error_msgs: [
+        "Register `r{register}` is read before being written.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped missing_input {
+    args: (name),
+    error_msgs: [
+        "No value given for input `{name}`.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped unexpected_input {
+    args: (name),
+    error_msgs: [
+        "Input `{name}` is not declared by the program header.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped input_type_mismatch {
+    args: (name, value),
+    error_msgs: [
+        "Value `{value}` does not match the declared type of input `{name}`.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped missing_function {
+    args: (index),
+    error_msgs: [
+        "Function `f{index}` does not exist.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped invalid_operand_types {
+    args: (instruction, operands),
+    error_msgs: [
+        "`{instruction}` is not defined for operands {operands}.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped integer_overflow {
+    args: (instruction),
+    error_msgs: [
+        "Integer overflow in `{instruction}`.",
+    ],
+    suggestions: [
+        "Use the wrapped `{instruction}.w` instruction if overflow is expected.",
+    ],
+  }
+
+  unwrapped division_by_zero {
+    args: (instruction),
+    error_msgs: [
+        "Division by zero in `{instruction}`.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped assertion_failed {
+    args: (instruction, lhs, rhs),
+    error_msgs: [
+        "`{instruction}` failed for `{lhs}` and `{rhs}`.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped condition_failed {
+    args: (instruction, cond),
+    error_msgs: [
+        "`{instruction}` failed: `{cond}` is false.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped invalid_field {
+    args: (value),
+    error_msgs: [
+        "`{value}` is not a valid field element.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped invalid_scalar {
+    args: (value),
+    error_msgs: [
+        "`{value}` is not a valid scalar.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped invalid_group {
+    args: (value),
+    error_msgs: [
+        "`{value}` is not an element of the group.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped no_square_root {
+    args: (value),
+    error_msgs: [
+        "`{value}` has no square root.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped unsupported_method {
+    args: (instruction),
+    error_msgs: [
+        "`{instruction}` is not supported by the evaluator.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped invalid_hash_input {
+    args: (instruction, reason),
+    error_msgs: [
+        "Invalid input for `{instruction}`: {reason}.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped invalid_cast {
+    args: (instruction, value, type_),
+    error_msgs: [
+        "`{instruction}` cannot convert `{value}` to `{type_}`.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped missing_entry {
+    args: (instruction, index),
+    error_msgs: [
+        "`{instruction}` found no entry at index {index}.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped unsupported_circuit_value {
+    args: (value),
+    error_msgs: [
+        "`{value}` cannot be represented in a circuit.",
+    ],
+    suggestions: [
+        "Only `boolean` and `field` values, and structs of them, can be used in circuits so far.",
+    ],
+  }
+
+  unwrapped unsupported_circuit_field {
+    args: (value),
+    error_msgs: [
+        "`{value}` does not fit the scalar field that circuits are proven over.",
+    ],
+    suggestions: [
+        "Circuits compute `field` values modulo the BLS12-377 scalar field, so their magnitude must be below its modulus.",
+    ],
+  }
+
+  unwrapped unsupported_circuit_instruction {
+    args: (instruction),
+    error_msgs: [
+        "`{instruction}` cannot be lowered to constraints.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped unsatisfied_circuit {
+    args: (),
+    error_msgs: [
+        "The constraints of the program are not satisfied by its inputs.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped duplicate_input {
+    args: (name, register),
+    error_msgs: [
+        "Input `{name}` in register `r{register}` reuses the name or register of another input.",
+    ],
+    suggestions: [
+        "Give every input in the header its own name and register.",
+    ],
+  }
+
+  unwrapped invalid_type_declaration {
+    args: (name, reason),
+    error_msgs: [
+        "Declared type `{name}` {reason}.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped undeclared_record {
+    args: (item, type_),
+    error_msgs: [
+        "{item} has the record type `{type_}`, which the program does not declare.",
+    ],
+    suggestions: [
+        "Declare the record type in the header, with the same entries and visibilities in the same order.",
+    ],
+  }
+
+  unwrapped duplicate_output {
+    args: (name, register),
+    error_msgs: [
+        "Output `{name}` in register `r{register}` reuses the name or register of another output.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped undefined_output {
+    args: (name, register),
+    error_msgs: [
+        "Output `{name}` reads register `r{register}`, which the first function does not write.",
+    ],
+    suggestions: [
+        "Write the output outside of any `mask` or `repeat` block of the first function.",
+    ],
+  }
+
+  unwrapped mismatched_output_type {
+    args: (name, found, expected),
+    error_msgs: [
+        "Output `{name}` is declared as `{expected}`, but its register holds `{found}`.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped private_flow {
+    args: (item),
+    error_msgs: [
+        "{item} is revealed, but computed from private data.",
+    ],
+    suggestions: [
+        "Make it private, or pass the value through `declassify` if revealing it is intended.",
+    ],
+  }
+
+  unwrapped undefined_register {
+    args: (function, index, instruction, register),
+    error_msgs: [
+        "f{function}:{index}: `{instruction}` reads register `r{register}` before it is written.",
+    ],
+    suggestions: [
+        "Declare `r{register}` as an input in the header, or write it in an earlier instruction.",
+    ],
+  }
+
+  unwrapped mismatched_operand_types {
+    args: (function, index, instruction, operands, expected),
+    error_msgs: [
+        "f{function}:{index}: `{instruction}` is not defined for operands of type {operands}.",
+    ],
+    suggestions: [
+        "`{instruction}` takes {expected}.",
+    ],
+  }
+
+  unwrapped input_register_written {
+    args: (function, index, instruction, register),
+    error_msgs: [
+        "f{function}:{index}: `{instruction}` overwrites input register `r{register}`.",
+    ],
+    suggestions: [
+        "Write the result to a register that is not declared in the header.",
+    ],
+  }
+
+  unwrapped hash_input_too_large {
+    args: (function, index, instruction, type_, bits, limit),
+    error_msgs: [
+        "f{function}:{index}: `{instruction}` takes at most {limit} bits, but `{type_}` has {bits}.",
+    ],
+    suggestions: [
+        "Split the value into smaller parts, or use a method that takes more bits.",
+    ],
+  }
+
+  unwrapped undefined_function {
+    args: (function, index, callee),
+    error_msgs: [
+        "f{function}:{index}: `call` refers to function `f{callee}`, which does not exist.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped recursive_call {
+    args: (function, index, callee),
+    error_msgs: [
+        "f{function}:{index}: `call` of `f{callee}` is recursive.",
+    ],
+    suggestions: [
+        "Functions cannot call themselves, directly or through other functions. Use `repeat` for a bounded loop.",
+    ],
+  }
+
+  unwrapped mismatched_return_count {
+    args: (function, index, callee, found, expected),
+    error_msgs: [
+        "f{function}:{index}: `f{callee}` returns {found} values, but the call stores {expected}.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped misplaced_return {
+    args: (function, index),
+    error_msgs: [
+        "f{function}:{index}: `return` is not the last instruction of its function.",
+    ],
+    suggestions: [
+        "Move the `return` to the end of the function, outside of any `mask` or `repeat` block.",
+    ],
+  }
+
+  unwrapped block_out_of_bounds {
+    args: (function, index, instruction, length),
+    error_msgs: [
+        "f{function}:{index}: The block of `{instruction}` has {length} instructions, more than follow it.",
+    ],
+    suggestions: [
+        "Blocks must end before the end of the block or function containing them.",
+    ],
+  }
+
+  unwrapped block_changes_type {
+    args: (function, index, instruction, register),
+    error_msgs: [
+        "f{function}:{index}: `{instruction}` changes the type of `r{register}`, which was written before the enclosing block.",
+    ],
+    suggestions: [
+        "Write the value to another register, or give it the type it had before the block.",
+    ],
+  }
+
+  unwrapped truncated_block {
+    args: (instruction, length),
+    error_msgs: [
+        "The block of `{instruction}` has {length} instructions, more than follow it.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped unexpected_token {
+    args: (line, column, found, expected),
+    error_msgs: [
+        "{line}:{column}: Expected {expected}, found {found}.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped invalid_literal {
+    args: (line, column, literal, reason),
+    error_msgs: [
+        "{line}:{column}: Invalid literal `{literal}`: {reason}.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped unknown_mnemonic {
+    args: (line, column, mnemonic),
+    error_msgs: [
+        "{line}:{column}: Unknown instruction `{mnemonic}`.",
+    ],
+    suggestions: [],
+  }
+
+  unwrapped misnumbered_instruction {
+    args: (line, column, found, expected),
+    error_msgs: [
+        "{line}:{column}: Instruction is labelled `{found}`, but is instruction {expected} of its function.",
+    ],
+    suggestions: [
+        "Fix the label, or leave it out to have the instruction numbered automatically.",
+    ],
+  }
 }
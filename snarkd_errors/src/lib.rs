@@ -1,4 +1,4 @@
-use error_stack::Report;
+pub use error_stack::Report;
 use thiserror::Error;
 
 mod create_error_type;
@@ -0,0 +1,184 @@
+use std::{fmt, iter::Peekable, str::CharIndices};
+
+use snarkd_errors::{IRError, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum TokenKind {
+    /// A name, keyword, register or address, like `add`, `r0` or `aleo1...`.
+    Ident(String),
+    /// Decimal digits and the type suffix directly following them, like `5u8`.
+    Number {
+        digits: String,
+        suffix: String,
+    },
+    /// A quoted string, with its escapes resolved.
+    String(String),
+    /// One of `( ) [ ] { } , : . + -`, or `->`.
+    Punct(&'static str),
+    End,
+}
+
+impl fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenKind::Ident(x) => write!(f, "`{x}`"),
+            TokenKind::Number { digits, suffix } => write!(f, "`{digits}{suffix}`"),
+            TokenKind::String(x) => write!(f, "`{x:?}`"),
+            TokenKind::Punct(x) => write!(f, "`{x}`"),
+            TokenKind::End => write!(f, "end of input"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct Token {
+    pub kind: TokenKind,
+    pub line: usize,
+    pub column: usize,
+    /// Whether the token directly follows the previous one, without whitespace or comments.
+    pub joined: bool,
+}
+
+const PUNCTUATION: [&str; 12] = ["->", "(", ")", "[", "]", "{", "}", ",", ":", ".", "+", "-"];
+
+struct Lexer<'a> {
+    source: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().map(|(_, x)| *x)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let (_, x) = self.chars.next()?;
+        if x == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(x)
+    }
+
+    fn offset(&mut self) -> usize {
+        self.chars.peek().map_or(self.source.len(), |(i, _)| *i)
+    }
+
+    /// Consumes characters while `f` holds, returning them.
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        let start = self.offset();
+        while self.peek().is_some_and(&f) {
+            self.bump();
+        }
+        let end = self.offset();
+        &self.source[start..end]
+    }
+
+    /// Skips whitespace and `//` comments, returning whether anything was skipped.
+    fn skip_trivia(&mut self) -> bool {
+        let mut skipped = false;
+        loop {
+            if !self.take_while(char::is_whitespace).is_empty() {
+                skipped = true;
+            } else if self.source[self.offset()..].starts_with("//") {
+                self.take_while(|x| x != '\n');
+                skipped = true;
+            } else {
+                return skipped;
+            }
+        }
+    }
+
+    fn string(&mut self, line: usize, column: usize) -> Result<String> {
+        let invalid = |reason: &str| IRError::invalid_literal(line, column, "string", reason);
+        self.bump();
+        let mut out = String::new();
+        loop {
+            match self
+                .bump()
+                .ok_or_else(|| invalid("missing closing quote"))?
+            {
+                '"' => return Ok(out),
+                '\\' => out.push(match self.bump() {
+                    Some('n') => '\n',
+                    Some('r') => '\r',
+                    Some('t') => '\t',
+                    Some('0') => '\0',
+                    Some(x @ ('\\' | '"' | '\'')) => x,
+                    Some('u') => {
+                        let escape = self.take_while(|x| x != '}');
+                        self.bump();
+                        escape
+                            .strip_prefix('{')
+                            .and_then(|x| u32::from_str_radix(x, 16).ok())
+                            .and_then(char::from_u32)
+                            .ok_or_else(|| invalid("invalid unicode escape"))?
+                    }
+                    _ => return Err(invalid("unknown escape").into()),
+                }),
+                x => out.push(x),
+            }
+        }
+    }
+
+    fn next(&mut self) -> Result<Token> {
+        let joined = !self.skip_trivia();
+        let (line, column) = (self.line, self.column);
+        let kind = match self.peek() {
+            None => TokenKind::End,
+            Some('"') => TokenKind::String(self.string(line, column)?),
+            Some(x) if x.is_ascii_digit() => TokenKind::Number {
+                digits: self.take_while(|x| x.is_ascii_digit()).into(),
+                suffix: self
+                    .take_while(|x| x.is_ascii_alphanumeric() || x == '_')
+                    .into(),
+            },
+            Some(x) if x.is_ascii_alphabetic() || x == '_' => TokenKind::Ident(
+                self.take_while(|x| x.is_ascii_alphanumeric() || x == '_')
+                    .into(),
+            ),
+            Some(x) => {
+                let rest = &self.source[self.offset()..];
+                let punct = PUNCTUATION
+                    .into_iter()
+                    .find(|x| rest.starts_with(x))
+                    .ok_or_else(|| {
+                        IRError::unexpected_token(line, column, format!("`{x}`"), "a token")
+                    })?;
+                for _ in 0..punct.len() {
+                    self.bump();
+                }
+                TokenKind::Punct(punct)
+            }
+        };
+        Ok(Token {
+            kind,
+            line,
+            column,
+            joined,
+        })
+    }
+}
+
+/// Splits `source` into tokens, ending with a [`TokenKind::End`] token.
+pub(super) fn tokenize(source: &str) -> Result<Vec<Token>> {
+    let mut lexer = Lexer {
+        source,
+        chars: source.char_indices().peekable(),
+        line: 1,
+        column: 1,
+    };
+    let mut tokens = vec![];
+    loop {
+        let token = lexer.next()?;
+        let end = token.kind == TokenKind::End;
+        tokens.push(token);
+        if end {
+            return Ok(tokens);
+        }
+    }
+}
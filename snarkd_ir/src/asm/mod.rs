@@ -0,0 +1,141 @@
+//! The assembly text format of programs, described in `docs/assembly.md`.
+//!
+//! The `Display` implementations of programs, instructions, operands and types write this format,
+//! and the `FromStr` implementations here read it back, so that `text.parse::<Program>()` of a
+//! printed program gives the same program.
+
+#[cfg(test)]
+mod tests;
+
+mod lexer;
+mod parser;
+
+use std::{fmt, str::FromStr};
+
+use snarkd_errors::{Error, Result};
+
+use crate::{Instruction, Operand, Program, Type};
+
+use parser::Parser;
+
+/// Ten to the power of the number of decimal digits that fit in a limb.
+const DECIMAL_LIMB: u128 = 10_000_000_000_000_000_000;
+
+/// Writes little endian limbs as a decimal number.
+fn to_decimal(values: &[u64]) -> String {
+    let mut limbs = values.to_vec();
+    let mut digits = vec![];
+    while limbs.iter().any(|x| *x != 0) {
+        let mut remainder = 0u128;
+        for limb in limbs.iter_mut().rev() {
+            let current = remainder << 64 | *limb as u128;
+            *limb = (current / DECIMAL_LIMB) as u64;
+            remainder = current % DECIMAL_LIMB;
+        }
+        digits.push(remainder as u64);
+    }
+    match digits.split_last() {
+        None => "0".into(),
+        Some((first, rest)) => rest
+            .iter()
+            .rev()
+            .fold(first.to_string(), |out, x| format!("{out}{x:019}")),
+    }
+}
+
+/// Reads a decimal number into the fewest little endian limbs that hold it.
+fn from_decimal(digits: &str) -> Vec<u64> {
+    let mut limbs = vec![0u64];
+    for digit in digits.bytes() {
+        let mut carry = (digit - b'0') as u128;
+        for limb in limbs.iter_mut() {
+            let current = *limb as u128 * 10 + carry;
+            *limb = current as u64;
+            carry = current >> 64;
+        }
+        if carry != 0 {
+            limbs.push(carry as u64);
+        }
+    }
+    limbs
+}
+
+/// A field, scalar or group element written from its limbs and type suffix, as `-5field`.
+/// Limbs that a decimal number would not read back exactly, like a zero high limb, are listed
+/// instead, as `field[5, 0]`.
+pub(crate) struct Limbs<'a> {
+    pub negate: bool,
+    pub values: &'a [u64],
+    pub suffix: &'static str,
+}
+
+impl fmt::Display for Limbs<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negate {
+            write!(f, "-")?;
+        }
+        if self.values.len() == 1 || self.values.last().is_some_and(|x| *x != 0) {
+            return write!(f, "{}{}", to_decimal(self.values), self.suffix);
+        }
+        write!(f, "{}[", self.suffix)?;
+        for (i, value) in self.values.iter().enumerate() {
+            let separator = if i == 0 { "" } else { ", " };
+            write!(f, "{separator}{value}")?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// The name of an input or struct member, quoted unless it is an identifier.
+pub(crate) struct Name<'a>(pub &'a str);
+
+/// Whether `name` can be written without quotes, `[A-Za-z_][A-Za-z0-9_]*`.
+pub(crate) fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|x| x.is_ascii_alphabetic() || x == '_')
+        && chars.all(|x| x.is_ascii_alphanumeric() || x == '_')
+}
+
+impl fmt::Display for Name<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match is_identifier(self.0) {
+            true => write!(f, "{}", self.0),
+            false => write!(f, "{:?}", self.0),
+        }
+    }
+}
+
+impl FromStr for Program {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Parser::new(s)?.finish(Parser::program)
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = Error;
+
+    /// Reads a single instruction, without an index label.
+    fn from_str(s: &str) -> Result<Self> {
+        Parser::new(s)?.finish(Parser::instruction)
+    }
+}
+
+impl FromStr for Operand {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Parser::new(s)?.finish(Parser::operand)
+    }
+}
+
+impl FromStr for Type {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Parser::new(s)?.finish(Parser::type_)
+    }
+}
@@ -0,0 +1,673 @@
+use std::{fmt, str::FromStr};
+
+use bech32::FromBase32;
+use snarkd_errors::{Error, IRError, Result};
+
+use super::{
+    from_decimal,
+    lexer::{tokenize, Token, TokenKind},
+};
+use crate::{
+    Address, Arg, Field, Function, Group, GroupCoordinate, Header, InputType, Instruction, Opcode,
+    Operand, OperandKind, OutputType, Program, Record, RecordType, RecordTypeEntry, Scalar, Struct,
+    StructType, StructTypeEntry, TupleGroup, Type, TypeDeclaration, Visibility, VisibleData,
+};
+
+fn unexpected(token: &Token, expected: impl fmt::Display) -> Error {
+    IRError::unexpected_token(token.line, token.column, &token.kind, expected).into()
+}
+
+fn integer<T: FromStr>(negate: bool, digits: &str) -> Option<T> {
+    match negate {
+        true => format!("-{digits}").parse().ok(),
+        false => digits.parse().ok(),
+    }
+}
+
+pub(super) struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+    /// Types declared so far, which later types may refer to by name.
+    types: Vec<TypeDeclaration>,
+}
+
+impl Parser {
+    pub fn new(source: &str) -> Result<Self> {
+        Ok(Self {
+            tokens: tokenize(source)?,
+            position: 0,
+            types: vec![],
+        })
+    }
+
+    /// Reads the whole input with `f`.
+    pub fn finish<T>(mut self, f: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        let out = f(&mut self)?;
+        match self.peek() {
+            Token {
+                kind: TokenKind::End,
+                ..
+            } => Ok(out),
+            token => Err(unexpected(token, "end of input")),
+        }
+    }
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.position]
+    }
+
+    /// The token after the next one, or the end token.
+    fn peek_second(&self) -> &Token {
+        &self.tokens[(self.position + 1).min(self.tokens.len() - 1)]
+    }
+
+    fn next(&mut self) -> Token {
+        let token = self.tokens[self.position].clone();
+        if token.kind != TokenKind::End {
+            self.position += 1;
+        }
+        token
+    }
+
+    fn is_punct(&self, punct: &str) -> bool {
+        matches!(&self.peek().kind, TokenKind::Punct(x) if *x == punct)
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(&self.peek().kind, TokenKind::Ident(x) if x == keyword)
+    }
+
+    fn eat(&mut self, punct: &str) -> bool {
+        let found = self.is_punct(punct);
+        if found {
+            self.next();
+        }
+        found
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let found = self.is_keyword(keyword);
+        if found {
+            self.next();
+        }
+        found
+    }
+
+    fn expect(&mut self, punct: &str) -> Result<()> {
+        match self.eat(punct) {
+            true => Ok(()),
+            false => Err(unexpected(self.peek(), format_args!("`{punct}`"))),
+        }
+    }
+
+    fn keyword(&mut self, keyword: &str) -> Result<()> {
+        match self.eat_keyword(keyword) {
+            true => Ok(()),
+            false => Err(unexpected(self.peek(), format_args!("`{keyword}`"))),
+        }
+    }
+
+    /// Reads items separated by commas up to `close`, after the opening delimiter.
+    fn list<T>(
+        &mut self,
+        close: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut items = vec![];
+        if self.eat(close) {
+            return Ok(items);
+        }
+        loop {
+            items.push(item(self)?);
+            if self.eat(close) {
+                return Ok(items);
+            }
+            if !self.eat(",") {
+                return Err(unexpected(self.peek(), format_args!("`,` or `{close}`")));
+            }
+        }
+    }
+
+    /// Reads a number without a type suffix.
+    fn number<T: FromStr>(&mut self, expected: &str) -> Result<T> {
+        let token = self.next();
+        match &token.kind {
+            TokenKind::Number { digits, suffix } if suffix.is_empty() => {
+                digits.parse().map_err(|_| {
+                    IRError::invalid_literal(token.line, token.column, digits, "too large").into()
+                })
+            }
+            _ => Err(unexpected(&token, expected)),
+        }
+    }
+
+    /// Reads an identifier made of `prefix` and a number, like `r0`.
+    fn indexed(&mut self, prefix: char, expected: &str) -> Result<u32> {
+        let token = self.next();
+        match &token.kind {
+            TokenKind::Ident(x) => x
+                .strip_prefix(prefix)
+                .filter(|x| !x.is_empty() && x.bytes().all(|x| x.is_ascii_digit()))
+                .and_then(|x| x.parse().ok())
+                .ok_or_else(|| unexpected(&token, expected)),
+            _ => Err(unexpected(&token, expected)),
+        }
+    }
+
+    fn register(&mut self) -> Result<u32> {
+        self.indexed('r', "a register")
+    }
+
+    fn name(&mut self) -> Result<String> {
+        let token = self.next();
+        match token.kind {
+            TokenKind::Ident(x) | TokenKind::String(x) => Ok(x),
+            _ => Err(unexpected(&token, "a name")),
+        }
+    }
+
+    fn visibility(&mut self) -> Result<Visibility> {
+        let token = self.next();
+        match &token.kind {
+            TokenKind::Ident(x) if x == "constant" => Ok(Visibility::Constant),
+            TokenKind::Ident(x) if x == "private" => Ok(Visibility::Private),
+            TokenKind::Ident(x) if x == "public" => Ok(Visibility::Public),
+            _ => Err(unexpected(&token, "a visibility")),
+        }
+    }
+
+    pub fn program(&mut self) -> Result<Program> {
+        let mut header = Header::default();
+        if self.eat_keyword("version") {
+            header.snarkd_major = self.number("a major version")?;
+            self.expect(".")?;
+            header.snarkd_minor = self.number("a minor version")?;
+            self.expect(".")?;
+            header.snarkd_patch = self.number("a patch version")?;
+        }
+        while self.is_keyword("struct") || self.is_keyword("record") {
+            let declaration = self.declaration()?;
+            self.types.push(declaration);
+        }
+        while self.eat_keyword("mapping") {
+            let token = self.peek().clone();
+            let name = self.name()?;
+            if header.declares_mapping(&name) {
+                return Err(unexpected(&token, "a new mapping name"));
+            }
+            header.mappings.push(name);
+        }
+        while self.eat_keyword("input") {
+            let token = self.next();
+            let inputs = match &token.kind {
+                TokenKind::Ident(x) => match x.as_str() {
+                    "constant" => &mut header.constant_inputs,
+                    "private" => &mut header.private_inputs,
+                    "public" => &mut header.public_inputs,
+                    "register" => &mut header.register_inputs,
+                    "public_state" => &mut header.public_states,
+                    "private_record_state" => &mut header.private_record_states,
+                    "private_leaf_state" => &mut header.private_leaf_states,
+                    _ => return Err(unexpected(&token, "an input category")),
+                },
+                _ => return Err(unexpected(&token, "an input category")),
+            };
+            let variable = self.register()?;
+            let name = self.name()?;
+            self.expect(":")?;
+            inputs.push(InputType {
+                variable,
+                name,
+                type_: self.type_()?,
+            });
+        }
+        while self.eat_keyword("output") {
+            let visibility = self.visibility()?;
+            let variable = self.register()?;
+            let name = self.name()?;
+            self.expect(":")?;
+            header.outputs.push(OutputType {
+                variable,
+                name,
+                type_: self.type_()?,
+                visibility,
+            });
+        }
+        header.types = std::mem::take(&mut self.types);
+        let mut functions = vec![];
+        while self.peek().kind != TokenKind::End {
+            functions.push(self.function(functions.len())?);
+        }
+        Ok(Program { header, functions })
+    }
+
+    /// Reads a named struct or record type, like `struct point { x: field, y: field }`.
+    fn declaration(&mut self) -> Result<TypeDeclaration> {
+        let is_struct = self.is_keyword("struct");
+        self.next();
+        let token = self.next();
+        let name = match &token.kind {
+            TokenKind::Ident(x) if !self.types.iter().any(|y| y.name == *x) => x.clone(),
+            _ => return Err(unexpected(&token, "a new type name")),
+        };
+        let type_ = match is_struct {
+            true => Type::Struct(self.struct_type()?),
+            false => Type::Record(self.record_type()?),
+        };
+        Ok(TypeDeclaration { name, type_ })
+    }
+
+    fn function(&mut self, index: usize) -> Result<Function> {
+        self.keyword("decl")?;
+        // Functions may be labelled with their index, which calls refer to them by.
+        if matches!(&self.peek().kind, TokenKind::Ident(x) if x.starts_with('f')) {
+            let token = self.peek().clone();
+            let label = self.indexed('f', "a function")?;
+            if label as usize != index {
+                return Err(unexpected(&token, format_args!("`f{index}`")));
+            }
+        }
+        let argument_start_variable = self.register()?;
+        let mut instructions = vec![];
+        while self.peek().kind != TokenKind::End && !self.is_keyword("decl") {
+            // Instructions may be labelled with their index, as they are printed.
+            if matches!(self.peek_second().kind, TokenKind::Punct(":")) {
+                let token = self.peek().clone();
+                let label: usize = self.number("an instruction")?;
+                if label != instructions.len() {
+                    return Err(IRError::misnumbered_instruction(
+                        token.line,
+                        token.column,
+                        label,
+                        instructions.len(),
+                    )
+                    .into());
+                }
+                self.expect(":")?;
+            }
+            instructions.push(self.instruction()?);
+        }
+        Ok(Function {
+            argument_start_variable,
+            instructions,
+        })
+    }
+
+    pub fn instruction(&mut self) -> Result<Instruction> {
+        let token = self.next();
+        let mut mnemonic = match &token.kind {
+            TokenKind::Ident(x) => x.clone(),
+            _ => return Err(unexpected(&token, "an instruction")),
+        };
+        while self.is_punct(".") && self.peek().joined && self.peek_second().joined {
+            match &self.peek_second().kind {
+                TokenKind::Ident(x) => mnemonic = format!("{mnemonic}.{x}"),
+                _ => break,
+            }
+            self.next();
+            self.next();
+        }
+
+        let opcode = Opcode::from_mnemonic(&mnemonic).ok_or_else(|| {
+            Error::from(IRError::unknown_mnemonic(
+                token.line,
+                token.column,
+                mnemonic,
+            ))
+        })?;
+        let mut args = vec![];
+        let mut previous = None;
+        for kind in opcode.info().operands.iter().copied() {
+            match kind.separator(previous).trim() {
+                "" => {}
+                "as" => self.keyword("as")?,
+                punct if kind.is_optional() => {
+                    if !self.eat(punct) {
+                        args.push(match kind {
+                            OperandKind::Values => Arg::Values(vec![]),
+                            _ => Arg::Numbers(vec![]),
+                        });
+                        continue;
+                    }
+                }
+                punct => self.expect(punct)?,
+            }
+            let arg = self.arg(kind)?;
+            if !arg.is_empty() {
+                previous = Some(kind);
+            }
+            args.push(arg);
+        }
+        Ok(Instruction::from_args(opcode, args))
+    }
+
+    /// Reads an operand of kind `kind`, after its separator.
+    fn arg(&mut self, kind: OperandKind) -> Result<Arg> {
+        Ok(match kind {
+            OperandKind::Value => Arg::Value(self.operand()?),
+            OperandKind::Values => Arg::Values(self.operands()?),
+            OperandKind::Type => Arg::Type(self.type_()?),
+            OperandKind::Index => Arg::Number(self.number("an entry index")?),
+            OperandKind::Count => Arg::Number(self.number("a repeat count")?),
+            OperandKind::Length => Arg::Number(self.number("a block length")?),
+            OperandKind::Function => Arg::Number(self.indexed('f', "a function")?),
+            OperandKind::Dest => Arg::Number(self.register()?),
+            OperandKind::Dests => {
+                let mut dests = vec![self.register()?];
+                while self.eat(",") {
+                    dests.push(self.register()?);
+                }
+                Arg::Numbers(dests)
+            }
+            OperandKind::Randomness => {
+                let token = self.peek().clone();
+                match self.numeric()? {
+                    Operand::Scalar(x) => Arg::Randomness(x),
+                    _ => return Err(unexpected(&token, "a scalar")),
+                }
+            }
+        })
+    }
+
+    /// Reads operands separated by commas, which may be none at all.
+    fn operands(&mut self) -> Result<Vec<Operand>> {
+        let mut operands = vec![];
+        if self.starts_operand() {
+            operands.push(self.operand()?);
+            while self.eat(",") {
+                operands.push(self.operand()?);
+            }
+        }
+        Ok(operands)
+    }
+
+    /// Whether the next token starts an operand, rather than an instruction, label or `decl`.
+    fn starts_operand(&self) -> bool {
+        match &self.peek().kind {
+            TokenKind::Number { .. } => !matches!(self.peek_second().kind, TokenKind::Punct(":")),
+            TokenKind::Punct("-" | "(") | TokenKind::String(_) => true,
+            TokenKind::Ident(x) => match x.as_str() {
+                "true" | "false" => true,
+                "struct" | "record" => matches!(self.peek_second().kind, TokenKind::Punct("(")),
+                "field" | "scalar" | "group" => {
+                    matches!(self.peek_second().kind, TokenKind::Punct("["))
+                }
+                _ => {
+                    x.starts_with("aleo1")
+                        || x.strip_prefix('r')
+                            .is_some_and(|x| !x.is_empty() && x.bytes().all(|x| x.is_ascii_digit()))
+                }
+            },
+            _ => false,
+        }
+    }
+
+    pub fn operand(&mut self) -> Result<Operand> {
+        let token = self.peek().clone();
+        match &token.kind {
+            TokenKind::Number { .. } | TokenKind::Punct("-") => self.numeric(),
+            TokenKind::Punct("(") => Ok(Operand::Group(Group::Tuple(self.tuple_group()?))),
+            TokenKind::String(x) => {
+                self.next();
+                Ok(Operand::String(x.clone()))
+            }
+            TokenKind::Ident(x) => match x.as_str() {
+                "field" | "scalar" | "group" => self.numeric(),
+                "true" | "false" => {
+                    self.next();
+                    Ok(Operand::Boolean(x == "true"))
+                }
+                "struct" => {
+                    self.next();
+                    self.expect("(")?;
+                    Ok(Operand::Struct(Struct {
+                        values: self.list(")", Self::operand)?,
+                    }))
+                }
+                "record" => Ok(Operand::Record(Box::new(self.record()?))),
+                _ if x.starts_with("aleo1") => Ok(Operand::Address(self.address()?)),
+                _ => self.register().map(Operand::Ref),
+            },
+            _ => Err(unexpected(&token, "an operand")),
+        }
+    }
+
+    /// Reads an integer, field, scalar or group literal, like `-5i8`, `5field` or `field[5, 0]`.
+    fn numeric(&mut self) -> Result<Operand> {
+        let negate = self.eat("-");
+        let token = self.next();
+        if negate && !token.joined {
+            return Err(unexpected(&token, "a number"));
+        }
+        let literal = match &token.kind {
+            TokenKind::Number { digits, suffix } => format!("{digits}{suffix}"),
+            TokenKind::Ident(x) => x.clone(),
+            kind => kind.to_string(),
+        };
+        let invalid = |reason: String| -> Error {
+            IRError::invalid_literal(token.line, token.column, &literal, reason).into()
+        };
+        let (suffix, values) = match &token.kind {
+            TokenKind::Number { digits, suffix } => {
+                let value = match suffix.as_str() {
+                    "u8" => Some(integer(negate, digits).map(Operand::U8)),
+                    "u16" => Some(integer(negate, digits).map(Operand::U16)),
+                    "u32" => Some(integer(negate, digits).map(Operand::U32)),
+                    "u64" => Some(integer(negate, digits).map(Operand::U64)),
+                    "u128" => Some(integer(negate, digits).map(Operand::U128)),
+                    "i8" => Some(integer(negate, digits).map(Operand::I8)),
+                    "i16" => Some(integer(negate, digits).map(Operand::I16)),
+                    "i32" => Some(integer(negate, digits).map(Operand::I32)),
+                    "i64" => Some(integer(negate, digits).map(Operand::I64)),
+                    "i128" => Some(integer(negate, digits).map(Operand::I128)),
+                    _ => None,
+                };
+                if let Some(value) = value {
+                    return value.ok_or_else(|| invalid(format!("out of range for `{suffix}`")));
+                }
+                (suffix.as_str(), from_decimal(digits))
+            }
+            TokenKind::Ident(x) if self.is_punct("[") && self.peek().joined => {
+                self.next();
+                (x.as_str(), self.list("]", |p| p.number("a limb"))?)
+            }
+            _ => return Err(unexpected(&token, "a number")),
+        };
+        let field = Field { negate, values };
+        match suffix {
+            "field" => Ok(Operand::Field(field)),
+            "group" => Ok(Operand::Group(Group::Single(field))),
+            "scalar" if !negate => Ok(Operand::Scalar(Scalar {
+                values: field.values,
+            })),
+            "scalar" => Err(invalid("scalars cannot be negative".into())),
+            "" => Err(invalid("missing a type suffix".into())),
+            _ => Err(invalid(format!("unknown type `{suffix}`"))),
+        }
+    }
+
+    /// Reads an affine group element, like `(5field, +)group`.
+    fn tuple_group(&mut self) -> Result<TupleGroup> {
+        self.expect("(")?;
+        let left = self.coordinate()?;
+        self.expect(",")?;
+        let right = self.coordinate()?;
+        self.expect(")")?;
+        let token = self.next();
+        match &token.kind {
+            TokenKind::Ident(x) if x == "group" && token.joined => Ok(TupleGroup { left, right }),
+            _ => Err(unexpected(&token, "`group`")),
+        }
+    }
+
+    fn coordinate(&mut self) -> Result<GroupCoordinate> {
+        let ends = matches!(self.peek_second().kind, TokenKind::Punct("," | ")"));
+        if self.eat("+") {
+            return Ok(GroupCoordinate::SignHigh);
+        }
+        if self.is_punct("-") && ends {
+            self.next();
+            return Ok(GroupCoordinate::SignLow);
+        }
+        if self.eat_keyword("_") {
+            return Ok(GroupCoordinate::Inferred);
+        }
+        let token = self.peek().clone();
+        match self.numeric()? {
+            Operand::Field(x) => Ok(GroupCoordinate::GroupField(x)),
+            _ => Err(unexpected(&token, "a field, `+`, `-` or `_`")),
+        }
+    }
+
+    fn address(&mut self) -> Result<Address> {
+        let token = self.next();
+        let text = match &token.kind {
+            TokenKind::Ident(x) => x,
+            _ => return Err(unexpected(&token, "an address")),
+        };
+        let invalid = |reason| IRError::invalid_literal(token.line, token.column, text, reason);
+        let (hrp, data, variant) = bech32::decode(text).map_err(|e| invalid(e.to_string()))?;
+        if hrp != "aleo" || variant != bech32::Variant::Bech32 {
+            return Err(invalid("not a bech32 `aleo` address".into()).into());
+        }
+        Ok(Address {
+            address: Vec::from_base32(&data).map_err(|e| invalid(e.to_string()))?,
+        })
+    }
+
+    /// Reads a `.visibility` suffix.
+    fn visible(&mut self) -> Result<Visibility> {
+        self.expect(".")?;
+        self.visibility()
+    }
+
+    fn record(&mut self) -> Result<Record> {
+        self.keyword("record")?;
+        self.expect("(")?;
+        self.keyword("owner")?;
+        self.expect(":")?;
+        let owner = self.address()?;
+        let owner_visibility = self.visible()?;
+        self.expect(",")?;
+        self.keyword("gates")?;
+        self.expect(":")?;
+        let token = self.peek().clone();
+        let gates = match self.numeric()? {
+            Operand::U64(x) => x,
+            _ => return Err(unexpected(&token, "a `u64`")),
+        };
+        let gates_visibility = self.visible()?;
+        self.expect(",")?;
+        self.keyword("data")?;
+        self.expect(":")?;
+        self.expect("(")?;
+        let data = self.list(")", |p| {
+            let value = p.operand()?;
+            Ok(VisibleData {
+                value,
+                visibility: p.visible()?,
+            })
+        })?;
+        self.expect(",")?;
+        self.keyword("nonce")?;
+        self.expect(":")?;
+        let token = self.peek().clone();
+        let nonce = match self.operand()? {
+            Operand::Group(x) => x,
+            _ => return Err(unexpected(&token, "a group")),
+        };
+        let nonce_visibility = self.visible()?;
+        self.expect(")")?;
+        Ok(Record {
+            owner,
+            owner_visibility,
+            gates,
+            gates_visibility,
+            data,
+            nonce,
+            nonce_visibility,
+        })
+    }
+
+    pub fn type_(&mut self) -> Result<Type> {
+        let token = self.next();
+        let name = match &token.kind {
+            TokenKind::Ident(x) => x.as_str(),
+            _ => return Err(unexpected(&token, "a type")),
+        };
+        Ok(match name {
+            "address" => Type::Address,
+            "boolean" => Type::Boolean,
+            "field" => Type::Field,
+            "group" => Type::Group,
+            "u8" => Type::U8,
+            "u16" => Type::U16,
+            "u32" => Type::U32,
+            "u64" => Type::U64,
+            "u128" => Type::U128,
+            "i8" => Type::I8,
+            "i16" => Type::I16,
+            "i32" => Type::I32,
+            "i64" => Type::I64,
+            "i128" => Type::I128,
+            "scalar" => Type::Scalar,
+            "string" => Type::String,
+            "struct" => Type::Struct(self.struct_type()?),
+            "record" => Type::Record(self.record_type()?),
+            _ => match self.types.iter().find(|x| x.name == name) {
+                Some(declaration) => declaration.type_.clone(),
+                None => return Err(unexpected(&token, "a type")),
+            },
+        })
+    }
+
+    fn struct_type(&mut self) -> Result<StructType> {
+        self.expect("{")?;
+        Ok(StructType {
+            fields: self.list("}", |p| {
+                let name = p.name()?;
+                p.expect(":")?;
+                Ok(StructTypeEntry {
+                    name,
+                    type_: p.type_()?,
+                })
+            })?,
+        })
+    }
+
+    /// Reads the entries of a record type, which start with its owner and gates and end with its
+    /// nonce.
+    fn record_type(&mut self) -> Result<RecordType> {
+        self.expect("{")?;
+        let entries = self.list("}", |p| {
+            let token = p.peek().clone();
+            let name = p.name()?;
+            p.expect(":")?;
+            let type_ = p.type_()?;
+            Ok((
+                token,
+                RecordTypeEntry {
+                    name,
+                    type_,
+                    visibility: p.visible()?,
+                },
+            ))
+        })?;
+        let close = self.tokens[self.position - 1].clone();
+        let fixed = |entry: Option<(Token, RecordTypeEntry)>, name: &str, type_: Type| match entry {
+            Some((_, entry)) if entry.name == name && entry.type_ == type_ => Ok(entry.visibility),
+            Some((token, _)) => Err(unexpected(&token, format_args!("`{name}: {type_}`"))),
+            None => Err(unexpected(&close, format_args!("`{name}: {type_}`"))),
+        };
+        let mut entries = entries.into_iter();
+        let owner = fixed(entries.next(), "owner", Type::Address)?;
+        let gates = fixed(entries.next(), "gates", Type::U64)?;
+        let nonce = fixed(entries.next_back(), "nonce", Type::Group)?;
+        Ok(RecordType {
+            owner,
+            gates,
+            data: entries.map(|(_, entry)| entry).collect(),
+            nonce,
+        })
+    }
+}
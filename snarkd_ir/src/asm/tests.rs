@@ -0,0 +1,469 @@
+use crate::*;
+
+fn field(negate: bool, values: Vec<u64>) -> Field {
+    Field { negate, values }
+}
+
+fn binary(lhs: Operand, rhs: Operand, dest: u32) -> BinaryData {
+    BinaryData { lhs, rhs, dest }
+}
+
+fn unary(operand: Operand, dest: u32) -> UnaryData {
+    UnaryData { operand, dest }
+}
+
+fn input(variable: u32, name: &str, type_: Type) -> InputType {
+    InputType {
+        variable,
+        name: name.into(),
+        type_,
+    }
+}
+
+fn record() -> Record {
+    Record {
+        owner: Address {
+            address: (0..32).collect(),
+        },
+        owner_visibility: Visibility::Private,
+        gates: 5,
+        gates_visibility: Visibility::Public,
+        data: vec![
+            VisibleData {
+                value: Operand::Struct(Struct { values: vec![] }),
+                visibility: Visibility::Constant,
+            },
+            VisibleData {
+                value: Operand::Ref(3),
+                visibility: Visibility::Private,
+            },
+        ],
+        nonce: Group::Tuple(TupleGroup {
+            left: GroupCoordinate::GroupField(field(false, vec![7])),
+            right: GroupCoordinate::SignLow,
+        }),
+        nonce_visibility: Visibility::Constant,
+    }
+}
+
+fn operands() -> Vec<Operand> {
+    vec![
+        Operand::Address(Address { address: vec![] }),
+        Operand::Boolean(true),
+        Operand::Boolean(false),
+        Operand::Field(field(false, vec![0])),
+        Operand::Field(field(true, vec![u64::MAX, u64::MAX, 1])),
+        Operand::Field(field(false, vec![1, 0])),
+        Operand::Field(field(true, vec![])),
+        Operand::Group(Group::Single(field(true, vec![2]))),
+        Operand::Group(Group::Tuple(TupleGroup {
+            left: GroupCoordinate::GroupField(field(true, vec![3, 0])),
+            right: GroupCoordinate::SignHigh,
+        })),
+        Operand::Group(Group::Tuple(TupleGroup {
+            left: GroupCoordinate::Inferred,
+            right: GroupCoordinate::GroupField(field(false, vec![4])),
+        })),
+        Operand::U8(u8::MAX),
+        Operand::U16(1),
+        Operand::U32(2),
+        Operand::U64(3),
+        Operand::U128(u128::MAX),
+        Operand::I8(i8::MIN),
+        Operand::I16(-1),
+        Operand::I32(0),
+        Operand::I64(i64::MAX),
+        Operand::I128(i128::MIN),
+        Operand::Ref(u32::MAX),
+        Operand::Scalar(Scalar {
+            values: vec![10_000_000_000_000_000_000u128 as u64, 1],
+        }),
+        Operand::String("a \"quoted\"\n\\ string\u{1}".into()),
+        Operand::Record(Box::new(record())),
+        Operand::Struct(Struct {
+            values: vec![Operand::U8(1), Operand::Struct(Struct { values: vec![] })],
+        }),
+    ]
+}
+
+fn types() -> Vec<Type> {
+    vec![
+        Type::Address,
+        Type::Boolean,
+        Type::Field,
+        Type::Group,
+        Type::U8,
+        Type::U16,
+        Type::U32,
+        Type::U64,
+        Type::U128,
+        Type::I8,
+        Type::I16,
+        Type::I32,
+        Type::I64,
+        Type::I128,
+        Type::Scalar,
+        Type::String,
+        Type::Struct(StructType { fields: vec![] }),
+        Type::Struct(StructType {
+            fields: vec![
+                StructTypeEntry {
+                    name: "a".into(),
+                    type_: Type::U8,
+                },
+                StructTypeEntry {
+                    name: "not an identifier".into(),
+                    type_: Type::Struct(StructType { fields: vec![] }),
+                },
+            ],
+        }),
+        Type::Record(RecordType {
+            owner: Visibility::Public,
+            gates: Visibility::Private,
+            data: vec![RecordTypeEntry {
+                name: "nonce".into(),
+                type_: Type::Group,
+                visibility: Visibility::Constant,
+            }],
+            nonce: Visibility::Private,
+        }),
+    ]
+}
+
+fn instructions() -> Vec<Instruction> {
+    use Instruction::*;
+    let b = || binary(Operand::Ref(0), Operand::U8(1), 2);
+    let u = || unary(Operand::Ref(0), 1);
+    let mut instructions = vec![
+        Abs(u()),
+        AbsWrapped(u()),
+        Add(b()),
+        AddWrapped(b()),
+        And(b()),
+        AssertEq(AssertData {
+            lhs: Operand::Ref(0),
+            rhs: Operand::Boolean(true),
+        }),
+        AssertNeq(AssertData {
+            lhs: Operand::Ref(0),
+            rhs: Operand::Ref(1),
+        }),
+        Div(b()),
+        DivWrapped(b()),
+        Double(u()),
+        Gt(b()),
+        Gte(b()),
+        Inv(u()),
+        IsEq(b()),
+        IsNeq(b()),
+        Lt(b()),
+        Lte(b()),
+        Mod(b()),
+        Mul(b()),
+        MulWrapped(b()),
+        Nand(b()),
+        Neg(u()),
+        Nor(b()),
+        Not(u()),
+        Or(b()),
+        Pow(b()),
+        PowWrapped(b()),
+        Rem(b()),
+        RemWrapped(b()),
+        Shl(b()),
+        ShlWrapped(b()),
+        Shr(b()),
+        ShrWrapped(b()),
+        Sqrt(u()),
+        Square(u()),
+        Sub(b()),
+        SubWrapped(b()),
+        Ternary(TernaryData {
+            cond: Operand::Ref(0),
+            lhs: Operand::Ref(1),
+            rhs: Operand::Ref(2),
+            dest: 3,
+        }),
+        Xor(b()),
+        Call(CallData {
+            function: 1,
+            arguments: vec![],
+            dests: vec![],
+        }),
+        Call(CallData {
+            function: 2,
+            arguments: vec![Operand::Ref(0), Operand::U8(1)],
+            dests: vec![3, 4],
+        }),
+        Mask(MaskData {
+            cond: Operand::Ref(0),
+            length: 2,
+        }),
+        Repeat(RepeatData {
+            count: 3,
+            length: 0,
+        }),
+        Assert(ConditionData {
+            cond: Operand::Ref(0),
+        }),
+        Cast(CastData {
+            operand: Operand::Ref(0),
+            type_: Type::Struct(StructType { fields: vec![] }),
+            dest: 1,
+        }),
+        FromBits(CastData {
+            operand: Operand::Struct(Struct {
+                values: vec![Operand::Boolean(true)],
+            }),
+            type_: Type::Boolean,
+            dest: 1,
+        }),
+        Lshr(b()),
+        Member(AccessData {
+            operand: Operand::Ref(0),
+            index: 2,
+            dest: 1,
+        }),
+        RecordField(AccessData {
+            operand: Operand::Record(Box::new(record())),
+            index: 0,
+            dest: 1,
+        }),
+        ToBits(u()),
+        Return(ReturnData { values: vec![] }),
+        Return(ReturnData {
+            values: vec![Operand::Ref(0), Operand::Boolean(true)],
+        }),
+    ];
+    for method in [
+        HashMethod::HashBhp256,
+        HashMethod::HashBhp512,
+        HashMethod::HashBhp768,
+        HashMethod::HashBhp1024,
+        HashMethod::HashPed64,
+        HashMethod::HashPed128,
+        HashMethod::HashPsd2,
+        HashMethod::HashPsd4,
+        HashMethod::HashPsd8,
+    ] {
+        instructions.push(Hash(HashData {
+            method,
+            chunk: Operand::Ref(0),
+            dest: 1,
+        }));
+    }
+    for method in [
+        CommitMethod::CommitBhp256,
+        CommitMethod::CommitBhp512,
+        CommitMethod::CommitBhp768,
+        CommitMethod::CommitBhp1024,
+        CommitMethod::CommitPed64,
+        CommitMethod::CommitPed128,
+    ] {
+        instructions.push(Commit(CommitData {
+            method,
+            chunk: Operand::Ref(0),
+            randomness: Scalar { values: vec![9] },
+            dest: 1,
+        }));
+    }
+    instructions
+}
+
+#[test]
+fn round_trip() {
+    let program = Program {
+        header: Header {
+            snarkd_major: 1,
+            snarkd_minor: 2,
+            snarkd_patch: 3,
+            constant_inputs: vec![input(0, "a", Type::U8), input(1, "b", Type::Field)],
+            private_inputs: vec![input(2, "with space", Type::Boolean)],
+            public_inputs: vec![input(3, "", Type::Group)],
+            register_inputs: vec![input(4, "r4", Type::Address)],
+            public_states: vec![input(5, "decl", Type::String)],
+            private_record_states: types()
+                .into_iter()
+                .enumerate()
+                .map(|(i, type_)| input(6 + i as u32, &format!("t{i}"), type_))
+                .collect(),
+            private_leaf_states: vec![],
+            types: types()
+                .into_iter()
+                .skip(16)
+                .enumerate()
+                .map(|(i, type_)| TypeDeclaration {
+                    name: format!("t{i}"),
+                    type_,
+                })
+                .collect(),
+            outputs: vec![
+                OutputType {
+                    variable: 1,
+                    name: "c".into(),
+                    type_: Type::Field,
+                    visibility: Visibility::Public,
+                },
+                OutputType {
+                    variable: 6,
+                    name: "with space".into(),
+                    type_: types()[16].clone(),
+                    visibility: Visibility::Private,
+                },
+            ],
+            mappings: vec!["counts".into(), "with space".into()],
+        },
+        functions: vec![
+            Function {
+                argument_start_variable: 0,
+                instructions: instructions(),
+            },
+            Function {
+                argument_start_variable: 7,
+                instructions: vec![],
+            },
+            Function {
+                argument_start_variable: 1,
+                instructions: operands()
+                    .into_iter()
+                    .map(|x| Instruction::Not(unary(x, 0)))
+                    .collect(),
+            },
+        ],
+    };
+    let text = program.to_string();
+    assert_eq!(text.parse::<Program>().unwrap(), program);
+
+    for operand in operands() {
+        assert_eq!(operand.to_string().parse::<Operand>().unwrap(), operand);
+    }
+    for type_ in types() {
+        assert_eq!(type_.to_string().parse::<Type>().unwrap(), type_);
+    }
+    for instruction in instructions() {
+        assert_eq!(
+            instruction.to_string().parse::<Instruction>().unwrap(),
+            instruction
+        );
+    }
+    assert_eq!(
+        Program::default().to_string().parse::<Program>().unwrap(),
+        Program::default()
+    );
+}
+
+#[test]
+fn hand_written() {
+    let program: Program = "
+        // Doubles `a` unless `b` is set.
+        input private r0 a: u8
+        input public  r1 b: boolean
+
+        decl f0 r2
+        add.w r0,r0->r2
+        ternary r1, r0, r2 -> r3
+        2: assert.neq r3, 0u8
+    "
+    .parse()
+    .unwrap();
+    assert_eq!(program.header.snarkd_major, 0);
+    assert_eq!(program.functions[0].instructions.len(), 3);
+
+    let input = InputData {
+        constant_inputs: vec![],
+        private_inputs: vec![InputValue {
+            name: "a".into(),
+            value: "200u8".parse().unwrap(),
+        }],
+        public_inputs: vec![InputValue {
+            name: "b".into(),
+            value: "false".parse().unwrap(),
+        }],
+        register_inputs: vec![],
+        public_states: vec![],
+        private_record_states: vec![],
+        private_leaf_states: vec![],
+    };
+    assert_eq!(
+        program.evaluate(&input).unwrap().get(3),
+        Some(&Operand::U8(144))
+    );
+}
+
+#[test]
+fn diagnostics() {
+    for (text, location, message) in [
+        ("decl r0\n  add r0, r1 r2", "2:14", "Expected `->`"),
+        (
+            "decl r0\n0: not r0 -> r1\n  0: not r0 -> r1",
+            "3:3",
+            "Instruction is labelled `0`",
+        ),
+        (
+            "decl r0\nadd.x r0, r1 -> r2",
+            "2:1",
+            "Unknown instruction `add.x`",
+        ),
+        (
+            "input private r0 a: u8\ninput r1",
+            "2:7",
+            "Expected an input category",
+        ),
+        (
+            "decl r0 not 256u8 -> r1",
+            "1:13",
+            "Invalid literal `256u8`: out of range for `u8`",
+        ),
+        (
+            "decl r0 not -1u8 -> r1",
+            "1:14",
+            "Invalid literal `1u8`: out of range for `u8`",
+        ),
+        (
+            "decl r0 not -1scalar -> r1",
+            "1:14",
+            "Invalid literal `1scalar`: scalars cannot be negative",
+        ),
+        (
+            "decl r0 not 1 -> r1",
+            "1:13",
+            "Invalid literal `1`: missing a type suffix",
+        ),
+        (
+            "decl r0 not aleo1xyz -> r1",
+            "1:13",
+            "Invalid literal `aleo1xyz`",
+        ),
+        (
+            "decl r0 not \"abc -> r1",
+            "1:13",
+            "Invalid literal `string`: missing closing quote",
+        ),
+        ("decl r0 not (1field, +) -> r1", "1:25", "Expected `group`"),
+        ("decl r0 not r0 -> r1 $", "1:22", "Expected a token"),
+        ("decl f1 r0", "1:6", "Expected `f0`"),
+        ("decl r0 call 1", "1:14", "Expected a function"),
+        ("decl r0 cast r0 -> r1", "1:17", "Expected `as`"),
+        (
+            "decl r0 member r0, r1 -> r2",
+            "1:20",
+            "Expected an entry index",
+        ),
+        (
+            "mapping counts\nmapping counts",
+            "2:9",
+            "Expected a new mapping name",
+        ),
+        (
+            "input private r0 a: record { owner: address.public }",
+            "1:52",
+            "Expected `gates: u64`",
+        ),
+    ] {
+        let error = format!("{:?}", text.parse::<Program>().unwrap_err());
+        assert!(
+            error.contains(&format!("{location}: {message}")),
+            "{text:?}: {error}"
+        );
+    }
+}
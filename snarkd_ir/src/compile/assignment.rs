@@ -0,0 +1,270 @@
+use std::collections::BTreeMap;
+
+use snarkd_crypto::{
+    bls12_377::{Field as _, Scalar},
+    circuit::helpers::Mode,
+    r1cs::{self, ConstraintSynthesizer, ConstraintSystem, Index, Variable},
+};
+
+/// A linear combination of circuit variables, along with its value in the assignment.
+#[derive(Clone, Debug)]
+pub(super) struct LinearCombination {
+    constant: Scalar,
+    terms: BTreeMap<Variable, Scalar>,
+    value: Scalar,
+}
+
+impl LinearCombination {
+    pub fn constant(value: Scalar) -> Self {
+        Self {
+            constant: value,
+            terms: BTreeMap::new(),
+            value,
+        }
+    }
+
+    pub fn zero() -> Self {
+        Self::constant(Scalar::ZERO)
+    }
+
+    pub fn one() -> Self {
+        Self::constant(Scalar::ONE)
+    }
+
+    fn variable(variable: Variable, value: Scalar) -> Self {
+        Self {
+            constant: Scalar::ZERO,
+            terms: [(variable, Scalar::ONE)].into(),
+            value,
+        }
+    }
+
+    /// Returns `true` if the linear combination does not depend on any variable.
+    pub fn is_constant(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    pub fn value(&self) -> Scalar {
+        self.value
+    }
+
+    pub fn scale(&self, factor: Scalar) -> Self {
+        if factor.is_zero() {
+            return Self::zero();
+        }
+        Self {
+            constant: self.constant * factor,
+            terms: self
+                .terms
+                .iter()
+                .map(|(variable, coefficient)| (*variable, *coefficient * factor))
+                .collect(),
+            value: self.value * factor,
+        }
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        let mut terms = self.terms.clone();
+        for (variable, coefficient) in &other.terms {
+            let sum = *terms.entry(*variable).or_insert(Scalar::ZERO) + *coefficient;
+            match sum.is_zero() {
+                true => terms.remove(variable),
+                false => terms.insert(*variable, sum),
+            };
+        }
+        Self {
+            constant: self.constant + other.constant,
+            terms,
+            value: self.value + other.value,
+        }
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        self.add(&other.neg())
+    }
+
+    pub fn neg(&self) -> Self {
+        self.scale(-Scalar::ONE)
+    }
+
+    /// Converts to a linear combination of `cs`, whose variables were allocated in the same order.
+    fn to_r1cs<CS: ConstraintSystem<Field = Scalar>>(&self) -> r1cs::LinearCombination<Scalar> {
+        let mut lc = r1cs::LinearCombination::zero();
+        if !self.constant.is_zero() {
+            lc += (self.constant, CS::one());
+        }
+        for (variable, coefficient) in &self.terms {
+            lc += (*coefficient, *variable);
+        }
+        lc
+    }
+}
+
+/// Constraints over the scalar field of BLS12-377, which Marlin proves over, along with their
+/// assignment. Public variables are numbered from 1, after the constant one of the constraint
+/// system.
+#[derive(Clone, Default)]
+pub struct Assignment {
+    public: Vec<Scalar>,
+    private: Vec<Scalar>,
+    constraints: Vec<(LinearCombination, LinearCombination, LinearCombination)>,
+}
+
+impl Assignment {
+    /// Returns a new variable of the given mode and value, constants being left out of the
+    /// assignment.
+    pub(super) fn new_variable(&mut self, mode: Mode, value: Scalar) -> LinearCombination {
+        match mode {
+            Mode::Constant => LinearCombination::constant(value),
+            Mode::Public => {
+                self.public.push(value);
+                let index = Index::Public(self.public.len());
+                LinearCombination::variable(Variable::new_unchecked(index), value)
+            }
+            Mode::Private => {
+                self.private.push(value);
+                let index = Index::Private(self.private.len() - 1);
+                LinearCombination::variable(Variable::new_unchecked(index), value)
+            }
+        }
+    }
+
+    /// Returns a new variable holding `value`, constrained to be `0` or `1`.
+    pub(super) fn new_boolean(&mut self, mode: Mode, value: bool) -> LinearCombination {
+        let value = self.new_variable(mode, Scalar::from(value as u64));
+        if !value.is_constant() {
+            self.enforce(
+                value.clone(),
+                LinearCombination::one().sub(&value),
+                LinearCombination::zero(),
+            );
+        }
+        value
+    }
+
+    /// Adds one constraint enforcing that `a * b == c`.
+    pub(super) fn enforce(
+        &mut self,
+        a: LinearCombination,
+        b: LinearCombination,
+        c: LinearCombination,
+    ) {
+        self.constraints.push((a, b, c));
+    }
+
+    /// Returns `a * b`, constrained unless one of them is constant.
+    pub(super) fn mul(
+        &mut self,
+        a: &LinearCombination,
+        b: &LinearCombination,
+    ) -> LinearCombination {
+        if a.is_constant() {
+            return b.scale(a.value);
+        }
+        if b.is_constant() {
+            return a.scale(b.value);
+        }
+        let product = self.new_variable(Mode::Private, a.value * b.value);
+        self.enforce(a.clone(), b.clone(), product.clone());
+        product
+    }
+
+    /// Returns a boolean holding whether `a` is zero.
+    pub(super) fn is_zero(&mut self, a: &LinearCombination) -> LinearCombination {
+        if a.is_constant() {
+            return LinearCombination::constant(Scalar::from(a.value.is_zero() as u64));
+        }
+        // `a * inverse == 1 - is_zero` and `a * is_zero == 0` leave `is_zero` no other value.
+        let is_zero = self.new_variable(Mode::Private, Scalar::from(a.value.is_zero() as u64));
+        let inverse = self.new_variable(Mode::Private, a.value.inverse().unwrap_or(Scalar::ZERO));
+        self.enforce(a.clone(), inverse, LinearCombination::one().sub(&is_zero));
+        self.enforce(a.clone(), is_zero.clone(), LinearCombination::zero());
+        is_zero
+    }
+
+    /// Enforces that `a` is not zero, by witnessing its inverse.
+    pub(super) fn assert_nonzero(&mut self, a: &LinearCombination) {
+        if a.is_constant() {
+            if a.value.is_zero() {
+                self.enforce(
+                    LinearCombination::zero(),
+                    LinearCombination::zero(),
+                    LinearCombination::one(),
+                );
+            }
+            return;
+        }
+        let inverse = self.new_variable(Mode::Private, a.value.inverse().unwrap_or(Scalar::ZERO));
+        self.enforce(a.clone(), inverse, LinearCombination::one());
+    }
+
+    fn evaluate(&self, lc: &LinearCombination) -> Scalar {
+        lc.terms
+            .iter()
+            .map(|(variable, coefficient)| {
+                let value = match variable.get_unchecked() {
+                    Index::Public(index) => self.public[index - 1],
+                    Index::Private(index) => self.private[index],
+                };
+                value * *coefficient
+            })
+            .fold(lc.constant, |sum, x| sum + x)
+    }
+
+    /// Returns `true` if the assignment satisfies every constraint.
+    pub fn is_satisfied(&self) -> bool {
+        self.constraints
+            .iter()
+            .all(|(a, b, c)| self.evaluate(a) * self.evaluate(b) == self.evaluate(c))
+    }
+
+    /// Returns the public inputs of the assignment, as given to the Marlin verifier.
+    pub fn public_inputs(&self) -> Vec<Scalar> {
+        self.public.clone()
+    }
+
+    /// Returns the number of public variables in the assignment.
+    pub fn num_public(&self) -> u64 {
+        self.public.len() as u64
+    }
+
+    /// Returns the number of private variables in the assignment.
+    pub fn num_private(&self) -> u64 {
+        self.private.len() as u64
+    }
+
+    /// Returns the number of constraints in the assignment.
+    pub fn num_constraints(&self) -> u64 {
+        self.constraints.len() as u64
+    }
+}
+
+impl ConstraintSynthesizer<Scalar> for Assignment {
+    fn generate_constraints<CS: ConstraintSystem<Field = Scalar>>(
+        &self,
+        cs: &mut CS,
+    ) -> anyhow::Result<()> {
+        // Variables are referred to by index, so they have to be allocated in a clean system.
+        assert_eq!(1, cs.num_public_variables());
+        assert_eq!(0, cs.num_private_variables());
+        assert_eq!(0, cs.num_constraints());
+
+        for (i, value) in self.public.iter().enumerate() {
+            let variable = cs.alloc_input(|| format!("Public {i}"), || Ok(*value))?;
+            assert_eq!(variable.get_unchecked(), Index::Public(i + 1));
+        }
+        for (i, value) in self.private.iter().enumerate() {
+            let variable = cs.alloc(|| format!("Private {i}"), || Ok(*value))?;
+            assert_eq!(variable.get_unchecked(), Index::Private(i));
+        }
+        for (i, (a, b, c)) in self.constraints.iter().enumerate() {
+            cs.enforce(
+                || format!("Constraint {i}"),
+                |lc| lc + a.to_r1cs::<CS>(),
+                |lc| lc + b.to_r1cs::<CS>(),
+                |lc| lc + c.to_r1cs::<CS>(),
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,621 @@
+#[cfg(test)]
+mod tests;
+
+mod assignment;
+pub use assignment::Assignment;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use snarkd_crypto::{
+    bls12_377::{Field as _, Scalar},
+    circuit::helpers::Mode,
+};
+use snarkd_errors::{Error, IRError, Result};
+
+use crate::{
+    eval::value::{from_scalar_field, to_scalar_field},
+    AccessData, AssertData, BinaryData, CallData, ConditionData, Function, InputData, Instruction,
+    MaskData, Operand, Program, Registers, RepeatData, ReturnData, Struct, TernaryData, UnaryData,
+};
+use assignment::LinearCombination;
+
+/// A register holding a value in the circuit. Booleans are variables constrained to `0` or `1`.
+#[derive(Clone)]
+enum Value {
+    Boolean(LinearCombination),
+    Field(LinearCombination),
+    Struct(Vec<Value>),
+}
+
+impl Value {
+    /// Reads `operand` as a constant, which takes no variables in the circuit.
+    fn constant(operand: &Operand) -> Result<Self> {
+        match operand {
+            Operand::Boolean(value) => Ok(Self::Boolean(LinearCombination::constant(
+                Scalar::from(*value as u64),
+            ))),
+            Operand::Field(value) => Ok(Self::Field(LinearCombination::constant(to_scalar_field(
+                value,
+            )?))),
+            Operand::Struct(value) => Ok(Self::Struct(
+                value
+                    .values
+                    .iter()
+                    .map(Self::constant)
+                    .collect::<Result<_>>()?,
+            )),
+            _ => Err(IRError::unsupported_circuit_value(operand).into()),
+        }
+    }
+
+    /// Injects `operand` into the circuit as a value of the given mode.
+    fn new(circuit: &mut Assignment, mode: Mode, operand: &Operand) -> Result<Self> {
+        match operand {
+            Operand::Boolean(value) => Ok(Self::Boolean(circuit.new_boolean(mode, *value))),
+            Operand::Field(value) => Ok(Self::Field(
+                circuit.new_variable(mode, to_scalar_field(value)?),
+            )),
+            Operand::Struct(value) => Ok(Self::Struct(
+                value
+                    .values
+                    .iter()
+                    .map(|x| Self::new(circuit, mode, x))
+                    .collect::<Result<_>>()?,
+            )),
+            _ => Err(IRError::unsupported_circuit_value(operand).into()),
+        }
+    }
+
+    /// Reads the assigned value back out of the circuit.
+    fn eject(&self) -> Operand {
+        match self {
+            Self::Boolean(value) => Operand::Boolean(!value.value().is_zero()),
+            Self::Field(value) => Operand::Field(from_scalar_field(value.value())),
+            Self::Struct(values) => Operand::Struct(Struct {
+                values: values.iter().map(Self::eject).collect(),
+            }),
+        }
+    }
+
+    /// Selects `a` if `cond` holds and `b` otherwise, for values of the same kind. Structs are
+    /// selected entry by entry.
+    fn ternary(
+        circuit: &mut Assignment,
+        cond: &LinearCombination,
+        a: &Self,
+        b: &Self,
+    ) -> Option<Self> {
+        match (a, b) {
+            (Self::Boolean(a), Self::Boolean(b)) => {
+                Some(Self::Boolean(ternary(circuit, cond, a, b)))
+            }
+            (Self::Field(a), Self::Field(b)) => Some(Self::Field(ternary(circuit, cond, a, b))),
+            (Self::Struct(a), Self::Struct(b)) if a.len() == b.len() => Some(Self::Struct(
+                a.iter()
+                    .zip(b)
+                    .map(|(a, b)| Self::ternary(circuit, cond, a, b))
+                    .collect::<Option<_>>()?,
+            )),
+            _ => None,
+        }
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Boolean(_) => "boolean",
+            Self::Field(_) => "field",
+            Self::Struct(_) => "struct",
+        }
+    }
+}
+
+fn not(a: &LinearCombination) -> LinearCombination {
+    LinearCombination::one().sub(a)
+}
+
+fn and(
+    circuit: &mut Assignment,
+    a: &LinearCombination,
+    b: &LinearCombination,
+) -> LinearCombination {
+    circuit.mul(a, b)
+}
+
+fn or(circuit: &mut Assignment, a: &LinearCombination, b: &LinearCombination) -> LinearCombination {
+    a.add(b).sub(&circuit.mul(a, b))
+}
+
+fn xor(
+    circuit: &mut Assignment,
+    a: &LinearCombination,
+    b: &LinearCombination,
+) -> LinearCombination {
+    a.add(b).sub(&circuit.mul(a, b).scale(Scalar::from(2u64)))
+}
+
+fn nand(
+    circuit: &mut Assignment,
+    a: &LinearCombination,
+    b: &LinearCombination,
+) -> LinearCombination {
+    not(&and(circuit, a, b))
+}
+
+fn nor(
+    circuit: &mut Assignment,
+    a: &LinearCombination,
+    b: &LinearCombination,
+) -> LinearCombination {
+    not(&or(circuit, a, b))
+}
+
+/// Returns `a` if the boolean `cond` holds and `b` otherwise, as `b + cond * (a - b)`.
+fn ternary(
+    circuit: &mut Assignment,
+    cond: &LinearCombination,
+    a: &LinearCombination,
+    b: &LinearCombination,
+) -> LinearCombination {
+    b.add(&circuit.mul(cond, &a.sub(b)))
+}
+
+fn invalid_operand_types(instruction: &str, operands: &[&Value]) -> Error {
+    IRError::invalid_operand_types(
+        instruction,
+        operands
+            .iter()
+            .map(|x| x.kind())
+            .collect::<Vec<_>>()
+            .join(", "),
+    )
+    .into()
+}
+
+/// Register file of a function being lowered into `circuit`.
+struct Compiler<'a> {
+    circuit: &'a mut Assignment,
+    registers: BTreeMap<u32, Value>,
+    /// Whether the instructions being lowered take effect, inside masked blocks.
+    guard: Option<LinearCombination>,
+    /// Registers written since the innermost masked block began.
+    written: BTreeSet<u32>,
+}
+
+impl<'a> Compiler<'a> {
+    fn new(circuit: &'a mut Assignment, guard: Option<LinearCombination>) -> Self {
+        Self {
+            circuit,
+            registers: BTreeMap::new(),
+            guard,
+            written: BTreeSet::new(),
+        }
+    }
+
+    /// Reads `operand`, looking up references in the registers, also within struct literals, and
+    /// reading literals as constants.
+    fn read(&self, operand: &Operand) -> Result<Value> {
+        match operand {
+            Operand::Ref(register) => self
+                .registers
+                .get(register)
+                .cloned()
+                .ok_or_else(|| IRError::unknown_register(*register).into()),
+            Operand::Struct(value) => Ok(Value::Struct(
+                value
+                    .values
+                    .iter()
+                    .map(|x| self.read(x))
+                    .collect::<Result<_>>()?,
+            )),
+            _ => Value::constant(operand),
+        }
+    }
+
+    fn unary(
+        &mut self,
+        instruction: &str,
+        data: &UnaryData,
+        op: impl FnOnce(&mut Assignment, &Value) -> Option<Value>,
+    ) -> Result<(u32, Value)> {
+        let operand = self.read(&data.operand)?;
+        let value = op(self.circuit, &operand)
+            .ok_or_else(|| invalid_operand_types(instruction, &[&operand]))?;
+        Ok((data.dest, value))
+    }
+
+    fn binary(
+        &mut self,
+        instruction: &str,
+        data: &BinaryData,
+        op: impl FnOnce(&mut Assignment, &Value, &Value) -> Option<Value>,
+    ) -> Result<(u32, Value)> {
+        let lhs = self.read(&data.lhs)?;
+        let rhs = self.read(&data.rhs)?;
+        let value = op(self.circuit, &lhs, &rhs)
+            .ok_or_else(|| invalid_operand_types(instruction, &[&lhs, &rhs]))?;
+        Ok((data.dest, value))
+    }
+
+    fn boolean(
+        &mut self,
+        instruction: &str,
+        data: &BinaryData,
+        op: fn(&mut Assignment, &LinearCombination, &LinearCombination) -> LinearCombination,
+    ) -> Result<(u32, Value)> {
+        self.binary(instruction, data, |circuit, lhs, rhs| match (lhs, rhs) {
+            (Value::Boolean(a), Value::Boolean(b)) => Some(Value::Boolean(op(circuit, a, b))),
+            _ => None,
+        })
+    }
+
+    fn field(
+        &mut self,
+        instruction: &str,
+        data: &BinaryData,
+        op: fn(&mut Assignment, &LinearCombination, &LinearCombination) -> LinearCombination,
+    ) -> Result<(u32, Value)> {
+        self.binary(instruction, data, |circuit, lhs, rhs| match (lhs, rhs) {
+            (Value::Field(a), Value::Field(b)) => Some(Value::Field(op(circuit, a, b))),
+            _ => None,
+        })
+    }
+
+    fn equals(
+        &mut self,
+        instruction: &str,
+        data: &BinaryData,
+        expected: bool,
+    ) -> Result<(u32, Value)> {
+        self.binary(instruction, data, |circuit, lhs, rhs| {
+            let is_equal = match (lhs, rhs) {
+                (Value::Boolean(a), Value::Boolean(b)) => not(&xor(circuit, a, b)),
+                (Value::Field(a), Value::Field(b)) => circuit.is_zero(&a.sub(b)),
+                _ => return None,
+            };
+            Some(Value::Boolean(match expected {
+                true => is_equal,
+                false => not(&is_equal),
+            }))
+        })
+    }
+
+    /// Lowers `instructions` in order, inlining calls into `functions` and unrolling repeats.
+    /// Returns the values of the `return` reached, if any. Registers first written in a block
+    /// are not visible after it.
+    fn run(
+        &mut self,
+        functions: &[Function],
+        instructions: &[Instruction],
+    ) -> Result<Option<Vec<Value>>> {
+        let mut i = 0;
+        while let Some(instruction) = instructions.get(i) {
+            i += 1;
+            let length = match instruction {
+                Instruction::Call(CallData {
+                    function,
+                    arguments,
+                    dests,
+                }) => {
+                    let callee = functions
+                        .get(*function as usize)
+                        .ok_or_else(|| IRError::missing_function(function))?;
+                    let arguments = arguments
+                        .iter()
+                        .map(|x| self.read(x))
+                        .collect::<Result<Vec<_>>>()?;
+                    let mut compiler = Compiler::new(self.circuit, self.guard.clone());
+                    compiler
+                        .registers
+                        .extend((callee.argument_start_variable..).zip(arguments));
+                    let values = compiler
+                        .run(functions, &callee.instructions)?
+                        .unwrap_or_default();
+                    self.registers.extend(dests.iter().copied().zip(values));
+                    self.written.extend(dests);
+                    continue;
+                }
+                Instruction::Return(ReturnData { values }) => {
+                    return values
+                        .iter()
+                        .map(|x| self.read(x))
+                        .collect::<Result<_>>()
+                        .map(Some);
+                }
+                Instruction::Mask(MaskData { length, .. })
+                | Instruction::Repeat(RepeatData { length, .. }) => *length as usize,
+                _ => {
+                    self.execute(instruction)?;
+                    continue;
+                }
+            };
+            let mnemonic = instruction.mnemonic();
+            let block = instructions
+                .get(i..i + length)
+                .ok_or_else(|| IRError::truncated_block(mnemonic, length))?;
+            i += length;
+            let outer = self.registers.keys().copied().collect::<BTreeSet<_>>();
+            let returned = match instruction {
+                Instruction::Repeat(RepeatData { count, .. }) => {
+                    for _ in 0..*count {
+                        if let Some(values) = self.run(functions, block)? {
+                            return Ok(Some(values));
+                        }
+                    }
+                    None
+                }
+                Instruction::Mask(MaskData { cond, .. }) => {
+                    let cond = match self.read(cond)? {
+                        Value::Boolean(cond) => cond,
+                        cond => return Err(invalid_operand_types(mnemonic, &[&cond])),
+                    };
+                    self.mask(functions, block, cond)?
+                }
+                _ => unreachable!(),
+            };
+            if returned.is_some() {
+                return Ok(returned);
+            }
+            self.registers
+                .retain(|register, _| outer.contains(register));
+        }
+        Ok(None)
+    }
+
+    /// Lowers a block that only takes effect when `cond` holds. A constant condition picks
+    /// whether the block is lowered at all. Otherwise the block is lowered under the condition,
+    /// and each register it writes selects between its new and old value.
+    fn mask(
+        &mut self,
+        functions: &[Function],
+        block: &[Instruction],
+        cond: LinearCombination,
+    ) -> Result<Option<Vec<Value>>> {
+        if cond.is_constant() {
+            return match cond.value().is_zero() {
+                false => self.run(functions, block),
+                true => Ok(None),
+            };
+        }
+        let before = self.registers.clone();
+        let guard = match &self.guard {
+            Some(guard) => and(self.circuit, guard, &cond),
+            None => cond.clone(),
+        };
+        let outer_guard = self.guard.replace(guard);
+        let outer_written = std::mem::take(&mut self.written);
+        let returned = self.run(functions, block);
+        self.guard = outer_guard;
+        let written = std::mem::replace(&mut self.written, outer_written);
+        if returned?.is_some() {
+            return Err(IRError::unsupported_circuit_instruction("return").into());
+        }
+        let after = std::mem::replace(&mut self.registers, before);
+        for register in written {
+            if let Some(old) = self.registers.get_mut(&register) {
+                let new = &after[&register];
+                *old = Value::ternary(self.circuit, &cond, new, old)
+                    .ok_or_else(|| invalid_operand_types("mask", &[new, old]))?;
+                self.written.insert(register);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns whether the instructions being lowered take effect for this assignment.
+    fn is_active(&self) -> bool {
+        match &self.guard {
+            Some(guard) => !guard.value().is_zero(),
+            None => true,
+        }
+    }
+
+    fn execute(&mut self, instruction: &Instruction) -> Result<()> {
+        let mnemonic = instruction.mnemonic();
+
+        use Instruction::*;
+        let (dest, value) = match instruction {
+            Not(data) => self.unary(mnemonic, data, |_, x| match x {
+                Value::Boolean(a) => Some(Value::Boolean(not(a))),
+                _ => None,
+            })?,
+            Neg(data) => self.unary(mnemonic, data, |_, x| match x {
+                Value::Field(a) => Some(Value::Field(a.neg())),
+                _ => None,
+            })?,
+            Declassify(data) => self.unary(mnemonic, data, |_, x| Some(x.clone()))?,
+            Double(data) => self.unary(mnemonic, data, |_, x| match x {
+                Value::Field(a) => Some(Value::Field(a.add(a))),
+                _ => None,
+            })?,
+            Square(data) => self.unary(mnemonic, data, |circuit, x| match x {
+                Value::Field(a) => Some(Value::Field(circuit.mul(a, a))),
+                _ => None,
+            })?,
+            Add(data) => self.field(mnemonic, data, |_, a, b| a.add(b))?,
+            Sub(data) => self.field(mnemonic, data, |_, a, b| a.sub(b))?,
+            Mul(data) => self.field(mnemonic, data, Assignment::mul)?,
+            And(data) => self.boolean(mnemonic, data, and)?,
+            Or(data) => self.boolean(mnemonic, data, or)?,
+            Xor(data) => self.boolean(mnemonic, data, xor)?,
+            Nand(data) => self.boolean(mnemonic, data, nand)?,
+            Nor(data) => self.boolean(mnemonic, data, nor)?,
+            IsEq(data) => self.equals(mnemonic, data, true)?,
+            IsNeq(data) => self.equals(mnemonic, data, false)?,
+            AssertEq(AssertData { lhs, rhs }) | AssertNeq(AssertData { lhs, rhs }) => {
+                let lhs = self.read(lhs)?;
+                let rhs = self.read(rhs)?;
+                let expected = matches!(instruction, AssertEq(_));
+                let (a, b) = match (&lhs, &rhs) {
+                    (Value::Boolean(a), Value::Boolean(b)) | (Value::Field(a), Value::Field(b)) => {
+                        (a, b)
+                    }
+                    _ => return Err(invalid_operand_types(mnemonic, &[&lhs, &rhs])),
+                };
+                // The assignment is known, so a failing assertion is reported here rather than
+                // as an unsatisfiable constraint.
+                if self.is_active() && (lhs.eject() == rhs.eject()) != expected {
+                    return Err(
+                        IRError::assertion_failed(mnemonic, lhs.eject(), rhs.eject()).into(),
+                    );
+                }
+                // Inside a masked block, the difference only has to be zero, or nonzero, when the
+                // block takes effect, and is replaced by a passing one otherwise.
+                let difference = a.sub(b);
+                let checked = match &self.guard {
+                    Some(guard) => {
+                        let pass = LinearCombination::constant(match expected {
+                            true => Scalar::ZERO,
+                            false => Scalar::ONE,
+                        });
+                        ternary(self.circuit, guard, &difference, &pass)
+                    }
+                    None => difference,
+                };
+                match expected {
+                    true => self.circuit.enforce(
+                        checked,
+                        LinearCombination::one(),
+                        LinearCombination::zero(),
+                    ),
+                    false => self.circuit.assert_nonzero(&checked),
+                }
+                return Ok(());
+            }
+            Ternary(TernaryData {
+                cond,
+                lhs,
+                rhs,
+                dest,
+            }) => {
+                let cond = self.read(cond)?;
+                let lhs = self.read(lhs)?;
+                let rhs = self.read(rhs)?;
+                let value = match &cond {
+                    Value::Boolean(c) => Value::ternary(self.circuit, c, &lhs, &rhs),
+                    _ => None,
+                }
+                .ok_or_else(|| invalid_operand_types(mnemonic, &[&cond, &lhs, &rhs]))?;
+                (*dest, value)
+            }
+            Member(AccessData {
+                operand,
+                index,
+                dest,
+            }) => {
+                let value = match self.read(operand)? {
+                    Value::Struct(values) => values
+                        .get(*index as usize)
+                        .cloned()
+                        .ok_or_else(|| IRError::missing_entry(mnemonic, index))?,
+                    value => return Err(invalid_operand_types(mnemonic, &[&value])),
+                };
+                (*dest, value)
+            }
+            Assert(ConditionData { cond: operand }) => {
+                let value = match self.read(operand)? {
+                    Value::Boolean(value) => value,
+                    value => return Err(invalid_operand_types(mnemonic, &[&value])),
+                };
+                if self.is_active() && value.value().is_zero() {
+                    return Err(IRError::condition_failed(mnemonic, operand).into());
+                }
+                // Inside a masked block, the condition only has to hold when the block takes effect.
+                let checked = match &self.guard {
+                    Some(guard) => nand(self.circuit, guard, &not(&value)),
+                    None => value,
+                };
+                self.circuit
+                    .enforce(checked, LinearCombination::one(), LinearCombination::one());
+                return Ok(());
+            }
+            _ => return Err(IRError::unsupported_circuit_instruction(mnemonic).into()),
+        };
+        self.registers.insert(dest, value);
+        self.written.insert(dest);
+        Ok(())
+    }
+}
+
+/// The constraints of a program over one input, along with the final values of its registers.
+pub struct Compiled {
+    /// The constraints and their assignment over the scalar field, ready to be proven by Marlin.
+    pub assignment: Assignment,
+    pub registers: Registers,
+}
+
+impl Program {
+    /// Lowers the first function of the program into constraints over `input`, inlining the
+    /// functions it calls and unrolling repeated blocks.
+    /// Constant, public and private inputs become constants, public variables and private
+    /// variables of the circuit; public states are public and the remaining inputs private.
+    ///
+    /// Only `boolean` and `field` values, and structs of them, can be compiled. The circuit is
+    /// built over the BLS12-377 scalar field that Marlin proves over, so `field` arithmetic is
+    /// computed modulo the scalar field rather than the base field used by [`Program::evaluate`],
+    /// and `field` values must be below the scalar modulus.
+    pub fn compile(&self, input: &InputData) -> Result<Compiled> {
+        self.validate()?;
+        let function = self
+            .functions
+            .first()
+            .ok_or_else(|| IRError::missing_function(0))?;
+        let mut assignment = Assignment::default();
+        let registers = self.synthesize(&mut assignment, function, input)?;
+        if !assignment.is_satisfied() {
+            return Err(IRError::unsatisfied_circuit().into());
+        }
+        Ok(Compiled {
+            assignment,
+            registers,
+        })
+    }
+
+    fn synthesize(
+        &self,
+        circuit: &mut Assignment,
+        function: &Function,
+        input: &InputData,
+    ) -> Result<Registers> {
+        let header = &self.header;
+        let mut compiler = Compiler::new(circuit, None);
+        for (types, values, mode) in [
+            (
+                &header.constant_inputs,
+                &input.constant_inputs,
+                Mode::Constant,
+            ),
+            (&header.private_inputs, &input.private_inputs, Mode::Private),
+            (&header.public_inputs, &input.public_inputs, Mode::Public),
+            (
+                &header.register_inputs,
+                &input.register_inputs,
+                Mode::Private,
+            ),
+            (&header.public_states, &input.public_states, Mode::Public),
+            (
+                &header.private_record_states,
+                &input.private_record_states,
+                Mode::Private,
+            ),
+            (
+                &header.private_leaf_states,
+                &input.private_leaf_states,
+                Mode::Private,
+            ),
+        ] {
+            // Inputs are validated the same way as for native evaluation before being injected.
+            let mut registers = Registers::default();
+            registers.load_inputs(types, values)?;
+            for (register, value) in registers.iter() {
+                let value = Value::new(compiler.circuit, mode, value)?;
+                compiler.registers.insert(register, value);
+            }
+        }
+        compiler.run(&self.functions, &function.instructions)?;
+        Ok(compiler
+            .registers
+            .iter()
+            .map(|(register, value)| (*register, value.eject()))
+            .collect())
+    }
+}
@@ -0,0 +1,340 @@
+use ruint::Uint;
+use snarkd_crypto::bls12_377::{fp, scalar, Scalar};
+
+use crate::*;
+
+fn field(value: u64) -> Operand {
+    Operand::Field(Field {
+        negate: false,
+        values: vec![value],
+    })
+}
+
+fn field_limbs<const BITS: usize, const LIMBS: usize>(value: Uint<BITS, LIMBS>) -> Operand {
+    Operand::Field(Field {
+        negate: false,
+        values: value.as_limbs().to_vec(),
+    })
+}
+
+fn binary(lhs: Operand, rhs: Operand, dest: u32) -> BinaryData {
+    BinaryData { lhs, rhs, dest }
+}
+
+fn unary(operand: Operand, dest: u32) -> UnaryData {
+    UnaryData { operand, dest }
+}
+
+fn input_type(variable: u32, name: &str, type_: Type) -> InputType {
+    InputType {
+        variable,
+        name: name.into(),
+        type_,
+    }
+}
+
+fn input_value(name: &str, value: Operand) -> InputValue {
+    InputValue {
+        name: name.into(),
+        value,
+    }
+}
+
+/// A program with a public field `x` in `r0`, a private field `y` in `r1` and a constant boolean `c` in `r2`.
+fn program(instructions: Vec<Instruction>) -> Program {
+    Program {
+        header: Header {
+            public_inputs: vec![input_type(0, "x", Type::Field)],
+            private_inputs: vec![input_type(1, "y", Type::Field)],
+            constant_inputs: vec![input_type(2, "c", Type::Boolean)],
+            ..Default::default()
+        },
+        functions: vec![Function {
+            argument_start_variable: 3,
+            instructions,
+        }],
+    }
+}
+
+fn input(x: Operand, y: Operand, c: bool) -> InputData {
+    InputData {
+        constant_inputs: vec![input_value("c", Operand::Boolean(c))],
+        private_inputs: vec![input_value("y", y)],
+        public_inputs: vec![input_value("x", x)],
+        register_inputs: vec![],
+        public_states: vec![],
+        private_record_states: vec![],
+        private_leaf_states: vec![],
+    }
+}
+
+#[test]
+fn compiles_like_evaluation() {
+    use Instruction::*;
+    let program = program(vec![
+        Mul(binary(Operand::Ref(0), Operand::Ref(1), 3)),
+        Add(binary(Operand::Ref(3), field(5), 4)),
+        Square(unary(Operand::Ref(4), 5)),
+        Sub(binary(Operand::Ref(5), Operand::Ref(0), 6)),
+        IsEq(binary(Operand::Ref(0), Operand::Ref(1), 7)),
+        Ternary(TernaryData {
+            cond: Operand::Ref(7),
+            lhs: Operand::Ref(3),
+            rhs: Operand::Ref(4),
+            dest: 8,
+        }),
+        Nor(binary(Operand::Ref(7), Operand::Ref(2), 9)),
+        Xor(binary(Operand::Ref(9), Operand::Boolean(true), 10)),
+        Double(unary(Operand::Ref(0), 11)),
+        Sub(binary(Operand::Ref(11), Operand::Ref(0), 12)),
+        AssertEq(AssertData {
+            lhs: Operand::Ref(12),
+            rhs: Operand::Ref(0),
+        }),
+        AssertNeq(AssertData {
+            lhs: Operand::Ref(8),
+            rhs: Operand::Ref(6),
+        }),
+    ]);
+
+    for (x, y, c) in [(3, 7, false), (9, 9, true), (0, 1, true)] {
+        let input = input(field(x), field(y), c);
+        let compiled = program.compile(&input).unwrap();
+        assert_eq!(compiled.registers, program.evaluate(&input).unwrap());
+
+        // Only `x` is public, and `y` and intermediate values are private.
+        assert_eq!(1, compiled.assignment.num_public());
+        assert_eq!(compiled.assignment.public_inputs(), vec![Scalar::from(x)]);
+        assert!(compiled.assignment.num_private() > 1);
+        assert!(compiled.assignment.num_constraints() > 0);
+    }
+}
+
+#[test]
+fn failed_assertions() {
+    use Instruction::*;
+    let program = program(vec![AssertEq(AssertData {
+        lhs: Operand::Ref(0),
+        rhs: Operand::Ref(1),
+    })]);
+    assert!(program.compile(&input(field(1), field(1), true)).is_ok());
+    assert!(program.compile(&input(field(1), field(2), true)).is_err());
+
+    let program = self::program(vec![AssertNeq(AssertData {
+        lhs: Operand::Ref(2),
+        rhs: Operand::Boolean(true),
+    })]);
+    assert!(program.compile(&input(field(1), field(2), false)).is_ok());
+    assert!(program.compile(&input(field(1), field(2), true)).is_err());
+}
+
+#[test]
+fn unsupported_programs() {
+    use Instruction::*;
+    let valid = input(field(1), field(2), true);
+
+    // Types and instructions without gadgets are rejected instead of being miscompiled.
+    let program = self::program(vec![Inv(unary(Operand::Ref(0), 3))]);
+    assert!(program.evaluate(&valid).is_ok());
+    assert!(program.compile(&valid).is_err());
+
+    let program = self::program(vec![Add(binary(Operand::U8(1), Operand::U8(2), 3))]);
+    assert!(program.evaluate(&valid).is_ok());
+    assert!(program.compile(&valid).is_err());
+
+    let program = self::program(vec![Add(binary(Operand::Ref(0), Operand::Ref(2), 3))]);
+    assert!(program.compile(&valid).is_err());
+
+    // Only `boolean` and `field` values, and structs of them, are lowered.
+    for operand in [
+        Operand::U8(1),
+        Operand::I64(-1),
+        Operand::U128(1),
+        Operand::Group(Group::Single(Field {
+            negate: false,
+            values: vec![1],
+        })),
+        Operand::Scalar(crate::Scalar { values: vec![1] }),
+        Operand::Address(Address {
+            address: vec![7; 32],
+        }),
+        Operand::String("a".into()),
+        Operand::Struct(Struct {
+            values: vec![Operand::Boolean(true), Operand::U8(1)],
+        }),
+    ] {
+        let program = self::program(vec![Declassify(unary(operand, 3))]);
+        assert!(program.evaluate(&valid).is_ok());
+        assert!(program.compile(&valid).is_err());
+    }
+
+    let program = self::program(vec![]);
+    assert!(program
+        .compile(&input(field(1), Operand::U8(2), true))
+        .is_err());
+    assert!(program.compile(&valid).is_ok());
+}
+
+#[test]
+fn masked_blocks() {
+    let program: Program = "
+        input public r0 x: field
+        input private r1 y: field
+        input constant r2 c: boolean
+
+        decl f0 r3
+        0: is.eq r0, r1 -> r3
+        1: add r0, r1 -> r4
+        2: is.neq r0, r1 -> r5
+        3: mask r3, 5
+        4: assert.eq r0, r1
+        5: assert.neq r5, true
+        6: call f1 r4 -> r4
+        7: mask r2, 1
+        8: double r4 -> r4
+        9: not r3 -> r6
+        10: mask r5, 1
+        11: assert.neq r0, 0field
+        12: repeat 2, 1
+        13: add r4, 1field -> r4
+
+        decl f1 r0
+        0: mul r0, r0 -> r1
+        1: return r1
+    "
+    .parse()
+    .unwrap();
+
+    // Assertions in blocks that do not take effect are not enforced, like `x != 0` here.
+    for (x, y, c, expected) in [(3, 7, false, 12), (9, 9, true, 650), (0, 0, false, 2)] {
+        let input = input(field(x), field(y), c);
+        let compiled = program.compile(&input).unwrap();
+        assert_eq!(compiled.registers, program.evaluate(&input).unwrap());
+        assert_eq!(compiled.registers.get(4), Some(&field(expected)));
+    }
+    assert!(program.compile(&input(field(0), field(1), true)).is_err());
+}
+
+#[test]
+fn structs_and_conditions() {
+    let program: Program = "
+        input public r0 x: field
+        input private r1 y: field
+        input constant r2 c: boolean
+
+        decl f0 r3
+        0: is.eq r0, r1 -> r3
+        1: ternary r3, struct(r0, true), struct(r1, false) -> r4
+        2: member r4, 0 -> r5
+        3: member r4, 1 -> r6
+        4: is.eq r6, r3 -> r7
+        5: assert r7
+        6: mask r3, 1
+        7: assert r2
+    "
+    .parse()
+    .unwrap();
+
+    // `assert r2` only applies when `x == y`.
+    for (x, y, c) in [(3, 7, false), (9, 9, true)] {
+        let input = input(field(x), field(y), c);
+        let compiled = program.compile(&input).unwrap();
+        assert_eq!(compiled.registers, program.evaluate(&input).unwrap());
+    }
+    assert!(program.compile(&input(field(9), field(9), false)).is_err());
+}
+
+#[test]
+fn scalar_field_arithmetic() {
+    use Instruction::*;
+    let program = program(vec![
+        Mul(binary(Operand::Ref(0), field(2), 3)),
+        Neg(unary(Operand::Ref(0), 4)),
+    ]);
+
+    // Circuits compute modulo the scalar field, so `(r - 1) * 2` wraps to `r - 2` and
+    // `-(r - 1)` is `1`, while evaluation over the base field gives other values.
+    let valid = input(field_limbs(scalar::MODULUS - Uint::from(1)), field(0), true);
+    let compiled = program.compile(&valid).unwrap();
+    let evaluated = program.evaluate(&valid).unwrap();
+    assert_eq!(
+        compiled.registers.get(3),
+        Some(&field_limbs(scalar::MODULUS - Uint::from(2)))
+    );
+    assert_eq!(compiled.registers.get(4), Some(&field(1)));
+    assert_ne!(compiled.registers.get(3), evaluated.get(3));
+    assert_ne!(compiled.registers.get(4), evaluated.get(4));
+
+    // Base field values from the scalar modulus up cannot be represented.
+    for x in [
+        field_limbs(scalar::MODULUS),
+        field_limbs(fp::MODULUS - Uint::from(1)),
+    ] {
+        let input = input(x, field(0), true);
+        assert!(program.evaluate(&input).is_ok());
+        assert!(program.compile(&input).is_err());
+    }
+
+    // Negative values wrap around the base field, so they do not fit either.
+    let negative = Operand::Field(Field {
+        negate: true,
+        values: vec![1],
+    });
+    assert!(program.compile(&input(negative, field(0), true)).is_err());
+}
+
+#[test]
+fn proves_with_marlin() {
+    use snarkd_crypto::{
+        marlin::{AHPForR1CS, MarlinSNARK, SNARK},
+        utils::PoseidonParameters,
+    };
+
+    let program: Program = "
+        input public r0 x: field
+        input private r1 y: field
+        input constant r2 c: boolean
+
+        decl f0 r3
+        0: mul r0, r1 -> r3
+        1: neg r3 -> r4
+        2: is.eq r0, r1 -> r5
+        3: ternary r5, r3, r4 -> r6
+        4: mask r2, 1
+        5: assert.neq r6, 0field
+    "
+    .parse()
+    .unwrap();
+
+    let ahp = AHPForR1CS { mode: true };
+    let universal_srs = MarlinSNARK::universal_setup(ahp.max_degree(64, 64, 256).unwrap()).unwrap();
+    let fs_parameters = PoseidonParameters::default();
+    let snark = MarlinSNARK { mode: true };
+    let assignment = |x, y| {
+        program
+            .compile(&input(field(x), field(y), true))
+            .unwrap()
+            .assignment
+    };
+
+    // The circuit only depends on the constant inputs, so one setup proves every other input.
+    let (index_pk, index_vk) =
+        MarlinSNARK::circuit_setup(&universal_srs, &assignment(1, 1), true).unwrap();
+    for (x, y) in [(3, 7), (9, 9)] {
+        let assignment = assignment(x, y);
+        let proof = snark
+            .prove(
+                &fs_parameters,
+                &index_pk,
+                &assignment,
+                &mut rand::thread_rng(),
+            )
+            .unwrap();
+        assert!(snark
+            .verify::<[Scalar], _>(&fs_parameters, &index_vk, [Scalar::from(x)], &proof)
+            .unwrap());
+        assert!(!snark
+            .verify::<[Scalar], _>(&fs_parameters, &index_vk, [Scalar::from(x + 1)], &proof)
+            .unwrap());
+    }
+}
@@ -0,0 +1,187 @@
+use ruint::Uint;
+use snarkd_crypto::bls12_377::{
+    self, fp, scalar, Affine, Field as _, Fp, G1Affine, G1Projective, Projective,
+};
+use snarkd_errors::IRError;
+
+use super::{
+    hash,
+    ops::{Fault, OpResult},
+    value::{from_fp, from_group, from_scalar, to_fp, to_group, to_scalar},
+};
+use crate::{Operand, Struct, Type};
+
+/// Number of bits of values of type `type_` in `to.bits` and `from.bits`, and whether they are
+/// signed integers. `None` for types that cannot be cast.
+fn bits(type_: &Type) -> Option<(u32, bool)> {
+    Some(match type_ {
+        Type::Boolean => (1, false),
+        Type::Field | Type::Group => (fp::MODULUS_BITS, false),
+        Type::Scalar => (scalar::MODULUS_BITS, false),
+        Type::U8 => (8, false),
+        Type::U16 => (16, false),
+        Type::U32 => (32, false),
+        Type::U64 => (64, false),
+        Type::U128 => (128, false),
+        Type::I8 => (8, true),
+        Type::I16 => (16, true),
+        Type::I32 => (32, true),
+        Type::I64 => (64, true),
+        Type::I128 => (128, true),
+        _ => return None,
+    })
+}
+
+fn signed(value: i128) -> Fp {
+    let magnitude = Fp::from(value.unsigned_abs());
+    if value < 0 {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// The field element `operand` is cast through: 0 or 1 for booleans, the value of integers and
+/// scalars, with negative integers as their negation, and the x-coordinate of groups.
+fn to_field(operand: &Operand) -> Result<Fp, Fault> {
+    Ok(match operand {
+        Operand::Boolean(v) => Fp::from(*v as u64),
+        Operand::U8(v) => Fp::from(*v as u64),
+        Operand::U16(v) => Fp::from(*v as u64),
+        Operand::U32(v) => Fp::from(*v as u64),
+        Operand::U64(v) => Fp::from(*v),
+        Operand::U128(v) => Fp::from(*v),
+        Operand::I8(v) => signed(*v as i128),
+        Operand::I16(v) => signed(*v as i128),
+        Operand::I32(v) => signed(*v as i128),
+        Operand::I64(v) => signed(*v as i128),
+        Operand::I128(v) => signed(*v),
+        Operand::Field(v) => to_fp(v)?,
+        Operand::Scalar(v) => Fp(Uint::from_limbs_slice(to_scalar(v)?.0.as_limbs())),
+        Operand::Group(v) => to_group(v)?.to_affine().x,
+        _ => return Err(Fault::OperandTypes),
+    })
+}
+
+fn to_u128(value: Fp) -> Option<u128> {
+    match value.0.as_limbs() {
+        [low, high, rest @ ..] if rest.iter().all(|x| *x == 0) => {
+            Some((*high as u128) << 64 | *low as u128)
+        }
+        _ => None,
+    }
+}
+
+/// The integer of type `type_` that is `magnitude`, negated if `negative`, if it is in range.
+fn integer(type_: &Type, negative: bool, magnitude: u128) -> Option<Operand> {
+    let signed = match negative {
+        false => i128::try_from(magnitude).ok(),
+        true => (magnitude <= 1 << 127).then(|| (magnitude as i128).wrapping_neg()),
+    };
+    let unsigned = (!negative || magnitude == 0).then_some(magnitude);
+    Some(match type_ {
+        Type::U8 => Operand::U8(unsigned?.try_into().ok()?),
+        Type::U16 => Operand::U16(unsigned?.try_into().ok()?),
+        Type::U32 => Operand::U32(unsigned?.try_into().ok()?),
+        Type::U64 => Operand::U64(unsigned?.try_into().ok()?),
+        Type::U128 => Operand::U128(unsigned?),
+        Type::I8 => Operand::I8(signed?.try_into().ok()?),
+        Type::I16 => Operand::I16(signed?.try_into().ok()?),
+        Type::I32 => Operand::I32(signed?.try_into().ok()?),
+        Type::I64 => Operand::I64(signed?.try_into().ok()?),
+        Type::I128 => Operand::I128(signed?),
+        _ => return None,
+    })
+}
+
+/// Reads `value` as type `type_`, if it is in range. Field elements that are the negation of a
+/// small value stand for negative integers, and groups are found from their x-coordinate.
+fn from_field(value: Fp, type_: &Type) -> Option<Operand> {
+    Some(match type_ {
+        Type::Boolean => match to_u128(value)? {
+            0 => Operand::Boolean(false),
+            1 => Operand::Boolean(true),
+            _ => return None,
+        },
+        Type::Field => Operand::Field(from_fp(value)),
+        Type::Scalar => {
+            let value = Uint::checked_from_limbs_slice(value.0.as_limbs())
+                .filter(|x| *x < scalar::MODULUS)?;
+            Operand::Scalar(from_scalar(bls12_377::Scalar(value)))
+        }
+        // The identity is the only element with a zero x-coordinate.
+        Type::Group if value.is_zero() => Operand::Group(from_group(G1Projective::ZERO)),
+        Type::Group => {
+            let point = G1Affine::from_x_coordinate(value, true)
+                .filter(|x| x.is_in_correct_subgroup_assuming_on_curve())?;
+            Operand::Group(from_group(point.to_projective()))
+        }
+        _ => match to_u128(value) {
+            Some(magnitude) => integer(type_, false, magnitude)?,
+            None => integer(type_, true, to_u128(-value)?)?,
+        },
+    })
+}
+
+/// Error for a value that is out of range for the type it is cast to.
+fn invalid(instruction: &str, operand: &Operand, type_: &Type) -> Fault {
+    Fault::Error(IRError::invalid_cast(instruction, operand, type_).into())
+}
+
+/// Casts between booleans, integers, fields, scalars and groups, failing if the value is out of
+/// range for `type_`. Casting a group to a group keeps it as it is.
+pub(super) fn cast(operand: &Operand, type_: &Type) -> OpResult {
+    match (operand, type_) {
+        (Operand::Group(_), Type::Group) => return Ok(operand.clone()),
+        _ if bits(type_).is_none() => return Err(Fault::OperandTypes),
+        _ => {}
+    }
+    from_field(to_field(operand)?, type_).ok_or_else(|| invalid("cast", operand, type_))
+}
+
+/// The little endian bits of a boolean, integer, field, scalar or group, as a struct of
+/// booleans. Signed integers are in two's complement, and groups are their x-coordinate.
+pub(super) fn to_bits(operand: &Operand) -> OpResult {
+    to_field(operand)?;
+    Ok(Operand::Struct(Struct {
+        values: hash::to_bits(operand)?
+            .into_iter()
+            .map(Operand::Boolean)
+            .collect(),
+    }))
+}
+
+/// Reads a struct of as many booleans as there are bits in `type_`, the inverse of `to_bits`.
+pub(super) fn from_bits(operand: &Operand, type_: &Type) -> OpResult {
+    let (width, is_signed) = bits(type_).ok_or(Fault::OperandTypes)?;
+    let bits = match operand {
+        Operand::Struct(Struct { values }) if values.len() == width as usize => values
+            .iter()
+            .map(|x| match x {
+                Operand::Boolean(x) => Ok(*x),
+                _ => Err(Fault::OperandTypes),
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        _ => return Err(Fault::OperandTypes),
+    };
+    let mut limbs = [0u64; 6];
+    for (i, bit) in bits.iter().enumerate() {
+        limbs[i / 64] |= (*bit as u64) << (i % 64);
+    }
+    let value = Uint::from_limbs(limbs);
+    let result = match type_ {
+        Type::Field | Type::Group if value >= fp::MODULUS => None,
+        Type::Field | Type::Group | Type::Scalar | Type::Boolean => from_field(Fp(value), type_),
+        _ => {
+            let value = to_u128(Fp(value)).expect("integers have at most 128 bits");
+            match is_signed && bits[width as usize - 1] {
+                false => integer(type_, false, value),
+                true => {
+                    let mask = u128::MAX >> (128 - width);
+                    integer(type_, true, value.wrapping_neg() & mask)
+                }
+            }
+        }
+    };
+    result.ok_or_else(|| invalid("from.bits", operand, type_))
+}
@@ -0,0 +1,190 @@
+use std::sync::OnceLock;
+
+use ruint::Uint;
+use snarkd_crypto::{
+    bls12_377::{fp, scalar, Fp, Projective},
+    utils::{Bhp, Pedersen, Poseidon},
+};
+use snarkd_errors::IRError;
+
+use super::value::{from_fp, to_fp, to_group, to_scalar};
+use crate::{CommitMethod, HashMethod, Operand, Result, Scalar};
+
+/// Number of bits prefixed to each hashed operand: an 8 bit type tag and a 32 bit payload length.
+pub(crate) const OPERAND_PREFIX_BITS: u32 = 40;
+
+fn push_bits(bits: &mut Vec<bool>, value: u128, width: usize) {
+    bits.extend((0..width).map(|i| value >> i & 1 == 1));
+}
+
+fn push_limbs(bits: &mut Vec<bool>, limbs: &[u64], width: u32) {
+    bits.extend((0..width as usize).map(|i| limbs[i / 64] >> (i % 64) & 1 == 1));
+}
+
+/// Appends the little endian bits of a canonical operand. Groups are represented by their x-coordinate,
+/// and the members of records and structs are prefixed as in `push_hashed_bits`.
+fn push_operand_bits(bits: &mut Vec<bool>, operand: &Operand) -> Result<()> {
+    match operand {
+        Operand::Address(v) => v
+            .address
+            .iter()
+            .for_each(|x| push_bits(bits, *x as u128, 8)),
+        Operand::Boolean(v) => bits.push(*v),
+        Operand::Field(v) => push_limbs(bits, to_fp(v)?.0.as_limbs(), fp::MODULUS_BITS),
+        Operand::Group(v) => push_limbs(
+            bits,
+            to_group(v)?.to_affine().x.0.as_limbs(),
+            fp::MODULUS_BITS,
+        ),
+        Operand::U8(v) => push_bits(bits, *v as u128, 8),
+        Operand::U16(v) => push_bits(bits, *v as u128, 16),
+        Operand::U32(v) => push_bits(bits, *v as u128, 32),
+        Operand::U64(v) => push_bits(bits, *v as u128, 64),
+        Operand::U128(v) => push_bits(bits, *v, 128),
+        Operand::I8(v) => push_bits(bits, *v as u128, 8),
+        Operand::I16(v) => push_bits(bits, *v as u128, 16),
+        Operand::I32(v) => push_bits(bits, *v as u128, 32),
+        Operand::I64(v) => push_bits(bits, *v as u128, 64),
+        Operand::I128(v) => push_bits(bits, *v as u128, 128),
+        Operand::Ref(_) => unreachable!("registers are resolved before hashing"),
+        Operand::Scalar(v) => push_limbs(bits, to_scalar(v)?.0.as_limbs(), scalar::MODULUS_BITS),
+        Operand::String(v) => v.bytes().for_each(|x| push_bits(bits, x as u128, 8)),
+        Operand::Record(v) => {
+            push_hashed_bits(bits, &Operand::Address(v.owner.clone()))?;
+            push_hashed_bits(bits, &Operand::U64(v.gates))?;
+            for entry in &v.data {
+                push_hashed_bits(bits, &entry.value)?;
+            }
+            push_hashed_bits(bits, &Operand::Group(v.nonce.clone()))?;
+        }
+        Operand::Struct(v) => {
+            for value in &v.values {
+                push_hashed_bits(bits, value)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Appends the bits of an operand prefixed with its type tag and the length of its bits, so that
+/// operands of different types or shapes never share an encoding.
+fn push_hashed_bits(bits: &mut Vec<bool>, operand: &Operand) -> Result<()> {
+    let tag = match operand {
+        Operand::Address(_) => 0,
+        Operand::Boolean(_) => 1,
+        Operand::Field(_) => 2,
+        Operand::Group(_) => 3,
+        Operand::U8(_) => 4,
+        Operand::U16(_) => 5,
+        Operand::U32(_) => 6,
+        Operand::U64(_) => 7,
+        Operand::U128(_) => 8,
+        Operand::I8(_) => 9,
+        Operand::I16(_) => 10,
+        Operand::I32(_) => 11,
+        Operand::I64(_) => 12,
+        Operand::I128(_) => 13,
+        Operand::Ref(_) => unreachable!("registers are resolved before hashing"),
+        Operand::Scalar(_) => 14,
+        Operand::String(_) => 15,
+        Operand::Record(_) => 16,
+        Operand::Struct(_) => 17,
+    };
+    push_bits(bits, tag, 8);
+    let mut payload = vec![];
+    push_operand_bits(&mut payload, operand)?;
+    push_bits(bits, payload.len() as u128, 32);
+    bits.extend(payload);
+    Ok(())
+}
+
+pub(super) fn to_bits(operand: &Operand) -> Result<Vec<bool>> {
+    let mut bits = vec![];
+    push_operand_bits(&mut bits, operand)?;
+    Ok(bits)
+}
+
+/// The prefixed bits of `operand`, as taken by hashes and commitments.
+fn hashed_bits(operand: &Operand) -> Result<Vec<bool>> {
+    let mut bits = vec![];
+    push_hashed_bits(&mut bits, operand)?;
+    Ok(bits)
+}
+
+/// Packs the prefixed bits of `operand` into field elements, filling all but the top bit of each.
+fn to_fields(operand: &Operand) -> Result<Vec<Fp>> {
+    Ok(hashed_bits(operand)?
+        .chunks(fp::CAPACITY as usize)
+        .map(|chunk| {
+            let mut limbs = [0u64; 6];
+            for (i, bit) in chunk.iter().enumerate() {
+                limbs[i / 64] |= (*bit as u64) << (i % 64);
+            }
+            Fp(Uint::from_limbs(limbs))
+        })
+        .collect())
+}
+
+/// The Pedersen schemes of `ped64` and `ped128`, taking 64 and 128 bits after the operand prefix.
+fn pedersen(num_bits: usize) -> &'static Pedersen {
+    static SCHEMES: OnceLock<[Pedersen; 2]> = OnceLock::new();
+    let schemes = SCHEMES.get_or_init(|| {
+        [64, 128].map(|num_bits| {
+            Pedersen::setup(num_bits as u64, num_bits + OPERAND_PREFIX_BITS as usize)
+        })
+    });
+    &schemes[num_bits / 64 - 1]
+}
+
+/// The BHP schemes of `bhp256` to `bhp1024`, with the window layouts of snarkVM.
+fn bhp(num_bits: usize) -> &'static Bhp {
+    static SCHEMES: OnceLock<[Bhp; 4]> = OnceLock::new();
+    let schemes = SCHEMES.get_or_init(|| {
+        [(256, 3, 57), (512, 6, 43), (768, 15, 23), (1024, 8, 54)].map(
+            |(num_bits, num_windows, window_size)| Bhp::setup(num_bits, num_windows, window_size),
+        )
+    });
+    &schemes[num_bits / 256 - 1]
+}
+
+pub(super) fn hash(method: HashMethod, chunk: &Operand, instruction: &str) -> Result<Operand> {
+    static PSD4: OnceLock<Poseidon<4>> = OnceLock::new();
+    static PSD8: OnceLock<Poseidon<8>> = OnceLock::new();
+    let value = match method {
+        HashMethod::HashBhp256 => Ok(bhp(256).hash(&hashed_bits(chunk)?)),
+        HashMethod::HashBhp512 => Ok(bhp(512).hash(&hashed_bits(chunk)?)),
+        HashMethod::HashBhp768 => Ok(bhp(768).hash(&hashed_bits(chunk)?)),
+        HashMethod::HashBhp1024 => Ok(bhp(1024).hash(&hashed_bits(chunk)?)),
+        HashMethod::HashPed64 => pedersen(64).hash(&hashed_bits(chunk)?),
+        HashMethod::HashPed128 => pedersen(128).hash(&hashed_bits(chunk)?),
+        HashMethod::HashPsd2 => Ok(Poseidon::<2>::setup().evaluate_with_len(&to_fields(chunk)?)),
+        HashMethod::HashPsd4 => Ok(PSD4
+            .get_or_init(Poseidon::setup)
+            .evaluate_with_len(&to_fields(chunk)?)),
+        HashMethod::HashPsd8 => Ok(PSD8
+            .get_or_init(Poseidon::setup)
+            .evaluate_with_len(&to_fields(chunk)?)),
+    }
+    .map_err(|e| IRError::invalid_hash_input(instruction, e))?;
+    Ok(Operand::Field(from_fp(value)))
+}
+
+pub(super) fn commit(
+    method: CommitMethod,
+    chunk: &Operand,
+    randomness: &Scalar,
+    instruction: &str,
+) -> Result<Operand> {
+    let bits = hashed_bits(chunk)?;
+    let randomness = to_scalar(randomness)?;
+    let value = match method {
+        CommitMethod::CommitBhp256 => Ok(bhp(256).commit(&bits, randomness)),
+        CommitMethod::CommitBhp512 => Ok(bhp(512).commit(&bits, randomness)),
+        CommitMethod::CommitBhp768 => Ok(bhp(768).commit(&bits, randomness)),
+        CommitMethod::CommitBhp1024 => Ok(bhp(1024).commit(&bits, randomness)),
+        CommitMethod::CommitPed64 => pedersen(64).commit(&bits, randomness),
+        CommitMethod::CommitPed128 => pedersen(128).commit(&bits, randomness),
+    }
+    .map_err(|e| IRError::invalid_hash_input(instruction, e))?;
+    Ok(Operand::Field(from_fp(value)))
+}
@@ -0,0 +1,379 @@
+mod cast;
+mod hash;
+mod ops;
+pub(crate) mod value;
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet},
+};
+
+use snarkd_errors::{Error, IRError, Result};
+
+use crate::{
+    AccessData, AssertData, BinaryData, CallData, CastData, CommitData, ConditionData, Function,
+    HashData, InputData, InputType, InputValue, Instruction, MaskData, Operand, Program,
+    RecordType, RepeatData, ReturnData, StructType, TernaryData, Type, UnaryData,
+};
+pub(crate) use hash::OPERAND_PREFIX_BITS;
+use ops::Fault;
+
+/// Register file of a function being evaluated. Registers only ever hold validated operands,
+/// with fields, scalars and groups in a single representation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Registers(BTreeMap<u32, Operand>);
+
+impl Registers {
+    pub fn get(&self, register: u32) -> Option<&Operand> {
+        self.0.get(&register)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &Operand)> {
+        self.0.iter().map(|(register, value)| (*register, value))
+    }
+
+    /// Reads `operand`, looking up references in the registers.
+    fn resolve(&self, operand: &Operand) -> Result<Operand> {
+        value::canonicalize(operand, &|register| {
+            self.get(register)
+                .cloned()
+                .ok_or_else(|| IRError::unknown_register(register).into())
+        })
+    }
+
+    /// Places each input value in the register declared by `types`.
+    pub(crate) fn load_inputs(&mut self, types: &[InputType], values: &[InputValue]) -> Result<()> {
+        if let Some(value) = values
+            .iter()
+            .find(|value| !types.iter().any(|type_| type_.name == value.name))
+        {
+            return Err(IRError::unexpected_input(&value.name).into());
+        }
+        for type_ in types {
+            let value = values
+                .iter()
+                .find(|value| value.name == type_.name)
+                .ok_or_else(|| IRError::missing_input(&type_.name))?;
+            if !matches_type(&value.value, &type_.type_) {
+                return Err(IRError::input_type_mismatch(&type_.name, &value.value).into());
+            }
+            let value = self.resolve(&value.value)?;
+            self.0.insert(type_.variable, value);
+        }
+        Ok(())
+    }
+
+    /// Runs `instructions` in order, calling into `functions`. Returns the values of the `return`
+    /// reached, if any.
+    fn run(
+        &mut self,
+        functions: &[Function],
+        instructions: &[Instruction],
+    ) -> Result<Option<Vec<Operand>>> {
+        let mut i = 0;
+        while let Some(instruction) = instructions.get(i) {
+            i += 1;
+            let (length, count) = match instruction {
+                Instruction::Call(CallData {
+                    function,
+                    arguments,
+                    dests,
+                }) => {
+                    let callee = functions
+                        .get(*function as usize)
+                        .ok_or_else(|| IRError::missing_function(function))?;
+                    let mut registers = Registers::default();
+                    for (register, argument) in (callee.argument_start_variable..).zip(arguments) {
+                        registers.0.insert(register, self.resolve(argument)?);
+                    }
+                    let values = registers
+                        .run(functions, &callee.instructions)?
+                        .unwrap_or_default();
+                    self.0.extend(dests.iter().copied().zip(values));
+                    continue;
+                }
+                Instruction::Return(ReturnData { values }) => {
+                    return values
+                        .iter()
+                        .map(|x| self.resolve(x))
+                        .collect::<Result<_>>()
+                        .map(Some);
+                }
+                Instruction::Mask(MaskData { cond, length }) => {
+                    let cond = self.resolve(cond)?;
+                    match cond {
+                        Operand::Boolean(x) => (*length, x as u32),
+                        _ => return Err(fault(Fault::OperandTypes, "mask", &[&cond])),
+                    }
+                }
+                Instruction::Repeat(RepeatData { count, length }) => (*length, *count),
+                _ => {
+                    self.execute(instruction)?;
+                    continue;
+                }
+            };
+            let block = instructions
+                .get(i..i + length as usize)
+                .ok_or_else(|| IRError::truncated_block(instruction.mnemonic(), length))?;
+            let outer = self.0.keys().copied().collect::<BTreeSet<_>>();
+            for _ in 0..count {
+                if let Some(values) = self.run(functions, block)? {
+                    return Ok(Some(values));
+                }
+            }
+            // Registers first written in the block are not visible after it.
+            self.0.retain(|register, _| outer.contains(register));
+            i += length as usize;
+        }
+        Ok(None)
+    }
+
+    /// Runs an instruction other than a call, a return or the start of a block.
+    pub(crate) fn execute(&mut self, instruction: &Instruction) -> Result<()> {
+        let mnemonic = instruction.mnemonic();
+        let unary = |data: &UnaryData, op: fn(&Operand) -> ops::OpResult| {
+            let operand = self.resolve(&data.operand)?;
+            let value = op(&operand).map_err(|e| fault(e, mnemonic, &[&operand]))?;
+            Ok::<_, Error>((data.dest, value))
+        };
+        let binary = |data: &BinaryData, op: &dyn Fn(&Operand, &Operand) -> ops::OpResult| {
+            let lhs = self.resolve(&data.lhs)?;
+            let rhs = self.resolve(&data.rhs)?;
+            let value = op(&lhs, &rhs).map_err(|e| fault(e, mnemonic, &[&lhs, &rhs]))?;
+            Ok::<_, Error>((data.dest, value))
+        };
+        let convert = |data: &CastData, op: fn(&Operand, &Type) -> ops::OpResult| {
+            let operand = self.resolve(&data.operand)?;
+            let value = op(&operand, &data.type_).map_err(|e| fault(e, mnemonic, &[&operand]))?;
+            Ok::<_, Error>((data.dest, value))
+        };
+        let access = |data: &AccessData, op: fn(&Operand, u32) -> ops::OpResult| {
+            let operand = self.resolve(&data.operand)?;
+            let value = op(&operand, data.index).map_err(|e| fault(e, mnemonic, &[&operand]))?;
+            Ok::<_, Error>((data.dest, value))
+        };
+        let compare = |data: &BinaryData, accept: fn(Ordering) -> bool| {
+            binary(data, &|lhs, rhs| {
+                Ok(Operand::Boolean(accept(ops::compare(lhs, rhs)?)))
+            })
+        };
+        let equals = |data: &BinaryData, expected: bool| {
+            binary(data, &|lhs, rhs| {
+                Ok(Operand::Boolean(ops::equals(lhs, rhs)? == expected))
+            })
+        };
+
+        use Instruction::*;
+        let (dest, value) = match instruction {
+            Abs(data) => unary(data, |x| ops::abs(x, false))?,
+            AbsWrapped(data) => unary(data, |x| ops::abs(x, true))?,
+            Declassify(data) => unary(data, |x| Ok(x.clone()))?,
+            Double(data) => unary(data, ops::double)?,
+            Inv(data) => unary(data, ops::inv)?,
+            Neg(data) => unary(data, ops::neg)?,
+            Not(data) => unary(data, ops::not)?,
+            Sqrt(data) => unary(data, ops::sqrt)?,
+            Square(data) => unary(data, ops::square)?,
+            Add(data) => binary(data, &|lhs, rhs| ops::add(lhs, rhs, false))?,
+            AddWrapped(data) => binary(data, &|lhs, rhs| ops::add(lhs, rhs, true))?,
+            Sub(data) => binary(data, &|lhs, rhs| ops::sub(lhs, rhs, false))?,
+            SubWrapped(data) => binary(data, &|lhs, rhs| ops::sub(lhs, rhs, true))?,
+            Mul(data) => binary(data, &|lhs, rhs| ops::mul(lhs, rhs, false))?,
+            MulWrapped(data) => binary(data, &|lhs, rhs| ops::mul(lhs, rhs, true))?,
+            Div(data) => binary(data, &|lhs, rhs| ops::div(lhs, rhs, false))?,
+            DivWrapped(data) => binary(data, &|lhs, rhs| ops::div(lhs, rhs, true))?,
+            Rem(data) => binary(data, &|lhs, rhs| ops::rem(lhs, rhs, false))?,
+            RemWrapped(data) => binary(data, &|lhs, rhs| ops::rem(lhs, rhs, true))?,
+            Mod(data) => binary(data, &ops::modulo)?,
+            Pow(data) => binary(data, &|lhs, rhs| ops::pow(lhs, rhs, false))?,
+            PowWrapped(data) => binary(data, &|lhs, rhs| ops::pow(lhs, rhs, true))?,
+            Shl(data) => binary(data, &|lhs, rhs| ops::shl(lhs, rhs, false))?,
+            ShlWrapped(data) => binary(data, &|lhs, rhs| ops::shl(lhs, rhs, true))?,
+            Shr(data) => binary(data, &|lhs, rhs| ops::shr(lhs, rhs, false))?,
+            ShrWrapped(data) => binary(data, &|lhs, rhs| ops::shr(lhs, rhs, true))?,
+            Lshr(data) => binary(data, &ops::lshr)?,
+            And(data) => binary(data, &ops::and)?,
+            Or(data) => binary(data, &ops::or)?,
+            Xor(data) => binary(data, &ops::xor)?,
+            Nand(data) => binary(data, &ops::nand)?,
+            Nor(data) => binary(data, &ops::nor)?,
+            Gt(data) => compare(data, Ordering::is_gt)?,
+            Gte(data) => compare(data, Ordering::is_ge)?,
+            Lt(data) => compare(data, Ordering::is_lt)?,
+            Lte(data) => compare(data, Ordering::is_le)?,
+            IsEq(data) => equals(data, true)?,
+            IsNeq(data) => equals(data, false)?,
+            Cast(data) => convert(data, cast::cast)?,
+            FromBits(data) => convert(data, cast::from_bits)?,
+            ToBits(data) => unary(data, cast::to_bits)?,
+            Member(data) => access(data, ops::member)?,
+            RecordField(data) => access(data, ops::record_field)?,
+            Assert(ConditionData { cond }) => {
+                return match self.resolve(cond)? {
+                    Operand::Boolean(true) => Ok(()),
+                    Operand::Boolean(false) => {
+                        Err(IRError::condition_failed(mnemonic, cond).into())
+                    }
+                    value => Err(fault(Fault::OperandTypes, mnemonic, &[&value])),
+                };
+            }
+            AssertEq(AssertData { lhs, rhs }) | AssertNeq(AssertData { lhs, rhs }) => {
+                let lhs = self.resolve(lhs)?;
+                let rhs = self.resolve(rhs)?;
+                let equal =
+                    ops::equals(&lhs, &rhs).map_err(|e| fault(e, mnemonic, &[&lhs, &rhs]))?;
+                if equal != matches!(instruction, AssertEq(_)) {
+                    return Err(IRError::assertion_failed(mnemonic, lhs, rhs).into());
+                }
+                return Ok(());
+            }
+            Commit(CommitData {
+                method,
+                chunk,
+                randomness,
+                dest,
+            }) => {
+                let chunk = self.resolve(chunk)?;
+                (*dest, hash::commit(*method, &chunk, randomness, mnemonic)?)
+            }
+            Hash(HashData {
+                method,
+                chunk,
+                dest,
+            }) => {
+                let chunk = self.resolve(chunk)?;
+                (*dest, hash::hash(*method, &chunk, mnemonic)?)
+            }
+            Ternary(TernaryData {
+                cond,
+                lhs,
+                rhs,
+                dest,
+            }) => {
+                let cond = self.resolve(cond)?;
+                let lhs = self.resolve(lhs)?;
+                let rhs = self.resolve(rhs)?;
+                let value = ops::ternary(&cond, &lhs, &rhs)
+                    .map_err(|e| fault(e, mnemonic, &[&cond, &lhs, &rhs]))?;
+                (*dest, value)
+            }
+            Call(_) | Return(_) | Mask(_) | Repeat(_) => {
+                unreachable!("control flow is handled by `Registers::run`")
+            }
+        };
+        self.0.insert(dest, value);
+        Ok(())
+    }
+}
+
+impl FromIterator<(u32, Operand)> for Registers {
+    fn from_iter<I: IntoIterator<Item = (u32, Operand)>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+/// Turns a failed operation of `instruction` on `operands` into an error.
+fn fault(fault: Fault, instruction: &str, operands: &[&Operand]) -> Error {
+    match fault {
+        Fault::OperandTypes => IRError::invalid_operand_types(
+            instruction,
+            operands
+                .iter()
+                .map(|x| value::kind(x))
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+        .into(),
+        Fault::Overflow => IRError::integer_overflow(instruction).into(),
+        Fault::DivisionByZero => IRError::division_by_zero(instruction).into(),
+        Fault::NoSquareRoot => IRError::no_square_root(operands[0]).into(),
+        Fault::Error(e) => e,
+    }
+}
+
+fn matches_type(operand: &Operand, type_: &Type) -> bool {
+    match (operand, type_) {
+        (Operand::Address(_), Type::Address)
+        | (Operand::Boolean(_), Type::Boolean)
+        | (Operand::Field(_), Type::Field)
+        | (Operand::Group(_), Type::Group)
+        | (Operand::U8(_), Type::U8)
+        | (Operand::U16(_), Type::U16)
+        | (Operand::U32(_), Type::U32)
+        | (Operand::U64(_), Type::U64)
+        | (Operand::U128(_), Type::U128)
+        | (Operand::I8(_), Type::I8)
+        | (Operand::I16(_), Type::I16)
+        | (Operand::I32(_), Type::I32)
+        | (Operand::I64(_), Type::I64)
+        | (Operand::I128(_), Type::I128)
+        | (Operand::Scalar(_), Type::Scalar)
+        | (Operand::String(_), Type::String) => true,
+        (Operand::Struct(value), Type::Struct(StructType { fields })) => {
+            value.values.len() == fields.len()
+                && value
+                    .values
+                    .iter()
+                    .zip(fields)
+                    .all(|(value, field)| matches_type(value, &field.type_))
+        }
+        (
+            Operand::Record(value),
+            Type::Record(RecordType {
+                owner,
+                gates,
+                data,
+                nonce,
+            }),
+        ) => {
+            value.owner_visibility == *owner
+                && value.gates_visibility == *gates
+                && value.nonce_visibility == *nonce
+                && value.data.len() == data.len()
+                && value.data.iter().zip(data).all(|(value, entry)| {
+                    value.visibility == entry.visibility && matches_type(&value.value, &entry.type_)
+                })
+        }
+        _ => false,
+    }
+}
+
+impl Function {
+    /// Runs the instructions of the function in order over `registers`. The function cannot call
+    /// other functions, which [`Program::evaluate`] provides.
+    pub fn evaluate(&self, mut registers: Registers) -> Result<Registers> {
+        registers.run(&[], &self.instructions)?;
+        Ok(registers)
+    }
+}
+
+impl Program {
+    /// Runs the first function of the program natively, without constraints or proofs, on `input`.
+    /// The program is validated first. Inputs are placed in the registers declared by the header,
+    /// and the final registers of the first function are returned.
+    pub fn evaluate(&self, input: &InputData) -> Result<Registers> {
+        self.validate()?;
+        let function = self
+            .functions
+            .first()
+            .ok_or_else(|| IRError::missing_function(0))?;
+        let header = &self.header;
+        let mut registers = Registers::default();
+        for (types, values) in [
+            (&header.constant_inputs, &input.constant_inputs),
+            (&header.private_inputs, &input.private_inputs),
+            (&header.public_inputs, &input.public_inputs),
+            (&header.register_inputs, &input.register_inputs),
+            (&header.public_states, &input.public_states),
+            (&header.private_record_states, &input.private_record_states),
+            (&header.private_leaf_states, &input.private_leaf_states),
+        ] {
+            registers.load_inputs(types, values)?;
+        }
+        registers.run(&self.functions, &function.instructions)?;
+        Ok(registers)
+    }
+}
@@ -0,0 +1,404 @@
+use std::{cmp::Ordering, mem};
+
+use snarkd_crypto::bls12_377::{Field as _, Fp, Projective};
+
+use snarkd_errors::IRError;
+
+use super::value::{from_fp, from_group, from_scalar, to_fp, to_group, to_scalar};
+use crate::{Error, Operand, Struct};
+
+/// Why an operation failed, turned into an `IRError` with the instruction at hand.
+pub(super) enum Fault {
+    OperandTypes,
+    Overflow,
+    DivisionByZero,
+    NoSquareRoot,
+    Error(Error),
+}
+
+impl From<Error> for Fault {
+    fn from(e: Error) -> Self {
+        Self::Error(e)
+    }
+}
+
+pub(super) type OpResult = Result<Operand, Fault>;
+
+/// Returns `$body` wrapped in the integer type of the operands, if both have the same type among `$variant`s.
+macro_rules! integers {
+    ($value:expr, |$a:ident| $body:expr, [$($variant:ident),*]) => {
+        match $value {
+            $(Operand::$variant($a) => return Ok(Operand::$variant($body)),)*
+            _ => {}
+        }
+    };
+    ($lhs:expr, $rhs:expr, |$a:ident, $b:ident| $body:expr, [$($variant:ident),*]) => {
+        match ($lhs, $rhs) {
+            $((Operand::$variant($a), Operand::$variant($b)) => return Ok(Operand::$variant($body)),)*
+            _ => {}
+        }
+    };
+}
+
+macro_rules! any_integer {
+    ($($args:tt)*) => {
+        integers!($($args)*, [U8, U16, U32, U64, U128, I8, I16, I32, I64, I128])
+    };
+}
+
+macro_rules! signed_integer {
+    ($($args:tt)*) => {
+        integers!($($args)*, [I8, I16, I32, I64, I128])
+    };
+}
+
+macro_rules! unsigned_integer {
+    ($($args:tt)*) => {
+        integers!($($args)*, [U8, U16, U32, U64, U128])
+    };
+}
+
+fn field(value: Fp) -> OpResult {
+    Ok(Operand::Field(from_fp(value)))
+}
+
+/// Reads the exponent of `pow` or the amount of `shl`/`shr`, which must be a `u8`, `u16` or `u32`.
+fn exponent(operand: &Operand) -> Result<u32, Fault> {
+    match operand {
+        Operand::U8(v) => Ok(*v as u32),
+        Operand::U16(v) => Ok(*v as u32),
+        Operand::U32(v) => Ok(*v),
+        _ => Err(Fault::OperandTypes),
+    }
+}
+
+pub(super) fn abs(operand: &Operand, wrapped: bool) -> OpResult {
+    if wrapped {
+        signed_integer!(operand, |a| a.wrapping_abs());
+    } else {
+        signed_integer!(operand, |a| a.checked_abs().ok_or(Fault::Overflow)?);
+    }
+    Err(Fault::OperandTypes)
+}
+
+pub(super) fn double(operand: &Operand) -> OpResult {
+    match operand {
+        Operand::Field(a) => field(to_fp(a)?.double()),
+        Operand::Group(a) => Ok(Operand::Group(from_group(to_group(a)?.double()))),
+        _ => Err(Fault::OperandTypes),
+    }
+}
+
+pub(super) fn inv(operand: &Operand) -> OpResult {
+    match operand {
+        Operand::Field(a) => field(to_fp(a)?.inverse().ok_or(Fault::DivisionByZero)?),
+        _ => Err(Fault::OperandTypes),
+    }
+}
+
+pub(super) fn neg(operand: &Operand) -> OpResult {
+    signed_integer!(operand, |a| a.checked_neg().ok_or(Fault::Overflow)?);
+    match operand {
+        Operand::Field(a) => field(-to_fp(a)?),
+        Operand::Group(a) => Ok(Operand::Group(from_group(-to_group(a)?))),
+        _ => Err(Fault::OperandTypes),
+    }
+}
+
+pub(super) fn not(operand: &Operand) -> OpResult {
+    any_integer!(operand, |a| !a);
+    match operand {
+        Operand::Boolean(a) => Ok(Operand::Boolean(!a)),
+        _ => Err(Fault::OperandTypes),
+    }
+}
+
+pub(super) fn sqrt(operand: &Operand) -> OpResult {
+    match operand {
+        Operand::Field(a) => field(to_fp(a)?.sqrt().ok_or(Fault::NoSquareRoot)?),
+        _ => Err(Fault::OperandTypes),
+    }
+}
+
+pub(super) fn square(operand: &Operand) -> OpResult {
+    match operand {
+        Operand::Field(a) => field(to_fp(a)?.square()),
+        _ => Err(Fault::OperandTypes),
+    }
+}
+
+pub(super) fn add(lhs: &Operand, rhs: &Operand, wrapped: bool) -> OpResult {
+    if wrapped {
+        any_integer!(lhs, rhs, |a, b| a.wrapping_add(*b));
+        return Err(Fault::OperandTypes);
+    }
+    any_integer!(lhs, rhs, |a, b| a.checked_add(*b).ok_or(Fault::Overflow)?);
+    match (lhs, rhs) {
+        (Operand::Field(a), Operand::Field(b)) => field(to_fp(a)? + to_fp(b)?),
+        (Operand::Group(a), Operand::Group(b)) => {
+            Ok(Operand::Group(from_group(to_group(a)? + to_group(b)?)))
+        }
+        (Operand::Scalar(a), Operand::Scalar(b)) => {
+            Ok(Operand::Scalar(from_scalar(to_scalar(a)? + to_scalar(b)?)))
+        }
+        _ => Err(Fault::OperandTypes),
+    }
+}
+
+pub(super) fn sub(lhs: &Operand, rhs: &Operand, wrapped: bool) -> OpResult {
+    if wrapped {
+        any_integer!(lhs, rhs, |a, b| a.wrapping_sub(*b));
+        return Err(Fault::OperandTypes);
+    }
+    any_integer!(lhs, rhs, |a, b| a.checked_sub(*b).ok_or(Fault::Overflow)?);
+    match (lhs, rhs) {
+        (Operand::Field(a), Operand::Field(b)) => field(to_fp(a)? - to_fp(b)?),
+        (Operand::Group(a), Operand::Group(b)) => {
+            Ok(Operand::Group(from_group(to_group(a)? - to_group(b)?)))
+        }
+        (Operand::Scalar(a), Operand::Scalar(b)) => {
+            Ok(Operand::Scalar(from_scalar(to_scalar(a)? - to_scalar(b)?)))
+        }
+        _ => Err(Fault::OperandTypes),
+    }
+}
+
+pub(super) fn mul(lhs: &Operand, rhs: &Operand, wrapped: bool) -> OpResult {
+    if wrapped {
+        any_integer!(lhs, rhs, |a, b| a.wrapping_mul(*b));
+        return Err(Fault::OperandTypes);
+    }
+    any_integer!(lhs, rhs, |a, b| a.checked_mul(*b).ok_or(Fault::Overflow)?);
+    match (lhs, rhs) {
+        (Operand::Field(a), Operand::Field(b)) => field(to_fp(a)? * to_fp(b)?),
+        (Operand::Group(a), Operand::Scalar(b)) | (Operand::Scalar(b), Operand::Group(a)) => {
+            Ok(Operand::Group(from_group(to_group(a)? * to_scalar(b)?)))
+        }
+        _ => Err(Fault::OperandTypes),
+    }
+}
+
+pub(super) fn div(lhs: &Operand, rhs: &Operand, wrapped: bool) -> OpResult {
+    if wrapped {
+        any_integer!(lhs, rhs, |a, b| {
+            if *b == 0 {
+                return Err(Fault::DivisionByZero);
+            }
+            a.wrapping_div(*b)
+        });
+        return Err(Fault::OperandTypes);
+    }
+    any_integer!(lhs, rhs, |a, b| {
+        if *b == 0 {
+            return Err(Fault::DivisionByZero);
+        }
+        a.checked_div(*b).ok_or(Fault::Overflow)?
+    });
+    match (lhs, rhs) {
+        (Operand::Field(a), Operand::Field(b)) => {
+            let b = to_fp(b)?;
+            if b.is_zero() {
+                return Err(Fault::DivisionByZero);
+            }
+            field(to_fp(a)? / b)
+        }
+        _ => Err(Fault::OperandTypes),
+    }
+}
+
+pub(super) fn rem(lhs: &Operand, rhs: &Operand, wrapped: bool) -> OpResult {
+    if wrapped {
+        any_integer!(lhs, rhs, |a, b| {
+            if *b == 0 {
+                return Err(Fault::DivisionByZero);
+            }
+            a.wrapping_rem(*b)
+        });
+    } else {
+        any_integer!(lhs, rhs, |a, b| {
+            if *b == 0 {
+                return Err(Fault::DivisionByZero);
+            }
+            a.checked_rem(*b).ok_or(Fault::Overflow)?
+        });
+    }
+    Err(Fault::OperandTypes)
+}
+
+/// Remainder of unsigned integers, which cannot overflow.
+pub(super) fn modulo(lhs: &Operand, rhs: &Operand) -> OpResult {
+    unsigned_integer!(lhs, rhs, |a, b| a
+        .checked_rem(*b)
+        .ok_or(Fault::DivisionByZero)?);
+    Err(Fault::OperandTypes)
+}
+
+pub(super) fn pow(lhs: &Operand, rhs: &Operand, wrapped: bool) -> OpResult {
+    if let (Operand::Field(a), Operand::Field(b)) = (lhs, rhs) {
+        if wrapped {
+            return Err(Fault::OperandTypes);
+        }
+        return field(to_fp(a)?.pow(to_fp(b)?.0.as_limbs()));
+    }
+    let exponent = exponent(rhs)?;
+    if wrapped {
+        any_integer!(lhs, |a| a.wrapping_pow(exponent));
+    } else {
+        any_integer!(lhs, |a| a.checked_pow(exponent).ok_or(Fault::Overflow)?);
+    }
+    Err(Fault::OperandTypes)
+}
+
+pub(super) fn shl(lhs: &Operand, rhs: &Operand, wrapped: bool) -> OpResult {
+    let amount = exponent(rhs)?;
+    if wrapped {
+        any_integer!(lhs, |a| a.wrapping_shl(amount));
+    } else {
+        any_integer!(lhs, |a| a.checked_shl(amount).ok_or(Fault::Overflow)?);
+    }
+    Err(Fault::OperandTypes)
+}
+
+pub(super) fn shr(lhs: &Operand, rhs: &Operand, wrapped: bool) -> OpResult {
+    let amount = exponent(rhs)?;
+    if wrapped {
+        any_integer!(lhs, |a| a.wrapping_shr(amount));
+    } else {
+        any_integer!(lhs, |a| a.checked_shr(amount).ok_or(Fault::Overflow)?);
+    }
+    Err(Fault::OperandTypes)
+}
+
+/// Shifts right without extending the sign, reading signed integers as unsigned ones of the same width.
+pub(super) fn lshr(lhs: &Operand, rhs: &Operand) -> OpResult {
+    let amount = exponent(rhs)?;
+    macro_rules! logical {
+        ($($variant:ident as $unsigned:ty),*) => {
+            match lhs {
+                $(Operand::$variant(a) => {
+                    let shifted = (*a as $unsigned).checked_shr(amount).ok_or(Fault::Overflow)?;
+                    return Ok(Operand::$variant(shifted as _));
+                })*
+                _ => {}
+            }
+        };
+    }
+    logical!(
+        U8 as u8,
+        U16 as u16,
+        U32 as u32,
+        U64 as u64,
+        U128 as u128,
+        I8 as u8,
+        I16 as u16,
+        I32 as u32,
+        I64 as u64,
+        I128 as u128
+    );
+    Err(Fault::OperandTypes)
+}
+
+pub(super) fn and(lhs: &Operand, rhs: &Operand) -> OpResult {
+    any_integer!(lhs, rhs, |a, b| a & b);
+    match (lhs, rhs) {
+        (Operand::Boolean(a), Operand::Boolean(b)) => Ok(Operand::Boolean(*a && *b)),
+        _ => Err(Fault::OperandTypes),
+    }
+}
+
+pub(super) fn or(lhs: &Operand, rhs: &Operand) -> OpResult {
+    any_integer!(lhs, rhs, |a, b| a | b);
+    match (lhs, rhs) {
+        (Operand::Boolean(a), Operand::Boolean(b)) => Ok(Operand::Boolean(*a || *b)),
+        _ => Err(Fault::OperandTypes),
+    }
+}
+
+pub(super) fn xor(lhs: &Operand, rhs: &Operand) -> OpResult {
+    any_integer!(lhs, rhs, |a, b| a ^ b);
+    match (lhs, rhs) {
+        (Operand::Boolean(a), Operand::Boolean(b)) => Ok(Operand::Boolean(a ^ b)),
+        _ => Err(Fault::OperandTypes),
+    }
+}
+
+pub(super) fn nand(lhs: &Operand, rhs: &Operand) -> OpResult {
+    match (lhs, rhs) {
+        (Operand::Boolean(a), Operand::Boolean(b)) => Ok(Operand::Boolean(!(*a && *b))),
+        _ => Err(Fault::OperandTypes),
+    }
+}
+
+pub(super) fn nor(lhs: &Operand, rhs: &Operand) -> OpResult {
+    match (lhs, rhs) {
+        (Operand::Boolean(a), Operand::Boolean(b)) => Ok(Operand::Boolean(!(*a || *b))),
+        _ => Err(Fault::OperandTypes),
+    }
+}
+
+/// Orders integers of the same type, and fields or scalars by their canonical integer value.
+pub(super) fn compare(lhs: &Operand, rhs: &Operand) -> Result<Ordering, Fault> {
+    macro_rules! compare_integers {
+        ($($variant:ident),*) => {
+            match (lhs, rhs) {
+                $((Operand::$variant(a), Operand::$variant(b)) => return Ok(a.cmp(b)),)*
+                _ => {}
+            }
+        };
+    }
+    compare_integers!(U8, U16, U32, U64, U128, I8, I16, I32, I64, I128);
+    match (lhs, rhs) {
+        (Operand::Field(a), Operand::Field(b)) => Ok(to_fp(a)?.cmp(&to_fp(b)?)),
+        (Operand::Scalar(a), Operand::Scalar(b)) => Ok(to_scalar(a)?.cmp(&to_scalar(b)?)),
+        _ => Err(Fault::OperandTypes),
+    }
+}
+
+/// Compares canonical operands of the same type.
+pub(super) fn equals(lhs: &Operand, rhs: &Operand) -> Result<bool, Fault> {
+    if mem::discriminant(lhs) != mem::discriminant(rhs) {
+        return Err(Fault::OperandTypes);
+    }
+    Ok(lhs == rhs)
+}
+
+pub(super) fn ternary(cond: &Operand, lhs: &Operand, rhs: &Operand) -> OpResult {
+    match cond {
+        Operand::Boolean(cond) if mem::discriminant(lhs) == mem::discriminant(rhs) => {
+            Ok(if *cond { lhs.clone() } else { rhs.clone() })
+        }
+        _ => Err(Fault::OperandTypes),
+    }
+}
+
+/// Reads the entry at `index` of a struct.
+pub(super) fn member(operand: &Operand, index: u32) -> OpResult {
+    match operand {
+        Operand::Struct(Struct { values }) => values
+            .get(index as usize)
+            .cloned()
+            .ok_or_else(|| Fault::Error(IRError::missing_entry("member", index).into())),
+        _ => Err(Fault::OperandTypes),
+    }
+}
+
+/// Reads the entry at `index` of a record: its owner, its gates, its data entries in order, then
+/// its nonce.
+pub(super) fn record_field(operand: &Operand, index: u32) -> OpResult {
+    let Operand::Record(record) = operand else {
+        return Err(Fault::OperandTypes);
+    };
+    let index = index as usize;
+    let data = record.data.len();
+    Ok(match index {
+        0 => Operand::Address(record.owner.clone()),
+        1 => Operand::U64(record.gates),
+        _ if index < data + 2 => record.data[index - 2].value.clone(),
+        _ if index == data + 2 => Operand::Group(record.nonce.clone()),
+        _ => {
+            return Err(Fault::Error(
+                IRError::missing_entry("record.field", index).into(),
+            ))
+        }
+    })
+}
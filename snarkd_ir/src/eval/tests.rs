@@ -0,0 +1,681 @@
+use snarkd_crypto::bls12_377::fp;
+
+use crate::*;
+
+fn field(value: u64) -> Operand {
+    Operand::Field(Field {
+        negate: false,
+        values: vec![value],
+    })
+}
+
+fn group(value: u64) -> Operand {
+    Operand::Group(Group::Single(Field {
+        negate: false,
+        values: vec![value],
+    }))
+}
+
+fn scalar(value: u64) -> Operand {
+    Operand::Scalar(Scalar {
+        values: vec![value],
+    })
+}
+
+fn binary(lhs: Operand, rhs: Operand, dest: u32) -> BinaryData {
+    BinaryData { lhs, rhs, dest }
+}
+
+fn run(instructions: Vec<Instruction>) -> Result<Registers> {
+    Function {
+        argument_start_variable: 0,
+        instructions,
+    }
+    .evaluate(Registers::default())
+}
+
+/// Runs a single instruction writing `r0` and returns its value.
+fn eval(instruction: Instruction) -> Result<Operand> {
+    Ok(run(vec![instruction])?.get(0).unwrap().clone())
+}
+
+#[test]
+fn checked_and_wrapped_integers() {
+    use Instruction::*;
+    assert!(eval(Add(binary(Operand::U8(200), Operand::U8(56), 0))).is_err());
+    assert_eq!(
+        eval(AddWrapped(binary(Operand::U8(200), Operand::U8(56), 0))).unwrap(),
+        Operand::U8(0)
+    );
+    assert!(eval(Sub(binary(Operand::U32(1), Operand::U32(2), 0))).is_err());
+    assert_eq!(
+        eval(SubWrapped(binary(Operand::U32(1), Operand::U32(2), 0))).unwrap(),
+        Operand::U32(u32::MAX)
+    );
+    assert!(eval(Mul(binary(Operand::I16(300), Operand::I16(300), 0))).is_err());
+    assert!(eval(Div(binary(Operand::I8(i8::MIN), Operand::I8(-1), 0))).is_err());
+    assert_eq!(
+        eval(DivWrapped(binary(Operand::I8(i8::MIN), Operand::I8(-1), 0))).unwrap(),
+        Operand::I8(i8::MIN)
+    );
+    assert!(eval(DivWrapped(binary(Operand::U64(1), Operand::U64(0), 0))).is_err());
+    assert!(eval(Rem(binary(Operand::U64(1), Operand::U64(0), 0))).is_err());
+    assert_eq!(
+        eval(Mod(binary(Operand::U16(17), Operand::U16(5), 0))).unwrap(),
+        Operand::U16(2)
+    );
+    assert!(eval(Mod(binary(Operand::I16(17), Operand::I16(5), 0))).is_err());
+    assert!(eval(Abs(UnaryData {
+        operand: Operand::I32(i32::MIN),
+        dest: 0
+    }))
+    .is_err());
+    assert_eq!(
+        eval(AbsWrapped(UnaryData {
+            operand: Operand::I32(i32::MIN),
+            dest: 0
+        }))
+        .unwrap(),
+        Operand::I32(i32::MIN)
+    );
+    assert_eq!(
+        eval(Neg(UnaryData {
+            operand: Operand::I64(5),
+            dest: 0
+        }))
+        .unwrap(),
+        Operand::I64(-5)
+    );
+}
+
+#[test]
+fn pow_and_shifts() {
+    use Instruction::*;
+    assert_eq!(
+        eval(Pow(binary(Operand::U64(3), Operand::U8(4), 0))).unwrap(),
+        Operand::U64(81)
+    );
+    assert!(eval(Pow(binary(Operand::U8(2), Operand::U8(8), 0))).is_err());
+    assert_eq!(
+        eval(PowWrapped(binary(Operand::U8(2), Operand::U8(8), 0))).unwrap(),
+        Operand::U8(0)
+    );
+    assert!(eval(Pow(binary(Operand::U8(2), Operand::U64(2), 0))).is_err());
+    assert_eq!(
+        eval(Shl(binary(Operand::U8(1), Operand::U32(7), 0))).unwrap(),
+        Operand::U8(128)
+    );
+    assert!(eval(Shl(binary(Operand::U8(1), Operand::U32(8), 0))).is_err());
+    assert_eq!(
+        eval(ShlWrapped(binary(Operand::U8(1), Operand::U32(9), 0))).unwrap(),
+        Operand::U8(2)
+    );
+    assert_eq!(
+        eval(Shr(binary(Operand::I8(-128), Operand::U8(7), 0))).unwrap(),
+        Operand::I8(-1)
+    );
+    assert!(eval(Shr(binary(Operand::I8(-128), Operand::U8(8), 0))).is_err());
+}
+
+#[test]
+fn bitwise_and_comparisons() {
+    use Instruction::*;
+    assert_eq!(
+        eval(Xor(binary(Operand::U8(0b1100), Operand::U8(0b1010), 0))).unwrap(),
+        Operand::U8(0b0110)
+    );
+    assert_eq!(
+        eval(Nand(binary(
+            Operand::Boolean(true),
+            Operand::Boolean(true),
+            0
+        )))
+        .unwrap(),
+        Operand::Boolean(false)
+    );
+    assert!(eval(Nor(binary(Operand::U8(1), Operand::U8(1), 0))).is_err());
+    assert_eq!(
+        eval(Lt(binary(Operand::I32(-1), Operand::I32(0), 0))).unwrap(),
+        Operand::Boolean(true)
+    );
+    assert_eq!(
+        eval(Gte(binary(field(7), field(7), 0))).unwrap(),
+        Operand::Boolean(true)
+    );
+    assert!(eval(Gt(binary(Operand::U8(1), Operand::U16(1), 0))).is_err());
+}
+
+#[test]
+fn field_arithmetic() {
+    use Instruction::*;
+    let minus_one = Operand::Field(Field {
+        negate: true,
+        values: vec![1],
+    });
+    assert_eq!(
+        eval(Add(binary(minus_one.clone(), field(1), 0))).unwrap(),
+        field(0)
+    );
+    assert_eq!(
+        eval(IsEq(binary(
+            minus_one,
+            Operand::Field(Field {
+                negate: false,
+                values: vec![
+                    0x8508c00000000000,
+                    0x170b5d4430000000,
+                    0x1ef3622fba094800,
+                    0x1a22d9f300f5138f,
+                    0xc63b05c06ca1493b,
+                    0x01ae3a4617c510ea,
+                ],
+            }),
+            0
+        )))
+        .unwrap(),
+        Operand::Boolean(true)
+    );
+
+    let registers = run(vec![
+        Div(binary(field(1), field(3), 0)),
+        Mul(binary(Operand::Ref(0), field(3), 1)),
+        Square(UnaryData {
+            operand: field(5),
+            dest: 2,
+        }),
+        Sqrt(UnaryData {
+            operand: Operand::Ref(2),
+            dest: 3,
+        }),
+        Square(UnaryData {
+            operand: Operand::Ref(3),
+            dest: 4,
+        }),
+        Pow(binary(field(2), field(10), 5)),
+    ])
+    .unwrap();
+    assert_eq!(registers.get(1), Some(&field(1)));
+    assert_eq!(registers.get(4), Some(&field(25)));
+    assert_eq!(registers.get(5), Some(&field(1024)));
+
+    assert!(eval(Div(binary(field(1), field(0), 0))).is_err());
+    assert!(eval(Inv(UnaryData {
+        operand: field(0),
+        dest: 0
+    }))
+    .is_err());
+    assert!(eval(Add(binary(field(1), Operand::Boolean(true), 0))).is_err());
+    assert!(eval(Add(binary(
+        Operand::Field(Field {
+            negate: false,
+            values: vec![u64::MAX; 6],
+        }),
+        field(1),
+        0
+    )))
+    .is_err());
+}
+
+#[test]
+fn group_and_scalar_arithmetic() {
+    use Instruction::*;
+    let registers = run(vec![
+        Add(binary(group(1), group(2), 0)),
+        Mul(binary(group(1), scalar(3), 1)),
+        IsEq(binary(Operand::Ref(0), Operand::Ref(1), 2)),
+        Double(UnaryData {
+            operand: group(3),
+            dest: 3,
+        }),
+        Add(binary(scalar(3), scalar(3), 4)),
+        Mul(binary(scalar(6), group(1), 5)),
+        IsEq(binary(Operand::Ref(3), Operand::Ref(5), 6)),
+        Neg(UnaryData {
+            operand: group(2),
+            dest: 7,
+        }),
+        Add(binary(Operand::Ref(7), group(2), 8)),
+    ])
+    .unwrap();
+    assert_eq!(registers.get(2), Some(&Operand::Boolean(true)));
+    assert_eq!(registers.get(4), Some(&scalar(6)));
+    assert_eq!(registers.get(6), Some(&Operand::Boolean(true)));
+    assert_eq!(registers.get(8), Some(&group(0)));
+
+    // a tuple given by its x-coordinate and sign matches the same point given by coordinates
+    let point = match registers.get(1).unwrap() {
+        Operand::Group(Group::Tuple(point)) => point.clone(),
+        _ => panic!("expected an affine point"),
+    };
+    let from_x = |right| {
+        Operand::Group(Group::Tuple(TupleGroup {
+            left: point.left.clone(),
+            right,
+        }))
+    };
+    let registers = run(vec![
+        IsEq(binary(from_x(GroupCoordinate::SignHigh), group(3), 0)),
+        IsEq(binary(from_x(GroupCoordinate::SignLow), group(3), 1)),
+    ])
+    .unwrap();
+    assert_ne!(registers.get(0), registers.get(1));
+    assert!(eval(IsEq(binary(
+        Operand::Group(Group::Tuple(TupleGroup {
+            left: point.left.clone(),
+            right: point.left,
+        })),
+        group(1),
+        0
+    )))
+    .is_err());
+}
+
+#[test]
+fn assertions_and_ternary() {
+    use Instruction::*;
+    assert!(run(vec![AssertEq(AssertData {
+        lhs: Operand::U8(1),
+        rhs: Operand::U8(1),
+    })])
+    .is_ok());
+    assert!(run(vec![AssertEq(AssertData {
+        lhs: Operand::U8(1),
+        rhs: Operand::U8(2),
+    })])
+    .is_err());
+    assert!(run(vec![AssertNeq(AssertData {
+        lhs: field(2),
+        rhs: field(2),
+    })])
+    .is_err());
+    assert!(run(vec![AssertEq(AssertData {
+        lhs: Operand::U8(1),
+        rhs: Operand::U16(1),
+    })])
+    .is_err());
+    assert_eq!(
+        eval(Ternary(TernaryData {
+            cond: Operand::Boolean(false),
+            lhs: Operand::U8(1),
+            rhs: Operand::U8(2),
+            dest: 0,
+        }))
+        .unwrap(),
+        Operand::U8(2)
+    );
+    assert!(eval(Ternary(TernaryData {
+        cond: Operand::U8(0),
+        lhs: Operand::U8(1),
+        rhs: Operand::U8(2),
+        dest: 0,
+    }))
+    .is_err());
+}
+
+#[test]
+fn hash_and_commit() {
+    use Instruction::*;
+    let hash = |method, chunk| {
+        eval(Hash(HashData {
+            method,
+            chunk,
+            dest: 0,
+        }))
+    };
+    let commit = |method, chunk, randomness| {
+        eval(Commit(CommitData {
+            method,
+            chunk,
+            randomness,
+            dest: 0,
+        }))
+    };
+    assert_eq!(
+        hash(HashMethod::HashPsd2, Operand::U64(1)).unwrap(),
+        hash(HashMethod::HashPsd2, Operand::U64(1)).unwrap()
+    );
+    assert_ne!(
+        hash(HashMethod::HashPsd2, Operand::U64(1)).unwrap(),
+        hash(HashMethod::HashPsd2, Operand::U64(2)).unwrap()
+    );
+    assert!(hash(HashMethod::HashPed64, Operand::U64(1)).is_ok());
+    assert!(hash(HashMethod::HashPed64, Operand::U128(1)).is_err());
+    let methods = [
+        HashMethod::HashBhp256,
+        HashMethod::HashBhp512,
+        HashMethod::HashBhp768,
+        HashMethod::HashBhp1024,
+        HashMethod::HashPed64,
+        HashMethod::HashPed128,
+        HashMethod::HashPsd2,
+        HashMethod::HashPsd4,
+        HashMethod::HashPsd8,
+    ];
+    let hashes = methods.map(|method| hash(method, Operand::U8(1)).unwrap());
+    for (i, value) in hashes.iter().enumerate() {
+        assert!(!hashes[..i].contains(value));
+    }
+
+    // Operands with the same bits but different types or shapes hash differently.
+    let pairs = [
+        (Operand::U8(1), Operand::I8(1)),
+        (Operand::U8(1), Operand::U16(1)),
+        (Operand::String("a".into()), Operand::String("a\0".into())),
+        (
+            Operand::Struct(Struct {
+                values: vec![Operand::U8(1), Operand::U8(2)],
+            }),
+            Operand::U16(0x0201),
+        ),
+        (
+            Operand::Struct(Struct {
+                values: vec![Operand::Struct(Struct {
+                    values: vec![Operand::U8(1)],
+                })],
+            }),
+            Operand::Struct(Struct {
+                values: vec![Operand::U8(1)],
+            }),
+        ),
+    ];
+    for method in [
+        HashMethod::HashBhp256,
+        HashMethod::HashPed128,
+        HashMethod::HashPsd4,
+    ] {
+        for (a, b) in &pairs {
+            assert_ne!(
+                hash(method, a.clone()).unwrap(),
+                hash(method, b.clone()).unwrap()
+            );
+        }
+    }
+
+    let randomness = |value| Scalar {
+        values: vec![value],
+    };
+    assert_ne!(
+        commit(CommitMethod::CommitPed128, Operand::U64(1), randomness(1)).unwrap(),
+        commit(CommitMethod::CommitPed128, Operand::U64(1), randomness(2)).unwrap()
+    );
+    assert_eq!(
+        commit(CommitMethod::CommitPed64, Operand::U64(1), randomness(0)).unwrap(),
+        hash(HashMethod::HashPed64, Operand::U64(1)).unwrap()
+    );
+    assert_eq!(
+        commit(CommitMethod::CommitBhp512, Operand::U64(1), randomness(0)).unwrap(),
+        hash(HashMethod::HashBhp512, Operand::U64(1)).unwrap()
+    );
+    assert_ne!(
+        commit(CommitMethod::CommitBhp1024, Operand::U64(1), randomness(1)).unwrap(),
+        commit(CommitMethod::CommitBhp1024, Operand::U64(1), randomness(2)).unwrap()
+    );
+}
+
+#[test]
+fn program_inputs() {
+    let program = Program {
+        header: Header {
+            private_inputs: vec![InputType {
+                variable: 0,
+                name: "a".into(),
+                type_: Type::U8,
+            }],
+            public_inputs: vec![InputType {
+                variable: 1,
+                name: "b".into(),
+                type_: Type::U8,
+            }],
+            ..Default::default()
+        },
+        functions: vec![Function {
+            argument_start_variable: 2,
+            instructions: vec![Instruction::Add(binary(
+                Operand::Ref(0),
+                Operand::Ref(1),
+                2,
+            ))],
+        }],
+    };
+    let input = |a, b| InputData {
+        constant_inputs: vec![],
+        private_inputs: vec![InputValue {
+            name: "a".into(),
+            value: a,
+        }],
+        public_inputs: vec![InputValue {
+            name: "b".into(),
+            value: b,
+        }],
+        register_inputs: vec![],
+        public_states: vec![],
+        private_record_states: vec![],
+        private_leaf_states: vec![],
+    };
+
+    let registers = program
+        .evaluate(&input(Operand::U8(1), Operand::U8(2)))
+        .unwrap();
+    assert_eq!(registers.get(2), Some(&Operand::U8(3)));
+    assert!(program
+        .evaluate(&input(Operand::U8(1), Operand::U16(2)))
+        .is_err());
+    assert!(program
+        .evaluate(&input(Operand::U8(255), Operand::U8(2)))
+        .is_err());
+
+    let mut missing = input(Operand::U8(1), Operand::U8(2));
+    missing.public_inputs.clear();
+    assert!(program.evaluate(&missing).is_err());
+}
+
+#[test]
+fn unknown_register() {
+    assert!(run(vec![Instruction::Not(UnaryData {
+        operand: Operand::Ref(3),
+        dest: 0,
+    })])
+    .is_err());
+}
+
+#[test]
+fn calls_and_blocks() {
+    let program: Program = "
+        input private r0 a: u8
+        input public r1 b: boolean
+
+        decl f0 r2
+        0: call f1 r0, 3u8 -> r2
+        1: mask r1, 1
+        2: add.w r2, 1u8 -> r2
+        3: repeat 3, 2
+        4: call f2 r2 -> r3
+        5: add.w r3, 0u8 -> r2
+        6: call f3
+
+        decl f1 r0
+        0: mul.w r0, r1 -> r2
+        1: return r2
+
+        decl f2 r0
+        0: add.w r0, r0 -> r1
+        1: return r1
+
+        decl f3 r0
+        0: return
+    "
+    .parse()
+    .unwrap();
+    let input = |b| InputData {
+        constant_inputs: vec![],
+        private_inputs: vec![InputValue {
+            name: "a".into(),
+            value: Operand::U8(5),
+        }],
+        public_inputs: vec![InputValue {
+            name: "b".into(),
+            value: Operand::Boolean(b),
+        }],
+        register_inputs: vec![],
+        public_states: vec![],
+        private_record_states: vec![],
+        private_leaf_states: vec![],
+    };
+
+    let registers = program.evaluate(&input(true)).unwrap();
+    assert_eq!(registers.get(2), Some(&Operand::U8(128)));
+    // Registers of called functions and of blocks are not kept.
+    assert_eq!(registers.iter().count(), 3);
+    let registers = program.evaluate(&input(false)).unwrap();
+    assert_eq!(registers.get(2), Some(&Operand::U8(120)));
+
+    // A function on its own has no other functions to call.
+    assert!(program.functions[0]
+        .evaluate(registers.iter().map(|(r, v)| (r, v.clone())).collect())
+        .is_err());
+}
+
+/// Runs a single instruction written in assembly, writing `r0`, and returns its value.
+fn eval_asm(text: &str) -> Result<Operand> {
+    eval(text.parse().unwrap())
+}
+
+#[test]
+fn casts_and_bits() {
+    assert_eq!(
+        eval_asm("cast -1i8 as field -> r0").unwrap(),
+        eval_asm("neg 1field -> r0").unwrap()
+    );
+    assert_eq!(
+        eval_asm("cast -1field as i128 -> r0").unwrap(),
+        Operand::I128(-1)
+    );
+    assert_eq!(
+        eval_asm("cast 255u8 as i16 -> r0").unwrap(),
+        Operand::I16(255)
+    );
+    assert_eq!(eval_asm("cast true as u32 -> r0").unwrap(), Operand::U32(1));
+    assert_eq!(eval_asm("cast 7field as scalar -> r0").unwrap(), scalar(7));
+    assert!(eval_asm("cast 256u16 as u8 -> r0").is_err());
+    assert!(eval_asm("cast -1i8 as u8 -> r0").is_err());
+    assert!(eval_asm("cast 2u8 as boolean -> r0").is_err());
+    assert!(eval_asm("cast 1u8 as address -> r0").is_err());
+
+    // Groups are cast through their x-coordinate, and back to the point of greater y-coordinate.
+    let x = eval_asm("cast 3group as field -> r0").unwrap();
+    let point = eval_asm(&format!("cast {x} as group -> r0")).unwrap();
+    assert_eq!(
+        point,
+        eval_asm(&format!("add 0group, ({x}, +)group -> r0")).unwrap()
+    );
+    assert_eq!(
+        eval_asm("cast 3group as group -> r0").unwrap(),
+        eval_asm("add 0group, 3group -> r0").unwrap()
+    );
+    assert!(eval_asm("cast 2field as group -> r0").is_err());
+    assert_eq!(eval_asm("cast 0group as field -> r0").unwrap(), field(0));
+    assert_eq!(eval_asm("cast 0field as group -> r0").unwrap(), group(0));
+
+    let bits = |values: &[bool]| {
+        Operand::Struct(Struct {
+            values: values.iter().copied().map(Operand::Boolean).collect(),
+        })
+    };
+    assert_eq!(
+        eval_asm("to.bits -2i8 -> r0").unwrap(),
+        bits(&[false, true, true, true, true, true, true, true])
+    );
+    for (value, type_) in [
+        ("-2i8", "i8"),
+        ("-170141183460469231731687303715884105728i128", "i128"),
+        ("65535u16", "u16"),
+        ("true", "boolean"),
+        ("-5field", "field"),
+        ("9scalar", "scalar"),
+    ] {
+        let value: Operand = value.parse().unwrap();
+        let bits = eval(Instruction::ToBits(UnaryData {
+            operand: value.clone(),
+            dest: 0,
+        }))
+        .unwrap();
+        let back = eval(Instruction::FromBits(CastData {
+            operand: bits,
+            type_: type_.parse().unwrap(),
+            dest: 0,
+        }));
+        let expected = eval_asm(&format!("cast {value} as {type_} -> r0")).unwrap();
+        assert_eq!(back.unwrap(), expected);
+    }
+    let bits_of_point = eval_asm("to.bits 3group -> r0").unwrap();
+    assert_eq!(
+        eval(Instruction::FromBits(CastData {
+            operand: bits_of_point,
+            type_: Type::Group,
+            dest: 0,
+        }))
+        .unwrap(),
+        point
+    );
+    assert!(eval_asm("from.bits struct(true) as u8 -> r0").is_err());
+    let all_set = bits(&[true; fp::MODULUS_BITS as usize]);
+    assert!(eval(Instruction::FromBits(CastData {
+        operand: all_set,
+        type_: Type::Field,
+        dest: 0,
+    }))
+    .is_err());
+}
+
+#[test]
+fn logical_shift_and_entries() {
+    assert_eq!(eval_asm("lshr -1i8, 4u8 -> r0").unwrap(), Operand::I8(15));
+    assert_eq!(eval_asm("shr -1i8, 4u8 -> r0").unwrap(), Operand::I8(-1));
+    assert_eq!(
+        eval_asm("lshr 256u16, 8u32 -> r0").unwrap(),
+        Operand::U16(1)
+    );
+    assert!(eval_asm("lshr 1u8, 8u8 -> r0").is_err());
+
+    assert_eq!(
+        eval_asm("member struct(1u8, struct(true)), 1 -> r0").unwrap(),
+        "struct(true)".parse().unwrap()
+    );
+    assert!(eval_asm("member struct(1u8), 1 -> r0").is_err());
+
+    let owner = Address {
+        address: vec![7; 32],
+    };
+    let record = Operand::Record(Box::new(Record {
+        owner: owner.clone(),
+        owner_visibility: Visibility::Private,
+        gates: 5,
+        gates_visibility: Visibility::Public,
+        data: vec![
+            VisibleData {
+                value: Operand::U8(1),
+                visibility: Visibility::Private,
+            },
+            VisibleData {
+                value: field(2),
+                visibility: Visibility::Public,
+            },
+        ],
+        nonce: Group::Single(Field {
+            negate: false,
+            values: vec![0],
+        }),
+        nonce_visibility: Visibility::Constant,
+    }));
+    let entry = |index| eval_asm(&format!("record.field {record}, {index} -> r0"));
+    assert_eq!(entry(0).unwrap(), Operand::Address(owner));
+    assert_eq!(entry(1).unwrap(), Operand::U64(5));
+    assert_eq!(entry(2).unwrap(), Operand::U8(1));
+    assert_eq!(entry(3).unwrap(), field(2));
+    assert_eq!(entry(4).unwrap(), group(0));
+    assert!(entry(5).is_err());
+
+    assert!(run(vec!["assert true".parse().unwrap()]).is_ok());
+    assert!(run(vec!["assert false".parse().unwrap()]).is_err());
+    assert!(run(vec!["assert 1u8".parse().unwrap()]).is_err());
+}
@@ -0,0 +1,165 @@
+use ruint::Uint;
+use snarkd_crypto::bls12_377::{
+    self, fp, scalar, Affine, Field as _, Fp, G1Affine, G1Projective, Projective,
+};
+
+use crate::{Field, Group, GroupCoordinate, Operand, Record, Result, Scalar, Struct, TupleGroup};
+use snarkd_errors::IRError;
+
+fn to_limbs<const BITS: usize, const LIMBS: usize>(value: Uint<BITS, LIMBS>) -> Vec<u64> {
+    let limbs = value.as_limbs();
+    let len = limbs.iter().rposition(|x| *x != 0).map_or(1, |x| x + 1);
+    limbs[..len].to_vec()
+}
+
+/// Reads the magnitude of `field`, ignoring its sign.
+fn to_fp_magnitude(field: &Field) -> Result<Fp> {
+    Uint::checked_from_limbs_slice(&field.values)
+        .filter(|x| *x < fp::MODULUS)
+        .map(Fp)
+        .ok_or_else(|| IRError::invalid_field(field).into())
+}
+
+pub(super) fn to_fp(field: &Field) -> Result<Fp> {
+    let value = to_fp_magnitude(field)?;
+    Ok(if field.negate { -value } else { value })
+}
+
+pub(super) fn from_fp(value: Fp) -> Field {
+    Field {
+        negate: false,
+        values: to_limbs(value.0),
+    }
+}
+
+/// Reads `field` as an element of the scalar field, which circuits are proven over. Values are
+/// read in the base field first, so negative literals only fit when they are zero.
+pub(crate) fn to_scalar_field(field: &Field) -> Result<bls12_377::Scalar> {
+    let value = to_fp(field)?.0;
+    Uint::checked_from_limbs_slice(value.as_limbs())
+        .filter(|x| *x < scalar::MODULUS)
+        .map(bls12_377::Scalar)
+        .ok_or_else(|| IRError::unsupported_circuit_field(field).into())
+}
+
+pub(crate) fn from_scalar_field(value: bls12_377::Scalar) -> Field {
+    Field {
+        negate: false,
+        values: to_limbs(value.0),
+    }
+}
+
+pub(super) fn to_scalar(value: &Scalar) -> Result<bls12_377::Scalar> {
+    Uint::checked_from_limbs_slice(&value.values)
+        .filter(|x| *x < scalar::MODULUS)
+        .map(bls12_377::Scalar)
+        .ok_or_else(|| IRError::invalid_scalar(value).into())
+}
+
+pub(super) fn from_scalar(value: bls12_377::Scalar) -> Scalar {
+    Scalar {
+        values: to_limbs(value.0),
+    }
+}
+
+/// Reads a group element. `Single(v)` is `v` times the generator, and a tuple is an affine point.
+/// The y-coordinate of a tuple may be given by its sign, which selects the greatest root when inferred.
+pub(super) fn to_group(group: &Group) -> Result<G1Projective> {
+    let invalid = || IRError::invalid_group(group);
+    match group {
+        Group::Single(value) => {
+            let bits = to_fp_magnitude(value)?
+                .0
+                .as_limbs()
+                .iter()
+                .rev()
+                .flat_map(|limb| (0..64).rev().map(move |i| limb >> i & 1 == 1))
+                .collect();
+            let point = G1Affine::prime_subgroup_generator().mul_bits(bits);
+            Ok(if value.negate { -point } else { point })
+        }
+        Group::Tuple(TupleGroup { left, right }) => {
+            let x = match left {
+                GroupCoordinate::GroupField(x) => to_fp(x)?,
+                _ => return Err(invalid().into()),
+            };
+            let point = match right {
+                GroupCoordinate::GroupField(y) => Some(G1Affine::new(x, to_fp(y)?, false)),
+                GroupCoordinate::SignHigh | GroupCoordinate::Inferred => {
+                    G1Affine::from_x_coordinate(x, true)
+                }
+                GroupCoordinate::SignLow => G1Affine::from_x_coordinate(x, false),
+            }
+            .filter(|x| x.is_on_curve() && x.is_in_correct_subgroup_assuming_on_curve())
+            .ok_or_else(invalid)?;
+            Ok(point.to_projective())
+        }
+    }
+}
+
+/// Writes a group element as its affine coordinates, or as `Single(0)` for the identity.
+pub(super) fn from_group(value: G1Projective) -> Group {
+    let point = value.to_affine();
+    if point.is_zero() {
+        return Group::Single(from_fp(Fp::ZERO));
+    }
+    Group::Tuple(TupleGroup {
+        left: GroupCoordinate::GroupField(from_fp(point.x)),
+        right: GroupCoordinate::GroupField(from_fp(point.y)),
+    })
+}
+
+/// Validates `operand` and rewrites its fields, scalars and groups in a single representation,
+/// so equal values compare equal.
+pub(crate) fn canonicalize(
+    operand: &Operand,
+    resolve: &impl Fn(u32) -> Result<Operand>,
+) -> Result<Operand> {
+    Ok(match operand {
+        Operand::Ref(register) => resolve(*register)?,
+        Operand::Field(v) => Operand::Field(from_fp(to_fp(v)?)),
+        Operand::Scalar(v) => Operand::Scalar(from_scalar(to_scalar(v)?)),
+        Operand::Group(v) => Operand::Group(from_group(to_group(v)?)),
+        Operand::Struct(v) => Operand::Struct(Struct {
+            values: v
+                .values
+                .iter()
+                .map(|x| canonicalize(x, resolve))
+                .collect::<Result<_>>()?,
+        }),
+        Operand::Record(v) => {
+            let mut record = Record::clone(v);
+            for entry in &mut record.data {
+                entry.value = canonicalize(&entry.value, resolve)?;
+            }
+            record.nonce = from_group(to_group(&record.nonce)?);
+            Operand::Record(Box::new(record))
+        }
+        _ => operand.clone(),
+    })
+}
+
+/// Name of the type of `operand`, for diagnostics.
+pub(super) fn kind(operand: &Operand) -> &'static str {
+    match operand {
+        Operand::Address(_) => "address",
+        Operand::Boolean(_) => "boolean",
+        Operand::Field(_) => "field",
+        Operand::Group(_) => "group",
+        Operand::U8(_) => "u8",
+        Operand::U16(_) => "u16",
+        Operand::U32(_) => "u32",
+        Operand::U64(_) => "u64",
+        Operand::U128(_) => "u128",
+        Operand::I8(_) => "i8",
+        Operand::I16(_) => "i16",
+        Operand::I32(_) => "i32",
+        Operand::I64(_) => "i64",
+        Operand::I128(_) => "i128",
+        Operand::Ref(_) => "register",
+        Operand::Scalar(_) => "scalar",
+        Operand::String(_) => "string",
+        Operand::Record(_) => "record",
+        Operand::Struct(_) => "struct",
+    }
+}
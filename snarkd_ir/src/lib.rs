@@ -2,14 +2,27 @@
 
 mod ir;
 
+mod asm;
+
+mod compile;
+pub use compile::*;
+
+mod eval;
+pub use eval::*;
+
 mod program;
 pub use program::*;
 
+mod validate;
+
 mod opcode;
 pub use opcode::*;
 
 mod operand;
 pub use operand::*;
 
+mod optimize;
+pub use optimize::*;
+
 #[cfg(test)]
 mod tests;
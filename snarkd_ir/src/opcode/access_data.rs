@@ -0,0 +1,45 @@
+use std::fmt;
+
+use super::ir;
+use crate::Operand;
+
+use snarkd_errors::{Error, IRError, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessData {
+    pub operand: Operand,
+    /// Position of the entry within the struct or record.
+    pub index: u32,
+    pub dest: u32,
+}
+
+impl TryFrom<ir::AccessData> for AccessData {
+    type Error = Error;
+
+    fn try_from(value: ir::AccessData) -> Result<Self> {
+        Ok(Self {
+            operand: value
+                .operand
+                .ok_or_else(|| IRError::missing_operand("entry access"))?
+                .try_into()?,
+            index: value.index,
+            dest: value.dest,
+        })
+    }
+}
+
+impl From<AccessData> for ir::AccessData {
+    fn from(value: AccessData) -> Self {
+        Self {
+            operand: Some(value.operand.into()),
+            index: value.index,
+            dest: value.dest,
+        }
+    }
+}
+
+impl fmt::Display for AccessData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {} -> r{}", self.operand, self.index, self.dest)
+    }
+}
@@ -42,6 +42,6 @@ impl From<BinaryData> for ir::BinaryData {
 
 impl fmt::Display for BinaryData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}, {}, {}", self.lhs, self.rhs, self.dest)
+        write!(f, "{}, {} -> r{}", self.lhs, self.rhs, self.dest)
     }
 }
@@ -0,0 +1,53 @@
+use std::fmt;
+
+use super::ir;
+use crate::Operand;
+
+use snarkd_errors::{Error, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallData {
+    /// Index of the called function within the program.
+    pub function: u32,
+    pub arguments: Vec<Operand>,
+    pub dests: Vec<u32>,
+}
+
+impl TryFrom<ir::CallData> for CallData {
+    type Error = Error;
+
+    fn try_from(value: ir::CallData) -> Result<Self> {
+        Ok(Self {
+            function: value.function,
+            arguments: value
+                .arguments
+                .into_iter()
+                .map(|v| v.try_into())
+                .collect::<Result<_>>()?,
+            dests: value.dests,
+        })
+    }
+}
+
+impl From<CallData> for ir::CallData {
+    fn from(value: CallData) -> Self {
+        Self {
+            function: value.function,
+            arguments: value.arguments.into_iter().map(|v| v.into()).collect(),
+            dests: value.dests,
+        }
+    }
+}
+
+impl fmt::Display for CallData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "f{}", self.function)?;
+        for (i, argument) in self.arguments.iter().enumerate() {
+            write!(f, "{}{argument}", if i == 0 { " " } else { ", " })?;
+        }
+        for (i, dest) in self.dests.iter().enumerate() {
+            write!(f, "{}r{dest}", if i == 0 { " -> " } else { ", " })?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use std::fmt;
+
+use super::ir;
+use crate::{Operand, Type};
+
+use snarkd_errors::{Error, IRError, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CastData {
+    pub operand: Operand,
+    pub type_: Type,
+    pub dest: u32,
+}
+
+impl TryFrom<ir::CastData> for CastData {
+    type Error = Error;
+
+    fn try_from(value: ir::CastData) -> Result<Self> {
+        Ok(Self {
+            operand: value
+                .operand
+                .ok_or_else(|| IRError::missing_operand("conversion"))?
+                .try_into()?,
+            type_: value
+                .r#type
+                .ok_or_else(|| IRError::unset("CastData type"))?
+                .try_into()?,
+            dest: value.dest,
+        })
+    }
+}
+
+impl From<CastData> for ir::CastData {
+    fn from(value: CastData) -> Self {
+        Self {
+            operand: Some(value.operand.into()),
+            r#type: Some(value.type_.into()),
+            dest: value.dest,
+        }
+    }
+}
+
+impl fmt::Display for CastData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} as {} -> r{}", self.operand, self.type_, self.dest)
+    }
+}
@@ -60,10 +60,6 @@ impl From<CommitData> for ir::opcode::CommitData {
 
 impl fmt::Display for CommitData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}, {}, {}, {}",
-            self.method, self.chunk, self.randomness, self.dest
-        )
+        write!(f, "{}, {} -> r{}", self.chunk, self.randomness, self.dest)
     }
 }
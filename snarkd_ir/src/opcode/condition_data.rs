@@ -0,0 +1,38 @@
+use std::fmt;
+
+use super::ir;
+use crate::Operand;
+
+use snarkd_errors::{Error, IRError, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConditionData {
+    pub cond: Operand,
+}
+
+impl TryFrom<ir::ConditionData> for ConditionData {
+    type Error = Error;
+
+    fn try_from(value: ir::ConditionData) -> Result<Self> {
+        Ok(Self {
+            cond: value
+                .cond
+                .ok_or_else(|| IRError::missing_operand("assertion condition"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<ConditionData> for ir::ConditionData {
+    fn from(value: ConditionData) -> Self {
+        Self {
+            cond: Some(value.cond.into()),
+        }
+    }
+}
+
+impl fmt::Display for ConditionData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.cond)
+    }
+}
@@ -57,6 +57,6 @@ impl From<HashData> for ir::opcode::HashData {
 
 impl fmt::Display for HashData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}, {}, {}", self.method, self.chunk, self.dest)
+        write!(f, "{} -> r{}", self.chunk, self.dest)
     }
 }
@@ -0,0 +1,42 @@
+use std::fmt;
+
+use super::ir;
+use crate::Operand;
+
+use snarkd_errors::{Error, IRError, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaskData {
+    pub cond: Operand,
+    /// Number of instructions after the mask that only take effect when `cond` holds.
+    pub length: u32,
+}
+
+impl TryFrom<ir::MaskData> for MaskData {
+    type Error = Error;
+
+    fn try_from(value: ir::MaskData) -> Result<Self> {
+        Ok(Self {
+            cond: value
+                .cond
+                .ok_or_else(|| IRError::missing_operand("mask condition"))?
+                .try_into()?,
+            length: value.length,
+        })
+    }
+}
+
+impl From<MaskData> for ir::MaskData {
+    fn from(value: MaskData) -> Self {
+        Self {
+            cond: Some(value.cond.into()),
+            length: value.length,
+        }
+    }
+}
+
+impl fmt::Display for MaskData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {}", self.cond, self.length)
+    }
+}
@@ -1,17 +1,34 @@
+mod access_data;
+pub use access_data::*;
 mod assert_data;
 pub use assert_data::*;
 mod binary_data;
 pub use binary_data::*;
+mod call_data;
+pub use call_data::*;
+mod cast_data;
+pub use cast_data::*;
 mod commit_data;
 pub use commit_data::*;
+mod condition_data;
+pub use condition_data::*;
 mod hash_data;
 pub use hash_data::*;
+mod mask_data;
+pub use mask_data::*;
+mod repeat_data;
+pub use repeat_data::*;
+mod return_data;
+pub use return_data::*;
 mod ternary_data;
 pub use ternary_data::*;
 mod unary_data;
 pub use unary_data::*;
+mod registry;
+pub use registry::*;
 
-use std::fmt;
+#[cfg(test)]
+mod tests;
 
 use super::ir;
 use snarkd_errors::{Error, IRError, Result};
@@ -23,20 +40,28 @@ pub enum Instruction {
     Add(BinaryData),
     AddWrapped(BinaryData),
     And(BinaryData),
+    Assert(ConditionData),
     AssertEq(AssertData),
     AssertNeq(AssertData),
+    Call(CallData),
+    Cast(CastData),
     Commit(CommitData),
+    Declassify(UnaryData),
     Div(BinaryData),
     DivWrapped(BinaryData),
     Double(UnaryData),
+    FromBits(CastData),
     Gt(BinaryData),
     Gte(BinaryData),
     Hash(HashData),
     Inv(UnaryData),
     IsEq(BinaryData),
     IsNeq(BinaryData),
+    Lshr(BinaryData),
     Lt(BinaryData),
     Lte(BinaryData),
+    Mask(MaskData),
+    Member(AccessData),
     Mod(BinaryData),
     Mul(BinaryData),
     MulWrapped(BinaryData),
@@ -47,8 +72,11 @@ pub enum Instruction {
     Or(BinaryData),
     Pow(BinaryData),
     PowWrapped(BinaryData),
+    RecordField(AccessData),
     Rem(BinaryData),
     RemWrapped(BinaryData),
+    Repeat(RepeatData),
+    Return(ReturnData),
     Shl(BinaryData),
     ShlWrapped(BinaryData),
     Shr(BinaryData),
@@ -58,75 +86,10 @@ pub enum Instruction {
     Sub(BinaryData),
     SubWrapped(BinaryData),
     Ternary(TernaryData),
+    ToBits(UnaryData),
     Xor(BinaryData),
 }
 
-impl Instruction {
-    pub const fn mnemonic(&self) -> &'static str {
-        use Instruction::*;
-        match self {
-            Abs(_) => "abs",
-            AbsWrapped(_) => "abs.w",
-            Add(_) => "add",
-            AddWrapped(_) => "add.w",
-            And(_) => "and",
-            AssertEq(_) => "assert.eq",
-            AssertNeq(_) => "assert.neq",
-            Commit(data) => match data.method {
-                CommitMethod::CommitBhp256 => "commit.bhp256",
-                CommitMethod::CommitBhp512 => "commit.bhp512",
-                CommitMethod::CommitBhp768 => "commit.bhp768",
-                CommitMethod::CommitBhp1024 => "commit.bhp1024",
-                CommitMethod::CommitPed64 => "commit.ped64",
-                CommitMethod::CommitPed128 => "commit.ped128",
-            },
-            Div(_) => "div",
-            DivWrapped(_) => "div.wrapped",
-            Double(_) => "double",
-            Gt(_) => "gt",
-            Gte(_) => "gte",
-            Hash(data) => match data.method {
-                HashMethod::HashBhp256 => "data.bhp256",
-                HashMethod::HashBhp512 => "hash.hbp512",
-                HashMethod::HashBhp768 => "hash.hbp768",
-                HashMethod::HashBhp1024 => "hash.hbp1024",
-                HashMethod::HashPed64 => "hash.ped64",
-                HashMethod::HashPed128 => "hash.ped128",
-                HashMethod::HashPsd2 => "hash.psd2",
-                HashMethod::HashPsd4 => "hash.psd4",
-                HashMethod::HashPsd8 => "hash.psd8",
-            },
-            Inv(_) => "inv",
-            IsEq(_) => "is.eq",
-            IsNeq(_) => "is.neq",
-            Lt(_) => "lt",
-            Lte(_) => "lte",
-            Mod(_) => "mod",
-            Mul(_) => "mul",
-            MulWrapped(_) => "mul.w",
-            Nand(_) => "nand",
-            Neg(_) => "neg",
-            Nor(_) => "nor",
-            Not(_) => "not",
-            Or(_) => "or",
-            Pow(_) => "pow",
-            PowWrapped(_) => "pow.w",
-            Rem(_) => "rem",
-            RemWrapped(_) => "rem.w",
-            Shl(_) => "shl",
-            ShlWrapped(_) => "shl.w",
-            Shr(_) => "shr",
-            ShrWrapped(_) => "shr.w",
-            Sqrt(_) => "sqrt",
-            Square(_) => "square",
-            Sub(_) => "sub",
-            SubWrapped(_) => "sub.w",
-            Ternary(_) => "ternary",
-            Xor(_) => "xor",
-        }
-    }
-}
-
 impl TryFrom<ir::Instruction> for Instruction {
     type Error = Error;
 
@@ -141,20 +104,28 @@ impl TryFrom<ir::Instruction> for Instruction {
                 ir::instruction::Instruction::Add(v) => Self::Add(v.try_into()?),
                 ir::instruction::Instruction::AddWrapped(v) => Self::AddWrapped(v.try_into()?),
                 ir::instruction::Instruction::And(v) => Self::And(v.try_into()?),
+                ir::instruction::Instruction::Assert(v) => Self::Assert(v.try_into()?),
                 ir::instruction::Instruction::AssertEq(v) => Self::AssertEq(v.try_into()?),
                 ir::instruction::Instruction::AssertNeq(v) => Self::AssertNeq(v.try_into()?),
+                ir::instruction::Instruction::Call(v) => Self::Call(v.try_into()?),
+                ir::instruction::Instruction::Cast(v) => Self::Cast(v.try_into()?),
                 ir::instruction::Instruction::Commit(v) => Self::Commit(v.try_into()?),
+                ir::instruction::Instruction::Declassify(v) => Self::Declassify(v.try_into()?),
                 ir::instruction::Instruction::Div(v) => Self::Div(v.try_into()?),
                 ir::instruction::Instruction::DivWrapped(v) => Self::DivWrapped(v.try_into()?),
                 ir::instruction::Instruction::Double(v) => Self::Double(v.try_into()?),
+                ir::instruction::Instruction::FromBits(v) => Self::FromBits(v.try_into()?),
                 ir::instruction::Instruction::Gt(v) => Self::Gt(v.try_into()?),
                 ir::instruction::Instruction::Gte(v) => Self::Gte(v.try_into()?),
                 ir::instruction::Instruction::Hash(v) => Self::Hash(v.try_into()?),
                 ir::instruction::Instruction::Inv(v) => Self::Inv(v.try_into()?),
                 ir::instruction::Instruction::IsEq(v) => Self::IsEq(v.try_into()?),
                 ir::instruction::Instruction::IsNeq(v) => Self::IsNeq(v.try_into()?),
+                ir::instruction::Instruction::Lshr(v) => Self::Lshr(v.try_into()?),
                 ir::instruction::Instruction::Lt(v) => Self::Lt(v.try_into()?),
                 ir::instruction::Instruction::Lte(v) => Self::Lte(v.try_into()?),
+                ir::instruction::Instruction::Mask(v) => Self::Mask(v.try_into()?),
+                ir::instruction::Instruction::Member(v) => Self::Member(v.try_into()?),
                 ir::instruction::Instruction::Mod(v) => Self::Mod(v.try_into()?),
                 ir::instruction::Instruction::Mul(v) => Self::Mul(v.try_into()?),
                 ir::instruction::Instruction::MulWrapped(v) => Self::MulWrapped(v.try_into()?),
@@ -165,8 +136,11 @@ impl TryFrom<ir::Instruction> for Instruction {
                 ir::instruction::Instruction::Or(v) => Self::Or(v.try_into()?),
                 ir::instruction::Instruction::Pow(v) => Self::Pow(v.try_into()?),
                 ir::instruction::Instruction::PowWrapped(v) => Self::PowWrapped(v.try_into()?),
+                ir::instruction::Instruction::RecordField(v) => Self::RecordField(v.try_into()?),
                 ir::instruction::Instruction::Rem(v) => Self::Rem(v.try_into()?),
                 ir::instruction::Instruction::RemWrapped(v) => Self::RemWrapped(v.try_into()?),
+                ir::instruction::Instruction::Repeat(v) => Self::Repeat(v.try_into()?),
+                ir::instruction::Instruction::Return(v) => Self::Return(v.try_into()?),
                 ir::instruction::Instruction::Shl(v) => Self::Shl(v.try_into()?),
                 ir::instruction::Instruction::ShlWrapped(v) => Self::ShlWrapped(v.try_into()?),
                 ir::instruction::Instruction::Shr(v) => Self::Shr(v.try_into()?),
@@ -176,6 +150,7 @@ impl TryFrom<ir::Instruction> for Instruction {
                 ir::instruction::Instruction::Sub(v) => Self::Sub(v.try_into()?),
                 ir::instruction::Instruction::SubWrapped(v) => Self::SubWrapped(v.try_into()?),
                 ir::instruction::Instruction::Ternary(v) => Self::Ternary(v.try_into()?),
+                ir::instruction::Instruction::ToBits(v) => Self::ToBits(v.try_into()?),
                 ir::instruction::Instruction::Xor(v) => Self::Xor(v.try_into()?),
             },
         )
@@ -191,21 +166,28 @@ impl From<Instruction> for ir::Instruction {
                 Instruction::Add(v) => ir::instruction::Instruction::Add(v.into()),
                 Instruction::AddWrapped(v) => ir::instruction::Instruction::AddWrapped(v.into()),
                 Instruction::And(v) => ir::instruction::Instruction::And(v.into()),
+                Instruction::Assert(v) => ir::instruction::Instruction::Assert(v.into()),
                 Instruction::AssertEq(v) => ir::instruction::Instruction::AssertEq(v.into()),
                 Instruction::AssertNeq(v) => ir::instruction::Instruction::AssertNeq(v.into()),
+                Instruction::Call(v) => ir::instruction::Instruction::Call(v.into()),
+                Instruction::Cast(v) => ir::instruction::Instruction::Cast(v.into()),
                 Instruction::Commit(v) => ir::instruction::Instruction::Commit(v.into()),
-
+                Instruction::Declassify(v) => ir::instruction::Instruction::Declassify(v.into()),
                 Instruction::Div(v) => ir::instruction::Instruction::Div(v.into()),
                 Instruction::DivWrapped(v) => ir::instruction::Instruction::DivWrapped(v.into()),
                 Instruction::Double(v) => ir::instruction::Instruction::Double(v.into()),
+                Instruction::FromBits(v) => ir::instruction::Instruction::FromBits(v.into()),
                 Instruction::Gt(v) => ir::instruction::Instruction::Gt(v.into()),
                 Instruction::Gte(v) => ir::instruction::Instruction::Gte(v.into()),
                 Instruction::Hash(v) => ir::instruction::Instruction::Hash(v.into()),
                 Instruction::Inv(v) => ir::instruction::Instruction::Inv(v.into()),
                 Instruction::IsEq(v) => ir::instruction::Instruction::IsEq(v.into()),
                 Instruction::IsNeq(v) => ir::instruction::Instruction::IsNeq(v.into()),
+                Instruction::Lshr(v) => ir::instruction::Instruction::Lshr(v.into()),
                 Instruction::Lt(v) => ir::instruction::Instruction::Lt(v.into()),
                 Instruction::Lte(v) => ir::instruction::Instruction::Lte(v.into()),
+                Instruction::Mask(v) => ir::instruction::Instruction::Mask(v.into()),
+                Instruction::Member(v) => ir::instruction::Instruction::Member(v.into()),
                 Instruction::Mod(v) => ir::instruction::Instruction::Mod(v.into()),
                 Instruction::Mul(v) => ir::instruction::Instruction::Mul(v.into()),
                 Instruction::MulWrapped(v) => ir::instruction::Instruction::MulWrapped(v.into()),
@@ -216,8 +198,11 @@ impl From<Instruction> for ir::Instruction {
                 Instruction::Or(v) => ir::instruction::Instruction::Or(v.into()),
                 Instruction::Pow(v) => ir::instruction::Instruction::Pow(v.into()),
                 Instruction::PowWrapped(v) => ir::instruction::Instruction::PowWrapped(v.into()),
+                Instruction::RecordField(v) => ir::instruction::Instruction::RecordField(v.into()),
                 Instruction::Rem(v) => ir::instruction::Instruction::Rem(v.into()),
                 Instruction::RemWrapped(v) => ir::instruction::Instruction::RemWrapped(v.into()),
+                Instruction::Repeat(v) => ir::instruction::Instruction::Repeat(v.into()),
+                Instruction::Return(v) => ir::instruction::Instruction::Return(v.into()),
                 Instruction::Shl(v) => ir::instruction::Instruction::Shl(v.into()),
                 Instruction::ShlWrapped(v) => ir::instruction::Instruction::ShlWrapped(v.into()),
                 Instruction::Shr(v) => ir::instruction::Instruction::Shr(v.into()),
@@ -227,58 +212,9 @@ impl From<Instruction> for ir::Instruction {
                 Instruction::Sub(v) => ir::instruction::Instruction::Sub(v.into()),
                 Instruction::SubWrapped(v) => ir::instruction::Instruction::SubWrapped(v.into()),
                 Instruction::Ternary(v) => ir::instruction::Instruction::Ternary(v.into()),
+                Instruction::ToBits(v) => ir::instruction::Instruction::ToBits(v.into()),
                 Instruction::Xor(v) => ir::instruction::Instruction::Xor(v.into()),
             }),
         }
     }
 }
-
-impl fmt::Display for Instruction {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        use Instruction::*;
-        write!(f, "{} ", self.mnemonic())?;
-        match self {
-            Abs(x) => x.fmt(f),
-            AbsWrapped(x) => x.fmt(f),
-            Add(x) => x.fmt(f),
-            AddWrapped(x) => x.fmt(f),
-            And(x) => x.fmt(f),
-            AssertEq(x) => x.fmt(f),
-            AssertNeq(x) => x.fmt(f),
-            Commit(x) => x.fmt(f),
-            Div(x) => x.fmt(f),
-            DivWrapped(x) => x.fmt(f),
-            Double(x) => x.fmt(f),
-            Gt(x) => x.fmt(f),
-            Gte(x) => x.fmt(f),
-            Hash(x) => x.fmt(f),
-            Inv(x) => x.fmt(f),
-            IsEq(x) => x.fmt(f),
-            IsNeq(x) => x.fmt(f),
-            Lt(x) => x.fmt(f),
-            Lte(x) => x.fmt(f),
-            Mod(x) => x.fmt(f),
-            Mul(x) => x.fmt(f),
-            MulWrapped(x) => x.fmt(f),
-            Nand(x) => x.fmt(f),
-            Neg(x) => x.fmt(f),
-            Nor(x) => x.fmt(f),
-            Not(x) => x.fmt(f),
-            Or(x) => x.fmt(f),
-            Pow(x) => x.fmt(f),
-            PowWrapped(x) => x.fmt(f),
-            Rem(x) => x.fmt(f),
-            RemWrapped(x) => x.fmt(f),
-            Shl(x) => x.fmt(f),
-            ShlWrapped(x) => x.fmt(f),
-            Shr(x) => x.fmt(f),
-            ShrWrapped(x) => x.fmt(f),
-            Sqrt(x) => x.fmt(f),
-            Square(x) => x.fmt(f),
-            Sub(x) => x.fmt(f),
-            SubWrapped(x) => x.fmt(f),
-            Ternary(x) => x.fmt(f),
-            Xor(x) => x.fmt(f),
-        }
-    }
-}
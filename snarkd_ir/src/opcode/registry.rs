@@ -0,0 +1,603 @@
+use std::{fmt, vec};
+
+use super::*;
+use crate::{Operand, Scalar, Type};
+
+/// Kinds of operand an instruction is written with, in the order they appear.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperandKind {
+    /// A single operand, like `r0` or `5u8`.
+    Value,
+    /// Operands separated by commas, possibly none.
+    Values,
+    /// The type a value is converted to, following `as`.
+    Type,
+    /// The position of a struct or record entry.
+    Index,
+    /// The number of times a block runs.
+    Count,
+    /// The number of instructions in a block.
+    Length,
+    /// The scalar randomness of a commitment.
+    Randomness,
+    /// A function of the program, like `f1`.
+    Function,
+    /// The register the result is stored in, following `->`.
+    Dest,
+    /// The registers results are stored in, following `->`, possibly none.
+    Dests,
+}
+
+impl OperandKind {
+    /// Text written before an operand of this kind, after an operand of kind `previous`.
+    pub(crate) fn separator(self, previous: Option<OperandKind>) -> &'static str {
+        match (previous, self) {
+            (_, OperandKind::Type) => " as ",
+            (_, OperandKind::Dest | OperandKind::Dests) => " -> ",
+            (None | Some(OperandKind::Function), _) => " ",
+            _ => ", ",
+        }
+    }
+
+    /// Whether operands of this kind may be left out, along with their separator.
+    pub(crate) fn is_optional(self) -> bool {
+        matches!(self, OperandKind::Values | OperandKind::Dests)
+    }
+}
+
+/// An entry of the opcode registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpcodeInfo {
+    pub opcode: Opcode,
+    pub mnemonic: &'static str,
+    pub operands: &'static [OperandKind],
+    /// Field number of the instruction in the `instruction` oneof of `opcode.proto`. Hash and
+    /// commit methods share the field of their instruction.
+    pub tag: u32,
+}
+
+impl OpcodeInfo {
+    /// Number of operands the instruction reads, not counting destinations, or `None` if it
+    /// reads a list of any length.
+    pub fn arity(&self) -> Option<usize> {
+        let mut arity = 0;
+        for kind in self.operands {
+            match kind {
+                OperandKind::Values => return None,
+                OperandKind::Dest | OperandKind::Dests => {}
+                _ => arity += 1,
+            }
+        }
+        Some(arity)
+    }
+}
+
+macro_rules! opcodes {
+    ($($opcode:ident = $mnemonic:literal, $tag:literal, [$($kind:ident),*];)*) => {
+        /// Every instruction of the IR, with hash and commit instructions split by method.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum Opcode {
+            $($opcode,)*
+        }
+
+        /// The opcode registry, in the order of [`Opcode`].
+        pub const OPCODES: &[OpcodeInfo] = &[
+            $(OpcodeInfo {
+                opcode: Opcode::$opcode,
+                mnemonic: $mnemonic,
+                operands: &[$(OperandKind::$kind),*],
+                tag: $tag,
+            },)*
+        ];
+    };
+}
+
+opcodes! {
+    Abs = "abs", 1, [Value, Dest];
+    AbsWrapped = "abs.w", 2, [Value, Dest];
+    Add = "add", 3, [Value, Value, Dest];
+    AddWrapped = "add.w", 4, [Value, Value, Dest];
+    And = "and", 5, [Value, Value, Dest];
+    Assert = "assert", 46, [Value];
+    AssertEq = "assert.eq", 6, [Value, Value];
+    AssertNeq = "assert.neq", 7, [Value, Value];
+    Call = "call", 42, [Function, Values, Dests];
+    Cast = "cast", 47, [Value, Type, Dest];
+    CommitBhp256 = "commit.bhp256", 8, [Value, Randomness, Dest];
+    CommitBhp512 = "commit.bhp512", 8, [Value, Randomness, Dest];
+    CommitBhp768 = "commit.bhp768", 8, [Value, Randomness, Dest];
+    CommitBhp1024 = "commit.bhp1024", 8, [Value, Randomness, Dest];
+    CommitPed64 = "commit.ped64", 8, [Value, Randomness, Dest];
+    CommitPed128 = "commit.ped128", 8, [Value, Randomness, Dest];
+    Declassify = "declassify", 54, [Value, Dest];
+    Div = "div", 9, [Value, Value, Dest];
+    DivWrapped = "div.w", 10, [Value, Value, Dest];
+    Double = "double", 11, [Value, Dest];
+    FromBits = "from.bits", 48, [Value, Type, Dest];
+    Gt = "gt", 12, [Value, Value, Dest];
+    Gte = "gte", 13, [Value, Value, Dest];
+    HashBhp256 = "hash.bhp256", 14, [Value, Dest];
+    HashBhp512 = "hash.bhp512", 14, [Value, Dest];
+    HashBhp768 = "hash.bhp768", 14, [Value, Dest];
+    HashBhp1024 = "hash.bhp1024", 14, [Value, Dest];
+    HashPed64 = "hash.ped64", 14, [Value, Dest];
+    HashPed128 = "hash.ped128", 14, [Value, Dest];
+    HashPsd2 = "hash.psd2", 14, [Value, Dest];
+    HashPsd4 = "hash.psd4", 14, [Value, Dest];
+    HashPsd8 = "hash.psd8", 14, [Value, Dest];
+    Inv = "inv", 15, [Value, Dest];
+    IsEq = "is.eq", 16, [Value, Value, Dest];
+    IsNeq = "is.neq", 17, [Value, Value, Dest];
+    Lshr = "lshr", 50, [Value, Value, Dest];
+    Lt = "lt", 18, [Value, Value, Dest];
+    Lte = "lte", 19, [Value, Value, Dest];
+    Mask = "mask", 44, [Value, Length];
+    Member = "member", 51, [Value, Index, Dest];
+    Mod = "mod", 20, [Value, Value, Dest];
+    Mul = "mul", 21, [Value, Value, Dest];
+    MulWrapped = "mul.w", 22, [Value, Value, Dest];
+    Nand = "nand", 23, [Value, Value, Dest];
+    Neg = "neg", 24, [Value, Dest];
+    Nor = "nor", 25, [Value, Value, Dest];
+    Not = "not", 26, [Value, Dest];
+    Or = "or", 27, [Value, Value, Dest];
+    Pow = "pow", 28, [Value, Value, Dest];
+    PowWrapped = "pow.w", 29, [Value, Value, Dest];
+    RecordField = "record.field", 52, [Value, Index, Dest];
+    Rem = "rem", 30, [Value, Value, Dest];
+    RemWrapped = "rem.w", 31, [Value, Value, Dest];
+    Repeat = "repeat", 45, [Count, Length];
+    Return = "return", 43, [Values];
+    Shl = "shl", 32, [Value, Value, Dest];
+    ShlWrapped = "shl.w", 33, [Value, Value, Dest];
+    Shr = "shr", 34, [Value, Value, Dest];
+    ShrWrapped = "shr.w", 35, [Value, Value, Dest];
+    Sqrt = "sqrt", 37, [Value, Dest];
+    Square = "square", 36, [Value, Dest];
+    Sub = "sub", 38, [Value, Value, Dest];
+    SubWrapped = "sub.w", 39, [Value, Value, Dest];
+    Ternary = "ternary", 53, [Value, Value, Value, Dest];
+    ToBits = "to.bits", 49, [Value, Dest];
+    Xor = "xor", 41, [Value, Value, Dest];
+}
+
+impl Opcode {
+    pub const fn info(self) -> &'static OpcodeInfo {
+        &OPCODES[self as usize]
+    }
+
+    pub const fn mnemonic(self) -> &'static str {
+        self.info().mnemonic
+    }
+
+    pub fn from_mnemonic(mnemonic: &str) -> Option<Self> {
+        OPCODES
+            .iter()
+            .find(|info| info.mnemonic == mnemonic)
+            .map(|info| info.opcode)
+    }
+}
+
+impl fmt::Display for Opcode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.mnemonic())
+    }
+}
+
+/// An operand of an instruction as it is written, of the kind at its position in the registry.
+/// Functions, indices, counts, lengths and destinations are numbers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Arg {
+    Value(Operand),
+    Values(Vec<Operand>),
+    Type(Type),
+    Number(u32),
+    Numbers(Vec<u32>),
+    Randomness(Scalar),
+}
+
+impl Arg {
+    pub(crate) fn is_empty(&self) -> bool {
+        match self {
+            Arg::Values(x) => x.is_empty(),
+            Arg::Numbers(x) => x.is_empty(),
+            _ => false,
+        }
+    }
+
+    /// Writes the operand as an operand of kind `kind`.
+    pub(crate) fn write(&self, f: &mut fmt::Formatter<'_>, kind: OperandKind) -> fmt::Result {
+        let prefix = match kind {
+            OperandKind::Function => "f",
+            OperandKind::Dest | OperandKind::Dests => "r",
+            _ => "",
+        };
+        match self {
+            Arg::Value(x) => write!(f, "{x}"),
+            Arg::Values(x) => write_list(f, x, ""),
+            Arg::Type(x) => write!(f, "{x}"),
+            Arg::Number(x) => write!(f, "{prefix}{x}"),
+            Arg::Numbers(x) => write_list(f, x, prefix),
+            Arg::Randomness(x) => write!(f, "{x}"),
+        }
+    }
+}
+
+fn write_list(
+    f: &mut fmt::Formatter<'_>,
+    items: &[impl fmt::Display],
+    prefix: &str,
+) -> fmt::Result {
+    for (i, item) in items.iter().enumerate() {
+        let separator = if i == 0 { "" } else { ", " };
+        write!(f, "{separator}{prefix}{item}")?;
+    }
+    Ok(())
+}
+
+/// Operands of an instruction being built, taken in the order of its registry entry.
+struct Args(vec::IntoIter<Arg>);
+
+impl Args {
+    fn next(&mut self) -> Arg {
+        self.0
+            .next()
+            .expect("instructions are built from as many operands as their registry entry lists")
+    }
+
+    fn value(&mut self) -> Operand {
+        match self.next() {
+            Arg::Value(x) => x,
+            x => unreachable!("expected a value, found {x:?}"),
+        }
+    }
+
+    fn values(&mut self) -> Vec<Operand> {
+        match self.next() {
+            Arg::Values(x) => x,
+            x => unreachable!("expected values, found {x:?}"),
+        }
+    }
+
+    fn type_(&mut self) -> Type {
+        match self.next() {
+            Arg::Type(x) => x,
+            x => unreachable!("expected a type, found {x:?}"),
+        }
+    }
+
+    fn number(&mut self) -> u32 {
+        match self.next() {
+            Arg::Number(x) => x,
+            x => unreachable!("expected a number, found {x:?}"),
+        }
+    }
+
+    fn numbers(&mut self) -> Vec<u32> {
+        match self.next() {
+            Arg::Numbers(x) => x,
+            x => unreachable!("expected numbers, found {x:?}"),
+        }
+    }
+
+    fn randomness(&mut self) -> Scalar {
+        match self.next() {
+            Arg::Randomness(x) => x,
+            x => unreachable!("expected a scalar, found {x:?}"),
+        }
+    }
+
+    fn unary(&mut self) -> UnaryData {
+        UnaryData {
+            operand: self.value(),
+            dest: self.number(),
+        }
+    }
+
+    fn binary(&mut self) -> BinaryData {
+        BinaryData {
+            lhs: self.value(),
+            rhs: self.value(),
+            dest: self.number(),
+        }
+    }
+
+    fn assert(&mut self) -> AssertData {
+        AssertData {
+            lhs: self.value(),
+            rhs: self.value(),
+        }
+    }
+
+    fn cast(&mut self) -> CastData {
+        CastData {
+            operand: self.value(),
+            type_: self.type_(),
+            dest: self.number(),
+        }
+    }
+
+    fn access(&mut self) -> AccessData {
+        AccessData {
+            operand: self.value(),
+            index: self.number(),
+            dest: self.number(),
+        }
+    }
+
+    fn hash(&mut self, method: HashMethod) -> HashData {
+        HashData {
+            method,
+            chunk: self.value(),
+            dest: self.number(),
+        }
+    }
+
+    fn commit(&mut self, method: CommitMethod) -> CommitData {
+        CommitData {
+            method,
+            chunk: self.value(),
+            randomness: self.randomness(),
+            dest: self.number(),
+        }
+    }
+}
+
+fn unary(x: &UnaryData) -> Vec<Arg> {
+    vec![Arg::Value(x.operand.clone()), Arg::Number(x.dest)]
+}
+
+fn binary(x: &BinaryData) -> Vec<Arg> {
+    vec![
+        Arg::Value(x.lhs.clone()),
+        Arg::Value(x.rhs.clone()),
+        Arg::Number(x.dest),
+    ]
+}
+
+fn cast(x: &CastData) -> Vec<Arg> {
+    vec![
+        Arg::Value(x.operand.clone()),
+        Arg::Type(x.type_.clone()),
+        Arg::Number(x.dest),
+    ]
+}
+
+fn access(x: &AccessData) -> Vec<Arg> {
+    vec![
+        Arg::Value(x.operand.clone()),
+        Arg::Number(x.index),
+        Arg::Number(x.dest),
+    ]
+}
+
+impl Instruction {
+    pub const fn opcode(&self) -> Opcode {
+        use Instruction::*;
+        match self {
+            Abs(_) => Opcode::Abs,
+            AbsWrapped(_) => Opcode::AbsWrapped,
+            Add(_) => Opcode::Add,
+            AddWrapped(_) => Opcode::AddWrapped,
+            And(_) => Opcode::And,
+            Assert(_) => Opcode::Assert,
+            AssertEq(_) => Opcode::AssertEq,
+            AssertNeq(_) => Opcode::AssertNeq,
+            Call(_) => Opcode::Call,
+            Cast(_) => Opcode::Cast,
+            Commit(data) => match data.method {
+                CommitMethod::CommitBhp256 => Opcode::CommitBhp256,
+                CommitMethod::CommitBhp512 => Opcode::CommitBhp512,
+                CommitMethod::CommitBhp768 => Opcode::CommitBhp768,
+                CommitMethod::CommitBhp1024 => Opcode::CommitBhp1024,
+                CommitMethod::CommitPed64 => Opcode::CommitPed64,
+                CommitMethod::CommitPed128 => Opcode::CommitPed128,
+            },
+            Declassify(_) => Opcode::Declassify,
+            Div(_) => Opcode::Div,
+            DivWrapped(_) => Opcode::DivWrapped,
+            Double(_) => Opcode::Double,
+            FromBits(_) => Opcode::FromBits,
+            Gt(_) => Opcode::Gt,
+            Gte(_) => Opcode::Gte,
+            Hash(data) => match data.method {
+                HashMethod::HashBhp256 => Opcode::HashBhp256,
+                HashMethod::HashBhp512 => Opcode::HashBhp512,
+                HashMethod::HashBhp768 => Opcode::HashBhp768,
+                HashMethod::HashBhp1024 => Opcode::HashBhp1024,
+                HashMethod::HashPed64 => Opcode::HashPed64,
+                HashMethod::HashPed128 => Opcode::HashPed128,
+                HashMethod::HashPsd2 => Opcode::HashPsd2,
+                HashMethod::HashPsd4 => Opcode::HashPsd4,
+                HashMethod::HashPsd8 => Opcode::HashPsd8,
+            },
+            Inv(_) => Opcode::Inv,
+            IsEq(_) => Opcode::IsEq,
+            IsNeq(_) => Opcode::IsNeq,
+            Lshr(_) => Opcode::Lshr,
+            Lt(_) => Opcode::Lt,
+            Lte(_) => Opcode::Lte,
+            Mask(_) => Opcode::Mask,
+            Member(_) => Opcode::Member,
+            Mod(_) => Opcode::Mod,
+            Mul(_) => Opcode::Mul,
+            MulWrapped(_) => Opcode::MulWrapped,
+            Nand(_) => Opcode::Nand,
+            Neg(_) => Opcode::Neg,
+            Nor(_) => Opcode::Nor,
+            Not(_) => Opcode::Not,
+            Or(_) => Opcode::Or,
+            Pow(_) => Opcode::Pow,
+            PowWrapped(_) => Opcode::PowWrapped,
+            RecordField(_) => Opcode::RecordField,
+            Rem(_) => Opcode::Rem,
+            RemWrapped(_) => Opcode::RemWrapped,
+            Repeat(_) => Opcode::Repeat,
+            Return(_) => Opcode::Return,
+            Shl(_) => Opcode::Shl,
+            ShlWrapped(_) => Opcode::ShlWrapped,
+            Shr(_) => Opcode::Shr,
+            ShrWrapped(_) => Opcode::ShrWrapped,
+            Sqrt(_) => Opcode::Sqrt,
+            Square(_) => Opcode::Square,
+            Sub(_) => Opcode::Sub,
+            SubWrapped(_) => Opcode::SubWrapped,
+            Ternary(_) => Opcode::Ternary,
+            ToBits(_) => Opcode::ToBits,
+            Xor(_) => Opcode::Xor,
+        }
+    }
+
+    pub const fn mnemonic(&self) -> &'static str {
+        self.opcode().mnemonic()
+    }
+
+    /// The operands of the instruction, in the order of its registry entry.
+    pub(crate) fn args(&self) -> Vec<Arg> {
+        use Instruction::*;
+        match self {
+            Abs(x) | AbsWrapped(x) | Declassify(x) | Double(x) | Inv(x) | Neg(x) | Not(x)
+            | Sqrt(x) | Square(x) | ToBits(x) => unary(x),
+            Add(x) | AddWrapped(x) | And(x) | Div(x) | DivWrapped(x) | Gt(x) | Gte(x) | IsEq(x)
+            | IsNeq(x) | Lshr(x) | Lt(x) | Lte(x) | Mod(x) | Mul(x) | MulWrapped(x) | Nand(x)
+            | Nor(x) | Or(x) | Pow(x) | PowWrapped(x) | Rem(x) | RemWrapped(x) | Shl(x)
+            | ShlWrapped(x) | Shr(x) | ShrWrapped(x) | Sub(x) | SubWrapped(x) | Xor(x) => binary(x),
+            Cast(x) | FromBits(x) => cast(x),
+            Member(x) | RecordField(x) => access(x),
+            Assert(x) => vec![Arg::Value(x.cond.clone())],
+            AssertEq(x) | AssertNeq(x) => {
+                vec![Arg::Value(x.lhs.clone()), Arg::Value(x.rhs.clone())]
+            }
+            Call(x) => vec![
+                Arg::Number(x.function),
+                Arg::Values(x.arguments.clone()),
+                Arg::Numbers(x.dests.clone()),
+            ],
+            Commit(x) => vec![
+                Arg::Value(x.chunk.clone()),
+                Arg::Randomness(x.randomness.clone()),
+                Arg::Number(x.dest),
+            ],
+            Hash(x) => vec![Arg::Value(x.chunk.clone()), Arg::Number(x.dest)],
+            Mask(x) => vec![Arg::Value(x.cond.clone()), Arg::Number(x.length)],
+            Repeat(x) => vec![Arg::Number(x.count), Arg::Number(x.length)],
+            Return(x) => vec![Arg::Values(x.values.clone())],
+            Ternary(x) => vec![
+                Arg::Value(x.cond.clone()),
+                Arg::Value(x.lhs.clone()),
+                Arg::Value(x.rhs.clone()),
+                Arg::Number(x.dest),
+            ],
+        }
+    }
+
+    /// Builds the instruction of `opcode` from operands of the kinds its registry entry lists.
+    pub(crate) fn from_args(opcode: Opcode, args: Vec<Arg>) -> Self {
+        let mut args = Args(args.into_iter());
+        let args = &mut args;
+        use Instruction::*;
+        match opcode {
+            Opcode::Abs => Abs(args.unary()),
+            Opcode::AbsWrapped => AbsWrapped(args.unary()),
+            Opcode::Add => Add(args.binary()),
+            Opcode::AddWrapped => AddWrapped(args.binary()),
+            Opcode::And => And(args.binary()),
+            Opcode::Assert => Assert(ConditionData { cond: args.value() }),
+            Opcode::AssertEq => AssertEq(args.assert()),
+            Opcode::AssertNeq => AssertNeq(args.assert()),
+            Opcode::Call => Call(CallData {
+                function: args.number(),
+                arguments: args.values(),
+                dests: args.numbers(),
+            }),
+            Opcode::Cast => Cast(args.cast()),
+            Opcode::CommitBhp256 => Commit(args.commit(CommitMethod::CommitBhp256)),
+            Opcode::CommitBhp512 => Commit(args.commit(CommitMethod::CommitBhp512)),
+            Opcode::CommitBhp768 => Commit(args.commit(CommitMethod::CommitBhp768)),
+            Opcode::CommitBhp1024 => Commit(args.commit(CommitMethod::CommitBhp1024)),
+            Opcode::CommitPed64 => Commit(args.commit(CommitMethod::CommitPed64)),
+            Opcode::CommitPed128 => Commit(args.commit(CommitMethod::CommitPed128)),
+            Opcode::Declassify => Declassify(args.unary()),
+            Opcode::Div => Div(args.binary()),
+            Opcode::DivWrapped => DivWrapped(args.binary()),
+            Opcode::Double => Double(args.unary()),
+            Opcode::FromBits => FromBits(args.cast()),
+            Opcode::Gt => Gt(args.binary()),
+            Opcode::Gte => Gte(args.binary()),
+            Opcode::HashBhp256 => Hash(args.hash(HashMethod::HashBhp256)),
+            Opcode::HashBhp512 => Hash(args.hash(HashMethod::HashBhp512)),
+            Opcode::HashBhp768 => Hash(args.hash(HashMethod::HashBhp768)),
+            Opcode::HashBhp1024 => Hash(args.hash(HashMethod::HashBhp1024)),
+            Opcode::HashPed64 => Hash(args.hash(HashMethod::HashPed64)),
+            Opcode::HashPed128 => Hash(args.hash(HashMethod::HashPed128)),
+            Opcode::HashPsd2 => Hash(args.hash(HashMethod::HashPsd2)),
+            Opcode::HashPsd4 => Hash(args.hash(HashMethod::HashPsd4)),
+            Opcode::HashPsd8 => Hash(args.hash(HashMethod::HashPsd8)),
+            Opcode::Inv => Inv(args.unary()),
+            Opcode::IsEq => IsEq(args.binary()),
+            Opcode::IsNeq => IsNeq(args.binary()),
+            Opcode::Lshr => Lshr(args.binary()),
+            Opcode::Lt => Lt(args.binary()),
+            Opcode::Lte => Lte(args.binary()),
+            Opcode::Mask => Mask(MaskData {
+                cond: args.value(),
+                length: args.number(),
+            }),
+            Opcode::Member => Member(args.access()),
+            Opcode::Mod => Mod(args.binary()),
+            Opcode::Mul => Mul(args.binary()),
+            Opcode::MulWrapped => MulWrapped(args.binary()),
+            Opcode::Nand => Nand(args.binary()),
+            Opcode::Neg => Neg(args.unary()),
+            Opcode::Nor => Nor(args.binary()),
+            Opcode::Not => Not(args.unary()),
+            Opcode::Or => Or(args.binary()),
+            Opcode::Pow => Pow(args.binary()),
+            Opcode::PowWrapped => PowWrapped(args.binary()),
+            Opcode::RecordField => RecordField(args.access()),
+            Opcode::Rem => Rem(args.binary()),
+            Opcode::RemWrapped => RemWrapped(args.binary()),
+            Opcode::Repeat => Repeat(RepeatData {
+                count: args.number(),
+                length: args.number(),
+            }),
+            Opcode::Return => Return(ReturnData {
+                values: args.values(),
+            }),
+            Opcode::Shl => Shl(args.binary()),
+            Opcode::ShlWrapped => ShlWrapped(args.binary()),
+            Opcode::Shr => Shr(args.binary()),
+            Opcode::ShrWrapped => ShrWrapped(args.binary()),
+            Opcode::Sqrt => Sqrt(args.unary()),
+            Opcode::Square => Square(args.unary()),
+            Opcode::Sub => Sub(args.binary()),
+            Opcode::SubWrapped => SubWrapped(args.binary()),
+            Opcode::Ternary => Ternary(TernaryData {
+                cond: args.value(),
+                lhs: args.value(),
+                rhs: args.value(),
+                dest: args.number(),
+            }),
+            Opcode::ToBits => ToBits(args.unary()),
+            Opcode::Xor => Xor(args.binary()),
+        }
+    }
+}
+
+/// Writes the mnemonic of the instruction and its operands, as laid out by its registry entry.
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let info = self.opcode().info();
+        write!(f, "{}", info.mnemonic)?;
+        let mut previous = None;
+        for (kind, arg) in info.operands.iter().zip(self.args()) {
+            if kind.is_optional() && arg.is_empty() {
+                continue;
+            }
+            write!(f, "{}", kind.separator(previous))?;
+            arg.write(f, *kind)?;
+            previous = Some(*kind);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use std::fmt;
+
+use super::ir;
+
+use snarkd_errors::{Error, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepeatData {
+    pub count: u32,
+    /// Number of instructions after the repeat that are run `count` times.
+    pub length: u32,
+}
+
+impl TryFrom<ir::RepeatData> for RepeatData {
+    type Error = Error;
+
+    fn try_from(value: ir::RepeatData) -> Result<Self> {
+        Ok(Self {
+            count: value.count,
+            length: value.length,
+        })
+    }
+}
+
+impl From<RepeatData> for ir::RepeatData {
+    fn from(value: RepeatData) -> Self {
+        Self {
+            count: value.count,
+            length: value.length,
+        }
+    }
+}
+
+impl fmt::Display for RepeatData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {}", self.count, self.length)
+    }
+}
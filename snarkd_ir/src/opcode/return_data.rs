@@ -0,0 +1,42 @@
+use std::fmt;
+
+use super::ir;
+use crate::Operand;
+
+use snarkd_errors::{Error, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReturnData {
+    pub values: Vec<Operand>,
+}
+
+impl TryFrom<ir::ReturnData> for ReturnData {
+    type Error = Error;
+
+    fn try_from(value: ir::ReturnData) -> Result<Self> {
+        Ok(Self {
+            values: value
+                .values
+                .into_iter()
+                .map(|v| v.try_into())
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+impl From<ReturnData> for ir::ReturnData {
+    fn from(value: ReturnData) -> Self {
+        Self {
+            values: value.values.into_iter().map(|v| v.into()).collect(),
+        }
+    }
+}
+
+impl fmt::Display for ReturnData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, value) in self.values.iter().enumerate() {
+            write!(f, "{}{value}", if i == 0 { "" } else { ", " })?;
+        }
+        Ok(())
+    }
+}
@@ -50,7 +50,7 @@ impl fmt::Display for TernaryData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{}, {}, {}, {}",
+            "{}, {}, {} -> r{}",
             self.cond, self.lhs, self.rhs, self.dest
         )
     }
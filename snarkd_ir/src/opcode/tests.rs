@@ -0,0 +1,71 @@
+use std::collections::HashSet;
+
+use prost::{encoding::decode_key, Message};
+
+use crate::{ir, *};
+
+/// Operands of the kinds of `info`, each with a value the assembly and protobuf encodings keep.
+fn sample_args(info: &OpcodeInfo) -> Vec<Arg> {
+    info.operands
+        .iter()
+        .enumerate()
+        .map(|(i, kind)| match kind {
+            OperandKind::Value => Arg::Value(Operand::Ref(i as u32)),
+            OperandKind::Values => Arg::Values(vec![Operand::Ref(4), Operand::Boolean(true)]),
+            OperandKind::Type => Arg::Type(Type::U8),
+            OperandKind::Index | OperandKind::Count | OperandKind::Length => Arg::Number(2),
+            OperandKind::Function => Arg::Number(1),
+            OperandKind::Randomness => Arg::Randomness(Scalar { values: vec![9] }),
+            OperandKind::Dest => Arg::Number(7),
+            OperandKind::Dests => Arg::Numbers(vec![7, 8]),
+        })
+        .collect()
+}
+
+#[test]
+fn registry_order() {
+    for (i, info) in OPCODES.iter().enumerate() {
+        assert_eq!(info.opcode as usize, i);
+        assert_eq!(info.opcode.info(), info);
+    }
+}
+
+#[test]
+fn mnemonics_are_unique() {
+    let mut mnemonics = HashSet::new();
+    for info in OPCODES {
+        assert!(mnemonics.insert(info.mnemonic), "{}", info.mnemonic);
+        assert_eq!(Opcode::from_mnemonic(info.mnemonic), Some(info.opcode));
+    }
+    assert_eq!(Opcode::from_mnemonic("div.wrapped"), None);
+}
+
+#[test]
+fn instructions_round_trip() {
+    for info in OPCODES {
+        let instruction = Instruction::from_args(info.opcode, sample_args(info));
+        assert_eq!(instruction.opcode(), info.opcode);
+        assert_eq!(instruction.mnemonic(), info.mnemonic);
+        assert_eq!(instruction.args(), sample_args(info));
+
+        let text = instruction.to_string();
+        assert!(text.starts_with(info.mnemonic), "{text}");
+        assert_eq!(text.parse::<Instruction>().unwrap(), instruction, "{text}");
+
+        let encoded = ir::Instruction::from(instruction.clone());
+        let bytes = encoded.encode_to_vec();
+        let (tag, _) = decode_key(&mut &bytes[..]).unwrap();
+        assert_eq!(tag, info.tag, "{}", info.mnemonic);
+        assert_eq!(Instruction::try_from(encoded).unwrap(), instruction);
+    }
+}
+
+#[test]
+fn arity() {
+    assert_eq!(Opcode::Add.info().arity(), Some(2));
+    assert_eq!(Opcode::Cast.info().arity(), Some(2));
+    assert_eq!(Opcode::CommitPed64.info().arity(), Some(2));
+    assert_eq!(Opcode::Ternary.info().arity(), Some(3));
+    assert_eq!(Opcode::Call.info().arity(), None);
+    assert_eq!(Opcode::Return.info().arity(), None);
+}
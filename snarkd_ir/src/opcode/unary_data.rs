@@ -36,6 +36,6 @@ impl From<UnaryData> for ir::UnaryData {
 
 impl fmt::Display for UnaryData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}, {}", self.operand, self.dest)
+        write!(f, "{} -> r{}", self.operand, self.dest)
     }
 }
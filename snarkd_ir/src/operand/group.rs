@@ -131,9 +131,13 @@ impl From<Group> for ir::operand::Group {
 impl fmt::Display for Group {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Group::Single(v) => v.fmt(f)?,
-            Group::Tuple(v) => v.fmt(f)?,
-        };
-        write!(f, "group")
+            Group::Single(v) => Limbs {
+                negate: v.negate,
+                values: &v.values,
+                suffix: "group",
+            }
+            .fmt(f),
+            Group::Tuple(v) => write!(f, "{v}group"),
+        }
     }
 }
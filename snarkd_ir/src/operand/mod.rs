@@ -10,7 +10,10 @@ pub use structs::*;
 mod types;
 pub use types::*;
 
-use crate::ir;
+use crate::{
+    asm::{Limbs, Name},
+    ir,
+};
 use bech32::ToBase32;
 pub use ir::operand::{Address, Field, Scalar, Visibility};
 use snarkd_errors::{Error, IRError, IntoSnarkdError, Result};
@@ -118,19 +121,19 @@ impl fmt::Display for Operand {
             Operand::Boolean(v) => v.fmt(f),
             Operand::Field(v) => v.fmt(f),
             Operand::Group(v) => v.fmt(f),
-            Operand::U8(v) => v.fmt(f),
-            Operand::U16(v) => v.fmt(f),
-            Operand::U32(v) => v.fmt(f),
-            Operand::U64(v) => v.fmt(f),
-            Operand::U128(v) => v.fmt(f),
-            Operand::I8(v) => v.fmt(f),
-            Operand::I16(v) => v.fmt(f),
-            Operand::I32(v) => v.fmt(f),
-            Operand::I64(v) => v.fmt(f),
-            Operand::I128(v) => v.fmt(f),
-            Operand::Ref(v) => v.fmt(f),
+            Operand::U8(v) => write!(f, "{v}u8"),
+            Operand::U16(v) => write!(f, "{v}u16"),
+            Operand::U32(v) => write!(f, "{v}u32"),
+            Operand::U64(v) => write!(f, "{v}u64"),
+            Operand::U128(v) => write!(f, "{v}u128"),
+            Operand::I8(v) => write!(f, "{v}i8"),
+            Operand::I16(v) => write!(f, "{v}i16"),
+            Operand::I32(v) => write!(f, "{v}i32"),
+            Operand::I64(v) => write!(f, "{v}i64"),
+            Operand::I128(v) => write!(f, "{v}i128"),
+            Operand::Ref(v) => write!(f, "r{v}"),
             Operand::Scalar(v) => v.fmt(f),
-            Operand::String(v) => v.fmt(f),
+            Operand::String(v) => write!(f, "{v:?}"),
             Operand::Record(v) => v.fmt(f),
             Operand::Struct(v) => v.fmt(f),
         }
@@ -152,7 +155,12 @@ impl Eq for Address {}
 
 impl fmt::Display for Scalar {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "scalar{:?}", self.values)
+        Limbs {
+            negate: false,
+            values: &self.values,
+            suffix: "scalar",
+        }
+        .fmt(f)
     }
 }
 impl Eq for Scalar {}
@@ -169,10 +177,12 @@ impl fmt::Display for Visibility {
 
 impl fmt::Display for Field {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.negate {
-            write!(f, "-")?
-        };
-        write!(f, "{:?}", self.values)
+        Limbs {
+            negate: self.negate,
+            values: &self.values,
+            suffix: "field",
+        }
+        .fmt(f)
     }
 }
 impl Eq for Field {}
@@ -91,8 +91,8 @@ impl fmt::Display for Record {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "record(owner: {}, gates: {}, data: (",
-            self.owner, self.gates
+            "record(owner: {}.{}, gates: {}u64.{}, data: (",
+            self.owner, self.owner_visibility, self.gates, self.gates_visibility
         )?;
         for (i, item) in self.data.iter().enumerate() {
             write!(
@@ -101,6 +101,6 @@ impl fmt::Display for Record {
                 if i == self.data.len() - 1 { "" } else { ", " }
             )?;
         }
-        write!(f, "), nonce: {})", self.nonce)
+        write!(f, "), nonce: {}.{})", self.nonce, self.nonce_visibility)
     }
 }
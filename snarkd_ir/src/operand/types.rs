@@ -205,3 +205,62 @@ impl From<Type> for ir::operand::Type {
         }
     }
 }
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::Address => write!(f, "address"),
+            Type::Boolean => write!(f, "boolean"),
+            Type::Field => write!(f, "field"),
+            Type::Group => write!(f, "group"),
+            Type::U8 => write!(f, "u8"),
+            Type::U16 => write!(f, "u16"),
+            Type::U32 => write!(f, "u32"),
+            Type::U64 => write!(f, "u64"),
+            Type::U128 => write!(f, "u128"),
+            Type::I8 => write!(f, "i8"),
+            Type::I16 => write!(f, "i16"),
+            Type::I32 => write!(f, "i32"),
+            Type::I64 => write!(f, "i64"),
+            Type::I128 => write!(f, "i128"),
+            Type::Scalar => write!(f, "scalar"),
+            Type::String => write!(f, "string"),
+            Type::Struct(type_) => write!(f, "struct {type_}"),
+            Type::Record(type_) => write!(f, "record {type_}"),
+        }
+    }
+}
+
+/// Writes the entries of the struct type, as `{ a: u8, b: field }`.
+impl fmt::Display for StructType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        for (i, field) in self.fields.iter().enumerate() {
+            let separator = if i == 0 { " " } else { ", " };
+            write!(f, "{separator}{}: {}", Name(&field.name), field.type_)?;
+        }
+        write!(f, " }}")
+    }
+}
+
+/// Writes the entries of the record type with their visibilities, starting with the owner and
+/// gates and ending with the nonce.
+impl fmt::Display for RecordType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{{ owner: address.{}, gates: u64.{}",
+            self.owner, self.gates
+        )?;
+        for entry in &self.data {
+            write!(
+                f,
+                ", {}: {}.{}",
+                Name(&entry.name),
+                entry.type_,
+                entry.visibility
+            )?;
+        }
+        write!(f, ", nonce: group.{} }}", self.nonce)
+    }
+}
@@ -0,0 +1,622 @@
+#[cfg(test)]
+mod tests;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use snarkd_errors::{IRError, Result};
+
+use crate::{
+    eval::value::canonicalize,
+    validate::{operand_type, operands, result_type},
+    Arg, AssertData, BinaryData, Field, Function, Instruction, MaskData, Opcode, Operand,
+    OperandKind, Program, Record, Registers, RepeatData, Scalar, Struct, Type, UnaryData,
+};
+
+/// Optimization passes run by [`Function::optimize`] and [`Program::optimize`], each of which can
+/// be turned off on its own. Passes are repeated until none of them changes the function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Passes {
+    /// Evaluates instructions reading no registers, and reads their results as literals.
+    /// Assertions known to hold are dropped, as are blocks masked by `false` or repeated zero
+    /// times, and blocks masked by `true` become blocks repeated once.
+    pub constant_folding: bool,
+    /// Reads the results of `mul x, 1`, `add x, 0`, `sub x, 0` and `ternary` with a known
+    /// condition or equal branches as the operand they equal, turns `double x` into `add x, x`
+    /// and drops `assert.eq x, x`.
+    pub algebraic_simplification: bool,
+    /// Reads the result of an instruction repeating an earlier one on the same operands as the
+    /// result of the earlier one.
+    pub common_subexpressions: bool,
+    /// Removes instructions whose results are never read, as long as they cannot fail.
+    pub dead_registers: bool,
+}
+
+impl Passes {
+    pub const NONE: Self = Self {
+        constant_folding: false,
+        algebraic_simplification: false,
+        common_subexpressions: false,
+        dead_registers: false,
+    };
+}
+
+impl Default for Passes {
+    fn default() -> Self {
+        Self {
+            constant_folding: true,
+            algebraic_simplification: true,
+            common_subexpressions: true,
+            dead_registers: true,
+        }
+    }
+}
+
+/// An instruction of a function, or a `mask` or `repeat` along with the instructions of its block.
+#[derive(Debug, Clone)]
+enum Node {
+    Instruction {
+        instruction: Instruction,
+        /// Whether the instruction cannot fail, as found before dead registers are removed.
+        total: bool,
+    },
+    Block {
+        head: Instruction,
+        body: Vec<Node>,
+    },
+}
+
+/// Nests the instructions of blocks under their `mask` or `repeat`. Blocks running past the end
+/// of `instructions` are cut short, as the function is expected to be valid.
+fn tree(instructions: &[Instruction]) -> Vec<Node> {
+    let mut nodes = vec![];
+    let mut i = 0;
+    while let Some(instruction) = instructions.get(i) {
+        i += 1;
+        let length = match instruction {
+            Instruction::Mask(MaskData { length, .. })
+            | Instruction::Repeat(RepeatData { length, .. }) => *length as usize,
+            _ => {
+                nodes.push(Node::Instruction {
+                    instruction: instruction.clone(),
+                    total: false,
+                });
+                continue;
+            }
+        };
+        let end = instructions.len().min(i + length);
+        nodes.push(Node::Block {
+            head: instruction.clone(),
+            body: tree(&instructions[i..end]),
+        });
+        i = end;
+    }
+    nodes
+}
+
+/// Lays `nodes` back out in order, updating the length of every block.
+fn flatten(nodes: Vec<Node>, instructions: &mut Vec<Instruction>) {
+    for node in nodes {
+        match node {
+            Node::Instruction { instruction, .. } => instructions.push(instruction),
+            Node::Block { head, body } => {
+                let start = instructions.len();
+                instructions.push(head);
+                flatten(body, instructions);
+                let length = (instructions.len() - start - 1) as u32;
+                match &mut instructions[start] {
+                    Instruction::Mask(MaskData { length: x, .. })
+                    | Instruction::Repeat(RepeatData { length: x, .. }) => *x = length,
+                    _ => unreachable!("blocks start with `mask` or `repeat`"),
+                }
+            }
+        }
+    }
+}
+
+/// Adds the registers `operand` reads, also within struct and record literals, to `registers`.
+fn refs(operand: &Operand, registers: &mut BTreeSet<u32>) {
+    match operand {
+        Operand::Ref(register) => {
+            registers.insert(*register);
+        }
+        Operand::Struct(value) => value.values.iter().for_each(|x| refs(x, registers)),
+        Operand::Record(value) => value.data.iter().for_each(|x| refs(&x.value, registers)),
+        _ => {}
+    }
+}
+
+fn reads(instruction: &Instruction) -> BTreeSet<u32> {
+    let mut registers = BTreeSet::new();
+    for arg in instruction.args() {
+        match arg {
+            Arg::Value(x) => refs(&x, &mut registers),
+            Arg::Values(x) => x.iter().for_each(|x| refs(x, &mut registers)),
+            _ => {}
+        }
+    }
+    registers
+}
+
+fn dests(instruction: &Instruction) -> Vec<u32> {
+    let kinds = instruction.opcode().info().operands;
+    kinds
+        .iter()
+        .zip(instruction.args())
+        .flat_map(|(kind, arg)| match (kind, arg) {
+            (OperandKind::Dest, Arg::Number(x)) => vec![x],
+            (OperandKind::Dests, Arg::Numbers(x)) => x,
+            _ => vec![],
+        })
+        .collect()
+}
+
+/// Registers written anywhere in `nodes`.
+fn written(nodes: &[Node], registers: &mut BTreeSet<u32>) {
+    for node in nodes {
+        match node {
+            Node::Instruction { instruction, .. } => registers.extend(dests(instruction)),
+            Node::Block { body, .. } => written(body, registers),
+        }
+    }
+}
+
+/// Rewrites every operand `instruction` reads with `f`, leaving the rest as it is.
+fn map_operands(instruction: &Instruction, f: &impl Fn(&Operand) -> Operand) -> Instruction {
+    let args = instruction
+        .args()
+        .into_iter()
+        .map(|arg| match arg {
+            Arg::Value(x) => Arg::Value(f(&x)),
+            Arg::Values(x) => Arg::Values(x.iter().map(f).collect()),
+            arg => arg,
+        })
+        .collect();
+    Instruction::from_args(instruction.opcode(), args)
+}
+
+/// Replaces the registers `operand` reads with the values they are known to hold.
+fn substitute(operand: &Operand, values: &BTreeMap<u32, Operand>) -> Operand {
+    match operand {
+        Operand::Ref(register) => values.get(register).unwrap_or(operand).clone(),
+        Operand::Struct(value) => Operand::Struct(Struct {
+            values: value.values.iter().map(|x| substitute(x, values)).collect(),
+        }),
+        Operand::Record(value) => {
+            let mut record = Record::clone(value);
+            for entry in &mut record.data {
+                entry.value = substitute(&entry.value, values);
+            }
+            Operand::Record(Box::new(record))
+        }
+        _ => operand.clone(),
+    }
+}
+
+/// The canonical form of a literal, or `None` if it reads registers or is not a valid value.
+fn literal(operand: &Operand) -> Option<Operand> {
+    canonicalize(operand, &|register| {
+        Err(IRError::unknown_register(register).into())
+    })
+    .ok()
+}
+
+/// Whether the literals in `operand` are valid values.
+fn is_valid(operand: &Operand) -> bool {
+    canonicalize(operand, &|_| Ok(Operand::Boolean(false))).is_ok()
+}
+
+/// Whether `operand` is an integer, field or scalar literal equal to `n`.
+fn is_number(operand: &Operand, n: u64) -> bool {
+    let n = n as i128;
+    match operand {
+        Operand::U8(v) => *v as i128 == n,
+        Operand::U16(v) => *v as i128 == n,
+        Operand::U32(v) => *v as i128 == n,
+        Operand::U64(v) => *v as i128 == n,
+        Operand::U128(v) => *v == n as u128,
+        Operand::I8(v) => *v as i128 == n,
+        Operand::I16(v) => *v as i128 == n,
+        Operand::I32(v) => *v as i128 == n,
+        Operand::I64(v) => *v as i128 == n,
+        Operand::I128(v) => *v == n,
+        Operand::Field(_) => {
+            let number = Operand::Field(Field {
+                negate: false,
+                values: vec![n as u64],
+            });
+            literal(operand).is_some_and(|x| Some(x) == literal(&number))
+        }
+        Operand::Scalar(_) => {
+            let number = Operand::Scalar(Scalar {
+                values: vec![n as u64],
+            });
+            literal(operand).is_some_and(|x| Some(x) == literal(&number))
+        }
+        _ => false,
+    }
+}
+
+/// What constant folding finds out about an instruction.
+enum Folded {
+    Unknown,
+    /// The instruction is an assertion that holds.
+    Holds,
+    /// The instruction writes this value.
+    Value(Operand),
+}
+
+/// Evaluates `instruction` if it reads no registers. Instructions that would fail are left to
+/// fail when the function runs.
+fn fold(instruction: &Instruction) -> Folded {
+    use Instruction::*;
+    if matches!(instruction, Call(_) | Return(_) | Mask(_) | Repeat(_))
+        || !reads(instruction).is_empty()
+    {
+        return Folded::Unknown;
+    }
+    let mut registers = Registers::default();
+    if registers.execute(instruction).is_err() {
+        return Folded::Unknown;
+    }
+    let value = registers.iter().next().map(|(_, value)| value.clone());
+    value.map_or(Folded::Holds, Folded::Value)
+}
+
+/// The operand `instruction` always gives the value of, by an algebraic identity.
+fn identity(instruction: &Instruction) -> Option<Operand> {
+    use Instruction::*;
+    match instruction {
+        Mul(BinaryData { lhs, rhs, .. }) | MulWrapped(BinaryData { lhs, rhs, .. }) => {
+            if is_number(rhs, 1) {
+                Some(lhs.clone())
+            } else if is_number(lhs, 1) {
+                Some(rhs.clone())
+            } else {
+                None
+            }
+        }
+        Add(BinaryData { lhs, rhs, .. }) | AddWrapped(BinaryData { lhs, rhs, .. }) => {
+            if is_number(rhs, 0) {
+                Some(lhs.clone())
+            } else if is_number(lhs, 0) {
+                Some(rhs.clone())
+            } else {
+                None
+            }
+        }
+        Sub(BinaryData { lhs, rhs, .. }) | SubWrapped(BinaryData { lhs, rhs, .. }) => {
+            is_number(rhs, 0).then(|| lhs.clone())
+        }
+        Ternary(data) => match &data.cond {
+            Operand::Boolean(true) => Some(data.lhs.clone()),
+            Operand::Boolean(false) => Some(data.rhs.clone()),
+            _ => (data.lhs == data.rhs).then(|| data.lhs.clone()),
+        },
+        _ => None,
+    }
+}
+
+/// An instruction already run, whose result is still held by `dest`.
+#[derive(Debug, Clone)]
+struct Expression {
+    opcode: Opcode,
+    /// The operands of the instruction, without its destination.
+    args: Vec<Arg>,
+    reads: BTreeSet<u32>,
+    dest: u32,
+}
+
+impl Expression {
+    fn new(instruction: &Instruction) -> Option<Self> {
+        let opcode = instruction.opcode();
+        let info = opcode.info();
+        if !matches!(info.operands.last(), Some(OperandKind::Dest)) {
+            return None;
+        }
+        let mut args = instruction.args();
+        let dest = match args.pop() {
+            Some(Arg::Number(dest)) => dest,
+            _ => return None,
+        };
+        let reads = reads(instruction);
+        // An instruction overwriting one of its operands cannot be repeated.
+        if reads.contains(&dest) {
+            return None;
+        }
+        Some(Self {
+            opcode,
+            args,
+            reads,
+            dest,
+        })
+    }
+}
+
+/// What is known about the registers at a point of a function.
+#[derive(Debug, Clone, Default)]
+struct Known {
+    /// Registers known to hold the same value as an operand.
+    values: BTreeMap<u32, Operand>,
+    /// Instructions whose results can be reused.
+    expressions: Vec<Expression>,
+}
+
+impl Known {
+    /// Forgets what depends on `registers`, which are about to be written.
+    fn forget(&mut self, registers: &BTreeSet<u32>) {
+        self.values.retain(|register, value| {
+            let mut reads = BTreeSet::new();
+            refs(value, &mut reads);
+            !registers.contains(register) && reads.is_disjoint(registers)
+        });
+        self.expressions
+            .retain(|x| !registers.contains(&x.dest) && x.reads.is_disjoint(registers));
+    }
+}
+
+/// Runs constant folding, algebraic simplification and common subexpression elimination over
+/// `nodes`, as enabled in `passes`, starting from what is `known`.
+fn propagate(nodes: Vec<Node>, known: &mut Known, passes: &Passes) -> Vec<Node> {
+    let mut result = vec![];
+    for node in nodes {
+        let (instruction, body) = match node {
+            Node::Instruction { instruction, .. } => (instruction, None),
+            Node::Block { head, body } => (head, Some(body)),
+        };
+        let mut instruction = map_operands(&instruction, &|x| substitute(x, &known.values));
+
+        if let Some(body) = body {
+            if passes.constant_folding {
+                match instruction {
+                    Instruction::Mask(MaskData {
+                        cond: Operand::Boolean(true),
+                        length,
+                    }) => instruction = Instruction::Repeat(RepeatData { count: 1, length }),
+                    Instruction::Mask(MaskData {
+                        cond: Operand::Boolean(false),
+                        ..
+                    })
+                    | Instruction::Repeat(RepeatData { count: 0, .. }) => continue,
+                    _ => {}
+                }
+            }
+            // What the block writes may change from one run of it to the next, and is not
+            // known after it, as it may not run at all.
+            let mut registers = BTreeSet::new();
+            written(&body, &mut registers);
+            known.forget(&registers);
+            let body = propagate(body, &mut known.clone(), passes);
+            if !body.is_empty() {
+                result.push(Node::Block {
+                    head: instruction,
+                    body,
+                });
+            }
+            continue;
+        }
+
+        if passes.algebraic_simplification {
+            if let Instruction::Double(UnaryData { operand, dest }) = instruction {
+                instruction = Instruction::Add(BinaryData {
+                    lhs: operand.clone(),
+                    rhs: operand,
+                    dest,
+                });
+            }
+        }
+        let mut value = None;
+        if passes.constant_folding {
+            match fold(&instruction) {
+                Folded::Holds => continue,
+                Folded::Value(x) => value = Some(x),
+                Folded::Unknown => {}
+            }
+        }
+        if passes.algebraic_simplification {
+            match &instruction {
+                Instruction::AssertEq(AssertData { lhs, rhs }) if lhs == rhs && is_valid(lhs) => {
+                    continue
+                }
+                _ if value.is_none() => value = identity(&instruction),
+                _ => {}
+            }
+        }
+        let expression = Expression::new(&instruction);
+        if passes.common_subexpressions && value.is_none() {
+            value = expression.as_ref().and_then(|x| {
+                known
+                    .expressions
+                    .iter()
+                    .find(|y| y.opcode == x.opcode && y.args == x.args)
+                    .map(|y| Operand::Ref(y.dest))
+            });
+        }
+
+        let dests = dests(&instruction);
+        known.forget(&dests.iter().copied().collect());
+        if let ([dest], Some(value)) = (&dests[..], value) {
+            if value == Operand::Ref(*dest) {
+                // The register already holds the value.
+                continue;
+            }
+            let mut reads = BTreeSet::new();
+            refs(&value, &mut reads);
+            if !reads.contains(dest) {
+                known.values.insert(*dest, value);
+            }
+        } else if let Some(expression) = expression.filter(|_| passes.common_subexpressions) {
+            known.expressions.push(expression);
+        }
+        result.push(Node::Instruction {
+            instruction,
+            total: false,
+        });
+    }
+    result
+}
+
+/// Whether `instruction` cannot fail once it is known to be defined for its operands, the first
+/// of which has type `first`.
+fn cannot_fail(instruction: &Instruction, first: Option<&Type>) -> bool {
+    use Instruction::*;
+    match instruction {
+        AbsWrapped(_) | AddWrapped(_) | SubWrapped(_) | MulWrapped(_) | PowWrapped(_)
+        | ShlWrapped(_) | ShrWrapped(_) | And(_) | Or(_) | Xor(_) | Nand(_) | Nor(_) | Not(_)
+        | Gt(_) | Gte(_) | Lt(_) | Lte(_) | IsEq(_) | IsNeq(_) | Ternary(_) | Double(_)
+        | Square(_) | Member(_) | RecordField(_) | ToBits(_) | Declassify(_) => true,
+        // Only integers overflow.
+        Add(_) | Sub(_) | Mul(_) | Neg(_) => {
+            matches!(first, Some(Type::Field | Type::Group | Type::Scalar))
+        }
+        Pow(_) => matches!(first, Some(Type::Field)),
+        _ => false,
+    }
+}
+
+/// Finds the instructions of `nodes` that cannot fail, given the types of registers before them.
+fn mark_total(nodes: &mut [Node], types: &mut BTreeMap<u32, Option<Type>>) {
+    for node in nodes {
+        match node {
+            Node::Instruction { instruction, total } => {
+                let (operands, dest) = operands(instruction);
+                let operand_types = operands
+                    .iter()
+                    .map(|x| operand_type(x, types).ok().flatten())
+                    .collect::<Option<Vec<_>>>();
+                let result = operand_types
+                    .as_ref()
+                    .and_then(|x| result_type(instruction, x));
+                // Valid functions only apply instructions to operands they are defined for, so
+                // operand types only matter where they decide whether an instruction can fail.
+                let defined = operand_types.is_none() || result.is_some();
+                *total = defined
+                    && operands.iter().all(|x| is_valid(x))
+                    && cannot_fail(instruction, operand_types.as_ref().and_then(|x| x.first()))
+                    || matches!(fold(instruction), Folded::Value(_));
+                match instruction {
+                    Instruction::Call(data) => {
+                        types.extend(data.dests.iter().map(|x| (*x, None)));
+                    }
+                    _ => {
+                        if let Some(dest) = dest {
+                            types.insert(dest, result);
+                        }
+                    }
+                }
+            }
+            // Blocks keep the types of registers written before them, and the registers first
+            // written in them are not readable after them.
+            Node::Block { body, .. } => mark_total(body, &mut types.clone()),
+        }
+    }
+}
+
+/// Removes the instructions of `nodes` that cannot fail and whose results are never read, given
+/// the registers `live` read after them. Leaves `live` as the registers read before them.
+fn sweep(nodes: &mut Vec<Node>, live: &mut BTreeSet<u32>) {
+    for i in (0..nodes.len()).rev() {
+        match &mut nodes[i] {
+            Node::Instruction { instruction, total } => {
+                let dests = dests(instruction);
+                if *total && !dests.is_empty() && dests.iter().all(|x| !live.contains(x)) {
+                    nodes.remove(i);
+                    continue;
+                }
+                for dest in &dests {
+                    live.remove(dest);
+                }
+                live.extend(reads(instruction));
+            }
+            Node::Block { head, body } => {
+                let mut end = live.clone();
+                if matches!(head, Instruction::Repeat(_)) {
+                    // Later runs of the block read what earlier runs write.
+                    loop {
+                        let mut start = end.clone();
+                        sweep(&mut body.clone(), &mut start);
+                        if start.is_subset(&end) {
+                            break;
+                        }
+                        end.extend(start);
+                    }
+                }
+                sweep(body, &mut end);
+                // The block may not run at all.
+                live.extend(end);
+                live.extend(reads(head));
+                if body.is_empty() {
+                    nodes.remove(i);
+                }
+            }
+        }
+    }
+}
+
+impl Function {
+    /// Optimizes the function with the enabled `passes`, without changing the values it returns,
+    /// the final values of the registers in `outputs`, or whether it fails. Other registers may
+    /// be left with other values, or not written at all.
+    ///
+    /// The function is expected to be valid, as [`Program::validate`] checks.
+    pub fn optimize(&mut self, passes: Passes, outputs: &BTreeSet<u32>) {
+        self.optimize_with(passes, outputs, &BTreeMap::new());
+    }
+
+    /// Optimizes the function, knowing the types of some registers at its start.
+    fn optimize_with(
+        &mut self,
+        passes: Passes,
+        outputs: &BTreeSet<u32>,
+        types: &BTreeMap<u32, Option<Type>>,
+    ) {
+        loop {
+            let mut nodes = tree(&self.instructions);
+            if passes.constant_folding
+                || passes.algebraic_simplification
+                || passes.common_subexpressions
+            {
+                nodes = propagate(nodes, &mut Known::default(), &passes);
+            }
+            if passes.dead_registers {
+                mark_total(&mut nodes, &mut types.clone());
+                sweep(&mut nodes, &mut outputs.clone());
+            }
+            let mut instructions = vec![];
+            flatten(nodes, &mut instructions);
+            if instructions == self.instructions {
+                return;
+            }
+            self.instructions = instructions;
+        }
+    }
+}
+
+impl Program {
+    /// Validates the program, then optimizes each of its functions with the enabled `passes`.
+    /// Running the program gives the same registers as before, or fails as before. Functions other
+    /// than the first only have to keep the values they return.
+    pub fn optimize(&mut self, passes: Passes) -> Result<()> {
+        self.validate()?;
+        let types = self
+            .header
+            .inputs()
+            .map(|x| (x.variable, Some(x.type_.clone())))
+            .collect();
+        for (i, function) in self.functions.iter_mut().enumerate() {
+            if i > 0 {
+                function.optimize(passes, &BTreeSet::new());
+                continue;
+            }
+            // The registers of the first function remaining once it finishes are the result of
+            // the program. Registers first written in blocks are not among them.
+            let mut outputs = BTreeSet::new();
+            for node in tree(&function.instructions) {
+                if let Node::Instruction { instruction, .. } = node {
+                    outputs.extend(dests(&instruction));
+                }
+            }
+            function.optimize_with(passes, &outputs, &types);
+        }
+        Ok(())
+    }
+}
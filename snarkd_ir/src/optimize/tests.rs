@@ -0,0 +1,295 @@
+use crate::*;
+
+/// Calls a function whose result only depends on some of its instructions, then adds to the
+/// result in blocks.
+const INTEGERS: &str = "
+    input private r0 a: u8
+    input public r1 b: boolean
+
+    decl f0 r2
+    0: call f1 r0, r1 -> r2
+    1: mask r1, 1
+    2: add.w r2, 1u8 -> r2
+    3: repeat 2, 2
+    4: mul.w 2u8, 3u8 -> r3
+    5: add.w r2, r3 -> r2
+
+    decl f1 r0
+    0: mul 2u8, 3u8 -> r2
+    1: add r0, r2 -> r3
+    2: add.w r0, r2 -> r4
+    3: add.w r0, r2 -> r5
+    4: mul.w r5, 1u8 -> r6
+    5: sub.w r6, 0u8 -> r7
+    6: ternary r1, r7, r3 -> r8
+    7: ternary true, r8, r0 -> r9
+    8: xor r0, r0 -> r10
+    9: assert.eq r2, 6u8
+    10: return r9
+";
+
+/// Field arithmetic the compiler supports, with blocks masked by constants.
+const FIELDS: &str = "
+    input private r0 a: field
+    input public r1 b: boolean
+
+    decl f0 r2
+    0: mul 2field, 3field -> r2
+    1: double r0 -> r3
+    2: add r3, r2 -> r4
+    3: add r0, r0 -> r5
+    4: mul r5, 1field -> r6
+    5: add r6, 0field -> r7
+    6: ternary r1, r7, r4 -> r8
+    7: mask false, 1
+    8: assert false
+    9: mask true, 1
+    10: assert.eq r7, r5
+";
+
+fn input(a: Operand, b: bool) -> InputData {
+    InputData {
+        constant_inputs: vec![],
+        private_inputs: vec![InputValue {
+            name: "a".into(),
+            value: a,
+        }],
+        public_inputs: vec![InputValue {
+            name: "b".into(),
+            value: Operand::Boolean(b),
+        }],
+        register_inputs: vec![],
+        public_states: vec![],
+        private_record_states: vec![],
+        private_leaf_states: vec![],
+    }
+}
+
+fn field(value: u64) -> Operand {
+    Operand::Field(Field {
+        negate: false,
+        values: vec![value],
+    })
+}
+
+/// Each pass on its own, all of them and none of them.
+fn pass_sets() -> Vec<Passes> {
+    vec![
+        Passes::NONE,
+        Passes {
+            constant_folding: true,
+            ..Passes::NONE
+        },
+        Passes {
+            algebraic_simplification: true,
+            ..Passes::NONE
+        },
+        Passes {
+            common_subexpressions: true,
+            ..Passes::NONE
+        },
+        Passes {
+            dead_registers: true,
+            ..Passes::NONE
+        },
+        Passes::default(),
+    ]
+}
+
+fn optimized(source: &str, passes: Passes) -> Program {
+    let mut program: Program = source.parse().unwrap();
+    program.optimize(passes).unwrap();
+    program
+}
+
+fn instructions(source: &str) -> Vec<Instruction> {
+    let function: Program = source.parse().unwrap();
+    function.functions[0].instructions.clone()
+}
+
+#[test]
+fn passes_keep_results() {
+    let inputs = [
+        input(Operand::U8(5), true),
+        input(Operand::U8(5), false),
+        // Overflows in `f1`.
+        input(Operand::U8(250), true),
+    ];
+    let program: Program = INTEGERS.parse().unwrap();
+    for passes in pass_sets() {
+        let optimized = optimized(INTEGERS, passes);
+        optimized.validate().unwrap();
+        for input in &inputs {
+            match (program.evaluate(input), optimized.evaluate(input)) {
+                (Ok(a), Ok(b)) => assert_eq!(a, b, "{passes:?}"),
+                (Err(_), Err(_)) => {}
+                (a, b) => panic!("{passes:?}: {a:?} became {b:?}"),
+            }
+        }
+    }
+
+    let inputs = [input(field(5), true), input(field(5), false)];
+    let program: Program = FIELDS.parse().unwrap();
+    for passes in pass_sets() {
+        let optimized = optimized(FIELDS, passes);
+        optimized.validate().unwrap();
+        for input in &inputs {
+            let expected = program.evaluate(input).unwrap();
+            assert_eq!(optimized.evaluate(input).unwrap(), expected, "{passes:?}");
+            let compiled = optimized.compile(input).unwrap();
+            assert_eq!(compiled.registers, expected, "{passes:?}");
+        }
+    }
+}
+
+#[test]
+fn all_passes() {
+    let program = optimized(INTEGERS, Passes::default());
+    assert_eq!(
+        program.functions[0].instructions,
+        instructions(
+            "
+            decl r2
+            call f1 r0, r1 -> r2
+            mask r1, 1
+            add.w r2, 1u8 -> r2
+            repeat 2, 1
+            add.w r2, 6u8 -> r2
+            "
+        )
+    );
+    assert_eq!(
+        program.functions[1].instructions,
+        instructions(
+            "
+            decl r0
+            add r0, 6u8 -> r3
+            add.w r0, 6u8 -> r4
+            ternary r1, r4, r3 -> r8
+            return r8
+            "
+        )
+    );
+
+    // Registers of the first function are kept, but read through what they are known to hold.
+    let program = optimized(FIELDS, Passes::default());
+    assert_eq!(
+        program.functions[0].instructions,
+        instructions(
+            "
+            decl r2
+            mul 2field, 3field -> r2
+            add r0, r0 -> r3
+            add r3, 6field -> r4
+            add r0, r0 -> r5
+            mul r3, 1field -> r6
+            add r3, 0field -> r7
+            ternary r1, r3, r4 -> r8
+            "
+        )
+    );
+}
+
+#[test]
+fn single_passes() {
+    let f1 = |passes| optimized(INTEGERS, passes).functions[1].clone();
+    let text = |function: &Function| {
+        function
+            .instructions
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+    };
+
+    let folded = f1(Passes {
+        constant_folding: true,
+        ..Passes::NONE
+    });
+    let folded = text(&folded);
+    assert_eq!(folded.len(), 10);
+    assert_eq!(folded[1], "add r0, 6u8 -> r3");
+    assert!(!folded.iter().any(|x| x.starts_with("assert")));
+
+    let simplified = f1(Passes {
+        algebraic_simplification: true,
+        ..Passes::NONE
+    });
+    let simplified = text(&simplified);
+    assert_eq!(simplified[5], "sub.w r5, 0u8 -> r7");
+    assert_eq!(simplified[6], "ternary r1, r5, r3 -> r8");
+    assert_eq!(simplified[10], "return r8");
+
+    let shared = f1(Passes {
+        common_subexpressions: true,
+        ..Passes::NONE
+    });
+    assert_eq!(text(&shared)[4], "mul.w r4, 1u8 -> r6");
+
+    // Only `r4` and `r10` are never read.
+    let swept = f1(Passes {
+        dead_registers: true,
+        ..Passes::NONE
+    });
+    let swept = text(&swept);
+    assert_eq!(swept.len(), 9);
+    assert!(!swept
+        .iter()
+        .any(|x| x.ends_with("-> r4") || x.ends_with("-> r10")));
+}
+
+#[test]
+fn failing_instructions_are_kept() {
+    let source = "
+        input private r0 a: u8
+        input public r1 b: boolean
+
+        decl f0 r2
+        0: call f1 r0 -> r2
+
+        decl f1 r0
+        0: add r0, 255u8 -> r1
+        1: div.w 1u8, r0 -> r2
+        2: add 255u8, 1u8 -> r3
+        3: return r0
+    ";
+    let program = optimized(source, Passes::default());
+    assert_eq!(program.functions[1].instructions.len(), 4);
+    assert!(program.evaluate(&input(Operand::U8(0), true)).is_err());
+    assert!(program.evaluate(&input(Operand::U8(1), true)).is_err());
+}
+
+#[test]
+fn function_outputs() {
+    let mut function = "
+        decl r0
+        0: add.w r0, 1u8 -> r1
+        1: add.w r0, 2u8 -> r1
+        2: mask true, 2
+        3: add.w r1, r1 -> r2
+        4: add.w r2, r0 -> r1
+        5: not r1 -> r3
+    "
+    .parse::<Program>()
+    .unwrap()
+    .functions
+    .remove(0);
+    let original = function.clone();
+    function.optimize(Passes::default(), &[1].into());
+    assert_eq!(
+        function.instructions,
+        instructions(
+            "
+            decl r0
+            add.w r0, 2u8 -> r1
+            repeat 1, 2
+            add.w r1, r1 -> r2
+            add.w r2, r0 -> r1
+            "
+        )
+    );
+    let registers = Registers::from_iter([(0, Operand::U8(3))]);
+    assert_eq!(
+        function.evaluate(registers.clone()).unwrap().get(1),
+        original.evaluate(registers).unwrap().get(1)
+    );
+}
@@ -0,0 +1,42 @@
+use prost::Message;
+use sha2::{Digest, Sha256};
+
+use super::{IRError, Program, Result};
+use crate::ir;
+
+/// Prepended to the canonical encoding when hashing it, so a fingerprint can't collide with the
+/// hash of some other object that happens to share its bytes.
+const FINGERPRINT_DOMAIN: &[u8] = b"snarkd.program.v1";
+
+impl Program {
+    /// The one encoding of this program that [`Program::from_canonical_bytes`] accepts.
+    ///
+    /// Protobuf decoders accept many encodings of the same message: fields in any order,
+    /// repeated fields split or unpacked, defaults written out, unknown fields and over-long
+    /// varints. The canonical encoding is the one prost writes, with fields in tag order, packed
+    /// repeated fields and defaults left out.
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        ir::Program::from(self.clone()).encode_to_vec()
+    }
+
+    /// Decodes a program, rejecting any bytes other than its canonical encoding.
+    pub fn from_canonical_bytes(input: &[u8]) -> Result<Self> {
+        let program = Self::deserialize(input)?;
+        if program.canonical_bytes() != input {
+            return Err(IRError::non_canonical_encoding().into());
+        }
+        Ok(program)
+    }
+
+    /// SHA-256 hash of the canonical encoding, which identifies the program regardless of how
+    /// it was encoded when it was received.
+    ///
+    /// Literals are hashed as written, so `1field` and a field written with extra zero limbs
+    /// are different programs.
+    pub fn fingerprint(&self) -> [u8; 32] {
+        let mut sha = Sha256::new();
+        sha.update(FINGERPRINT_DOMAIN);
+        sha.update(self.canonical_bytes());
+        sha.finalize().into()
+    }
+}
@@ -0,0 +1,98 @@
+use std::fmt;
+
+use snarkd_errors::IRError;
+
+use crate::{asm::Name, ir, Error, Result, Type, Visibility};
+
+/// A struct or record type named by the program header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeDeclaration {
+    pub name: String,
+    pub type_: Type,
+}
+
+impl TryFrom<ir::TypeDeclaration> for TypeDeclaration {
+    type Error = Error;
+
+    fn try_from(value: ir::TypeDeclaration) -> Result<Self> {
+        Ok(Self {
+            name: value.name,
+            type_: value
+                .r#type
+                .ok_or_else(|| IRError::unset("TypeDeclaration type"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<TypeDeclaration> for ir::TypeDeclaration {
+    fn from(value: TypeDeclaration) -> Self {
+        Self {
+            name: value.name,
+            r#type: Some(value.type_.into()),
+        }
+    }
+}
+
+/// Writes the declaration as `struct name { .. }` or `record name { .. }`.
+impl fmt::Display for TypeDeclaration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = Name(&self.name);
+        match &self.type_ {
+            Type::Struct(type_) => write!(f, "struct {name} {type_}"),
+            Type::Record(type_) => write!(f, "record {name} {type_}"),
+            // Only structs and records can be declared, so this does not read back.
+            type_ => write!(f, "type {name} {type_}"),
+        }
+    }
+}
+
+/// A register left by the first function that the program outputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputType {
+    pub variable: u32,
+    pub name: String,
+    pub type_: Type,
+    pub visibility: Visibility,
+}
+
+impl TryFrom<ir::OutputType> for OutputType {
+    type Error = Error;
+
+    fn try_from(value: ir::OutputType) -> Result<Self> {
+        Ok(Self {
+            variable: value.variable,
+            name: value.name,
+            type_: value
+                .r#type
+                .ok_or_else(|| IRError::unset("Output type"))?
+                .try_into()?,
+            visibility: Visibility::from_i32(value.visibility)
+                .ok_or_else(|| IRError::invalid_visibility("Output"))?,
+        })
+    }
+}
+
+impl From<OutputType> for ir::OutputType {
+    fn from(value: OutputType) -> Self {
+        Self {
+            variable: value.variable,
+            name: value.name,
+            r#type: Some(value.type_.into()),
+            visibility: value.visibility as i32,
+        }
+    }
+}
+
+impl fmt::Display for OutputType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} r{} {}: {}",
+            self.visibility,
+            self.variable,
+            Name(&self.name),
+            self.type_
+        )
+    }
+}
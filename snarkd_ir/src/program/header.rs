@@ -1,4 +1,6 @@
-use crate::{ir, Error, InputType, Result};
+use std::fmt;
+
+use crate::{asm::Name, ir, Error, InputType, OutputType, Result, Type, TypeDeclaration};
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct Header {
@@ -12,6 +14,33 @@ pub struct Header {
     pub public_states: Vec<InputType>,
     pub private_record_states: Vec<InputType>,
     pub private_leaf_states: Vec<InputType>,
+    pub types: Vec<TypeDeclaration>,
+    pub outputs: Vec<OutputType>,
+    pub mappings: Vec<String>,
+}
+
+impl Header {
+    /// All inputs declared by the header, across every category.
+    pub fn inputs(&self) -> impl Iterator<Item = &InputType> {
+        self.constant_inputs
+            .iter()
+            .chain(&self.private_inputs)
+            .chain(&self.public_inputs)
+            .chain(&self.register_inputs)
+            .chain(&self.public_states)
+            .chain(&self.private_record_states)
+            .chain(&self.private_leaf_states)
+    }
+
+    /// Whether the program declares the mapping named `name`.
+    pub fn declares_mapping(&self, name: &str) -> bool {
+        self.mappings.iter().any(|x| x == name)
+    }
+
+    /// The declared type named `name`, if any.
+    pub fn declared_type(&self, name: &str) -> Option<&Type> {
+        self.types.iter().find(|x| x.name == name).map(|x| &x.type_)
+    }
 }
 
 impl TryFrom<ir::Header> for Header {
@@ -32,6 +61,17 @@ impl TryFrom<ir::Header> for Header {
             register_inputs: get_inputs(value.register_inputs)?,
             private_record_states: get_inputs(value.private_record_states)?,
             private_leaf_states: get_inputs(value.private_leaf_states)?,
+            types: value
+                .types
+                .into_iter()
+                .map(|x| x.try_into())
+                .collect::<Result<_>>()?,
+            outputs: value
+                .outputs
+                .into_iter()
+                .map(|x| x.try_into())
+                .collect::<Result<_>>()?,
+            mappings: value.mappings,
         })
     }
 }
@@ -52,6 +92,42 @@ impl From<Header> for ir::Header {
             public_states: get_inputs(value.public_states),
             private_record_states: get_inputs(value.private_record_states),
             private_leaf_states: get_inputs(value.private_leaf_states),
+            types: value.types.into_iter().map(|x| x.into()).collect(),
+            outputs: value.outputs.into_iter().map(|x| x.into()).collect(),
+            mappings: value.mappings,
+        }
+    }
+}
+
+impl fmt::Display for Header {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "version {}.{}.{}",
+            self.snarkd_major, self.snarkd_minor, self.snarkd_patch
+        )?;
+        for declaration in &self.types {
+            writeln!(f, "{declaration}")?;
+        }
+        for mapping in &self.mappings {
+            writeln!(f, "mapping {}", Name(mapping))?;
+        }
+        for (category, inputs) in [
+            ("constant", &self.constant_inputs),
+            ("private", &self.private_inputs),
+            ("public", &self.public_inputs),
+            ("register", &self.register_inputs),
+            ("public_state", &self.public_states),
+            ("private_record_state", &self.private_record_states),
+            ("private_leaf_state", &self.private_leaf_states),
+        ] {
+            for input in inputs {
+                writeln!(f, "input {category} {input}")?;
+            }
+        }
+        for output in &self.outputs {
+            writeln!(f, "output {output}")?;
         }
+        Ok(())
     }
 }
@@ -1,6 +1,8 @@
+use std::fmt;
+
 use snarkd_errors::IRError;
 
-use crate::{ir, Error, Operand, Result, Type};
+use crate::{asm::Name, ir, Error, Operand, Result, Type};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct InputType {
@@ -34,10 +36,16 @@ impl From<InputType> for ir::InputType {
     }
 }
 
+impl fmt::Display for InputType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "r{} {}: {}", self.variable, Name(&self.name), self.type_)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct InputValue {
-    name: String,
-    value: Operand,
+    pub name: String,
+    pub value: Operand,
 }
 
 impl TryFrom<ir::InputValue> for InputValue {
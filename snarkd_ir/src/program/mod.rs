@@ -1,5 +1,8 @@
+mod canonical;
+mod declaration;
 mod header;
 mod input;
+pub use declaration::*;
 pub use header::*;
 pub use input::*;
 
@@ -45,9 +48,14 @@ impl From<Function> for ir::Function {
     }
 }
 
-impl fmt::Display for Function {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "decl f{}", self.argument_start_variable)?;
+impl Function {
+    /// Writes the function, labelled `f{index}` when its index in the program is known.
+    fn write(&self, f: &mut fmt::Formatter<'_>, index: Option<usize>) -> fmt::Result {
+        write!(f, "decl ")?;
+        if let Some(index) = index {
+            write!(f, "f{index} ")?;
+        }
+        writeln!(f, "r{}", self.argument_start_variable)?;
         for (i, instruction) in self.instructions.iter().enumerate() {
             writeln!(f, "{i}: {instruction}")?;
         }
@@ -55,6 +63,12 @@ impl fmt::Display for Function {
     }
 }
 
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, None)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct Program {
     pub header: Header,
@@ -135,11 +149,13 @@ impl From<Program> for ir::Program {
     }
 }
 
+/// Writes the program in the assembly format, which `str::parse` reads back.
 impl fmt::Display for Program {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for function in &self.functions {
-            function.fmt(f)?;
+        self.header.fmt(f)?;
+        for (i, function) in self.functions.iter().enumerate() {
             writeln!(f)?;
+            function.write(f, Some(i))?;
         }
         Ok(())
     }
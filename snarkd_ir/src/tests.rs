@@ -1,3 +1,5 @@
+use prost::Message;
+
 use super::*;
 
 fn example_header() -> Header {
@@ -120,6 +122,102 @@ fn struct_program() -> Program {
     }
 }
 
+fn control_flow_program() -> Program {
+    Program {
+        header: example_header(),
+        functions: vec![
+            Function {
+                argument_start_variable: 6,
+                instructions: vec![
+                    Instruction::Call(CallData {
+                        function: 1,
+                        arguments: vec![Operand::Ref(0), Operand::U8(3)],
+                        dests: vec![6, 7],
+                    }),
+                    Instruction::Mask(MaskData {
+                        cond: Operand::Ref(7),
+                        length: 2,
+                    }),
+                    Instruction::Repeat(RepeatData {
+                        count: 4,
+                        length: 1,
+                    }),
+                    Instruction::AddWrapped(BinaryData {
+                        lhs: Operand::Ref(6),
+                        rhs: Operand::Ref(1),
+                        dest: 6,
+                    }),
+                    Instruction::Call(CallData {
+                        function: 2,
+                        arguments: vec![],
+                        dests: vec![],
+                    }),
+                ],
+            },
+            Function {
+                argument_start_variable: 0,
+                instructions: vec![
+                    Instruction::MulWrapped(BinaryData {
+                        lhs: Operand::Ref(0),
+                        rhs: Operand::Ref(1),
+                        dest: 2,
+                    }),
+                    Instruction::Return(ReturnData {
+                        values: vec![Operand::Ref(2), Operand::Boolean(true)],
+                    }),
+                ],
+            },
+            Function {
+                argument_start_variable: 0,
+                instructions: vec![Instruction::Return(ReturnData { values: vec![] })],
+            },
+        ],
+    }
+}
+
+fn conversion_program() -> Program {
+    Program {
+        header: example_header(),
+        functions: vec![Function {
+            argument_start_variable: 6,
+            instructions: vec![
+                Instruction::Cast(CastData {
+                    operand: Operand::Ref(0),
+                    type_: Type::Field,
+                    dest: 6,
+                }),
+                Instruction::ToBits(UnaryData {
+                    operand: Operand::Ref(6),
+                    dest: 7,
+                }),
+                Instruction::FromBits(CastData {
+                    operand: Operand::Ref(7),
+                    type_: Type::Field,
+                    dest: 8,
+                }),
+                Instruction::Lshr(BinaryData {
+                    lhs: Operand::Ref(0),
+                    rhs: Operand::U8(1),
+                    dest: 9,
+                }),
+                Instruction::Member(AccessData {
+                    operand: Operand::Ref(7),
+                    index: 3,
+                    dest: 10,
+                }),
+                Instruction::RecordField(AccessData {
+                    operand: Operand::Ref(5),
+                    index: 1,
+                    dest: 11,
+                }),
+                Instruction::Assert(ConditionData {
+                    cond: Operand::Ref(10),
+                }),
+            ],
+        }],
+    }
+}
+
 fn test_program(mut input: Program) {
     let bytes = input.serialize().unwrap();
     let output = Program::deserialize(&bytes).unwrap();
@@ -140,3 +238,77 @@ fn struct_test() {
 fn basic_record_test() {
     test_program(example_basic_record_program())
 }
+
+#[test]
+fn control_flow_test() {
+    test_program(control_flow_program())
+}
+
+#[test]
+fn conversion_test() {
+    test_program(conversion_program())
+}
+
+#[test]
+fn canonical_encoding_test() {
+    for mut program in [
+        example_program(),
+        struct_program(),
+        example_basic_record_program(),
+        control_flow_program(),
+        conversion_program(),
+    ] {
+        let bytes = program.canonical_bytes();
+        assert_eq!(bytes, program.serialize().unwrap());
+        assert_eq!(Program::from_canonical_bytes(&bytes).unwrap(), program);
+    }
+}
+
+#[test]
+fn non_canonical_encoding_test() {
+    let program = control_flow_program();
+    let encoded = ir::Program::from(program.clone());
+
+    // Functions before the header, which decoders merge into the same message.
+    let mut reordered = ir::Program {
+        header: None,
+        functions: encoded.functions.clone(),
+    }
+    .encode_to_vec();
+    reordered.extend(
+        ir::Program {
+            header: encoded.header,
+            functions: vec![],
+        }
+        .encode_to_vec(),
+    );
+    // An unknown varint field, which decoders skip.
+    let mut unknown = program.canonical_bytes();
+    unknown.extend([15 << 3, 1]);
+
+    for bytes in [reordered, unknown] {
+        let decoded = Program::deserialize(&bytes).unwrap();
+        assert_eq!(decoded, program);
+        assert_eq!(decoded.fingerprint(), program.fingerprint());
+        assert!(Program::from_canonical_bytes(&bytes).is_err());
+    }
+}
+
+#[test]
+fn fingerprint_test() {
+    let program = example_program();
+    let hex = program
+        .fingerprint()
+        .iter()
+        .map(|x| format!("{x:02x}"))
+        .collect::<String>();
+    // Nodes compare fingerprints computed by different builds, so they must never change.
+    assert_eq!(
+        hex,
+        "5f9911c8bce640b950fbd2abfcc69f3f97ff1484213465259b9d82545439325a"
+    );
+
+    let mut changed = program.clone();
+    changed.functions[0].argument_start_variable = 1;
+    assert_ne!(changed.fingerprint(), program.fingerprint());
+}
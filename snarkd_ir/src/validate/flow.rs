@@ -0,0 +1,318 @@
+//! Information flow of private data through a program that type checks.
+//!
+//! Every register is either derived from private data or not. Values derived from private data
+//! cannot be revealed, by a public or constant output or by an entry of a record that is not
+//! private, unless they pass through `declassify`.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use snarkd_errors::{IRError, Report};
+
+use super::{operand_type, operands, result_type};
+use crate::{
+    AccessData, CallData, Instruction, MaskData, Operand, Program, RecordType, RepeatData,
+    ReturnData, StructType, TernaryData, Type, Visibility,
+};
+
+/// Whether values of type `type_` hold private record entries, which stay private wherever the
+/// value goes but are revealed by anything computed from the whole value.
+fn has_private(type_: &Type) -> bool {
+    match type_ {
+        Type::Struct(StructType { fields }) => fields.iter().any(|x| has_private(&x.type_)),
+        Type::Record(RecordType {
+            owner,
+            gates,
+            data,
+            nonce,
+        }) => {
+            [owner, gates, nonce].contains(&&Visibility::Private)
+                || data
+                    .iter()
+                    .any(|x| x.visibility == Visibility::Private || has_private(&x.type_))
+        }
+        _ => false,
+    }
+}
+
+/// Visibility of the entry at `index` of a record, indexed as by `record.field`.
+fn entry_visibility(record: &RecordType, index: u32) -> Option<Visibility> {
+    match index as usize {
+        0 => Some(record.owner),
+        1 => Some(record.gates),
+        i if i == record.data.len() + 2 => Some(record.nonce),
+        i => Some(record.data.get(i - 2)?.visibility),
+    }
+}
+
+/// The type of a value and whether it is derived from private data.
+type Labelled = (Option<Type>, bool);
+
+/// The arguments or returned values of a call.
+type Values = Vec<Labelled>;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct State {
+    types: BTreeMap<u32, Option<Type>>,
+    /// Registers holding values derived from private data.
+    private: BTreeSet<u32>,
+}
+
+struct Flow<'a> {
+    program: &'a Program,
+    /// What reveals private data, each described once.
+    errors: Vec<String>,
+    /// Values returned by functions already walked, keyed by function and arguments.
+    returns: Vec<((u32, Values), Values)>,
+}
+
+impl Flow<'_> {
+    fn reveal(&mut self, item: String) {
+        if !self.errors.contains(&item) {
+            self.errors.push(item);
+        }
+    }
+
+    /// Walks `function` from `state`, returning the values it returns and its state once it ends.
+    fn function(&mut self, function: u32, state: State) -> (Values, State) {
+        let program = self.program;
+        let mut walker = Walker {
+            flow: self,
+            function: function as usize,
+            state,
+            returns: vec![],
+        };
+        walker.block(&program.functions[function as usize].instructions, 0, false);
+        (walker.returns, walker.state)
+    }
+
+    fn call(&mut self, function: u32, arguments: Values) -> Values {
+        let key = (function, arguments);
+        if let Some((_, returns)) = self.returns.iter().find(|(x, _)| *x == key) {
+            return returns.clone();
+        }
+        let start = self.program.functions[function as usize].argument_start_variable;
+        let mut state = State::default();
+        for (register, (type_, private)) in (start..).zip(&key.1) {
+            state.types.insert(register, type_.clone());
+            if *private {
+                state.private.insert(register);
+            }
+        }
+        let (returns, _) = self.function(function, state);
+        self.returns.push((key, returns.clone()));
+        returns
+    }
+}
+
+struct Walker<'a, 'b> {
+    flow: &'b mut Flow<'a>,
+    function: usize,
+    state: State,
+    returns: Values,
+}
+
+impl Walker<'_, '_> {
+    fn type_of(&self, operand: &Operand) -> Option<Type> {
+        operand_type(operand, &self.state.types).ok().flatten()
+    }
+
+    /// Whether `operand` is derived from private data, leaving out private record entries.
+    fn label(&self, operand: &Operand) -> bool {
+        match operand {
+            Operand::Ref(register) => self.state.private.contains(register),
+            Operand::Struct(value) => value.values.iter().any(|x| self.label(x)),
+            Operand::Record(value) => value
+                .data
+                .iter()
+                .any(|x| x.visibility != Visibility::Private && self.label(&x.value)),
+            _ => false,
+        }
+    }
+
+    /// Whether anything computed from the whole of `operand` is derived from private data.
+    fn exposed(&self, operand: &Operand) -> bool {
+        self.label(operand) || self.type_of(operand).is_some_and(|x| has_private(&x))
+    }
+
+    /// Reports entries of record literals within `operand` that reveal private data.
+    fn check_literal(&mut self, index: usize, mnemonic: &str, operand: &Operand) {
+        match operand {
+            Operand::Struct(value) => {
+                for value in &value.values {
+                    self.check_literal(index, mnemonic, value);
+                }
+            }
+            Operand::Record(value) => {
+                for (i, entry) in value.data.iter().enumerate() {
+                    self.check_literal(index, mnemonic, &entry.value);
+                    if entry.visibility != Visibility::Private && self.exposed(&entry.value) {
+                        self.flow.reveal(format!(
+                            "f{}:{index}: Entry {} of a record in `{mnemonic}`",
+                            self.function,
+                            i + 2
+                        ));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn write(&mut self, dest: u32, (type_, private): Labelled) {
+        self.state.types.insert(dest, type_);
+        match private {
+            true => self.state.private.insert(dest),
+            false => self.state.private.remove(&dest),
+        };
+    }
+
+    /// Walks the instructions of a function or block, the first of which is at `offset` in its
+    /// function. Everything written in a block masked by private data is private.
+    fn block(&mut self, instructions: &[Instruction], offset: usize, masked: bool) {
+        let mut i = 0;
+        while let Some(instruction) = instructions.get(i) {
+            let index = offset + i;
+            i += 1;
+            let (operands, dest) = operands(instruction);
+            for operand in &operands {
+                self.check_literal(index, instruction.mnemonic(), operand);
+            }
+            let (length, count, masked_body) = match instruction {
+                Instruction::Call(data) => {
+                    self.call(data, masked);
+                    continue;
+                }
+                Instruction::Return(ReturnData { values }) => {
+                    self.returns = values
+                        .iter()
+                        .map(|x| (self.type_of(x), self.label(x)))
+                        .collect();
+                    continue;
+                }
+                Instruction::Mask(MaskData { cond, length }) => {
+                    (*length, 1, masked || self.exposed(cond))
+                }
+                Instruction::Repeat(RepeatData { count, length }) => (*length, *count, masked),
+                _ => {
+                    if let Some(dest) = dest {
+                        let value = self.instruction(instruction, &operands);
+                        self.write(dest, (value.0, value.1 || masked));
+                    }
+                    continue;
+                }
+            };
+            let end = instructions.len().min(i + length as usize);
+            let types = self.state.types.clone();
+            // Repeated blocks are walked until the registers they leave private settle.
+            loop {
+                let start = self.state.clone();
+                self.block(&instructions[i..end], offset + i, masked_body);
+                // Registers first written in the block are dropped after it, and the others may
+                // hold their value from before it.
+                let private = self
+                    .state
+                    .private
+                    .iter()
+                    .filter(|x| types.contains_key(x))
+                    .chain(&start.private)
+                    .copied()
+                    .collect();
+                self.state = State {
+                    types: types.clone(),
+                    private,
+                };
+                if count < 2 || self.state == start {
+                    break;
+                }
+            }
+            i = end;
+        }
+    }
+
+    fn call(&mut self, data: &CallData, masked: bool) {
+        let arguments = data
+            .arguments
+            .iter()
+            .map(|x| (self.type_of(x), self.label(x)))
+            .collect();
+        let returns = self.flow.call(data.function, arguments);
+        for (dest, (type_, private)) in data.dests.iter().zip(returns) {
+            self.write(*dest, (type_, private || masked));
+        }
+    }
+
+    /// The value written by an instruction other than a call, a return or the start of a block.
+    fn instruction(&self, instruction: &Instruction, operands: &[&Operand]) -> Labelled {
+        let types = operands
+            .iter()
+            .map(|x| self.type_of(x))
+            .collect::<Option<Vec<_>>>();
+        let type_ = types.as_ref().and_then(|x| result_type(instruction, x));
+        let private = match instruction {
+            Instruction::Declassify(_) => return (type_, false),
+            // Entries of structs and records keep their labels.
+            Instruction::Member(AccessData { operand, .. }) => self.label(operand),
+            Instruction::RecordField(AccessData { operand, index, .. }) => {
+                let visibility = match types.as_deref() {
+                    Some([Type::Record(record)]) => entry_visibility(record, *index),
+                    _ => None,
+                };
+                !matches!(visibility, Some(Visibility::Public | Visibility::Constant))
+                    || self.label(operand)
+            }
+            Instruction::Ternary(TernaryData { cond, lhs, rhs, .. }) => {
+                self.exposed(cond) || self.label(lhs) || self.label(rhs)
+            }
+            _ => operands.iter().any(|x| self.exposed(x)),
+        };
+        (type_, private)
+    }
+}
+
+impl Program {
+    /// Checks that private data is never revealed, returning each problem found separately.
+    ///
+    /// Values are private when they are computed from private, register or private state inputs,
+    /// from private record entries, or in blocks masked by private conditions. `declassify` makes
+    /// its value public. Private values cannot be public or constant outputs, nor entries of
+    /// record literals that are not private.
+    ///
+    /// The program must pass [`Program::type_check`] first.
+    pub fn flow_check(&self) -> Vec<Report<IRError>> {
+        let mut flow = Flow {
+            program: self,
+            errors: vec![],
+            returns: vec![],
+        };
+        let header = &self.header;
+        let mut state = State::default();
+        for (inputs, private) in [
+            (&header.constant_inputs, false),
+            (&header.private_inputs, true),
+            (&header.public_inputs, false),
+            (&header.register_inputs, true),
+            (&header.public_states, false),
+            (&header.private_record_states, true),
+            (&header.private_leaf_states, true),
+        ] {
+            for input in inputs {
+                state
+                    .types
+                    .insert(input.variable, Some(input.type_.clone()));
+                if private {
+                    state.private.insert(input.variable);
+                }
+            }
+        }
+        if !self.functions.is_empty() {
+            state = flow.function(0, state).1;
+        }
+        for output in &header.outputs {
+            if output.visibility != Visibility::Private && state.private.contains(&output.variable)
+            {
+                flow.reveal(format!("Output `{}`", output.name));
+            }
+        }
+        flow.errors.into_iter().map(IRError::private_flow).collect()
+    }
+}
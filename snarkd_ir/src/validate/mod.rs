@@ -0,0 +1,792 @@
+mod flow;
+#[cfg(test)]
+mod tests;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use snarkd_crypto::bls12_377::{fp, scalar};
+use snarkd_errors::{IRError, Report, Result};
+
+use crate::{
+    asm::is_identifier, eval::OPERAND_PREFIX_BITS, AccessData, AssertData, BinaryData, CallData,
+    CastData, CommitData, CommitMethod, ConditionData, HashData, HashMethod, Instruction, MaskData,
+    Operand, Program, RecordType, RecordTypeEntry, RepeatData, ReturnData, StructType,
+    StructTypeEntry, TernaryData, Type, UnaryData,
+};
+
+/// Whether values of types `a` and `b` are interchangeable.
+/// Struct and record entries are matched by position, as literals carry no entry names.
+fn same_type(a: &Type, b: &Type) -> bool {
+    match (a, b) {
+        (Type::Struct(a), Type::Struct(b)) => {
+            a.fields.len() == b.fields.len()
+                && a.fields
+                    .iter()
+                    .zip(&b.fields)
+                    .all(|(a, b)| same_type(&a.type_, &b.type_))
+        }
+        (Type::Record(a), Type::Record(b)) => {
+            a.owner == b.owner
+                && a.gates == b.gates
+                && a.nonce == b.nonce
+                && a.data.len() == b.data.len()
+                && a.data
+                    .iter()
+                    .zip(&b.data)
+                    .all(|(a, b)| a.visibility == b.visibility && same_type(&a.type_, &b.type_))
+        }
+        _ => a == b,
+    }
+}
+
+/// Record types within `type_`, including `type_` itself, in the order they are written.
+fn records<'a>(type_: &'a Type, out: &mut Vec<&'a Type>) {
+    match type_ {
+        Type::Struct(StructType { fields }) => {
+            for field in fields {
+                records(&field.type_, out);
+            }
+        }
+        Type::Record(RecordType { data, .. }) => {
+            out.push(type_);
+            for entry in data {
+                records(&entry.type_, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Names that declared types cannot take, as they already are types or start one.
+const BUILTIN_TYPES: &[&str] = &[
+    "address", "boolean", "field", "group", "u8", "u16", "u32", "u64", "u128", "i8", "i16", "i32",
+    "i64", "i128", "scalar", "string", "struct", "record",
+];
+
+fn is_signed(type_: &Type) -> bool {
+    matches!(
+        type_,
+        Type::I8 | Type::I16 | Type::I32 | Type::I64 | Type::I128
+    )
+}
+
+fn is_unsigned(type_: &Type) -> bool {
+    matches!(
+        type_,
+        Type::U8 | Type::U16 | Type::U32 | Type::U64 | Type::U128
+    )
+}
+
+fn is_integer(type_: &Type) -> bool {
+    is_signed(type_) || is_unsigned(type_)
+}
+
+/// Whether `type_` can be the exponent of `pow` or the amount of `shl`/`shr`.
+fn is_exponent(type_: &Type) -> bool {
+    matches!(type_, Type::U8 | Type::U16 | Type::U32)
+}
+
+/// Whether `type_` can be cast, or converted to and from bits.
+fn is_castable(type_: &Type) -> bool {
+    is_integer(type_)
+        || matches!(
+            type_,
+            Type::Boolean | Type::Field | Type::Scalar | Type::Group
+        )
+}
+
+/// Number of bits a value of type `type_` is hashed as, if it does not depend on the value.
+fn bit_size(type_: &Type) -> Option<u32> {
+    Some(match type_ {
+        Type::Boolean => 1,
+        Type::Field | Type::Group => fp::MODULUS_BITS,
+        Type::Scalar => scalar::MODULUS_BITS,
+        Type::U8 | Type::I8 => 8,
+        Type::U16 | Type::I16 => 16,
+        Type::U32 | Type::I32 => 32,
+        Type::U64 | Type::I64 => 64,
+        Type::U128 | Type::I128 => 128,
+        Type::Struct(StructType { fields }) => fields
+            .iter()
+            .map(|field| bit_size(&field.type_))
+            .sum::<Option<u32>>()?,
+        Type::Address | Type::String | Type::Record(_) => return None,
+    })
+}
+
+/// Number of bits a value of type `type_` is hashed as after its own prefix, counting the
+/// prefixes of struct members, if it does not depend on the value.
+fn hashed_size(type_: &Type) -> Option<u32> {
+    match type_ {
+        Type::Struct(StructType { fields }) => fields
+            .iter()
+            .map(|field| Some(OPERAND_PREFIX_BITS + hashed_size(&field.type_)?))
+            .sum(),
+        _ => bit_size(type_),
+    }
+}
+
+/// Operand types accepted by `instruction`, for diagnostics.
+fn signature(instruction: &Instruction) -> &'static str {
+    use Instruction::*;
+    match instruction {
+        Abs(_) | AbsWrapped(_) => "a signed integer",
+        Double(_) => "a field or a group",
+        Inv(_) | Sqrt(_) | Square(_) => "a field",
+        Neg(_) => "a signed integer, a field or a group",
+        Not(_) => "an integer or a boolean",
+        Add(_) | Sub(_) => "two integers of the same type, two fields, two groups or two scalars",
+        AddWrapped(_) | SubWrapped(_) | MulWrapped(_) | DivWrapped(_) | Rem(_) | RemWrapped(_) => {
+            "two integers of the same type"
+        }
+        Mul(_) => "two integers of the same type, two fields, or a group and a scalar",
+        Div(_) => "two integers of the same type or two fields",
+        Mod(_) => "two unsigned integers of the same type",
+        Pow(_) => "two fields, or an integer and a `u8`, `u16` or `u32` exponent",
+        PowWrapped(_) => "an integer and a `u8`, `u16` or `u32` exponent",
+        Shl(_) | ShlWrapped(_) | Shr(_) | ShrWrapped(_) | Lshr(_) => {
+            "an integer and a `u8`, `u16` or `u32` amount"
+        }
+        And(_) | Or(_) | Xor(_) => "two integers of the same type or two booleans",
+        Nand(_) | Nor(_) => "two booleans",
+        Gt(_) | Gte(_) | Lt(_) | Lte(_) => {
+            "two integers of the same type, two fields or two scalars"
+        }
+        IsEq(_) | IsNeq(_) | AssertEq(_) | AssertNeq(_) => "two operands of the same type",
+        Ternary(_) => "a boolean condition and two operands of the same type",
+        Hash(_) | Commit(_) | Declassify(_) => "a value of any type",
+        Mask(_) | Assert(_) => "a boolean condition",
+        Cast(_) | ToBits(_) => "a boolean, an integer, a field, a scalar or a group",
+        FromBits(_) => "a struct of one boolean for every bit of the target type",
+        Member(_) => "a struct with an entry at the index",
+        RecordField(_) => "a record with an entry at the index",
+        Call(_) | Return(_) => "values of any type",
+        Repeat(_) => "no operands",
+    }
+}
+
+/// Type of the value `instruction` computes from operands of `types`, or `None` if it is not defined for them.
+/// Assertions and masks have no result, so they give the type of their operands.
+/// Calls, returns and repeats are checked separately.
+pub(crate) fn result_type(instruction: &Instruction, types: &[Type]) -> Option<Type> {
+    use Instruction::*;
+    if let (ToBits(_), [a]) = (instruction, types) {
+        let fields = vec![
+            StructTypeEntry {
+                name: String::new(),
+                type_: Type::Boolean,
+            };
+            bit_size(a).filter(|_| is_castable(a))? as usize
+        ];
+        return Some(Type::Struct(StructType { fields }));
+    }
+    let result = match (instruction, types) {
+        (Abs(_) | AbsWrapped(_), [a]) if is_signed(a) => a,
+        (Double(_), [a @ (Type::Field | Type::Group)]) => a,
+        (Inv(_) | Sqrt(_) | Square(_), [a @ Type::Field]) => a,
+        (Neg(_), [a]) if is_signed(a) || matches!(a, Type::Field | Type::Group) => a,
+        (Not(_), [a]) if is_integer(a) || *a == Type::Boolean => a,
+        (Add(_) | Sub(_), [a, b])
+            if a == b
+                && (is_integer(a) || matches!(a, Type::Field | Type::Group | Type::Scalar)) =>
+        {
+            a
+        }
+        (
+            AddWrapped(_) | SubWrapped(_) | MulWrapped(_) | DivWrapped(_) | Rem(_) | RemWrapped(_),
+            [a, b],
+        ) if a == b && is_integer(a) => a,
+        (Mul(_) | Div(_), [a, b]) if a == b && (is_integer(a) || *a == Type::Field) => a,
+        (Mul(_), [Type::Group, Type::Scalar] | [Type::Scalar, Type::Group]) => &Type::Group,
+        (Mod(_), [a, b]) if a == b && is_unsigned(a) => a,
+        (Pow(_), [a @ Type::Field, Type::Field]) => a,
+        (
+            Pow(_) | PowWrapped(_) | Shl(_) | ShlWrapped(_) | Shr(_) | ShrWrapped(_) | Lshr(_),
+            [a, b],
+        ) if is_integer(a) && is_exponent(b) => a,
+        (And(_) | Or(_) | Xor(_), [a, b]) if a == b && (is_integer(a) || *a == Type::Boolean) => a,
+        (Nand(_) | Nor(_), [Type::Boolean, Type::Boolean]) => &Type::Boolean,
+        (Gt(_) | Gte(_) | Lt(_) | Lte(_), [a, b])
+            if a == b && (is_integer(a) || matches!(a, Type::Field | Type::Scalar)) =>
+        {
+            &Type::Boolean
+        }
+        (IsEq(_) | IsNeq(_), [a, b]) if same_type(a, b) => &Type::Boolean,
+        (AssertEq(_) | AssertNeq(_), [a, b]) if same_type(a, b) => a,
+        (Ternary(_), [Type::Boolean, a, b]) if same_type(a, b) => a,
+        (Hash(_) | Commit(_), [_]) => &Type::Field,
+        (Declassify(_), [a]) => a,
+        (Mask(_) | Assert(_), [a @ Type::Boolean]) => a,
+        (Cast(CastData { type_, .. }), [a]) if is_castable(a) && is_castable(type_) => type_,
+        (FromBits(CastData { type_, .. }), [Type::Struct(StructType { fields })])
+            if is_castable(type_)
+                && bit_size(type_) == Some(fields.len() as u32)
+                && fields.iter().all(|x| x.type_ == Type::Boolean) =>
+        {
+            type_
+        }
+        (Member(AccessData { index, .. }), [Type::Struct(StructType { fields })]) => {
+            &fields.get(*index as usize)?.type_
+        }
+        (RecordField(AccessData { index, .. }), [Type::Record(RecordType { data, .. })]) => {
+            match *index as usize {
+                0 => &Type::Address,
+                1 => &Type::U64,
+                i if i == data.len() + 2 => &Type::Group,
+                i => &data.get(i - 2)?.type_,
+            }
+        }
+        _ => return None,
+    };
+    Some(result.clone())
+}
+
+/// Operands read by `instruction` and the register it writes, if any.
+/// The registers written by a call are its destinations.
+pub(crate) fn operands(instruction: &Instruction) -> (Vec<&Operand>, Option<u32>) {
+    use Instruction::*;
+    match instruction {
+        Abs(UnaryData { operand, dest })
+        | AbsWrapped(UnaryData { operand, dest })
+        | Declassify(UnaryData { operand, dest })
+        | Double(UnaryData { operand, dest })
+        | Inv(UnaryData { operand, dest })
+        | Neg(UnaryData { operand, dest })
+        | Not(UnaryData { operand, dest })
+        | Sqrt(UnaryData { operand, dest })
+        | Square(UnaryData { operand, dest })
+        | ToBits(UnaryData { operand, dest })
+        | Cast(CastData { operand, dest, .. })
+        | FromBits(CastData { operand, dest, .. })
+        | Member(AccessData { operand, dest, .. })
+        | RecordField(AccessData { operand, dest, .. })
+        | Hash(HashData {
+            chunk: operand,
+            dest,
+            ..
+        })
+        | Commit(CommitData {
+            chunk: operand,
+            dest,
+            ..
+        }) => (vec![operand], Some(*dest)),
+        Add(BinaryData { lhs, rhs, dest })
+        | AddWrapped(BinaryData { lhs, rhs, dest })
+        | And(BinaryData { lhs, rhs, dest })
+        | Div(BinaryData { lhs, rhs, dest })
+        | DivWrapped(BinaryData { lhs, rhs, dest })
+        | Gt(BinaryData { lhs, rhs, dest })
+        | Gte(BinaryData { lhs, rhs, dest })
+        | IsEq(BinaryData { lhs, rhs, dest })
+        | IsNeq(BinaryData { lhs, rhs, dest })
+        | Lt(BinaryData { lhs, rhs, dest })
+        | Lte(BinaryData { lhs, rhs, dest })
+        | Lshr(BinaryData { lhs, rhs, dest })
+        | Mod(BinaryData { lhs, rhs, dest })
+        | Mul(BinaryData { lhs, rhs, dest })
+        | MulWrapped(BinaryData { lhs, rhs, dest })
+        | Nand(BinaryData { lhs, rhs, dest })
+        | Nor(BinaryData { lhs, rhs, dest })
+        | Or(BinaryData { lhs, rhs, dest })
+        | Pow(BinaryData { lhs, rhs, dest })
+        | PowWrapped(BinaryData { lhs, rhs, dest })
+        | Rem(BinaryData { lhs, rhs, dest })
+        | RemWrapped(BinaryData { lhs, rhs, dest })
+        | Shl(BinaryData { lhs, rhs, dest })
+        | ShlWrapped(BinaryData { lhs, rhs, dest })
+        | Shr(BinaryData { lhs, rhs, dest })
+        | ShrWrapped(BinaryData { lhs, rhs, dest })
+        | Sub(BinaryData { lhs, rhs, dest })
+        | SubWrapped(BinaryData { lhs, rhs, dest })
+        | Xor(BinaryData { lhs, rhs, dest }) => (vec![lhs, rhs], Some(*dest)),
+        AssertEq(AssertData { lhs, rhs }) | AssertNeq(AssertData { lhs, rhs }) => {
+            (vec![lhs, rhs], None)
+        }
+        Ternary(TernaryData {
+            cond,
+            lhs,
+            rhs,
+            dest,
+        }) => (vec![cond, lhs, rhs], Some(*dest)),
+        Call(CallData { arguments, .. }) => (arguments.iter().collect(), None),
+        Return(ReturnData { values }) => (values.iter().collect(), None),
+        Mask(MaskData { cond, .. }) | Assert(ConditionData { cond }) => (vec![cond], None),
+        Repeat(RepeatData { .. }) => (vec![], None),
+    }
+}
+
+/// Finds the type of `operand` from the types of `registers`, or the first register it reads that
+/// has no type yet.
+pub(crate) fn operand_type(
+    operand: &Operand,
+    registers: &BTreeMap<u32, Option<Type>>,
+) -> Result<Option<Type>, u32> {
+    Ok(Some(match operand {
+        Operand::Ref(register) => return registers.get(register).cloned().ok_or(*register),
+        Operand::Address(_) => Type::Address,
+        Operand::Boolean(_) => Type::Boolean,
+        Operand::Field(_) => Type::Field,
+        Operand::Group(_) => Type::Group,
+        Operand::U8(_) => Type::U8,
+        Operand::U16(_) => Type::U16,
+        Operand::U32(_) => Type::U32,
+        Operand::U64(_) => Type::U64,
+        Operand::U128(_) => Type::U128,
+        Operand::I8(_) => Type::I8,
+        Operand::I16(_) => Type::I16,
+        Operand::I32(_) => Type::I32,
+        Operand::I64(_) => Type::I64,
+        Operand::I128(_) => Type::I128,
+        Operand::Scalar(_) => Type::Scalar,
+        Operand::String(_) => Type::String,
+        Operand::Struct(value) => {
+            let mut fields = vec![];
+            for value in &value.values {
+                let Some(type_) = operand_type(value, registers)? else {
+                    return Ok(None);
+                };
+                fields.push(StructTypeEntry {
+                    name: String::new(),
+                    type_,
+                });
+            }
+            Type::Struct(StructType { fields })
+        }
+        Operand::Record(value) => {
+            let mut data = vec![];
+            for entry in &value.data {
+                let Some(type_) = operand_type(&entry.value, registers)? else {
+                    return Ok(None);
+                };
+                data.push(RecordTypeEntry {
+                    name: String::new(),
+                    type_,
+                    visibility: entry.visibility,
+                });
+            }
+            Type::Record(RecordType {
+                owner: value.owner_visibility,
+                gates: value.gates_visibility,
+                data,
+                nonce: value.nonce_visibility,
+            })
+        }
+    }))
+}
+
+/// Types of a list of values, with `None` where a type could not be determined.
+type Types = Vec<Option<Type>>;
+
+/// State shared by the checks of every function of a program.
+struct Context<'a> {
+    program: &'a Program,
+    errors: Vec<Report<IRError>>,
+    /// Types returned by functions already checked, keyed by function and argument types.
+    returns: Vec<((u32, Types), Types)>,
+    /// Functions being checked, the innermost last, to reject recursive calls.
+    stack: Vec<u32>,
+}
+
+impl Context<'_> {
+    /// Checks the struct and record types declared by the header. Names must be identifiers that
+    /// are not already types, entries must have distinct names, and records cannot be nested.
+    fn check_declarations(&mut self) {
+        let types = &self.program.header.types;
+        for (i, declaration) in types.iter().enumerate() {
+            let name = &declaration.name;
+            let mut invalid = |reason: String| {
+                self.errors
+                    .push(IRError::invalid_type_declaration(name, reason))
+            };
+            if !is_identifier(name) {
+                invalid("is not an identifier".into());
+            } else if BUILTIN_TYPES.contains(&name.as_str()) {
+                invalid("has the name of a built-in type".into());
+            } else if types[..i].iter().any(|x| x.name == *name) {
+                invalid("is declared more than once".into());
+            }
+            // The fixed entries of a record take their names.
+            let (mut names, entries) = match &declaration.type_ {
+                Type::Struct(StructType { fields }) => (
+                    vec![],
+                    fields
+                        .iter()
+                        .map(|x| (x.name.as_str(), &x.type_))
+                        .collect::<Vec<_>>(),
+                ),
+                Type::Record(RecordType { data, .. }) => (
+                    vec!["owner", "gates", "nonce"],
+                    data.iter().map(|x| (x.name.as_str(), &x.type_)).collect(),
+                ),
+                _ => {
+                    invalid("is not a struct or record type".into());
+                    continue;
+                }
+            };
+            for (entry, type_) in entries {
+                if entry.is_empty() {
+                    invalid("has an unnamed entry".into());
+                } else if names.contains(&entry) {
+                    invalid(format!("has more than one entry named `{entry}`"));
+                }
+                names.push(entry);
+                let mut nested = vec![];
+                records(type_, &mut nested);
+                if !nested.is_empty() {
+                    invalid(format!("nests a record in entry `{entry}`"));
+                }
+            }
+        }
+    }
+
+    /// Reports the record types within `type_` that match no record type of the header. `item`
+    /// describes where the type was found.
+    fn check_declared(&mut self, item: impl Fn() -> String, type_: &Type) {
+        let mut found = vec![];
+        records(type_, &mut found);
+        for record in found {
+            let header = &self.program.header;
+            if !header.types.iter().any(|x| same_type(&x.type_, record)) {
+                self.errors.push(IRError::undeclared_record(item(), record));
+            }
+        }
+    }
+
+    /// Checks `function` starting from the register types `registers`, returning the types of
+    /// the values it returns and of its registers once it ends.
+    fn check_function(
+        &mut self,
+        function: u32,
+        inputs: &BTreeSet<u32>,
+        registers: BTreeMap<u32, Option<Type>>,
+    ) -> (Types, BTreeMap<u32, Option<Type>>) {
+        let program = self.program;
+        self.stack.push(function);
+        let mut checker = Checker {
+            function: function as usize,
+            inputs,
+            registers,
+            blocks: vec![],
+            returns: vec![],
+            context: self,
+        };
+        checker.check_block(&program.functions[function as usize].instructions, 0);
+        let Checker {
+            returns, registers, ..
+        } = checker;
+        self.stack.pop();
+        (returns, registers)
+    }
+
+    /// Checks a call of `function` with arguments of types `arguments`. Functions are checked
+    /// once for every distinct list of argument types they are called with.
+    fn call(&mut self, function: u32, arguments: Types) -> Types {
+        let key = (function, arguments);
+        if let Some((_, returns)) = self.returns.iter().find(|(x, _)| *x == key) {
+            return returns.clone();
+        }
+        let start = self.program.functions[function as usize].argument_start_variable;
+        let registers = (start..).zip(key.1.iter().cloned()).collect();
+        let (returns, _) = self.check_function(function, &BTreeSet::new(), registers);
+        self.returns.push((key, returns.clone()));
+        returns
+    }
+}
+
+/// Register types of a function being checked. A register maps to `None` once it is written
+/// with a value whose type could not be determined, so the error is not reported again at every read.
+struct Checker<'a, 'b> {
+    function: usize,
+    inputs: &'b BTreeSet<u32>,
+    registers: BTreeMap<u32, Option<Type>>,
+    /// Register types before each enclosing block, the innermost last.
+    blocks: Vec<BTreeMap<u32, Option<Type>>>,
+    returns: Types,
+    context: &'b mut Context<'a>,
+}
+
+impl Checker<'_, '_> {
+    /// Finds the types of `operands`, reporting registers read before they are written.
+    fn operand_types(&mut self, index: usize, mnemonic: &str, operands: &[&Operand]) -> Types {
+        let mut types = vec![];
+        for operand in operands {
+            let type_ = match operand_type(operand, &self.registers) {
+                Ok(type_) => type_,
+                Err(register) => {
+                    self.context.errors.push(IRError::undefined_register(
+                        self.function,
+                        index,
+                        mnemonic,
+                        register,
+                    ));
+                    None
+                }
+            };
+            if let (false, Some(type_)) = (matches!(operand, Operand::Ref(_)), &type_) {
+                let function = self.function;
+                self.context.check_declared(
+                    || format!("f{function}:{index}: A literal operand of `{mnemonic}`"),
+                    type_,
+                );
+            }
+            types.push(type_);
+        }
+        types
+    }
+
+    /// Records that `dest` holds a value of type `result`.
+    fn write(&mut self, index: usize, mnemonic: &str, dest: u32, result: Option<Type>) {
+        if self.inputs.contains(&dest) {
+            self.context.errors.push(IRError::input_register_written(
+                self.function,
+                index,
+                mnemonic,
+                dest,
+            ));
+            return;
+        }
+        if let (Some(Some(before)), Some(after)) =
+            (self.blocks.last().and_then(|x| x.get(&dest)), &result)
+        {
+            if !same_type(before, after) {
+                self.context.errors.push(IRError::block_changes_type(
+                    self.function,
+                    index,
+                    mnemonic,
+                    dest,
+                ));
+                return;
+            }
+        }
+        self.registers.insert(dest, result);
+    }
+
+    /// Checks the instructions of a function or block, the first of which is at `offset` in its
+    /// function. Registers first written inside a block cannot be read after it.
+    fn check_block(&mut self, instructions: &[Instruction], offset: usize) {
+        let mut i = 0;
+        while let Some(instruction) = instructions.get(i) {
+            let index = offset + i;
+            i += 1;
+            let length = match instruction {
+                Instruction::Call(data) => {
+                    self.call(index, data);
+                    continue;
+                }
+                Instruction::Return(ReturnData { values }) => {
+                    let values = values.iter().collect::<Vec<_>>();
+                    self.returns = self.operand_types(index, "return", &values);
+                    if !self.blocks.is_empty() || i != instructions.len() {
+                        self.context
+                            .errors
+                            .push(IRError::misplaced_return(self.function, index));
+                    }
+                    continue;
+                }
+                Instruction::Mask(MaskData { length, .. }) => {
+                    self.check(index, instruction);
+                    *length as usize
+                }
+                Instruction::Repeat(RepeatData { length, .. }) => *length as usize,
+                _ => {
+                    self.check(index, instruction);
+                    continue;
+                }
+            };
+            if length > instructions.len() - i {
+                self.context.errors.push(IRError::block_out_of_bounds(
+                    self.function,
+                    index,
+                    instruction.mnemonic(),
+                    length,
+                ));
+            }
+            let end = instructions.len().min(i + length);
+            self.blocks.push(self.registers.clone());
+            self.check_block(&instructions[i..end], offset + i);
+            self.registers = self.blocks.pop().unwrap();
+            i = end;
+        }
+    }
+
+    fn call(&mut self, index: usize, data: &CallData) {
+        let CallData {
+            function: callee,
+            arguments,
+            dests,
+        } = data;
+        let arguments = arguments.iter().collect::<Vec<_>>();
+        let arguments = self.operand_types(index, "call", &arguments);
+        let returns = if *callee as usize >= self.context.program.functions.len() {
+            self.context
+                .errors
+                .push(IRError::undefined_function(self.function, index, callee));
+            None
+        } else if self.context.stack.contains(callee) {
+            self.context
+                .errors
+                .push(IRError::recursive_call(self.function, index, callee));
+            None
+        } else {
+            Some(self.context.call(*callee, arguments))
+        };
+        if let Some(returns) = &returns {
+            if returns.len() != dests.len() {
+                self.context.errors.push(IRError::mismatched_return_count(
+                    self.function,
+                    index,
+                    callee,
+                    returns.len(),
+                    dests.len(),
+                ));
+            }
+        }
+        for (i, dest) in dests.iter().enumerate() {
+            let result = returns.as_ref().and_then(|x| x.get(i).cloned().flatten());
+            self.write(index, "call", *dest, result);
+        }
+    }
+
+    fn check(&mut self, index: usize, instruction: &Instruction) {
+        let mnemonic = instruction.mnemonic();
+        let (operands, dest) = operands(instruction);
+        let types = self
+            .operand_types(index, mnemonic, &operands)
+            .into_iter()
+            .collect::<Option<Vec<_>>>();
+
+        let result = types.and_then(|types| {
+            let result = result_type(instruction, &types);
+            if result.is_none() {
+                self.context.errors.push(IRError::mismatched_operand_types(
+                    self.function,
+                    index,
+                    mnemonic,
+                    types
+                        .iter()
+                        .map(|x| format!("`{x}`"))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    signature(instruction),
+                ));
+            }
+            let limit = match instruction {
+                Instruction::Hash(HashData { method, .. }) => match method {
+                    HashMethod::HashPed64 => Some(64),
+                    HashMethod::HashPed128 => Some(128),
+                    _ => None,
+                },
+                Instruction::Commit(CommitData { method, .. }) => match method {
+                    CommitMethod::CommitPed64 => Some(64),
+                    CommitMethod::CommitPed128 => Some(128),
+                    _ => None,
+                },
+                _ => None,
+            };
+            if let Some(limit) = limit {
+                if let Some(bits) = hashed_size(&types[0]).filter(|bits| *bits > limit) {
+                    self.context.errors.push(IRError::hash_input_too_large(
+                        self.function,
+                        index,
+                        mnemonic,
+                        &types[0],
+                        bits,
+                        limit,
+                    ));
+                }
+            }
+            result
+        });
+
+        if let Some(dest) = dest {
+            self.write(index, mnemonic, dest, result);
+        }
+    }
+}
+
+impl Program {
+    /// Checks the program before it is run or deployed. Every register must be written before it is read,
+    /// input registers must not be written, and every instruction must be applied to operand types
+    /// it is defined for. Calls must refer to existing functions without recursion, store as many
+    /// values as their function returns, and blocks must fit in their function. Records must have
+    /// a declared record type, and outputs must hold their declared types. All problems are
+    /// reported together.
+    ///
+    /// Programs that type check must then pass [`Program::flow_check`].
+    pub fn validate(&self) -> Result<()> {
+        let mut errors = self.type_check();
+        if errors.is_empty() {
+            errors = self.flow_check();
+        }
+        let mut errors = errors.into_iter();
+        match errors.next() {
+            None => Ok(()),
+            Some(mut report) => {
+                for error in errors {
+                    report.extend_one(error);
+                }
+                Err(report.into())
+            }
+        }
+    }
+
+    /// Runs the checks of [`Program::validate`] other than the flow check, returning each problem
+    /// found separately.
+    /// Problems in instructions are located by function and instruction index.
+    ///
+    /// The first function is checked with the inputs of the header. Other functions are checked
+    /// at the calls that reach them, with the types of their arguments, so functions that are never
+    /// called are not checked.
+    pub fn type_check(&self) -> Vec<Report<IRError>> {
+        let mut context = Context {
+            program: self,
+            errors: vec![],
+            returns: vec![],
+            stack: vec![],
+        };
+        context.check_declarations();
+        let mut registers = BTreeMap::new();
+        let mut names = BTreeSet::new();
+        for input in self.header.inputs() {
+            context.check_declared(|| format!("Input `{}`", input.name), &input.type_);
+            let register_taken = registers
+                .insert(input.variable, Some(input.type_.clone()))
+                .is_some();
+            let name_taken = !names.insert(&input.name);
+            if register_taken || name_taken {
+                context
+                    .errors
+                    .push(IRError::duplicate_input(&input.name, input.variable));
+            }
+        }
+
+        let inputs = registers.keys().copied().collect();
+        let registers = match self.functions.is_empty() {
+            true => BTreeMap::new(),
+            false => context.check_function(0, &inputs, registers).1,
+        };
+
+        let mut names = BTreeSet::new();
+        let mut variables = BTreeSet::new();
+        for output in &self.header.outputs {
+            let register_taken = !variables.insert(output.variable);
+            let name_taken = !names.insert(&output.name);
+            if register_taken || name_taken {
+                context
+                    .errors
+                    .push(IRError::duplicate_output(&output.name, output.variable));
+            }
+            context.check_declared(|| format!("Output `{}`", output.name), &output.type_);
+            match registers.get(&output.variable) {
+                None => context
+                    .errors
+                    .push(IRError::undefined_output(&output.name, output.variable)),
+                Some(Some(found)) if !same_type(found, &output.type_) => context.errors.push(
+                    IRError::mismatched_output_type(&output.name, found, &output.type_),
+                ),
+                _ => {}
+            }
+        }
+        context.errors
+    }
+}
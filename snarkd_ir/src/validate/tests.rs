@@ -0,0 +1,373 @@
+use crate::*;
+
+fn field(value: u64) -> Operand {
+    Operand::Field(Field {
+        negate: false,
+        values: vec![value],
+    })
+}
+
+fn binary(lhs: Operand, rhs: Operand, dest: u32) -> BinaryData {
+    BinaryData { lhs, rhs, dest }
+}
+
+fn unary(operand: Operand, dest: u32) -> UnaryData {
+    UnaryData { operand, dest }
+}
+
+/// A program with a private `u8` input in `r0` and a public `field` input in `r1`.
+fn program(instructions: Vec<Instruction>) -> Program {
+    Program {
+        header: Header {
+            private_inputs: vec![InputType {
+                variable: 0,
+                name: "a".into(),
+                type_: Type::U8,
+            }],
+            public_inputs: vec![InputType {
+                variable: 1,
+                name: "b".into(),
+                type_: Type::Field,
+            }],
+            ..Default::default()
+        },
+        functions: vec![Function {
+            argument_start_variable: 2,
+            instructions,
+        }],
+    }
+}
+
+#[test]
+fn valid_program() {
+    use Instruction::*;
+    let program = program(vec![
+        AddWrapped(binary(Operand::Ref(0), Operand::U8(1), 2)),
+        Shl(binary(Operand::Ref(2), Operand::U32(3), 3)),
+        Gt(binary(Operand::Ref(3), Operand::Ref(0), 4)),
+        Square(unary(Operand::Ref(1), 5)),
+        Pow(binary(Operand::Ref(5), field(3), 6)),
+        Mul(binary(
+            Operand::Group(Group::Single(Field {
+                negate: false,
+                values: vec![1],
+            })),
+            Operand::Scalar(Scalar { values: vec![2] }),
+            7,
+        )),
+        Ternary(TernaryData {
+            cond: Operand::Ref(4),
+            lhs: Operand::Struct(Struct {
+                values: vec![Operand::Ref(0), Operand::Ref(1)],
+            }),
+            rhs: Operand::Struct(Struct {
+                values: vec![Operand::U8(0), field(0)],
+            }),
+            dest: 8,
+        }),
+        Hash(HashData {
+            method: HashMethod::HashPed64,
+            chunk: Operand::Ref(3),
+            dest: 9,
+        }),
+        Hash(HashData {
+            method: HashMethod::HashPsd2,
+            chunk: Operand::Ref(8),
+            dest: 10,
+        }),
+        AssertNeq(AssertData {
+            lhs: Operand::Ref(9),
+            rhs: Operand::Ref(10),
+        }),
+        // Registers that are not inputs may be written again.
+        Not(unary(Operand::Ref(4), 2)),
+    ]);
+    assert!(program.type_check().is_empty());
+    assert!(program.validate().is_ok());
+}
+
+#[test]
+fn reports_every_error() {
+    use Instruction::*;
+    let program = program(vec![
+        Add(binary(Operand::Ref(0), Operand::U8(1), 2)),
+        Add(binary(
+            Operand::Boolean(true),
+            Operand::Group(Group::Single(Field {
+                negate: false,
+                values: vec![1],
+            })),
+            3,
+        )),
+        Not(unary(Operand::Ref(7), 4)),
+        Sub(binary(Operand::Ref(1), field(1), 1)),
+        Hash(HashData {
+            method: HashMethod::HashPed128,
+            chunk: Operand::Ref(1),
+            dest: 5,
+        }),
+        Shr(binary(Operand::Ref(2), Operand::U64(1), 6)),
+        // Results of rejected instructions are not reported again.
+        Double(unary(Operand::Ref(3), 7)),
+        Nand(binary(Operand::Ref(4), Operand::Ref(6), 8)),
+    ]);
+    let errors = program.type_check();
+    assert_eq!(errors.len(), 5);
+    for (error, location) in errors.iter().zip(["f0:1", "f0:2", "f0:3", "f0:4", "f0:5"]) {
+        assert!(format!("{error:?}").contains(location));
+    }
+    assert!(program.validate().is_err());
+}
+
+#[test]
+fn rejects_before_execution() {
+    let mut program = program(vec![Instruction::Not(unary(Operand::Ref(0), 0))]);
+    program.header.constant_inputs.push(InputType {
+        variable: 1,
+        name: "c".into(),
+        type_: Type::Boolean,
+    });
+    // The overwritten input and the duplicated input register.
+    assert_eq!(program.type_check().len(), 2);
+
+    let input = InputData {
+        constant_inputs: vec![],
+        private_inputs: vec![InputValue {
+            name: "a".into(),
+            value: Operand::U8(1),
+        }],
+        public_inputs: vec![InputValue {
+            name: "b".into(),
+            value: field(1),
+        }],
+        register_inputs: vec![],
+        public_states: vec![],
+        private_record_states: vec![],
+        private_leaf_states: vec![],
+    };
+    assert!(program.evaluate(&input).is_err());
+    program.header.constant_inputs.clear();
+    assert!(program.evaluate(&input).is_err());
+    program.functions[0].instructions = vec![Instruction::Not(unary(Operand::Ref(0), 2))];
+    assert!(program.evaluate(&input).is_ok());
+}
+
+#[test]
+fn control_flow_errors() {
+    let program: Program = "
+        input private r0 a: u8
+
+        decl f0 r1
+        0: call f5 -> r1
+        1: call f1 r0 -> r2, r3
+        2: mask r0, 0
+        3: not r0 -> r4
+        4: mask true, 1
+        5: not true -> r4
+        6: mask true, 1
+        7: not r0 -> r9
+        8: not r9 -> r10
+        9: repeat 2, 5
+        10: return
+
+        decl f1 r0
+        0: call f2 r0 -> r1
+        1: return r1
+
+        decl f2 r0
+        0: call f1 r0 -> r1
+        1: return r0
+    "
+    .parse()
+    .unwrap();
+    let errors = program
+        .type_check()
+        .iter()
+        .map(|x| format!("{x:?}"))
+        .collect::<Vec<_>>();
+    assert_eq!(errors.len(), 8, "{errors:#?}");
+    for (location, message) in [
+        ("f0:0", "does not exist"),
+        ("f0:1", "returns 1 values, but the call stores 2"),
+        ("f0:2", "`mask` is not defined"),
+        ("f0:5", "changes the type of `r4`"),
+        ("f0:8", "reads register `r9`"),
+        ("f0:9", "more than follow it"),
+        ("f0:10", "not the last instruction"),
+        ("f2:0", "is recursive"),
+    ] {
+        let expected = format!("{location}: ");
+        assert!(
+            errors
+                .iter()
+                .any(|x| x.contains(&expected) && x.contains(message)),
+            "{location}: {errors:#?}"
+        );
+    }
+}
+
+#[test]
+fn conversion_and_entry_types() {
+    let program: Program = "
+        input private r0 a: i8
+        input private r1 b: struct { x: u8, y: field }
+
+        decl f0 r2
+        0: cast r0 as field -> r2
+        1: to.bits r0 -> r3
+        2: from.bits r3 as u8 -> r4
+        3: member r1, 1 -> r5
+        4: add r5, r2 -> r6
+        5: lshr r0, 1u8 -> r7
+        6: lt r7, r0 -> r8
+        7: assert r8
+        8: cast r0 as address -> r9
+        9: from.bits r3 as u16 -> r10
+        10: member r1, 2 -> r11
+        11: assert r0
+        12: record.field r1, 0 -> r12
+        13: ternary r8, r1, struct(1u8, 2field) -> r13
+        14: member r13, 0 -> r14
+        15: add r14, r4 -> r15
+    "
+    .parse()
+    .unwrap();
+    let errors = program
+        .type_check()
+        .iter()
+        .map(|x| format!("{x:?}"))
+        .collect::<Vec<_>>();
+    assert_eq!(errors.len(), 5, "{errors:#?}");
+    for location in ["f0:8", "f0:9", "f0:10", "f0:11", "f0:12"] {
+        let expected = format!("{location}: ");
+        assert!(
+            errors.iter().any(|x| x.contains(&expected)),
+            "{location}: {errors:#?}"
+        );
+    }
+}
+
+#[test]
+fn declared_types() {
+    let mut program: Program = "
+        struct u8 { a: u8 }
+        struct pair { a: u8, a: field }
+        record token { owner: address.private, gates: u64.private, owner: u8.public, nonce: group.constant }
+        struct wrapper { inner: record { owner: address.private, gates: u64.private, nonce: group.constant } }
+        input private r0 a: u8
+        input private_record_state r1 coin: record { owner: address.private, gates: u64.public, nonce: group.constant }
+        output private r2 sum: field
+        output private r2 total: u8
+        output public r9 missing: u8
+
+        decl f0 r2
+        0: add.w r0, r0 -> r2
+    "
+    .parse()
+    .unwrap();
+    program.header.types.push(TypeDeclaration {
+        name: "alias".into(),
+        type_: Type::U8,
+    });
+    let errors = program
+        .type_check()
+        .iter()
+        .map(|x| format!("{x:?}"))
+        .collect::<Vec<_>>();
+    assert_eq!(errors.len(), 9, "{errors:#?}");
+    for message in [
+        "`u8` has the name of a built-in type",
+        "`pair` has more than one entry named `a`",
+        "`token` has more than one entry named `owner`",
+        "`wrapper` nests a record in entry `inner`",
+        "`alias` is not a struct or record type",
+        "Input `coin` has the record type",
+        "Output `sum` is declared as `field`, but its register holds `u8`",
+        "Output `total` in register `r2` reuses",
+        "Output `missing` reads register `r9`",
+    ] {
+        assert!(
+            errors.iter().any(|x| x.contains(message)),
+            "{message}: {errors:#?}"
+        );
+    }
+}
+
+#[test]
+fn information_flow() {
+    let owner = Operand::Address(Address {
+        address: vec![0; 32],
+    });
+    let program: Program = format!(
+        "
+        record token {{
+            owner: address.private,
+            gates: u64.private,
+            amount: u64.public,
+            secret: u64.private,
+            nonce: group.constant
+        }}
+        input private r0 a: u8
+        input public r1 b: u8
+        input public r2 coin: token
+        output public r4 leak: u8
+        output public r5 masked: u8
+        output public r6 chosen: u8
+        output public r7 called: u8
+        output public r8 secret: u64
+        output public r9 amount: u64
+        output public r10 declassified: u8
+        output public r11 looped: u8
+        output private r14 kept: u8
+
+        decl f0 r3
+        0: lt r0, r1 -> r3
+        1: add.w r0, r1 -> r4
+        2: add.w r1, r1 -> r5
+        3: mask r3, 1
+        4: add.w r1, 1u8 -> r5
+        5: ternary r3, r1, r1 -> r6
+        6: call f1 r0 -> r7
+        7: record.field r2, 3 -> r8
+        8: record.field r2, 2 -> r9
+        9: declassify r4 -> r10
+        10: add.w r1, r1 -> r11
+        11: add.w r1, r1 -> r12
+        // `r11` only reads private data in the second iteration.
+        12: repeat 2, 2
+        13: add.w r12, 0u8 -> r11
+        14: add.w r0, 0u8 -> r12
+        15: ternary true, record(owner: {owner}.private, gates: 0u64.private, data: (r8.public, r9.private), nonce: 0group.constant), r2 -> r13
+        16: add.w r0, r0 -> r14
+
+        decl f1 r0
+        0: add.w r0, 1u8 -> r1
+        1: return r1
+        "
+    )
+    .parse()
+    .unwrap();
+    assert!(program.type_check().is_empty());
+    let errors = program
+        .flow_check()
+        .iter()
+        .map(|x| format!("{x:?}"))
+        .collect::<Vec<_>>();
+    assert_eq!(errors.len(), 7, "{errors:#?}");
+    for item in [
+        "Output `leak`",
+        "Output `masked`",
+        "Output `chosen`",
+        "Output `called`",
+        "Output `secret`",
+        "Output `looped`",
+        "f0:15: Entry 2 of a record in `ternary`",
+    ] {
+        let expected = format!("{item} is revealed");
+        assert!(
+            errors.iter().any(|x| x.contains(&expected)),
+            "{item}: {errors:#?}"
+        );
+    }
+    assert!(program.validate().is_err());
+}
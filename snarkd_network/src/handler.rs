@@ -88,6 +88,20 @@ pub trait RequestHandler: Send + Sync + 'static {
         response: Option<ResponseHandle<'_>>,
     ) -> Result<()>;
 
+    /// Receives encoded snarkd transactions, see `snarkd_common::objects::Transaction::to_bytes`.
+    async fn on_unconfirmed_transactions(
+        &mut self,
+        transactions: Vec<Vec<u8>>,
+        response: Option<ResponseHandle<'_>>,
+    ) -> Result<()>;
+
+    /// Receives encoded coinbase puzzle prover solutions.
+    async fn on_prover_solutions(
+        &mut self,
+        solutions: Vec<Vec<u8>>,
+        response: Option<ResponseHandle<'_>>,
+    ) -> Result<()>;
+
     // todo: can we use a bloom filter for memory pool?
     async fn on_sync_memory_pool(
         &mut self,
@@ -143,6 +157,22 @@ pub trait RequestHandler: Send + Sync + 'static {
                     .context("invalid packet body value")?;
                 self.on_get_blocks(digests, packet.response).await?;
             }
+            CommandId::UnconfirmedTransactions => {
+                let transactions = packet
+                    .body
+                    .into_unconfirmed_transactions()
+                    .context("invalid packet body value")?;
+                self.on_unconfirmed_transactions(transactions.transactions, packet.response)
+                    .await?;
+            }
+            CommandId::ProverSolutions => {
+                let solutions = packet
+                    .body
+                    .into_prover_solutions()
+                    .context("invalid packet body value")?;
+                self.on_prover_solutions(solutions.solutions, packet.response)
+                    .await?;
+            }
             CommandId::SyncMemoryPool => {
                 let digests = packet
                     .body
@@ -17,6 +17,20 @@ impl PacketBody {
         }
     }
 
+    pub fn into_unconfirmed_transactions(self) -> Option<UnconfirmedTransactions> {
+        match self {
+            PacketBody::UnconfirmedTransactions(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    pub fn into_prover_solutions(self) -> Option<ProverSolutions> {
+        match self {
+            PacketBody::ProverSolutions(x) => Some(x),
+            _ => None,
+        }
+    }
+
     pub fn into_digests(self) -> Option<Vec<Digest>> {
         match self {
             PacketBody::Digests(x) => Some(x.hashes),
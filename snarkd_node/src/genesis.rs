@@ -0,0 +1,69 @@
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use snarkd_common::{network::Network, objects::Block, Digest};
+use snarkd_crypto::{coinbase_puzzle::CoinbasePuzzle, keys::PrivateKey};
+use snarkd_storage::Database;
+
+/// Inserts and commits `block` as the first canon block.
+async fn commit_genesis(
+    database: &Database,
+    puzzle: &CoinbasePuzzle,
+    block: Block,
+) -> Result<Digest> {
+    ensure!(
+        database.canon().await?.is_empty(),
+        "database already holds a chain"
+    );
+    let hash = block.header.block_hash.clone();
+    let previous_state_root = block.header.previous_state_root.clone();
+    database.insert_block(block).await?;
+    database
+        .commit_block(hash.clone(), previous_state_root, puzzle.clone())
+        .await?;
+    Ok(hash)
+}
+
+/// Makes sure the canon chain starts from the genesis block `genesis_hash` of `network`.
+/// Genesis blocks are signed by whoever created them, so they cannot be rebuilt locally and have to already be in the database.
+pub async fn ensure_genesis(
+    database: &Database,
+    network: Network,
+    genesis_hash: &Digest,
+) -> Result<()> {
+    let parameters = network.parameters();
+    if database.canon().await?.is_empty() {
+        if network == Network::Devnet {
+            bail!("no genesis block found, create one with `snarkd genesis`");
+        }
+        bail!(
+            "no genesis block found, the {} genesis block {genesis_hash} has to be imported",
+            parameters.name
+        );
+    }
+
+    let hash = database
+        .get_block_hash(0)
+        .await?
+        .ok_or_else(|| anyhow!("missing canon genesis block"))?;
+    ensure!(
+        &hash == genesis_hash,
+        "database starts from genesis block {hash}, expected {genesis_hash} of {}",
+        parameters.name
+    );
+    let header = database.get_block_header(hash).await?;
+    parameters
+        .verify_genesis(&header)
+        .with_context(|| format!("database does not hold a {} chain", parameters.name))
+}
+
+/// Creates and commits a devnet genesis block created at `timestamp` and signed by `private_key`.
+pub async fn create_devnet_genesis(
+    database: &Database,
+    puzzle: &CoinbasePuzzle,
+    timestamp: i64,
+    private_key: &PrivateKey,
+) -> Result<Digest> {
+    let block = Network::Devnet
+        .parameters()
+        .genesis_block(timestamp, private_key);
+    commit_genesis(database, puzzle, block).await
+}
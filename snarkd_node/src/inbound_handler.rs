@@ -3,9 +3,16 @@ use std::net::SocketAddr;
 use anyhow::Result;
 use chrono::Utc;
 use log::{debug, info};
-use snarkd_common::Digest;
+use snarkd_common::{objects, Digest};
+use snarkd_crypto::{
+    coinbase_puzzle::ProverSolution,
+    serialization::{CanonicalDeserialize, Compress},
+};
 use snarkd_network::{
-    proto::{packet::PacketBody, Block, Introduction, Ping, ResponseCode, Transaction},
+    proto::{
+        packet::PacketBody, Block, CommandId, Introduction, Ping, ProverSolutions, ResponseCode,
+        Transaction, UnconfirmedTransactions,
+    },
     RequestHandler, ResponseHandle,
 };
 use tokio::sync::oneshot;
@@ -81,6 +88,56 @@ impl RequestHandler for InboundHandler {
         todo!()
     }
 
+    async fn on_unconfirmed_transactions(
+        &mut self,
+        transactions: Vec<Vec<u8>>,
+        _response: Option<ResponseHandle<'_>>,
+    ) -> Result<()> {
+        // only transactions new to the memory pool are relayed on, which stops them going in circles
+        let mut relayed = vec![];
+        for bytes in transactions {
+            let transaction = objects::Transaction::from_bytes(&bytes)?;
+            let id = transaction.id().clone();
+            match self.peer_book.memory_pool().insert(transaction) {
+                Ok(true) => relayed.push(bytes),
+                Ok(false) => (),
+                Err(e) => debug!("rejected transaction {id} from {}: {e:?}", self.address),
+            }
+        }
+        if !relayed.is_empty() {
+            self.peer_book.broadcast(
+                CommandId::UnconfirmedTransactions,
+                PacketBody::UnconfirmedTransactions(UnconfirmedTransactions {
+                    transactions: relayed,
+                }),
+            );
+        }
+        Ok(())
+    }
+
+    async fn on_prover_solutions(
+        &mut self,
+        solutions: Vec<Vec<u8>>,
+        _response: Option<ResponseHandle<'_>>,
+    ) -> Result<()> {
+        let mut relayed = vec![];
+        for bytes in solutions {
+            let solution = ProverSolution::from_bytes(&bytes, Compress::Yes)?;
+            match self.peer_book.memory_pool().insert_solution(solution) {
+                Ok(true) => relayed.push(bytes),
+                Ok(false) => (),
+                Err(e) => debug!("rejected prover solution from {}: {e:?}", self.address),
+            }
+        }
+        if !relayed.is_empty() {
+            self.peer_book.broadcast(
+                CommandId::ProverSolutions,
+                PacketBody::ProverSolutions(ProverSolutions { solutions: relayed }),
+            );
+        }
+        Ok(())
+    }
+
     async fn on_sync_memory_pool(
         &mut self,
         _digests: Vec<Digest>,
@@ -99,10 +156,12 @@ impl RequestHandler for InboundHandler {
 
     async fn on_sync_blocks(
         &mut self,
-        _digests: Vec<Digest>,
+        digests: Vec<Digest>,
         _response: Option<ResponseHandle<'_>>,
     ) -> Result<()> {
-        todo!()
+        //TODO: fetch announced blocks from the peer syncer
+        debug!("{} announced {} blocks", self.address, digests.len());
+        Ok(())
     }
 
     async fn on_ping(&mut self, ping: Ping, response: Option<ResponseHandle<'_>>) -> Result<()> {
@@ -4,29 +4,61 @@ use std::{
     time::Duration,
 };
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use config::{CONFIG, NODE_ID};
 use log::{debug, error, info, warn, LevelFilter};
 use peer_book::PeerBook;
-use snarkd_common::config::Verbosity;
+use snarkd_common::{config::Verbosity, network::Network};
+use snarkd_crypto::{coinbase_puzzle::CoinbasePuzzle, polycommit::parameters};
 use snarkd_network::Connection;
 use snarkd_peer::announcer::AnnouncerConsumer;
 use snarkd_rpc::server::websocket_server;
 use snarkd_storage::{Database, PeerDirection};
 use tokio::{net::TcpListener, sync::oneshot, time::MissedTickBehavior};
 
-use crate::{inbound_handler::InboundHandler, peer::PEER_PING_INTERVAL};
+use crate::{
+    genesis::{create_devnet_genesis, ensure_genesis},
+    inbound_handler::InboundHandler,
+    memory_pool::MemoryPool,
+    peer::PEER_PING_INTERVAL,
+    producer::Producer,
+};
 
 mod config;
+mod genesis;
 mod inbound_handler;
+mod memory_pool;
 mod peer;
 mod peer_book;
+mod producer;
 mod rpc;
 
 /// Snarkd Blockchain Node
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
-struct Args {}
+struct Args {
+    #[command(subcommand)]
+    command: Option<Commands>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Commands {
+    /// Creates the genesis block of a private devnet in the configured database
+    Genesis {
+        /// Unix timestamp of the genesis block, defaults to now
+        #[arg(long)]
+        timestamp: Option<i64>,
+    },
+    /// Generates the universal SRS of a private devnet in the configured parameters directory
+    Setup {
+        /// Maximum polynomial degree supported, defaults to the degree required by the coinbase puzzle
+        #[arg(long)]
+        max_degree: Option<usize>,
+        /// Replaces existing parameters
+        #[arg(long)]
+        force: bool,
+    },
+}
 
 const ANNOUNCE: &str = r#"                            ,-▄▄██▄▄,,
                        ,▄██▀▀╙`      "╙▀█▓▄▄,
@@ -66,6 +98,7 @@ const ANNOUNCE: &str = r#"                            ,-▄▄██▄▄,,
 
 #[tokio::main]
 async fn main() {
+    let args = Args::parse();
     println!("{ANNOUNCE}");
     lazy_static::initialize(&CONFIG);
 
@@ -91,6 +124,74 @@ async fn main() {
         }
     }
 
+    if let Some(Commands::Setup { max_degree, force }) = args.command {
+        let dir = std::path::Path::new(&config.parameters_dir);
+        if config.network != Network::Devnet {
+            error!(
+                "universal parameters can only be generated for a devnet, {} uses published ones, which must be downloaded to {}",
+                config.network.parameters().name,
+                dir.display()
+            );
+            std::process::exit(1);
+        }
+        if dir.join(parameters::UNIVERSAL_SRS_FILE).exists() && !force {
+            error!(
+                "parameters already exist in {}, pass --force to replace them",
+                dir.display()
+            );
+            std::process::exit(1);
+        }
+        let max_degree = match max_degree {
+            Some(x) => x,
+            None => match CoinbasePuzzle::srs_degree(config.network.parameters().puzzle_config()) {
+                Ok(x) => x,
+                Err(e) => {
+                    error!("invalid coinbase puzzle degree: {e:?}");
+                    std::process::exit(1);
+                }
+            },
+        };
+        info!("generating universal parameters of degree {max_degree}...");
+        match parameters::setup(dir, max_degree) {
+            Ok(checksum) => {
+                info!(
+                    "wrote universal parameters to {} with checksum {checksum}",
+                    dir.display()
+                );
+                return;
+            }
+            Err(e) => {
+                error!("failed to generate universal parameters: {e:?}");
+                std::process::exit(1);
+            }
+        }
+    }
+    let universal_srs_checksum = match config.network.parameters().universal_srs_checksum() {
+        Ok(x) => x,
+        Err(e) => {
+            error!("refusing to join {:?}: {e:?}", config.network);
+            std::process::exit(1);
+        }
+    };
+    if let Err(e) = parameters::set_parameters_dir(&config.parameters_dir, universal_srs_checksum) {
+        error!("invalid parameters directory: {e:?}");
+        std::process::exit(1);
+    }
+    // every committed block is checked against the coinbase puzzle, so it is needed before touching the chain
+    info!("loading coinbase puzzle...");
+    let puzzle_config = config.network.parameters().puzzle_config();
+    let puzzle = match tokio::task::spawn_blocking(move || CoinbasePuzzle::load(puzzle_config))
+        .await
+        .map_err(anyhow::Error::from)
+        .and_then(|x| x)
+    {
+        Ok(x) => x,
+        Err(e) => {
+            error!("failed to load coinbase puzzle: {e:?}");
+            std::process::exit(1);
+        }
+    };
+
     let database = match config.database_file.as_ref() {
         Some(path) => match Database::open_file(path.clone()).await {
             Ok(x) => x,
@@ -111,9 +212,65 @@ async fn main() {
         }
     };
     let database = Arc::new(database);
+    let private_key = if config.producer.private_key.is_some() {
+        match config.producer.private_key() {
+            Ok(x) => Some(x),
+            Err(e) => {
+                error!("invalid producer private key: {e:?}");
+                std::process::exit(1);
+            }
+        }
+    } else {
+        None
+    };
+
+    if let Some(Commands::Genesis { timestamp }) = args.command {
+        if config.network != Network::Devnet {
+            error!("genesis blocks can only be created for a devnet, other networks pin theirs");
+            std::process::exit(1);
+        }
+        if config.database_file.is_none() {
+            error!("a database file is required to create a genesis block");
+            std::process::exit(1);
+        }
+        let private_key = match private_key.as_ref() {
+            Some(x) => x,
+            None => {
+                error!("a producer private key is required to sign the genesis block");
+                std::process::exit(1);
+            }
+        };
+        let timestamp = timestamp.unwrap_or_else(|| chrono::Utc::now().timestamp());
+        match create_devnet_genesis(&database, &puzzle, timestamp, private_key).await {
+            Ok(hash) => {
+                info!("created devnet genesis block {hash} at timestamp {timestamp}, set `genesis_hash: {hash}` in the config of every devnet node");
+                return;
+            }
+            Err(e) => {
+                error!("failed to create genesis block: {e:?}");
+                std::process::exit(1);
+            }
+        }
+    }
+
+    let genesis_hash = match config.genesis_hash() {
+        Ok(x) => x,
+        Err(e) => {
+            error!("refusing to join {:?}: {e:?}", config.network);
+            std::process::exit(1);
+        }
+    };
+    if let Err(e) = ensure_genesis(&database, config.network, &genesis_hash).await {
+        error!(
+            "failed to set up the {:?} genesis block: {e:?}",
+            config.network
+        );
+        std::process::exit(1);
+    }
     let rpc_channels = Arc::new(rpc::RpcChannels::new(rpc_enabled));
 
-    let peer_book = PeerBook::new(rpc_channels.clone());
+    let memory_pool = MemoryPool::default();
+    let peer_book = PeerBook::new(rpc_channels.clone(), memory_pool.clone());
 
     // spawn network listener
     {
@@ -200,6 +357,14 @@ async fn main() {
     if let Err(e) = peer_book.load_saved_peers(&database).await {
         error!("failed to load peers from database: {e:?}");
     }
+    match config.network.parameters().seed_peers() {
+        Ok(seed_peers) => {
+            if let Err(e) = peer_book.discovered_peers(&database, seed_peers).await {
+                error!("failed to add seed peers: {e:?}");
+            }
+        }
+        Err(e) => error!("failed to load seed peers: {e:?}"),
+    }
 
     // spawn peer connect/disconnect task (also saves peers if dirty)
     {
@@ -290,7 +455,8 @@ async fn main() {
     let rpc_handle = if rpc_enabled {
         let rpc_addr = SocketAddr::new(config.rpc_ip.into(), config.rpc_port);
         let rpc_module = rpc::SnarkdRpc {
-            peer_book,
+            peer_book: peer_book.clone(),
+            database: database.clone(),
             channels: rpc_channels,
         }
         .module();
@@ -309,7 +475,28 @@ async fn main() {
         None
     };
 
-    //TODO: start miner
+    // spawn block producer
+    if let Some(private_key) = private_key.filter(|_| config.producer.enabled) {
+        let network = config.network;
+        let prover_threads = config.producer.prover_threads;
+        let max_reorg_depth = config.max_reorg_depth;
+        let database = database.clone();
+        let puzzle = puzzle.clone();
+        tokio::spawn(async move {
+            Arc::new(Producer::new(
+                network,
+                database,
+                peer_book,
+                memory_pool,
+                puzzle,
+                private_key,
+                prover_threads,
+                max_reorg_depth,
+            ))
+            .run()
+            .await;
+        });
+    }
 
     tokio::select! {
         _ = std::future::pending::<()>() => {
@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use anyhow::{bail, Context, Result};
+use dashmap::DashMap;
+use snarkd_common::{objects::Transaction, Digest};
+use snarkd_crypto::coinbase_puzzle::{ProverSolution, PuzzleCommitment};
+
+/// The maximum number of prover solutions waiting to be picked up by the block producer.
+const MAX_UNCONFIRMED_SOLUTIONS: usize = 1 << 12; // 4,096 prover solutions
+
+/// Unconfirmed transactions and prover solutions waiting to be included in a block.
+#[derive(Clone, Default)]
+pub struct MemoryPool {
+    transactions: Arc<DashMap<Digest, Transaction>>,
+    solutions: Arc<DashMap<PuzzleCommitment, ProverSolution>>,
+}
+
+impl MemoryPool {
+    /// Adds a transaction to the pool, returns false if it was already present.
+    /// Deployments must carry a valid, canonically encoded program, as blocks including them are otherwise rejected.
+    pub fn insert(&self, transaction: Transaction) -> Result<bool> {
+        if let Transaction::Deploy(deploy) = &transaction {
+            deploy
+                .deployment
+                .verify()
+                .with_context(|| format!("invalid program in deploy transaction {}", deploy.id))?;
+        }
+        Ok(self
+            .transactions
+            .insert(transaction.id().clone(), transaction)
+            .is_none())
+    }
+
+    /// Removes confirmed transactions from the pool.
+    pub fn remove<'a>(&self, ids: impl IntoIterator<Item = &'a Digest>) {
+        for id in ids {
+            self.transactions.remove(id);
+        }
+    }
+
+    /// Selects up to `count` transactions for a new block, highest fee first.
+    pub fn select(&self, count: usize) -> Vec<Transaction> {
+        let mut transactions = self
+            .transactions
+            .iter()
+            .map(|x| x.value().clone())
+            .collect::<Vec<_>>();
+        transactions.sort_by_key(|x| std::cmp::Reverse(x.fee()));
+        transactions.truncate(count);
+        transactions
+    }
+
+    /// Adds a prover solution from a peer to the pool, returns false if it was already present.
+    /// Solutions are verified by the producer once it takes them, against the block it is working on.
+    /// A full pool makes room by dropping its solution with the lowest target, if that is below the new one.
+    pub fn insert_solution(&self, solution: ProverSolution) -> Result<bool> {
+        let commitment = solution.commitment();
+        if self.solutions.contains_key(&commitment) {
+            return Ok(false);
+        }
+        if self.solutions.len() >= MAX_UNCONFIRMED_SOLUTIONS {
+            let target = solution.to_target()?;
+            let lowest = self
+                .solutions
+                .iter()
+                .map(|x| (x.to_target().unwrap_or_default(), *x.key()))
+                .min_by_key(|x| x.0);
+            match lowest {
+                Some((lowest_target, lowest)) if lowest_target < target => {
+                    self.solutions.remove(&lowest);
+                }
+                _ => bail!("too many unconfirmed prover solutions"),
+            }
+        }
+        Ok(self.solutions.insert(commitment, solution).is_none())
+    }
+
+    /// Removes and returns the prover solutions in the pool.
+    pub fn take_solutions(&self) -> Vec<ProverSolution> {
+        let commitments = self.solutions.iter().map(|x| *x.key()).collect::<Vec<_>>();
+        commitments
+            .iter()
+            .filter_map(|x| self.solutions.remove(x))
+            .map(|x| x.1)
+            .collect()
+    }
+}
@@ -1,6 +1,11 @@
 use std::{net::SocketAddr, sync::Arc};
 
-use crate::{config::CONFIG, peer::Peer, rpc::RpcChannels};
+use crate::{
+    config::CONFIG,
+    memory_pool::MemoryPool,
+    peer::{Peer, PEER_TIMEOUT},
+    rpc::RpcChannels,
+};
 use anyhow::Result;
 use dashmap::{
     mapref::{
@@ -14,22 +19,30 @@ use itertools::Itertools;
 use log::{debug, error, info, trace, warn};
 use rand::seq::IteratorRandom;
 use rand::thread_rng;
+use snarkd_network::proto::{packet::PacketBody, CommandId};
 use snarkd_storage::{Database, PeerData, PeerDirection};
 
 #[derive(Clone)]
 pub struct PeerBook {
     rpc_channels: Arc<RpcChannels>,
+    memory_pool: MemoryPool,
     peers: Arc<DashMap<SocketAddr, Peer>>,
 }
 
 impl PeerBook {
-    pub fn new(rpc_channels: Arc<RpcChannels>) -> Self {
+    pub fn new(rpc_channels: Arc<RpcChannels>, memory_pool: MemoryPool) -> Self {
         Self {
             rpc_channels,
+            memory_pool,
             peers: Default::default(),
         }
     }
 
+    /// The memory pool filled by transactions and prover solutions relayed from peers.
+    pub fn memory_pool(&self) -> &MemoryPool {
+        &self.memory_pool
+    }
+
     pub async fn load_saved_peers(&self, db: &Database) -> Result<()> {
         for peer_data in db.load_all_peers().await? {
             let mut peer = self.peers.entry(peer_data.address).or_insert_with(|| {
@@ -85,6 +98,23 @@ impl PeerBook {
         self.connected_peers().count()
     }
 
+    /// send a request to every connected peer, without waiting for it to be flushed
+    pub fn broadcast(&self, command: CommandId, body: PacketBody) {
+        for peer in self.connected_peers() {
+            let connection = match peer.connection() {
+                Some(x) => x.clone(),
+                None => continue,
+            };
+            let address = peer.address;
+            let body = body.clone();
+            tokio::spawn(async move {
+                if let Err(e) = connection.request(command, body, PEER_TIMEOUT).await {
+                    debug!("failed to broadcast to {address}: {e:?}");
+                }
+            });
+        }
+    }
+
     /// disconnect from `count` peers at random
     pub fn disconnect_from_peers(&self, count: usize) {
         if count == 0 {
@@ -0,0 +1,395 @@
+use std::{
+    cmp::Reverse,
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use anyhow::{anyhow, ensure, Result};
+use chrono::Utc;
+use log::{debug, info, trace, warn};
+use parking_lot::Mutex;
+use snarkd_common::{
+    consensus::{self, MAX_TRANSACTIONS_PER_BLOCK},
+    network::{Network, NetworkParameters},
+    objects::{Block, BlockHeader},
+    Digest,
+};
+use snarkd_crypto::{
+    coinbase_puzzle::{
+        CoinbasePuzzle, EpochChallenge, ProverSolution, PuzzleCommitment, MAX_PROVER_SOLUTIONS,
+    },
+    keys::{Address, ComputeKey, PrivateKey},
+    serialization::{CanonicalSerialize, Compress},
+};
+use snarkd_network::proto::{packet::PacketBody, CommandId, DigestList, ProverSolutions};
+use snarkd_storage::{BlockStatus, Database};
+
+use crate::{memory_pool::MemoryPool, peer_book::PeerBook};
+
+/// How often the producer checks for a new canon block or enough accumulated solutions.
+const PRODUCER_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A block being worked on, extending the canon block `parent`.
+struct Job {
+    parent: BlockHeader,
+    epoch_challenge: EpochChallenge,
+}
+
+#[derive(Default)]
+struct Round {
+    job: Option<Arc<Job>>,
+    /// Valid solutions for `job`, keyed by commitment so duplicates are only counted once.
+    solutions: HashMap<PuzzleCommitment, ProverSolution>,
+}
+
+/// Proves the coinbase puzzle, accumulates prover solutions and produces blocks on top of canon.
+pub struct Producer {
+    network: &'static NetworkParameters,
+    database: Arc<Database>,
+    peer_book: PeerBook,
+    memory_pool: MemoryPool,
+    puzzle: CoinbasePuzzle,
+    private_key: PrivateKey,
+    address: Address,
+    prover_threads: usize,
+    /// See [`snarkd_common::config::Config::max_reorg_depth`]
+    max_reorg_depth: usize,
+    round: Mutex<Round>,
+    /// Set to stop the prover threads working on the current job.
+    terminator: AtomicBool,
+}
+
+impl Producer {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        network: Network,
+        database: Arc<Database>,
+        peer_book: PeerBook,
+        memory_pool: MemoryPool,
+        puzzle: CoinbasePuzzle,
+        private_key: PrivateKey,
+        prover_threads: usize,
+        max_reorg_depth: usize,
+    ) -> Self {
+        let address = ComputeKey::from(&private_key).to_address();
+        Self {
+            network: network.parameters(),
+            database,
+            peer_book,
+            memory_pool,
+            puzzle,
+            private_key,
+            address,
+            prover_threads,
+            max_reorg_depth,
+            round: Default::default(),
+            terminator: AtomicBool::new(false),
+        }
+    }
+
+    /// Produces blocks until the process exits.
+    pub async fn run(self: Arc<Self>) {
+        info!(
+            "producing blocks with {} prover threads",
+            self.prover_threads
+        );
+        loop {
+            if let Err(e) = self.clone().produce().await {
+                warn!("block production failed: {e:?}");
+                tokio::time::sleep(PRODUCER_POLL_INTERVAL).await;
+            }
+        }
+    }
+
+    /// Adds a prover solution for the current job, from a local prover thread or relayed by a peer.
+    pub fn add_solution(&self, solution: ProverSolution) -> Result<()> {
+        let job = self
+            .round
+            .lock()
+            .job
+            .clone()
+            .ok_or_else(|| anyhow!("no block is being produced"))?;
+        ensure!(
+            solution.verify(
+                self.puzzle.coinbase_verifying_key(),
+                &job.epoch_challenge,
+                job.parent.metadata.proof_target,
+            )?,
+            "invalid prover solution"
+        );
+
+        let mut round = self.round.lock();
+        // the job may have been replaced while verifying
+        ensure!(
+            round.job.as_ref().map(|x| Arc::ptr_eq(x, &job)) == Some(true),
+            "prover solution is for a stale job"
+        );
+        ensure!(
+            round.solutions.len() < MAX_PROVER_SOLUTIONS,
+            "too many prover solutions"
+        );
+        round.solutions.insert(solution.commitment(), solution);
+        Ok(())
+    }
+
+    /// Works on a block extending the current canon block, until either the block is produced or canon moves on.
+    async fn produce(self: Arc<Self>) -> Result<()> {
+        let canon = self.database.canon().await?;
+        if canon.is_empty() {
+            debug!("waiting for a genesis block before producing");
+            tokio::time::sleep(PRODUCER_POLL_INTERVAL).await;
+            return Ok(());
+        }
+
+        let parent = self.database.get_block_header(canon.hash.clone()).await?;
+        let height = parent
+            .metadata
+            .height
+            .checked_add(1)
+            .ok_or_else(|| anyhow!("block height overflowed"))?;
+        let epoch_number = consensus::epoch_number(self.network, height);
+        let epoch_block_hash = self
+            .database
+            .get_block_hash(consensus::epoch_block_height(self.network, epoch_number))
+            .await?
+            .ok_or_else(|| anyhow!("missing epoch block for epoch {epoch_number}"))?;
+        let job = Arc::new(Job {
+            parent,
+            epoch_challenge: consensus::epoch_challenge(
+                self.network,
+                epoch_number,
+                &epoch_block_hash,
+            )?,
+        });
+        *self.round.lock() = Round {
+            job: Some(job.clone()),
+            solutions: Default::default(),
+        };
+
+        debug!("starting block production at height {height}");
+        self.terminator.store(false, Ordering::SeqCst);
+        let provers = (0..self.prover_threads)
+            .map(|_| {
+                let self_ = self.clone();
+                let job = job.clone();
+                tokio::task::spawn_blocking(move || self_.prove(&job))
+            })
+            .collect::<Vec<_>>();
+
+        let solutions = self.wait_for_solutions(&canon.hash, &job).await;
+
+        self.terminator.store(true, Ordering::SeqCst);
+        for prover in provers {
+            prover.await.ok();
+        }
+        self.round.lock().job = None;
+
+        let solutions = match solutions? {
+            Some(solutions) => solutions,
+            None => {
+                info!("canon moved on, restarting block production");
+                return Ok(());
+            }
+        };
+        let block = self.assemble(&job, solutions)?;
+        self.publish(block).await
+    }
+
+    /// Proves the coinbase puzzle with random nonces until terminated.
+    fn prove(&self, job: &Job) {
+        while !self.terminator.load(Ordering::Relaxed) {
+            let solution = match self.puzzle.prove(
+                &job.epoch_challenge,
+                self.address,
+                rand::random(),
+                Some(job.parent.metadata.proof_target),
+            ) {
+                Ok(solution) => solution,
+                // most nonces do not meet the proof target
+                Err(_) => continue,
+            };
+            match self.add_solution(solution) {
+                // other producers pay out the solution to this prover as well
+                Ok(()) => self.peer_book.broadcast(
+                    CommandId::ProverSolutions,
+                    PacketBody::ProverSolutions(ProverSolutions {
+                        solutions: vec![solution.to_bytes(Compress::Yes)],
+                    }),
+                ),
+                Err(e) => trace!("discarding local prover solution: {e:?}"),
+            }
+        }
+    }
+
+    /// Waits until the accumulated solutions, local or from the memory pool, reach the coinbase target,
+    /// or returns `None` if `canon_hash` is no longer canon.
+    async fn wait_for_solutions(
+        &self,
+        canon_hash: &Digest,
+        job: &Job,
+    ) -> Result<Option<Vec<ProverSolution>>> {
+        loop {
+            tokio::time::sleep(PRODUCER_POLL_INTERVAL).await;
+            if &self.database.canon().await?.hash != canon_hash {
+                return Ok(None);
+            }
+            for solution in self.memory_pool.take_solutions() {
+                if let Err(e) = self.add_solution(solution) {
+                    trace!("discarding relayed prover solution: {e:?}");
+                }
+            }
+
+            let round = self.round.lock();
+            let cumulative_target = round
+                .solutions
+                .values()
+                .map(|x| x.to_target().map(u128::from))
+                .sum::<Result<u128>>()?;
+            if cumulative_target >= job.parent.metadata.coinbase_target as u128 {
+                return Ok(Some(round.solutions.values().copied().collect()));
+            }
+        }
+    }
+
+    /// Accumulates the prover solutions and assembles a signed block from the memory pool, paying out its rewards.
+    fn assemble(&self, job: &Job, mut solutions: Vec<ProverSolution>) -> Result<Block> {
+        solutions.sort_by_cached_key(|x| Reverse(x.to_target().unwrap_or_default()));
+        solutions.truncate(MAX_PROVER_SOLUTIONS);
+        let coinbase = self
+            .puzzle
+            .accumulate_unchecked(&job.epoch_challenge, &solutions)?;
+
+        let timestamp = Utc::now()
+            .timestamp()
+            .max(job.parent.metadata.timestamp + 1);
+        let metadata = consensus::next_metadata(self.network, &job.parent.metadata, timestamp)?;
+        let transactions = self.memory_pool.select(MAX_TRANSACTIONS_PER_BLOCK);
+        let rewards = consensus::block_rewards(
+            self.network,
+            metadata.height,
+            self.address,
+            Some(&coinbase),
+            Block::transaction_fees(&transactions),
+        )?;
+        let header = BlockHeader::new(
+            job.parent.block_hash.clone(),
+            job.parent.previous_state_root.clone(),
+            Block::transactions_root(&transactions),
+            Block::coinbase_root(Some(&coinbase)),
+            Block::rewards_root(&rewards),
+            metadata,
+            &self.private_key,
+        );
+        Ok(Block {
+            header,
+            transactions,
+            coinbase: Some(coinbase),
+            rewards,
+        })
+    }
+
+    /// Moves canon onto a produced block and announces it to connected peers.
+    async fn publish(&self, block: Block) -> Result<()> {
+        let hash = block.header.block_hash.clone();
+        let height = block.header.metadata.height;
+        let transaction_ids = block
+            .transactions
+            .iter()
+            .map(|x| x.id().clone())
+            .collect::<Vec<_>>();
+
+        let status = self
+            .database
+            .accept_block(block, self.puzzle.clone(), self.max_reorg_depth)
+            .await?;
+        ensure!(
+            matches!(status, BlockStatus::Committed(_)),
+            "produced block {hash} did not become canon"
+        );
+        self.memory_pool.remove(&transaction_ids);
+        info!("produced block {hash} at height {height}");
+
+        self.peer_book.broadcast(
+            CommandId::SyncBlocks,
+            PacketBody::Digests(DigestList { hashes: vec![hash] }),
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use snarkd_crypto::serialization::CanonicalDeserialize;
+
+    use super::*;
+    use crate::{genesis::create_devnet_genesis, rpc::RpcChannels};
+
+    #[tokio::test]
+    async fn test_relayed_solutions_are_included() {
+        let network = Network::Devnet;
+        let config = network.parameters().puzzle_config();
+        let srs = CoinbasePuzzle::setup(config).unwrap();
+        let puzzle = CoinbasePuzzle::trim(&srs, config).unwrap();
+        let database = Arc::new(Database::open_in_memory().await.unwrap());
+        let private_key = PrivateKey::rand();
+        let genesis = create_devnet_genesis(
+            &database,
+            &puzzle,
+            Utc::now().timestamp() - 100,
+            &private_key,
+        )
+        .await
+        .unwrap();
+        let parent = database.get_block_header(genesis.clone()).await.unwrap();
+        let epoch_challenge =
+            consensus::epoch_challenge(network.parameters(), 0, &genesis).unwrap();
+
+        // another prover reaches the whole coinbase target, as the producer has no prover threads of its own
+        let prover = ComputeKey::from(&PrivateKey::rand()).to_address();
+        let memory_pool = MemoryPool::default();
+        let mut cumulative_target = 0u128;
+        let mut nonce = 0;
+        while cumulative_target < parent.metadata.coinbase_target as u128 {
+            if let Ok(solution) = puzzle.prove(
+                &epoch_challenge,
+                prover,
+                nonce,
+                Some(parent.metadata.proof_target),
+            ) {
+                cumulative_target += solution.to_target().unwrap() as u128;
+                let bytes = solution.to_bytes(Compress::Yes);
+                let relayed = ProverSolution::from_bytes(&bytes, Compress::Yes).unwrap();
+                assert!(memory_pool.insert_solution(relayed).unwrap());
+            }
+            nonce += 1;
+        }
+
+        let peer_book = PeerBook::new(Arc::new(RpcChannels::new(false)), memory_pool.clone());
+        let producer = Arc::new(Producer::new(
+            network,
+            database.clone(),
+            peer_book,
+            memory_pool,
+            puzzle,
+            private_key,
+            0,
+            100,
+        ));
+        producer.produce().await.unwrap();
+
+        let canon = database.canon().await.unwrap();
+        assert_eq!(canon.block_height, 1);
+        let block = database.get_block(canon.hash).await.unwrap();
+        let coinbase = block.coinbase.unwrap();
+        assert!(!coinbase.is_empty());
+        assert!(coinbase
+            .partial_solutions()
+            .iter()
+            .all(|x| x.address() == prover));
+        assert!(block.rewards.iter().any(|x| x.0 == prover));
+    }
+}
@@ -3,13 +3,14 @@ use std::sync::Arc;
 use anyhow::Result;
 use async_trait::async_trait;
 use log::debug;
+use snarkd_common::objects::{Identifier, ProgramID};
 pub use snarkd_rpc::common::PeerMessage;
 use snarkd_rpc::{
-    common::{RpcError, RpcServer},
+    common::{MappingEntry, RpcError, RpcServer, MAX_MAPPING_ENTRIES},
     jsonrpsee::{core::error::SubscriptionClosed, types::SubscriptionResult, SubscriptionSink},
     server::RpcModule,
 };
-use snarkd_storage::PeerData;
+use snarkd_storage::{Database, PeerData};
 use tokio::sync::broadcast::Sender;
 use tokio_stream::wrappers::BroadcastStream;
 
@@ -42,9 +43,20 @@ impl RpcChannels {
 
 pub struct SnarkdRpc {
     pub peer_book: PeerBook,
+    pub database: Arc<Database>,
     pub channels: Arc<RpcChannels>,
 }
 
+fn parse_mapping(program_id: &str, mapping: &str) -> Result<(ProgramID, Identifier), RpcError> {
+    let program_id = program_id
+        .parse()
+        .map_err(|e| RpcError::Custom(format!("invalid program id: {e}")))?;
+    let mapping = mapping
+        .parse()
+        .map_err(|e| RpcError::Custom(format!("invalid mapping name: {e}")))?;
+    Ok((program_id, mapping))
+}
+
 #[async_trait]
 impl RpcServer for SnarkdRpc {
     fn foo(&self) -> Result<String, RpcError> {
@@ -67,6 +79,48 @@ impl RpcServer for SnarkdRpc {
             .collect())
     }
 
+    async fn get_mapping_value(
+        &self,
+        program_id: String,
+        mapping: String,
+        key: String,
+    ) -> Result<Option<String>, RpcError> {
+        let (program_id, mapping) = parse_mapping(&program_id, &mapping)?;
+        let key =
+            hex::decode(key).map_err(|e| RpcError::Custom(format!("invalid mapping key: {e}")))?;
+        let value = self
+            .database
+            .get_mapping_value(program_id, mapping, key)
+            .await
+            .map_err(|e| RpcError::Custom(format!("failed to read mapping value: {e:?}")))?;
+        Ok(value.map(hex::encode))
+    }
+
+    async fn get_mapping_entries(
+        &self,
+        program_id: String,
+        mapping: String,
+        offset: u32,
+        limit: Option<u32>,
+    ) -> Result<Vec<MappingEntry>, RpcError> {
+        let (program_id, mapping) = parse_mapping(&program_id, &mapping)?;
+        let limit = limit
+            .unwrap_or(MAX_MAPPING_ENTRIES)
+            .min(MAX_MAPPING_ENTRIES);
+        let entries = self
+            .database
+            .get_mapping_entries(program_id, mapping, offset, limit)
+            .await
+            .map_err(|e| RpcError::Custom(format!("failed to read mapping entries: {e:?}")))?;
+        Ok(entries
+            .into_iter()
+            .map(|(key, value)| MappingEntry {
+                key: hex::encode(key),
+                value: hex::encode(value),
+            })
+            .collect())
+    }
+
     fn subscribe_peers(&self, mut sink: SubscriptionSink) -> SubscriptionResult {
         let channel = match &*self.channels {
             RpcChannels::Enabled { peer_broadcast, .. } => peer_broadcast,
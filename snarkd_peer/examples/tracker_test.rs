@@ -1,6 +1,6 @@
 use anyhow::Result;
 use serde_json::json;
-use snarkd_common::config::PeerConfig;
+use snarkd_common::{config::PeerConfig, network::Network};
 use snarkd_peer::torrent::{AnnounceRequest, Tracker, TrackerHTTP};
 
 pub async fn announce_scrape_tracker(conf: &PeerConfig, tracker: String) -> Result<()> {
@@ -35,7 +35,12 @@ pub async fn announce_scrape_tracker(conf: &PeerConfig, tracker: String) -> Resu
 #[tokio::main]
 async fn main() {
     // parse config from yaml
-    let conf: PeerConfig = serde_json::from_str("{}").unwrap();
+    let mut conf: PeerConfig = serde_json::from_str("{}").unwrap();
+    conf.info_hash = Network::Testnet
+        .parameters()
+        .info_hash
+        .expect("testnet pins its info hash")
+        .to_string();
     conf.print();
 
     announce_scrape_tracker(
@@ -5,6 +5,9 @@ use jsonrpsee::proc_macros::rpc;
 use serde::{Deserialize, Serialize};
 pub use snarkd_storage::PeerData;
 
+/// The most mapping entries returned by a single `get_mapping_entries` call.
+pub const MAX_MAPPING_ENTRIES: u32 = 1000;
+
 #[rpc(server, client, namespace = "snarkd")]
 #[async_trait]
 pub trait Rpc {
@@ -20,6 +23,26 @@ pub trait Rpc {
     /// Returns a list of peer data
     async fn list_peers(&self) -> Result<Vec<PeerData>, RpcError>;
 
+    #[method(name = "get_mapping_value")]
+    /// Returns the hex encoded value of a hex encoded key in a program mapping, if the key is set
+    async fn get_mapping_value(
+        &self,
+        program_id: String,
+        mapping: String,
+        key: String,
+    ) -> Result<Option<String>, RpcError>;
+
+    #[method(name = "get_mapping_entries")]
+    /// Returns up to `limit` keys and values of a program mapping, ordered by key and skipping the first `offset`.
+    /// Servers return at most [`MAX_MAPPING_ENTRIES`], which is also the default `limit`
+    async fn get_mapping_entries(
+        &self,
+        program_id: String,
+        mapping: String,
+        offset: u32,
+        limit: Option<u32>,
+    ) -> Result<Vec<MappingEntry>, RpcError>;
+
     #[subscription(name = "subscribe_peers", item = PeerMessage)]
     /// Subscription that produces a PeerMessage.
     fn subscribe_peers(&self);
@@ -34,3 +57,10 @@ pub enum PeerMessage {
     Update { address: SocketAddr, peer: PeerData },
     Disconnect(SocketAddr),
 }
+
+/// A key and value of a program mapping, both hex encoded.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MappingEntry {
+    pub key: String,
+    pub value: String,
+}
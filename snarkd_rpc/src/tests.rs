@@ -3,7 +3,7 @@ use snarkd_storage::PeerData;
 
 use crate::{
     client,
-    common::{self, PeerMessage},
+    common::{self, MappingEntry, PeerMessage},
     server,
 };
 
@@ -32,6 +32,28 @@ async fn test_snarkd_rpc() -> anyhow::Result<()> {
             Ok(vec![])
         }
 
+        async fn get_mapping_value(
+            &self,
+            _program_id: String,
+            _mapping: String,
+            key: String,
+        ) -> Result<Option<String>, RpcError> {
+            Ok(Some(key))
+        }
+
+        async fn get_mapping_entries(
+            &self,
+            _program_id: String,
+            _mapping: String,
+            _offset: u32,
+            _limit: Option<u32>,
+        ) -> Result<Vec<MappingEntry>, RpcError> {
+            Ok(vec![MappingEntry {
+                key: "01".to_string(),
+                value: "02".to_string(),
+            }])
+        }
+
         fn subscribe_peers(&self, mut sink: SubscriptionSink) -> SubscriptionResult {
             let _ = sink.send(&PeerMessage::Accept("0.0.0.0:0".parse().unwrap()));
             Ok(())
@@ -45,6 +67,21 @@ async fn test_snarkd_rpc() -> anyhow::Result<()> {
     assert_eq!(rpc.foo().await?, "foo");
     assert_eq!(rpc.bar("bar".to_string()).await?, "bar");
     assert_eq!(rpc.list_peers().await?.len(), 0);
+    assert_eq!(
+        rpc.get_mapping_value(
+            "token.aleo".to_string(),
+            "balances".to_string(),
+            "01".to_string()
+        )
+        .await?,
+        Some("01".to_string())
+    );
+    assert_eq!(
+        rpc.get_mapping_entries("token.aleo".to_string(), "balances".to_string(), 0, None)
+            .await?
+            .len(),
+        1
+    );
     let mut subscription = rpc.subscribe_peers().await?;
     assert!(subscription.next().await.is_some());
     subscription.unsubscribe().await?;
@@ -0,0 +1,12 @@
+ALTER TABLE blocks ADD COLUMN coinbase_proof_w BLOB;
+ALTER TABLE blocks ADD COLUMN coinbase_proof_random_v BLOB;
+
+CREATE TABLE coinbase_solutions(
+    id INTEGER PRIMARY KEY,
+    block_id INTEGER NOT NULL REFERENCES blocks(id) ON DELETE CASCADE,
+    solution_order INTEGER NOT NULL,
+    address BLOB NOT NULL,
+    nonce BIGINT NOT NULL,
+    commitment BLOB NOT NULL
+);
+CREATE UNIQUE INDEX coinbase_solution_ordering ON coinbase_solutions(block_id, solution_order);
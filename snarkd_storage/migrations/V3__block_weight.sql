@@ -0,0 +1,2 @@
+ALTER TABLE blocks ADD COLUMN weight BIGINT NOT NULL DEFAULT 0;
+UPDATE blocks SET weight = coinbase_target;
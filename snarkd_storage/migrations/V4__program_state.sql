@@ -0,0 +1,17 @@
+ALTER TABLE deployments ADD COLUMN program_name BLOB;
+ALTER TABLE deployments ADD COLUMN program_network BLOB;
+CREATE INDEX deployment_program_lookup ON deployments(program_name, program_network, edition);
+
+-- mapping updates are kept for every stored block, only those of canon blocks make up the mapping state
+CREATE TABLE mapping_updates(
+    id INTEGER PRIMARY KEY,
+    block_id INTEGER NOT NULL REFERENCES blocks(id) ON DELETE CASCADE,
+    update_order INTEGER NOT NULL,
+    program_name BLOB NOT NULL,
+    program_network BLOB NOT NULL,
+    mapping BLOB NOT NULL,
+    key BLOB NOT NULL,
+    value BLOB -- NULL when the key was removed
+);
+CREATE UNIQUE INDEX mapping_update_ordering ON mapping_updates(block_id, update_order);
+CREATE INDEX mapping_update_lookup ON mapping_updates(program_name, program_network, mapping, key);
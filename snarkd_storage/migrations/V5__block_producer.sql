@@ -0,0 +1,2 @@
+-- the producer address is part of the block hash, blocks stored before it was added can not be verified
+ALTER TABLE blocks ADD COLUMN producer BLOB;
@@ -0,0 +1,2 @@
+-- the coinbase root is part of the block hash, blocks stored before it was added can not be verified
+ALTER TABLE blocks ADD COLUMN coinbase_root BLOB;
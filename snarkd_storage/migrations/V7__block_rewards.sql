@@ -0,0 +1,12 @@
+ALTER TABLE blocks ADD COLUMN rewards_root BLOB;
+
+-- rewards are kept for every stored block, only those of canon blocks are paid out
+CREATE TABLE block_rewards(
+    id INTEGER PRIMARY KEY,
+    block_id INTEGER NOT NULL REFERENCES blocks(id) ON DELETE CASCADE,
+    reward_order INTEGER NOT NULL,
+    address BLOB NOT NULL,
+    amount BIGINT NOT NULL
+);
+CREATE UNIQUE INDEX block_reward_ordering ON block_rewards(block_id, reward_order);
+CREATE INDEX block_reward_lookup ON block_rewards(address);
@@ -0,0 +1,4 @@
+-- identity of the deployed program, see `Deployment::fingerprint` and `Deployment::id`
+ALTER TABLE deployments ADD COLUMN fingerprint BLOB;
+ALTER TABLE deployments ADD COLUMN deployment_id BLOB;
+CREATE INDEX deployment_id_lookup ON deployments(deployment_id);
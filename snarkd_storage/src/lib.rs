@@ -3,3 +3,6 @@ pub use db::Database;
 
 mod objects;
 pub use objects::*;
+
+#[cfg(test)]
+mod tests;
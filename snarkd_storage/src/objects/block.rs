@@ -2,15 +2,25 @@ use anyhow::{anyhow, bail, Context, Result};
 use log::warn;
 use rusqlite::{params, Connection, OptionalExtension, ToSql};
 use snarkd_common::{
+    consensus,
     objects::{
-        Block, BlockHeader, DeployTransaction, Deployment, ExecuteTransaction, Execution,
-        Identifier, Metadata, ProgramID, Transaction, Transition,
+        Block, BlockHeader, DeployTransaction, Deployment, ExecuteTransaction, Execution, Metadata,
+        ProgramID, Transaction, Transition,
     },
     Digest,
 };
-use snarkd_crypto::keys::{ComputeKey, Signature};
+use snarkd_crypto::{
+    bls12_377::{G1Affine, Scalar},
+    coinbase_puzzle::{CoinbaseSolution, PartialSolution},
+    keys::{Address, ComputeKey, Signature},
+    polycommit::kzg10::{Commitment, Proof},
+};
 
-use crate::db::InnerDatabase;
+use crate::{
+    db::InnerDatabase,
+    objects::program::{read_deployment, write_mapping_updates},
+    Database,
+};
 
 /// Current state of a block in storage
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -23,7 +33,44 @@ pub enum BlockStatus {
     Uncommitted,
 }
 
+impl Database {
+    pub async fn insert_block(&self, block: Block) -> Result<()> {
+        self.call(move |db| db.insert_block(block)).await
+    }
+
+    pub async fn get_block(&self, hash: Digest) -> Result<Block> {
+        self.call(move |db| db.get_block(&hash)).await
+    }
+
+    pub async fn get_block_hash(&self, block_num: u32) -> Result<Option<Digest>> {
+        self.call(move |db| db.get_block_hash(block_num)).await
+    }
+
+    pub async fn get_reward_balance(&self, address: Address) -> Result<u64> {
+        self.call(move |db| db.get_reward_balance(&address)).await
+    }
+
+    pub async fn get_block_header(&self, hash: Digest) -> Result<BlockHeader> {
+        self.call(move |db| db.get_block_header(&hash)).await
+    }
+
+    pub async fn get_block_state(&self, hash: Digest) -> Result<BlockStatus> {
+        self.call(move |db| db.get_block_state(&hash)).await
+    }
+}
+
+/// Writes a deployment along with its identity, failing if the program is not canonically encoded.
 fn write_deployment(connection: &Connection, deployment: &Deployment) -> Result<i32> {
+    let fingerprint = deployment.fingerprint().with_context(|| {
+        format!(
+            "invalid program for edition {} of {}",
+            deployment.edition, deployment.program_id
+        )
+    })?;
+    let deployment_id = deployment
+        .program_id
+        .deployment_id(deployment.edition, &fingerprint);
+
     let mut deployment_query = connection.prepare_cached(
         r"
         INSERT INTO deployments (
@@ -31,9 +78,17 @@ fn write_deployment(connection: &Connection, deployment: &Deployment) -> Result<
             program,
             verifying_key_id,
             verifying_key,
-            certificate
+            certificate,
+            program_name,
+            program_network,
+            fingerprint,
+            deployment_id
         )
         VALUES (
+            ?,
+            ?,
+            ?,
+            ?,
             ?,
             ?,
             ?,
@@ -43,12 +98,16 @@ fn write_deployment(connection: &Connection, deployment: &Deployment) -> Result<
     ",
     )?;
 
-    deployment_query.execute::<[&dyn ToSql; 5]>([
+    deployment_query.execute::<[&dyn ToSql; 9]>([
         &(deployment.edition as i32),
         &deployment.program,
         &deployment.verifying_key_id,
         &deployment.verifying_key,
         &deployment.certificate,
+        &deployment.program_id.name,
+        &deployment.program_id.network,
+        &fingerprint,
+        &deployment_id,
     ])?;
     Ok(connection.last_insert_rowid() as i32)
 }
@@ -59,7 +118,7 @@ fn read_transitions(
 ) -> Result<Vec<(i32, Transition)>> {
     let mut transition_query = connection.prepare_cached(
         r"
-        SELECT (
+        SELECT
             transaction_order,
             transition_id,
             program_name,
@@ -71,9 +130,8 @@ fn read_transitions(
             proof,
             tpk,
             tcm,
-            fee,
-            deployment_id
-        ) FROM transitions
+            fee
+        FROM transitions
         WHERE transaction_id = ?
         ORDER BY transaction_order ASC
     ",
@@ -167,6 +225,130 @@ fn write_transition(
     Ok(())
 }
 
+fn write_coinbase(
+    connection: &Connection,
+    block_id: i64,
+    coinbase: &CoinbaseSolution,
+) -> Result<()> {
+    connection.execute(
+        r"UPDATE blocks SET coinbase_proof_w = ?, coinbase_proof_random_v = ? WHERE id = ?",
+        params![&coinbase.proof().w, &coinbase.proof().random_v, block_id],
+    )?;
+
+    let mut solution_query = connection.prepare_cached(
+        r"
+        INSERT INTO coinbase_solutions (
+            block_id,
+            solution_order,
+            address,
+            nonce,
+            commitment
+        )
+        VALUES (
+            ?,
+            ?,
+            ?,
+            ?,
+            ?
+        )
+    ",
+    )?;
+    for (i, solution) in coinbase.partial_solutions().iter().enumerate() {
+        solution_query.execute(params![
+            block_id,
+            i,
+            &solution.address().0,
+            solution.nonce() as i64,
+            &solution.commitment().0,
+        ])?;
+    }
+    Ok(())
+}
+
+fn read_coinbase(connection: &Connection, block_id: i32) -> Result<Option<CoinbaseSolution>> {
+    let (w, random_v) = connection.query_row(
+        r"SELECT coinbase_proof_w, coinbase_proof_random_v FROM blocks WHERE id = ?",
+        [block_id],
+        |row| {
+            Ok((
+                row.get::<_, Option<G1Affine>>(0)?,
+                row.get::<_, Option<Scalar>>(1)?,
+            ))
+        },
+    )?;
+    let w = match w {
+        Some(w) => w,
+        None => return Ok(None),
+    };
+
+    let mut solution_query = connection.prepare_cached(
+        r"
+        SELECT
+            address,
+            nonce,
+            commitment
+        FROM coinbase_solutions
+        WHERE block_id = ?
+        ORDER BY solution_order ASC
+    ",
+    )?;
+    let mut rows = solution_query.query([block_id])?;
+    let mut partial_solutions = vec![];
+    while let Some(row) = rows.next()? {
+        partial_solutions.push(PartialSolution::new(
+            Address(row.get(0)?),
+            row.get::<_, i64>(1)? as u64,
+            Commitment(row.get(2)?),
+        ));
+    }
+    Ok(Some(CoinbaseSolution::new(
+        partial_solutions,
+        Proof { w, random_v },
+    )))
+}
+
+fn write_rewards(connection: &Connection, block_id: i64, rewards: &[(Address, u64)]) -> Result<()> {
+    let mut reward_query = connection.prepare_cached(
+        r"
+        INSERT INTO block_rewards (
+            block_id,
+            reward_order,
+            address,
+            amount
+        )
+        VALUES (
+            ?,
+            ?,
+            ?,
+            ?
+        )
+    ",
+    )?;
+    for (i, (address, amount)) in rewards.iter().enumerate() {
+        reward_query.execute(params![block_id, i, &address.0, *amount as i64])?;
+    }
+    Ok(())
+}
+
+fn read_rewards(connection: &Connection, block_id: i32) -> Result<Vec<(Address, u64)>> {
+    let mut reward_query = connection.prepare_cached(
+        r"
+        SELECT
+            address,
+            amount
+        FROM block_rewards
+        WHERE block_id = ?
+        ORDER BY reward_order ASC
+    ",
+    )?;
+    let rewards = reward_query
+        .query_map([block_id], |row| {
+            Ok((Address(row.get(0)?), row.get::<_, i64>(1)? as u64))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(rewards)
+}
+
 impl InnerDatabase {
     /// Inserts a block into storage, not committing it.
     pub fn insert_block(&mut self, block: Block) -> Result<()> {
@@ -201,7 +383,11 @@ impl InnerDatabase {
                 response,
                 compute_key_public_key_signature,
                 compute_key_public_randomness_signature,
-                compute_key_secret_key_program
+                compute_key_secret_key_program,
+                weight,
+                producer,
+                coinbase_root,
+                rewards_root
             )
             VALUES (
                 ?,
@@ -219,11 +405,17 @@ impl InnerDatabase {
                 ?,
                 ?,
                 ?,
+                ?,
+                ?,
+                ?,
+                ?,
                 ?
             )
             ",
             )?;
-            block_query.execute::<[&dyn ToSql; 16]>([
+            let weight = i64::try_from(consensus::block_weight(&block.header.metadata))
+                .context("block weight does not fit in storage")?;
+            block_query.execute::<[&dyn ToSql; 20]>([
                 &hash,
                 &block.header.previous_hash,
                 &block.header.previous_hash,
@@ -244,12 +436,21 @@ impl InnerDatabase {
                     .compute_key
                     .public_randomness_signature,
                 &block.header.signature.compute_key.prf_secret_key,
+                &weight,
+                &block.header.producer.0,
+                &block.header.coinbase_root,
+                &block.header.rewards_root,
             ])?;
             let block_id = transaction.last_insert_rowid();
             transaction.execute(
                 "UPDATE blocks SET previous_block_id = ? WHERE previous_block_hash = ?",
                 params![block_id, &hash],
             )?;
+            if let Some(coinbase) = &block.coinbase {
+                write_coinbase(&transaction, block_id, coinbase)?;
+            }
+            write_rewards(&transaction, block_id, &block.rewards)?;
+            write_mapping_updates(&transaction, block_id, &block.transactions)?;
 
             let mut transaction_query = transaction.prepare_cached(
                 r"
@@ -327,7 +528,11 @@ impl InnerDatabase {
                                 None,
                             )?;
                         }
-                        transaction_block_query.execute(params![&tx.id, block_id as usize, i])?;
+                        transaction_block_query.execute(params![
+                            &transaction_id,
+                            block_id as usize,
+                            i
+                        ])?;
                     }
                 }
             }
@@ -341,20 +546,22 @@ impl InnerDatabase {
         let (block_id, header) = self.get_block_header_and_id(hash)?;
         let mut transaction_query = self.connection.prepare_cached(
             r"
-            SELECT (
-                id,
-                transaction_id,
-                execute_edition
-                transaction_type,
-                edition,
-                program,
-                verifying_key_id,
-                verifying_key,
-                certificate
-            )
+            SELECT
+                transactions.id,
+                transactions.transaction_id,
+                transactions.execute_edition,
+                transactions.transaction_type,
+                deployments.edition,
+                deployments.program,
+                deployments.verifying_key_id,
+                deployments.verifying_key,
+                deployments.certificate,
+                deployments.program_name,
+                deployments.program_network
             FROM transactions
             INNER JOIN transaction_blocks ON transaction_blocks.transaction_id = transactions.id
-            LEFT JOIN deployments ON transactions.deployment_id IS NOT NULL AND deployments.id = transactions.deployment_id
+            LEFT JOIN transitions ON transitions.transaction_id = transactions.id AND transitions.deployment_id IS NOT NULL
+            LEFT JOIN deployments ON deployments.id = transitions.deployment_id
             WHERE transaction_blocks.block_id = ?
             ORDER BY transaction_blocks.block_order ASC
         ",
@@ -372,24 +579,8 @@ impl InnerDatabase {
                     }
                     out.push(Transaction::Deploy(Box::new(DeployTransaction {
                         id: transaction_id,
-                        deployment: Deployment {
-                            edition: row
-                                .get::<_, Option<i32>>(4)?
-                                .context("missing edition for deploy transaction")?
-                                as u16,
-                            program: row
-                                .get::<_, Option<Vec<u8>>>(5)?
-                                .context("missing program for deploy transaction")?,
-                            verifying_key_id: row
-                                .get::<_, Option<Identifier>>(6)?
-                                .context("missing verifying_key_id for deploy transaction")?,
-                            verifying_key: row
-                                .get::<_, Option<Digest>>(7)?
-                                .context("missing verifying_key for deploy transaction")?,
-                            certificate: row
-                                .get::<_, Option<Digest>>(8)?
-                                .context("missing certificate for deploy transaction")?,
-                        },
+                        deployment: read_deployment(row, 4)?
+                            .context("missing deployment for deploy transaction")?,
                         transition: transitions.into_iter().next().unwrap().1,
                     })));
                 }
@@ -421,6 +612,8 @@ impl InnerDatabase {
         Ok(Block {
             header,
             transactions: out,
+            coinbase: read_coinbase(&self.connection, block_id)?,
+            rewards: read_rewards(&self.connection, block_id)?,
         })
     }
 
@@ -466,6 +659,22 @@ impl InnerDatabase {
             .map(|x| Digest::from(&x[..])))
     }
 
+    /// Gets the sum of the rewards paid to `address` by canon blocks
+    pub fn get_reward_balance(&mut self, address: &Address) -> Result<u64> {
+        self.optimize()?;
+
+        let balance = self.connection.query_row(
+            r"
+            SELECT coalesce(sum(block_rewards.amount), 0) FROM block_rewards
+            INNER JOIN blocks ON blocks.id = block_rewards.block_id
+            WHERE block_rewards.address = ? AND blocks.canon_height IS NOT NULL
+            ",
+            [&address.0],
+            |row| row.get::<_, i64>(0),
+        )?;
+        Ok(balance as u64)
+    }
+
     /// Gets a block header for a given hash
     pub fn get_block_header(&mut self, hash: &Digest) -> Result<BlockHeader> {
         self.get_block_header_and_id(hash).map(|x| x.1)
@@ -478,7 +687,7 @@ impl InnerDatabase {
             .query_row(
                 r"
         SELECT
-            previous_hash,
+            previous_block_hash,
             previous_state_root,
             transactions_root,
             network,
@@ -492,17 +701,22 @@ impl InnerDatabase {
             compute_key_public_key_signature,
             compute_key_public_randomness_signature,
             compute_key_secret_key_program,
+            producer,
+            coinbase_root,
+            rewards_root,
             id
         FROM blocks WHERE hash = ?",
                 [&hash[..]],
                 |row| {
                     Ok((
-                        row.get(14)?,
+                        row.get(17)?,
                         BlockHeader {
                             block_hash: hash.clone(),
                             previous_hash: row.get(0)?,
                             previous_state_root: row.get(1)?,
                             transactions_root: row.get(2)?,
+                            coinbase_root: row.get(15)?,
+                            rewards_root: row.get(16)?,
                             metadata: Metadata {
                                 network: row.get(3)?,
                                 round: row.get(4)?,
@@ -511,6 +725,7 @@ impl InnerDatabase {
                                 proof_target: row.get(7)?,
                                 timestamp: row.get(8)?,
                             },
+                            producer: Address(row.get(14)?),
                             signature: Signature {
                                 challenge: row.get(9)?,
                                 response: row.get(10)?,
@@ -1,11 +1,18 @@
 use std::collections::{HashMap, VecDeque};
 
-use anyhow::{anyhow, bail, Result};
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use chrono::Utc;
 use log::debug;
 use rusqlite::params;
-use snarkd_common::{objects::Block, Digest, DigestTree};
+use snarkd_common::{
+    consensus,
+    network::{Network, NetworkParameters},
+    objects::{Block, BlockHeader},
+    Digest, DigestTree,
+};
+use snarkd_crypto::coinbase_puzzle::CoinbasePuzzle;
 
-use crate::{db::InnerDatabase, BlockStatus};
+use crate::{db::InnerDatabase, BlockStatus, Database};
 
 #[derive(Debug)]
 pub struct CanonData {
@@ -20,6 +27,8 @@ pub struct ForkPath {
     pub base_index: u32,
     /// Set of digests from `base_index`'s corresponding block to the target block
     pub path: Vec<Digest>,
+    /// Cumulative weight of the blocks in `path`
+    pub weight: u128,
 }
 
 pub enum ForkDescription {
@@ -37,12 +46,43 @@ impl CanonData {
     }
 }
 
+impl Database {
+    pub async fn commit_block(
+        &self,
+        hash: Digest,
+        previous_state_root: Digest,
+        puzzle: CoinbasePuzzle,
+    ) -> Result<BlockStatus> {
+        self.call(move |db| db.commit_block(&hash, &previous_state_root, &puzzle))
+            .await
+    }
+
+    pub async fn accept_block(
+        &self,
+        block: Block,
+        puzzle: CoinbasePuzzle,
+        max_reorg_depth: usize,
+    ) -> Result<BlockStatus> {
+        self.call(move |db| db.accept_block(block, &puzzle, max_reorg_depth))
+            .await
+    }
+
+    pub async fn decommit_blocks(&self, hash: Digest) -> Result<Vec<Block>> {
+        self.call(move |db| db.decommit_blocks(&hash)).await
+    }
+
+    pub async fn canon(&self) -> Result<CanonData> {
+        self.call(move |db| db.canon()).await
+    }
+}
+
 impl InnerDatabase {
-    /// Commits a block into canon.
+    /// Commits a block into canon, after checking it against its parent and the coinbase puzzle of its network.
     pub fn commit_block(
         &mut self,
         hash: &Digest,
         previous_state_root: &Digest,
+        puzzle: &CoinbasePuzzle,
     ) -> Result<BlockStatus> {
         self.optimize()?;
 
@@ -54,11 +94,69 @@ impl InnerDatabase {
             BlockStatus::Unknown => bail!("attempted to commit unknown block"),
             _ => (),
         }
+        let block = self.get_block(hash)?;
+        let header = &block.header;
+        ensure!(
+            header.verify_signature(),
+            "invalid producer signature for block {hash}"
+        );
+        ensure!(
+            header.transactions_root == Block::transactions_root(&block.transactions),
+            "transactions of block {hash} do not match its header"
+        );
+        ensure!(
+            header.coinbase_root == Block::coinbase_root(block.coinbase.as_ref()),
+            "coinbase solution of block {hash} does not match its header"
+        );
+        let network = Network::from_id(header.metadata.network).ok_or_else(|| {
+            anyhow!(
+                "block {hash} is for unknown network {}",
+                header.metadata.network
+            )
+        })?;
         let next_canon_height = if canon.is_empty() {
+            network
+                .parameters()
+                .verify_genesis(header)
+                .with_context(|| format!("invalid genesis block {hash}"))?;
+            ensure!(
+                block.coinbase.is_none(),
+                "genesis block {hash} must not have a coinbase solution"
+            );
             0
         } else {
+            if header.previous_hash != canon.hash {
+                bail!("attempted to commit block {hash} that does not extend canon");
+            }
+            let parent = self.get_block_header(&canon.hash)?;
+            consensus::verify_metadata(
+                network.parameters(),
+                &parent.metadata,
+                &header.metadata,
+                Utc::now().timestamp(),
+            )
+            .with_context(|| format!("invalid consensus metadata for block {hash}"))?;
+            self.verify_coinbase(network.parameters(), &parent, &block, puzzle)
+                .with_context(|| format!("invalid coinbase solution for block {hash}"))?;
             canon.block_height + 1
         };
+        let rewards = consensus::block_rewards(
+            network.parameters(),
+            header.metadata.height,
+            header.producer,
+            block.coinbase.as_ref(),
+            Block::transaction_fees(&block.transactions),
+        )?;
+        ensure!(
+            block.rewards == rewards,
+            "rewards of block {hash} do not match its coinbase solution and fees"
+        );
+        ensure!(
+            header.rewards_root == Block::rewards_root(&block.rewards),
+            "rewards of block {hash} do not match its header"
+        );
+        self.verify_program_state(&block)
+            .with_context(|| format!("invalid program state for block {hash}"))?;
         let inner_hash = hash.clone();
         self.connection.execute(
             r"UPDATE blocks SET canon_height = ?, previous_state_root = ? WHERE hash = ?",
@@ -67,78 +165,126 @@ impl InnerDatabase {
         self.get_block_state(hash)
     }
 
-    pub fn recommit_blockchain(&mut self, root_hash: &Digest) -> Result<()> {
-        let canon = self.canon()?;
-        match self.get_block_state(root_hash)? {
-            BlockStatus::Committed(_) => {
-                return Err(anyhow!("attempted to recommit block {}", root_hash));
-            }
-            BlockStatus::Unknown => return Err(anyhow!("attempted to commit unknown block")),
-            _ => (),
+    /// Checks that the coinbase solution of `block` answers its epoch challenge and reaches the targets of `parent`.
+    fn verify_coinbase(
+        &mut self,
+        parameters: &NetworkParameters,
+        parent: &BlockHeader,
+        block: &Block,
+        puzzle: &CoinbasePuzzle,
+    ) -> Result<()> {
+        let coinbase = block
+            .coinbase
+            .as_ref()
+            .ok_or_else(|| anyhow!("missing coinbase solution"))?;
+        let epoch_number = consensus::epoch_number(parameters, block.header.metadata.height);
+        let epoch_block_hash = self
+            .get_block_hash(consensus::epoch_block_height(parameters, epoch_number))?
+            .ok_or_else(|| anyhow!("missing epoch block for epoch {epoch_number}"))?;
+        let epoch_challenge =
+            consensus::epoch_challenge(parameters, epoch_number, &epoch_block_hash)?;
+        ensure!(
+            puzzle.verify(
+                coinbase,
+                &epoch_challenge,
+                parent.metadata.coinbase_target,
+                parent.metadata.proof_target,
+            )?,
+            "coinbase proof does not verify"
+        );
+        Ok(())
+    }
+
+    /// Commits the preferred chain descending from `root_hash`, see [`Self::heaviest_child_path`].
+    /// Every block is validated by [`Self::commit_block`], committing stops at the first invalid block.
+    pub fn recommit_blockchain(
+        &mut self,
+        root_hash: &Digest,
+        puzzle: &CoinbasePuzzle,
+    ) -> Result<()> {
+        for hash in self.heaviest_child_path(root_hash)? {
+            self.recommit_block(&hash, puzzle)?;
         }
-        let next_canon_height = if canon.is_empty() {
-            0
-        } else {
-            canon.block_height + 1
-        };
-        self.connection.execute(
-            r"
-            WITH RECURSIVE
-                children(parent, sub, length) AS (
-                    SELECT ?, NULL, 0 as length
-                    UNION ALL
-                    SELECT blocks.hash, blocks.previous_block_hash, children.length + 1 FROM blocks
-                    INNER JOIN children
-                    WHERE blocks.previous_block_hash = children.parent
-                ),
-                preferred_tip AS (
-                    SELECT parent, sub, length FROM children
-                    WHERE length = (SELECT max(length) FROM children)
-                    ORDER BY parent
-                    LIMIT 1
-                ),
-                total_tip(parent, remaining, digest) AS (
-                    SELECT preferred_tip.parent, preferred_tip.length, NULL FROM preferred_tip
-                    UNION ALL
-                    SELECT blocks.previous_block_hash, total_tip.remaining - 1, blocks.canon_ledger_digest
-                    FROM total_tip
-                    INNER JOIN blocks ON blocks.hash = total_tip.parent
-                )
-                UPDATE blocks SET
-                    canon_height = total_tip.remaining + ?
-                FROM total_tip
-                WHERE
-                    total_tip.parent = blocks.hash
-                    AND total_tip.digest IS NOT NULL;
-            ",
-            params![root_hash, next_canon_height],
-        )?;
         Ok(())
     }
 
-    /// Attempts to recommit a block into canon if it has a ledger digest.
-    pub fn recommit_block(&mut self, hash: &Digest) -> Result<BlockStatus> {
-        let canon = self.canon()?;
-        match self.get_block_state(hash)? {
-            BlockStatus::Committed(_) => {
-                return Err(anyhow!("attempted to recommit block {}", hash));
+    /// Recommits a stored block into canon, validated by [`Self::commit_block`] against its stored state root.
+    pub fn recommit_block(
+        &mut self,
+        hash: &Digest,
+        puzzle: &CoinbasePuzzle,
+    ) -> Result<BlockStatus> {
+        let previous_state_root = self.get_block_header(hash)?.previous_state_root;
+        self.commit_block(hash, &previous_state_root, puzzle)
+    }
+
+    /// Stores `block` and moves canon onto it if it completes a chain that is preferred over canon, see [`Self::get_fork_path`].
+    /// Forks based more than `max_reorg_depth` blocks below the canon tip, or with unknown ancestors, are only stored.
+    pub fn accept_block(
+        &mut self,
+        block: Block,
+        puzzle: &CoinbasePuzzle,
+        max_reorg_depth: usize,
+    ) -> Result<BlockStatus> {
+        let hash = block.header.hash();
+        let previous_state_root = block.header.previous_state_root.clone();
+        match self.get_block_state(&hash)? {
+            BlockStatus::Unknown => self.insert_block(block)?,
+            BlockStatus::Committed(n) => return Ok(BlockStatus::Committed(n)),
+            BlockStatus::Uncommitted => (),
+        }
+        if self.canon()?.is_empty() {
+            return self.commit_block(&hash, &previous_state_root, puzzle);
+        }
+
+        match self.get_fork_path(&hash, max_reorg_depth)? {
+            ForkDescription::Path(fork) => {
+                if self.is_preferred_fork(&fork)? {
+                    self.reorganize(&fork, puzzle)?;
+                } else {
+                    debug!("block {hash} is on a fork not preferred over canon");
+                }
             }
-            BlockStatus::Unknown => return Err(anyhow!("attempted to commit unknown block")),
-            _ => (),
+            ForkDescription::TooLong => {
+                debug!("block {hash} is on a fork deeper than {max_reorg_depth} blocks");
+            }
+            ForkDescription::Orphan => debug!("block {hash} has unknown ancestors"),
         }
-        let next_canon_height = if canon.is_empty() {
-            0
-        } else {
-            canon.block_height + 1
+        self.get_block_state(&hash)
+    }
+
+    /// Replaces the canon blocks above `fork.base_index` with the blocks of `fork`.
+    /// If any block of `fork` fails validation, the replaced canon blocks are restored.
+    fn reorganize(&mut self, fork: &ForkPath, puzzle: &CoinbasePuzzle) -> Result<()> {
+        let replaced = match self.get_block_hash(fork.base_index + 1)? {
+            Some(hash) => self.decommit_blocks(&hash)?,
+            None => vec![],
         };
-        self.connection.execute(
-            r"UPDATE blocks SET canon_height = ? WHERE hash = ? AND canon_ledger_digest IS NOT NULL",
-            params![next_canon_height, hash],
-        )?;
-        self.get_block_state(hash)
+        if !replaced.is_empty() {
+            debug!(
+                "reorganizing {} canon blocks above {}",
+                replaced.len(),
+                fork.base_index
+            );
+        }
+
+        for (i, hash) in fork.path.iter().enumerate() {
+            if let Err(e) = self.recommit_block(hash, puzzle) {
+                if i > 0 {
+                    self.decommit_blocks(&fork.path[0])?;
+                }
+                for block in &replaced {
+                    self.recommit_block(&block.header.block_hash, puzzle)
+                        .context("failed to restore canon after an invalid fork")?;
+                }
+                return Err(e.context(format!("invalid block {hash} in fork")));
+            }
+        }
+        Ok(())
     }
 
-    /// Decommits a block and all descendent blocks, returning them in ascending order
+    /// Decommits a block and all descendent blocks, returning them in ascending order.
+    /// Deployments and mapping updates of decommitted blocks leave the program state along with them.
     pub fn decommit_blocks(&mut self, hash: &Digest) -> Result<Vec<Block>> {
         self.optimize()?;
 
@@ -176,6 +322,7 @@ impl InnerDatabase {
             last_hash = new_last_hash;
         }
 
+        decommitted.reverse();
         Ok(decommitted)
     }
 
@@ -212,42 +359,86 @@ impl InnerDatabase {
         })
     }
 
-    /// Gets the longest, committed or uncommitted, chain of blocks originating from `block_hash`, including `block_hash`.
-    pub fn longest_child_path(&mut self, block_hash: &Digest) -> Result<Vec<Digest>> {
+    /// Gets the preferred, committed or uncommitted, chain of blocks originating from `block_hash`, including `block_hash`.
+    /// The chain with the most cumulative weight is preferred, ties go to the lowest tip hash, see [`consensus::heaviest_chain`].
+    pub fn heaviest_child_path(&mut self, block_hash: &Digest) -> Result<Vec<Digest>> {
         self.optimize()?;
 
+        // weights are summed here rather than in sqlite, which turns overflowing integer sums into floats
         let mut stmt = self.connection.prepare_cached(
             r"
             WITH RECURSIVE
-                children(parent, sub, length) AS (
-                    SELECT ?, NULL, 0 as length
-                    UNION ALL
-                    SELECT blocks.hash, blocks.previous_block_hash, children.length + 1 FROM blocks
-                    INNER JOIN children
-                    WHERE blocks.previous_block_hash = children.parent
-                ),
-                preferred_tip AS (
-                    SELECT parent, sub, length FROM children
-                    WHERE length = (SELECT max(length) FROM children)
-                    ORDER BY parent
-                    LIMIT 1
-                ),
-                total_tip(parent, remaining) AS (
-                    SELECT preferred_tip.parent, preferred_tip.length FROM preferred_tip
+                children(hash, weight) AS (
+                    SELECT hash, weight FROM blocks WHERE hash = ?
                     UNION ALL
-                    SELECT blocks.previous_block_hash, total_tip.remaining - 1
-                    FROM total_tip
-                    INNER JOIN blocks ON blocks.hash = total_tip.parent
-                    WHERE total_tip.remaining > 0
+                    SELECT blocks.hash, blocks.weight FROM blocks
+                    INNER JOIN children ON blocks.previous_block_hash = children.hash
                 )
-                SELECT total_tip.parent, total_tip.remaining FROM total_tip
-                order by remaining;
+                SELECT hash, weight FROM children;
         ",
         )?;
-        let out = stmt
-            .query_map([block_hash], |row| row.get(0))?
-            .collect::<rusqlite::Result<Vec<Digest>>>()?;
-        Ok(out)
+        let weights = stmt
+            .query_map([block_hash], |row| {
+                Ok((row.get::<_, Digest>(0)?, row.get::<_, i64>(1)?))
+            })?
+            .map(|row| {
+                let (hash, weight) = row?;
+                Ok((hash, u128::try_from(weight)?))
+            })
+            .collect::<Result<HashMap<Digest, u128>>>()?;
+        drop(stmt);
+        if !weights.contains_key(block_hash) {
+            return Ok(vec![]);
+        }
+
+        let tree = self.get_block_digest_tree(block_hash)?;
+        let (_, chain) = consensus::heaviest_chain(&tree, |hash| weights[hash]);
+        Ok(chain.into_iter().cloned().collect())
+    }
+
+    /// Gets the fork choice weight of a stored block
+    pub fn get_block_weight(&mut self, hash: &Digest) -> Result<u128> {
+        self.optimize()?;
+
+        let weight = self.connection.query_row(
+            r"SELECT weight FROM blocks WHERE hash = ?",
+            [hash],
+            |row| row.get::<_, i64>(0),
+        )?;
+        Ok(u128::try_from(weight)?)
+    }
+
+    /// Gets the cumulative weight of the canon blocks above `base_index`
+    pub fn canon_weight_after(&mut self, base_index: u32) -> Result<u128> {
+        self.optimize()?;
+
+        let mut stmt = self
+            .connection
+            .prepare_cached(r"SELECT weight FROM blocks WHERE canon_height > ?")?;
+        let weights = stmt
+            .query_map([base_index], |row| row.get::<_, i64>(0))?
+            .collect::<rusqlite::Result<Vec<i64>>>()?;
+        weights.into_iter().try_fold(0u128, |total, x| {
+            total
+                .checked_add(u128::try_from(x)?)
+                .ok_or_else(|| anyhow!("canon weight overflowed"))
+        })
+    }
+
+    /// Returns true if `fork` is preferred over the canon blocks it would replace.
+    pub fn is_preferred_fork(&mut self, fork: &ForkPath) -> Result<bool> {
+        let fork_tip = match fork.path.last() {
+            Some(x) => x,
+            None => return Ok(false),
+        };
+        let canon = self.canon()?;
+        let canon_weight = self.canon_weight_after(fork.base_index)?;
+        Ok(consensus::is_preferred_chain(
+            fork.weight,
+            fork_tip,
+            canon_weight,
+            &canon.hash,
+        ))
     }
 
     /// Gets a tree structure representing all the descendents of [`block_hash`]
@@ -363,7 +554,8 @@ impl InnerDatabase {
         Ok(out)
     }
 
-    /// Finds a fork path from any applicable canon node within `oldest_fork_threshold` to `hash`.
+    /// Finds a fork path from any applicable canon node within `oldest_fork_threshold` to `hash`, following the heaviest descendents of `hash`.
+    /// `oldest_fork_threshold` is the maximum reorg depth, forks based on older canon blocks are reported as [`ForkDescription::TooLong`].
     pub fn get_fork_path(
         &mut self,
         hash: &Digest,
@@ -383,13 +575,22 @@ impl InnerDatabase {
                         debug!("exceeded maximum fork length in extended path");
                         return Ok(ForkDescription::TooLong);
                     }
-                    let longest_path = self.longest_child_path(hash)?;
-                    // let descendents = self.get_block_digest_tree(hash)?;
-                    debug!("longest child path terminating in {:?}", longest_path.len());
-                    side_chain_path.extend(longest_path);
+                    let heaviest_path = self.heaviest_child_path(hash)?;
+                    debug!(
+                        "heaviest child path terminating in {:?}",
+                        heaviest_path.len()
+                    );
+                    side_chain_path.extend(heaviest_path);
+                    let mut weight = 0u128;
+                    for hash in side_chain_path.iter() {
+                        weight = weight
+                            .checked_add(self.get_block_weight(hash)?)
+                            .ok_or_else(|| anyhow!("fork weight overflowed"))?;
+                    }
                     return Ok(ForkDescription::Path(ForkPath {
                         base_index: block_num as u32,
                         path: side_chain_path.into(),
+                        weight,
                     }));
                 }
                 // Add to the side_chain_path
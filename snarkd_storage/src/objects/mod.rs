@@ -6,3 +6,5 @@ pub use block::*;
 
 mod commit;
 pub use commit::*;
+
+mod program;
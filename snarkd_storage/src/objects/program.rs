@@ -0,0 +1,464 @@
+use anyhow::{bail, ensure, Context, Result};
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use snarkd_common::objects::{
+    Block, Deployment, DeploymentID, Identifier, ProgramID, Transaction, Transition,
+};
+
+use crate::{db::InnerDatabase, Database};
+
+impl Database {
+    pub async fn get_deployment(
+        &self,
+        program_id: ProgramID,
+        edition: Option<u16>,
+    ) -> Result<Option<Deployment>> {
+        self.call(move |db| db.get_deployment(&program_id, edition))
+            .await
+    }
+
+    pub async fn get_deployment_by_id(
+        &self,
+        deployment_id: DeploymentID,
+    ) -> Result<Option<Deployment>> {
+        self.call(move |db| db.get_deployment_by_id(&deployment_id))
+            .await
+    }
+
+    pub async fn get_mapping_value(
+        &self,
+        program_id: ProgramID,
+        mapping: Identifier,
+        key: Vec<u8>,
+    ) -> Result<Option<Vec<u8>>> {
+        self.call(move |db| db.get_mapping_value(&program_id, &mapping, &key))
+            .await
+    }
+
+    pub async fn get_mapping_entries(
+        &self,
+        program_id: ProgramID,
+        mapping: Identifier,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        self.call(move |db| db.get_mapping_entries(&program_id, &mapping, offset, limit))
+            .await
+    }
+}
+
+/// Reads a deployment from `row`, starting at column `offset` with the columns
+/// `edition, program, verifying_key_id, verifying_key, certificate, program_name, program_network`.
+pub(crate) fn read_deployment(row: &Row, offset: usize) -> Result<Option<Deployment>> {
+    let edition = match row.get::<_, Option<i32>>(offset)? {
+        Some(x) => x as u16,
+        None => return Ok(None),
+    };
+    Ok(Some(Deployment {
+        program_id: ProgramID {
+            name: row
+                .get::<_, Option<Identifier>>(offset + 5)?
+                .context("missing program name for deployment")?,
+            network: row
+                .get::<_, Option<Identifier>>(offset + 6)?
+                .context("missing program network for deployment")?,
+        },
+        edition,
+        program: row.get(offset + 1)?,
+        verifying_key_id: row.get(offset + 2)?,
+        verifying_key: row.get(offset + 3)?,
+        certificate: row.get(offset + 4)?,
+    }))
+}
+
+/// Records the mapping updates made by the transitions of a block, in the order they are finalized.
+pub(crate) fn write_mapping_updates(
+    connection: &Connection,
+    block_id: i64,
+    transactions: &[Transaction],
+) -> Result<()> {
+    let mut update_query = connection.prepare_cached(
+        r"
+        INSERT INTO mapping_updates (
+            block_id,
+            update_order,
+            program_name,
+            program_network,
+            mapping,
+            key,
+            value
+        )
+        VALUES (
+            ?,
+            ?,
+            ?,
+            ?,
+            ?,
+            ?,
+            ?
+        )
+    ",
+    )?;
+
+    let transitions = transactions.iter().flat_map(|transaction| {
+        let (transitions, fee): (&[Transition], _) = match transaction {
+            Transaction::Deploy(tx) => (&[], Some(&tx.transition)),
+            Transaction::Execute(tx) => (&tx.execution.transitions, tx.transition.as_ref()),
+        };
+        transitions.iter().chain(fee)
+    });
+    let mut update_order = 0usize;
+    for transition in transitions {
+        let operations = transition
+            .finalize_operations()
+            .with_context(|| format!("invalid finalize for transition {}", transition.id))?;
+        for operation in operations {
+            update_query.execute(params![
+                block_id,
+                update_order,
+                &transition.program_id.name,
+                &transition.program_id.network,
+                &operation.mapping,
+                &operation.key,
+                &operation.value,
+            ])?;
+            update_order += 1;
+        }
+    }
+    Ok(())
+}
+
+impl InnerDatabase {
+    /// Gets a canon deployment of `program_id`, the latest edition if `edition` is not given.
+    pub fn get_deployment(
+        &mut self,
+        program_id: &ProgramID,
+        edition: Option<u16>,
+    ) -> Result<Option<Deployment>> {
+        self.optimize()?;
+
+        let mut stmt = self.connection.prepare_cached(
+            r"
+            SELECT
+                deployments.edition,
+                deployments.program,
+                deployments.verifying_key_id,
+                deployments.verifying_key,
+                deployments.certificate,
+                deployments.program_name,
+                deployments.program_network
+            FROM deployments
+            INNER JOIN transitions ON transitions.deployment_id = deployments.id
+            INNER JOIN transaction_blocks ON transaction_blocks.transaction_id = transitions.transaction_id
+            INNER JOIN blocks ON blocks.id = transaction_blocks.block_id
+            WHERE deployments.program_name = ?1 AND deployments.program_network = ?2
+                AND (?3 IS NULL OR deployments.edition = ?3)
+                AND blocks.canon_height IS NOT NULL
+            ORDER BY deployments.edition DESC, blocks.canon_height ASC
+            LIMIT 1
+        ",
+        )?;
+        let mut rows = stmt.query(params![
+            &program_id.name,
+            &program_id.network,
+            edition.map(|x| x as i32),
+        ])?;
+        match rows.next()? {
+            Some(row) => read_deployment(row, 0),
+            None => Ok(None),
+        }
+    }
+
+    /// Gets the latest deployment of `program_id` that is canon or in the block `block_id`.
+    fn get_latest_deployment(
+        &mut self,
+        program_id: &ProgramID,
+        block_id: i64,
+    ) -> Result<Option<Deployment>> {
+        let mut stmt = self.connection.prepare_cached(
+            r"
+            SELECT
+                deployments.edition,
+                deployments.program,
+                deployments.verifying_key_id,
+                deployments.verifying_key,
+                deployments.certificate,
+                deployments.program_name,
+                deployments.program_network
+            FROM deployments
+            INNER JOIN transitions ON transitions.deployment_id = deployments.id
+            INNER JOIN transaction_blocks ON transaction_blocks.transaction_id = transitions.transaction_id
+            INNER JOIN blocks ON blocks.id = transaction_blocks.block_id
+            WHERE deployments.program_name = ?1 AND deployments.program_network = ?2
+                AND (blocks.canon_height IS NOT NULL OR blocks.id = ?3)
+            ORDER BY deployments.edition DESC
+            LIMIT 1
+        ",
+        )?;
+        let mut rows = stmt.query(params![&program_id.name, &program_id.network, block_id])?;
+        match rows.next()? {
+            Some(row) => read_deployment(row, 0),
+            None => Ok(None),
+        }
+    }
+
+    /// Gets a canon deployment by its identity, see [`Deployment::id`].
+    pub fn get_deployment_by_id(
+        &mut self,
+        deployment_id: &DeploymentID,
+    ) -> Result<Option<Deployment>> {
+        self.optimize()?;
+
+        let mut stmt = self.connection.prepare_cached(
+            r"
+            SELECT
+                deployments.edition,
+                deployments.program,
+                deployments.verifying_key_id,
+                deployments.verifying_key,
+                deployments.certificate,
+                deployments.program_name,
+                deployments.program_network
+            FROM deployments
+            INNER JOIN transitions ON transitions.deployment_id = deployments.id
+            INNER JOIN transaction_blocks ON transaction_blocks.transaction_id = transitions.transaction_id
+            INNER JOIN blocks ON blocks.id = transaction_blocks.block_id
+            WHERE deployments.deployment_id = ? AND blocks.canon_height IS NOT NULL
+            ORDER BY blocks.canon_height ASC
+            LIMIT 1
+        ",
+        )?;
+        let mut rows = stmt.query([deployment_id])?;
+        match rows.next()? {
+            Some(row) => read_deployment(row, 0),
+            None => Ok(None),
+        }
+    }
+
+    /// Gets the value of `key` in a mapping of `program_id`, as of the canon chain.
+    pub fn get_mapping_value(
+        &mut self,
+        program_id: &ProgramID,
+        mapping: &Identifier,
+        key: &[u8],
+    ) -> Result<Option<Vec<u8>>> {
+        self.optimize()?;
+
+        let value = self
+            .connection
+            .query_row(
+                r"
+            SELECT mapping_updates.value FROM mapping_updates
+            INNER JOIN blocks ON blocks.id = mapping_updates.block_id
+            WHERE mapping_updates.program_name = ? AND mapping_updates.program_network = ?
+                AND mapping_updates.mapping = ? AND mapping_updates.key = ?
+                AND blocks.canon_height IS NOT NULL
+            ORDER BY blocks.canon_height DESC, mapping_updates.update_order DESC
+            LIMIT 1
+        ",
+                params![&program_id.name, &program_id.network, mapping, key],
+                |row| row.get::<_, Option<Vec<u8>>>(0),
+            )
+            .optional()?;
+        Ok(value.flatten())
+    }
+
+    /// Gets up to `limit` keys and values of a mapping of `program_id`, as of the canon chain, ordered by key and skipping the first `offset`.
+    pub fn get_mapping_entries(
+        &mut self,
+        program_id: &ProgramID,
+        mapping: &Identifier,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        self.optimize()?;
+
+        let mut stmt = self.connection.prepare_cached(
+            r"
+            SELECT key, value FROM (
+                SELECT
+                    mapping_updates.key,
+                    mapping_updates.value,
+                    row_number() OVER (
+                        PARTITION BY mapping_updates.key
+                        ORDER BY blocks.canon_height DESC, mapping_updates.update_order DESC
+                    ) AS recency
+                FROM mapping_updates
+                INNER JOIN blocks ON blocks.id = mapping_updates.block_id
+                WHERE mapping_updates.program_name = ? AND mapping_updates.program_network = ?
+                    AND mapping_updates.mapping = ?
+                    AND blocks.canon_height IS NOT NULL
+            )
+            WHERE recency = 1 AND value IS NOT NULL
+            ORDER BY key
+            LIMIT ? OFFSET ?
+        ",
+        )?;
+        let out = stmt
+            .query_map(
+                params![
+                    &program_id.name,
+                    &program_id.network,
+                    mapping,
+                    limit,
+                    offset
+                ],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(out)
+    }
+
+    /// Checks that the programs deployed and finalized by a block agree with the canon program registry.
+    /// Deployed programs must be canonically encoded and valid, deployments must use a newer edition
+    /// than any canon one and appear once in the block, and mapping updates must target mappings declared by the
+    /// latest deployment of their program.
+    pub(crate) fn verify_program_state(&mut self, block: &Block) -> Result<()> {
+        for transaction in &block.transactions {
+            if let Transaction::Deploy(transaction) = transaction {
+                let deployment = &transaction.deployment;
+                deployment.verify().with_context(|| {
+                    format!(
+                        "invalid program for edition {} of {}",
+                        deployment.edition, deployment.program_id
+                    )
+                })?;
+            }
+        }
+
+        let hash = &block.header.block_hash;
+        let block_id =
+            self.connection
+                .query_row(r"SELECT id FROM blocks WHERE hash = ?", [hash], |row| {
+                    row.get::<_, i64>(0)
+                })?;
+        let stale_deployment = self
+            .connection
+            .query_row(
+                r"
+            SELECT deployments.program_name, deployments.program_network, deployments.edition
+            FROM deployments
+            INNER JOIN transitions ON transitions.deployment_id = deployments.id
+            INNER JOIN transaction_blocks ON transaction_blocks.transaction_id = transitions.transaction_id
+            WHERE transaction_blocks.block_id = ?1 AND EXISTS (
+                SELECT 1 FROM deployments canon_deployments
+                INNER JOIN transitions ON transitions.deployment_id = canon_deployments.id
+                INNER JOIN transaction_blocks ON transaction_blocks.transaction_id = transitions.transaction_id
+                INNER JOIN blocks ON blocks.id = transaction_blocks.block_id
+                WHERE canon_deployments.program_name = deployments.program_name
+                    AND canon_deployments.program_network = deployments.program_network
+                    AND canon_deployments.edition >= deployments.edition
+                    AND blocks.canon_height IS NOT NULL
+            )
+            LIMIT 1
+        ",
+                [block_id],
+                |row| {
+                    Ok((
+                        ProgramID {
+                            name: row.get(0)?,
+                            network: row.get(1)?,
+                        },
+                        row.get::<_, i32>(2)?,
+                    ))
+                },
+            )
+            .optional()?;
+        if let Some((program_id, edition)) = stale_deployment {
+            bail!("edition {edition} of program {program_id} is already deployed");
+        }
+
+        let duplicate_deployment = self
+            .connection
+            .query_row(
+                r"
+            SELECT deployments.program_name, deployments.program_network, deployments.edition
+            FROM deployments
+            INNER JOIN transitions ON transitions.deployment_id = deployments.id
+            INNER JOIN transaction_blocks ON transaction_blocks.transaction_id = transitions.transaction_id
+            WHERE transaction_blocks.block_id = ?1
+            GROUP BY deployments.program_name, deployments.program_network, deployments.edition
+            HAVING count(*) > 1
+            LIMIT 1
+        ",
+                [block_id],
+                |row| {
+                    Ok((
+                        ProgramID {
+                            name: row.get(0)?,
+                            network: row.get(1)?,
+                        },
+                        row.get::<_, i32>(2)?,
+                    ))
+                },
+            )
+            .optional()?;
+        if let Some((program_id, edition)) = duplicate_deployment {
+            bail!(
+                "edition {edition} of program {program_id} is deployed more than once in the block"
+            );
+        }
+
+        let undeployed = self
+            .connection
+            .query_row(
+                r"
+            SELECT mapping_updates.program_name, mapping_updates.program_network
+            FROM mapping_updates
+            WHERE mapping_updates.block_id = ?1 AND NOT EXISTS (
+                SELECT 1 FROM deployments
+                INNER JOIN transitions ON transitions.deployment_id = deployments.id
+                INNER JOIN transaction_blocks ON transaction_blocks.transaction_id = transitions.transaction_id
+                INNER JOIN blocks ON blocks.id = transaction_blocks.block_id
+                WHERE deployments.program_name = mapping_updates.program_name
+                    AND deployments.program_network = mapping_updates.program_network
+                    AND (blocks.canon_height IS NOT NULL OR blocks.id = ?1)
+            )
+            LIMIT 1
+        ",
+                [block_id],
+                |row| {
+                    Ok(ProgramID {
+                        name: row.get(0)?,
+                        network: row.get(1)?,
+                    })
+                },
+            )
+            .optional()?;
+        if let Some(program_id) = undeployed {
+            bail!("finalize updates mappings of undeployed program {program_id}");
+        }
+
+        let mut stmt = self.connection.prepare_cached(
+            r"
+            SELECT DISTINCT mapping_updates.program_name, mapping_updates.program_network, mapping_updates.mapping
+            FROM mapping_updates
+            WHERE mapping_updates.block_id = ?
+        ",
+        )?;
+        let updated = stmt
+            .query_map([block_id], |row| {
+                Ok((
+                    ProgramID {
+                        name: row.get(0)?,
+                        network: row.get(1)?,
+                    },
+                    row.get::<_, Identifier>(2)?,
+                ))
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        drop(stmt);
+        for (program_id, mapping) in updated {
+            let deployment = self
+                .get_latest_deployment(&program_id, block_id)?
+                .with_context(|| format!("missing deployment of program {program_id}"))?;
+            ensure!(
+                deployment
+                    .decode_program()?
+                    .header
+                    .declares_mapping(&mapping.to_string()),
+                "finalize updates mapping {mapping} that is not declared by program {program_id}"
+            );
+        }
+        Ok(())
+    }
+}
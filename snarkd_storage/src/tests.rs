@@ -0,0 +1,683 @@
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    OnceLock,
+};
+
+use snarkd_common::{
+    consensus,
+    network::Network,
+    objects::{
+        Block, BlockHeader, DeployTransaction, Deployment, ExecuteTransaction, Execution,
+        FinalizeOperation, Identifier, ProgramID, Transaction, Transition,
+    },
+    Digest,
+};
+use snarkd_crypto::{
+    bls12_377::{Field, Scalar},
+    coinbase_puzzle::{CoinbasePuzzle, CoinbaseSolution},
+    keys::{Address, ComputeKey, PrivateKey},
+    marlin::{AHPForR1CS, Certificate, CircuitVerifyingKey, MarlinSNARK, Proof, SNARK},
+    r1cs::{ConstraintSynthesizer, ConstraintSystem},
+    PoseidonParameters,
+};
+
+use crate::{BlockStatus, Database};
+
+const DEVNET: Network = Network::Devnet;
+const GENESIS_TIMESTAMP: i64 = 1_000_000;
+const MAX_REORG_DEPTH: usize = 100;
+
+/// The devnet coinbase puzzle, set up once for all tests.
+fn puzzle() -> CoinbasePuzzle {
+    static PUZZLE: OnceLock<CoinbasePuzzle> = OnceLock::new();
+    PUZZLE
+        .get_or_init(|| {
+            let config = DEVNET.parameters().puzzle_config();
+            let srs = CoinbasePuzzle::setup(config).unwrap();
+            CoinbasePuzzle::trim(&srs, config).unwrap()
+        })
+        .clone()
+}
+
+fn genesis() -> Block {
+    static GENESIS: OnceLock<Block> = OnceLock::new();
+    GENESIS
+        .get_or_init(|| {
+            DEVNET
+                .parameters()
+                .genesis_block(GENESIS_TIMESTAMP, &PrivateKey::rand())
+        })
+        .clone()
+}
+
+async fn genesis_database() -> Database {
+    let database = Database::open_in_memory().await.unwrap();
+    accept(&database, &genesis(), MAX_REORG_DEPTH)
+        .await
+        .unwrap();
+    database
+}
+
+async fn accept(
+    database: &Database,
+    block: &Block,
+    max_reorg_depth: usize,
+) -> anyhow::Result<BlockStatus> {
+    database
+        .accept_block(block.clone(), puzzle(), max_reorg_depth)
+        .await
+}
+
+/// Proves a coinbase solution reaching the targets of `parent` for the challenge of `epoch_number`, which is seeded by genesis.
+fn prove(parent: &Block, key: &PrivateKey, epoch_number: u32) -> CoinbaseSolution {
+    let puzzle = puzzle();
+    let challenge = consensus::epoch_challenge(
+        DEVNET.parameters(),
+        epoch_number,
+        &genesis().header.block_hash,
+    )
+    .unwrap();
+    let address = ComputeKey::from(key).to_address();
+    let metadata = &parent.header.metadata;
+    let mut solutions = vec![];
+    let mut cumulative_target = 0u128;
+    let mut nonce = 0;
+    while cumulative_target < metadata.coinbase_target as u128 {
+        if let Ok(solution) = puzzle.prove(&challenge, address, nonce, Some(metadata.proof_target))
+        {
+            cumulative_target += solution.to_target().unwrap() as u128;
+            solutions.push(solution);
+        }
+        nonce += 1;
+    }
+    puzzle.accumulate_unchecked(&challenge, &solutions).unwrap()
+}
+
+/// Assembles a block of `transactions` on top of `parent` signed by `key`, `block_time` seconds after it.
+fn assemble(
+    parent: &Block,
+    key: &PrivateKey,
+    coinbase: Option<CoinbaseSolution>,
+    transactions: Vec<Transaction>,
+    block_time: i64,
+) -> Block {
+    let parameters = DEVNET.parameters();
+    let metadata = consensus::next_metadata(
+        parameters,
+        &parent.header.metadata,
+        parent.header.metadata.timestamp + block_time,
+    )
+    .unwrap();
+    let rewards = consensus::block_rewards(
+        parameters,
+        metadata.height,
+        ComputeKey::from(key).to_address(),
+        coinbase.as_ref(),
+        Block::transaction_fees(&transactions),
+    )
+    .unwrap();
+    let header = BlockHeader::new(
+        parent.header.block_hash.clone(),
+        parent.header.previous_state_root.clone(),
+        Block::transactions_root(&transactions),
+        Block::coinbase_root(coinbase.as_ref()),
+        Block::rewards_root(&rewards),
+        metadata,
+        key,
+    );
+    Block {
+        header,
+        transactions,
+        coinbase,
+        rewards,
+    }
+}
+
+/// Produces a valid block of `transactions` on top of `parent`, at the anchor time so that all blocks weigh the same.
+fn produce_with(parent: &Block, transactions: Vec<Transaction>) -> Block {
+    let key = PrivateKey::rand();
+    let coinbase = prove(parent, &key, 0);
+    assemble(
+        parent,
+        &key,
+        Some(coinbase),
+        transactions,
+        DEVNET.parameters().anchor_time as i64,
+    )
+}
+
+fn produce(parent: &Block) -> Block {
+    produce_with(parent, vec![])
+}
+
+/// Sum of the rewards `block` pays to `address`. Producers in these tests prove their own coinbase solutions, so they are paid more than once.
+fn paid(block: &Block, address: Address) -> u64 {
+    block
+        .rewards
+        .iter()
+        .filter(|x| x.0 == address)
+        .map(|x| x.1)
+        .sum()
+}
+
+/// Proves knowledge of `a` and `b` for the public inputs `a * b` and `a * b * b`.
+#[derive(Clone, Copy)]
+struct Circuit {
+    a: Scalar,
+    b: Scalar,
+}
+
+impl ConstraintSynthesizer<Scalar> for Circuit {
+    fn generate_constraints<CS: ConstraintSystem<Field = Scalar>>(
+        &self,
+        cs: &mut CS,
+    ) -> anyhow::Result<()> {
+        let a = cs.alloc(|| "a", || Ok(self.a))?;
+        let b = cs.alloc(|| "b", || Ok(self.b))?;
+        let c = cs.alloc_input(|| "c", || Ok(self.a * self.b))?;
+        let d = cs.alloc_input(|| "d", || Ok(self.a * self.b * self.b))?;
+        cs.enforce(|| "c", |lc| lc + a, |lc| lc + b, |lc| lc + c);
+        cs.enforce(|| "d", |lc| lc + c, |lc| lc + b, |lc| lc + d);
+        Ok(())
+    }
+}
+
+/// A proof, verifying key and certificate for the transactions of these tests, which are stored but not verified.
+struct Snark {
+    proof: Proof,
+    verifying_key: CircuitVerifyingKey,
+    certificate: Certificate,
+}
+
+fn snark() -> &'static Snark {
+    static SNARK: OnceLock<Snark> = OnceLock::new();
+    SNARK.get_or_init(|| {
+        let mode = true;
+        let fs_parameters = PoseidonParameters::default();
+        let circuit = Circuit {
+            a: Scalar::rand(),
+            b: Scalar::rand(),
+        };
+        let max_degree = AHPForR1CS { mode }.max_degree(8, 8, 16).unwrap();
+        let srs = MarlinSNARK::universal_setup(max_degree).unwrap();
+        let (proving_key, verifying_key) =
+            MarlinSNARK::circuit_setup(&srs, &circuit, mode).unwrap();
+        let certificate =
+            MarlinSNARK::prove_vk(&fs_parameters, &verifying_key, &proving_key).unwrap();
+        let proof = MarlinSNARK { mode }
+            .prove(
+                &fs_parameters,
+                &proving_key,
+                &circuit,
+                &mut rand::thread_rng(),
+            )
+            .unwrap();
+        Snark {
+            proof,
+            verifying_key,
+            certificate,
+        }
+    })
+}
+
+/// A digest not used by any other object of these tests.
+fn unique_digest() -> Digest {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    Digest::from(COUNTER.fetch_add(1, Ordering::Relaxed).to_le_bytes())
+}
+
+fn identifier(name: &str) -> Identifier {
+    name.parse().unwrap()
+}
+
+fn transition(program_id: &ProgramID, finalize: Option<Vec<FinalizeOperation>>) -> Transition {
+    Transition {
+        id: unique_digest(),
+        program_id: program_id.clone(),
+        function_name: identifier("main"),
+        inputs: vec![],
+        outputs: vec![],
+        finalize: finalize.map(|x| FinalizeOperation::encode_all(&x)),
+        proof: snark().proof.clone(),
+        tpk: unique_digest(),
+        tcm: unique_digest(),
+        fee: 0,
+    }
+}
+
+fn deploy(program_id: &ProgramID, edition: u16) -> Transaction {
+    let snark = snark();
+    Transaction::Deploy(Box::new(DeployTransaction {
+        id: unique_digest(),
+        deployment: Deployment {
+            program_id: program_id.clone(),
+            edition,
+            program: snarkd_ir::Program {
+                header: snarkd_ir::Header {
+                    mappings: vec!["counts".to_string()],
+                    ..Default::default()
+                },
+                functions: vec![],
+            }
+            .canonical_bytes(),
+            verifying_key_id: identifier("main"),
+            verifying_key: snark.verifying_key.clone(),
+            certificate: snark.certificate.clone(),
+        },
+        transition: transition(program_id, None),
+    }))
+}
+
+/// Executes `program_id`, setting each key of `updates` to its value in the `counts` mapping, or removing it.
+fn execute(program_id: &ProgramID, updates: &[(u8, Option<u8>)]) -> Transaction {
+    execute_mapping(program_id, "counts", updates)
+}
+
+/// Executes `program_id`, setting each key of `updates` to its value in `mapping`, or removing it.
+fn execute_mapping(
+    program_id: &ProgramID,
+    mapping: &str,
+    updates: &[(u8, Option<u8>)],
+) -> Transaction {
+    let operations = updates
+        .iter()
+        .map(|(key, value)| FinalizeOperation {
+            mapping: identifier(mapping),
+            key: vec![*key],
+            value: value.map(|x| vec![x]),
+        })
+        .collect();
+    Transaction::Execute(Box::new(ExecuteTransaction {
+        id: unique_digest(),
+        execution: Execution {
+            edition: 1,
+            transitions: vec![transition(program_id, Some(operations))],
+        },
+        transition: None,
+    }))
+}
+
+#[test]
+fn test_transaction_encoding() {
+    let program_id: ProgramID = "counter.aleo".parse().unwrap();
+    for transaction in [
+        deploy(&program_id, 1),
+        execute(&program_id, &[(1, Some(1)), (2, None)]),
+    ] {
+        let bytes = transaction.to_bytes();
+        assert_eq!(Transaction::from_bytes(&bytes).unwrap(), transaction);
+        assert!(Transaction::from_bytes(&bytes[1..]).is_err());
+    }
+}
+
+#[tokio::test]
+async fn test_commit_verifies_blocks() {
+    let genesis = genesis();
+    let block = produce(&genesis);
+    let database = genesis_database().await;
+    assert_eq!(
+        accept(&database, &block, MAX_REORG_DEPTH).await.unwrap(),
+        BlockStatus::Committed(1)
+    );
+    assert_eq!(
+        database
+            .get_block(block.header.block_hash.clone())
+            .await
+            .unwrap(),
+        block
+    );
+    let producer = block.header.producer;
+    assert_eq!(
+        database.get_reward_balance(producer).await.unwrap(),
+        paid(&block, producer)
+    );
+
+    // contents not matching the signed header
+    let mut tampered = block.clone();
+    tampered.rewards[0].1 += 1;
+    let mut stolen = block.clone();
+    stolen.rewards[0].0 = ComputeKey::from(&PrivateKey::rand()).to_address();
+    let mut unpaid = block.clone();
+    unpaid.coinbase = None;
+
+    // consistent headers over invalid coinbase solutions
+    let key = PrivateKey::rand();
+    let missing = assemble(&genesis, &key, None, vec![], 5);
+    let wrong_epoch = assemble(&genesis, &key, Some(prove(&genesis, &key, 1)), vec![], 5);
+
+    for invalid in [tampered, stolen, unpaid, missing, wrong_epoch] {
+        let database = genesis_database().await;
+        assert!(accept(&database, &invalid, MAX_REORG_DEPTH).await.is_err());
+        assert_eq!(database.canon().await.unwrap().block_height, 0);
+    }
+}
+
+async fn assert_canon(database: &Database, chain: &[&Block]) {
+    let canon = database.canon().await.unwrap();
+    assert_eq!(canon.block_height, chain.len());
+    for (i, block) in chain.iter().enumerate() {
+        assert_eq!(
+            database
+                .get_block_state(block.header.block_hash.clone())
+                .await
+                .unwrap(),
+            BlockStatus::Committed(i + 1)
+        );
+    }
+}
+
+async fn assert_uncommitted(database: &Database, blocks: &[&Block]) {
+    for block in blocks {
+        assert_eq!(
+            database
+                .get_block_state(block.header.block_hash.clone())
+                .await
+                .unwrap(),
+            BlockStatus::Uncommitted
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_heavier_fork_replaces_canon() {
+    let genesis = genesis();
+    let a1 = produce(&genesis);
+    let a2 = produce(&a1);
+    let b1 = produce(&genesis);
+    let b2 = produce(&b1);
+    let b3 = produce(&b2);
+
+    let database = genesis_database().await;
+    for block in [&a1, &a2, &b1] {
+        accept(&database, block, MAX_REORG_DEPTH).await.unwrap();
+    }
+    assert_canon(&database, &[&a1, &a2]).await;
+    assert_uncommitted(&database, &[&b1]).await;
+
+    // b2 ties with canon and either may win, b3 makes the fork heavier
+    accept(&database, &b2, MAX_REORG_DEPTH).await.unwrap();
+    accept(&database, &b3, MAX_REORG_DEPTH).await.unwrap();
+    assert_canon(&database, &[&b1, &b2, &b3]).await;
+    assert_uncommitted(&database, &[&a1, &a2]).await;
+    assert_eq!(
+        database
+            .get_reward_balance(a1.header.producer)
+            .await
+            .unwrap(),
+        0
+    );
+    assert_eq!(
+        database
+            .get_reward_balance(b1.header.producer)
+            .await
+            .unwrap(),
+        paid(&b1, b1.header.producer)
+    );
+
+    // blocks arriving before their parent connect once it does
+    let database = genesis_database().await;
+    for block in [&a2, &a1] {
+        accept(&database, block, MAX_REORG_DEPTH).await.unwrap();
+    }
+    assert_canon(&database, &[&a1, &a2]).await;
+}
+
+#[tokio::test]
+async fn test_reorg_depth() {
+    let genesis = genesis();
+    let a1 = produce(&genesis);
+    let a2 = produce(&a1);
+    let b1 = produce(&genesis);
+    let b2 = produce(&b1);
+    let b3 = produce(&b2);
+
+    let database = genesis_database().await;
+    for block in [&a1, &a2] {
+        accept(&database, block, MAX_REORG_DEPTH).await.unwrap();
+    }
+    // the fork would replace two canon blocks
+    for block in [&b1, &b2, &b3] {
+        accept(&database, block, 1).await.unwrap();
+    }
+    assert_canon(&database, &[&a1, &a2]).await;
+    assert_uncommitted(&database, &[&b1, &b2, &b3]).await;
+
+    accept(&database, &b3, 2).await.unwrap();
+    assert_canon(&database, &[&b1, &b2, &b3]).await;
+}
+
+#[tokio::test]
+async fn test_invalid_fork_restores_canon() {
+    let genesis = genesis();
+    let a1 = produce(&genesis);
+    let a2 = produce(&a1);
+    let b1 = produce(&genesis);
+    let b2 = assemble(&b1, &PrivateKey::rand(), None, vec![], 5);
+    let b3 = produce(&b2);
+
+    let database = genesis_database().await;
+    for block in [&a1, &a2, &b1] {
+        accept(&database, block, MAX_REORG_DEPTH).await.unwrap();
+    }
+    // b2 ties with canon, and fails if it wins
+    accept(&database, &b2, MAX_REORG_DEPTH).await.ok();
+    assert!(accept(&database, &b3, MAX_REORG_DEPTH).await.is_err());
+    assert_canon(&database, &[&a1, &a2]).await;
+    assert_uncommitted(&database, &[&b1, &b2, &b3]).await;
+}
+
+/// Stores a block on top of `parent` with `coinbase_target`, which is never checked as the block is never committed.
+fn with_target(parent: &Block, coinbase_target: u64) -> Block {
+    let mut metadata = consensus::next_metadata(
+        DEVNET.parameters(),
+        &parent.header.metadata,
+        parent.header.metadata.timestamp + 5,
+    )
+    .unwrap();
+    metadata.coinbase_target = coinbase_target;
+    let header = BlockHeader::new(
+        parent.header.block_hash.clone(),
+        parent.header.previous_state_root.clone(),
+        Block::transactions_root(&[]),
+        Block::coinbase_root(None),
+        Block::rewards_root(&[]),
+        metadata,
+        &PrivateKey::rand(),
+    );
+    Block {
+        header,
+        transactions: vec![],
+        coinbase: None,
+        rewards: vec![],
+    }
+}
+
+async fn store_with_target(database: &Database, parent: &Block, coinbase_target: u64) -> Block {
+    let block = with_target(parent, coinbase_target);
+    database.insert_block(block.clone()).await.unwrap();
+    block
+}
+
+#[tokio::test]
+async fn test_heaviest_child_path_sums_exactly() {
+    let genesis = genesis();
+    let database = genesis_database().await;
+    let heavy = i64::MAX as u64;
+    let a1 = store_with_target(&database, &genesis, heavy).await;
+    let a2 = store_with_target(&database, &a1, 1).await;
+    let b1 = store_with_target(&database, &genesis, heavy).await;
+    // b wins by a single unit of weight on top of a sum that no longer fits a 64 bit integer, whatever the tip hashes
+    let b2 = store_with_target(&database, &b1, 2).await;
+
+    let hash = genesis.header.block_hash.clone();
+    let path = database
+        .call(move |db| db.heaviest_child_path(&hash))
+        .await
+        .unwrap();
+    assert_eq!(
+        path,
+        vec![
+            genesis.header.block_hash.clone(),
+            b1.header.block_hash.clone(),
+            b2.header.block_hash.clone()
+        ]
+    );
+    assert_ne!(path[2], a2.header.block_hash);
+
+    // weights that do not fit storage are rejected rather than clamped
+    let block = with_target(&genesis, u64::MAX);
+    assert!(database.insert_block(block).await.is_err());
+}
+
+async fn assert_counts(database: &Database, program_id: &ProgramID, counts: &[(u8, u8)]) {
+    let entries = database
+        .get_mapping_entries(program_id.clone(), identifier("counts"), 0, u32::MAX)
+        .await
+        .unwrap();
+    let expected = counts
+        .iter()
+        .map(|(key, value)| (vec![*key], vec![*value]))
+        .collect::<Vec<_>>();
+    assert_eq!(entries, expected);
+    // entries are paged in key order
+    let page = database
+        .get_mapping_entries(program_id.clone(), identifier("counts"), 1, 1)
+        .await
+        .unwrap();
+    assert_eq!(
+        page,
+        expected.iter().skip(1).take(1).cloned().collect::<Vec<_>>()
+    );
+    for key in 0..4 {
+        let value = database
+            .get_mapping_value(program_id.clone(), identifier("counts"), vec![key])
+            .await
+            .unwrap();
+        let expected = counts.iter().find(|x| x.0 == key).map(|x| vec![x.1]);
+        assert_eq!(value, expected);
+    }
+}
+
+#[tokio::test]
+async fn test_mapping_updates_follow_canon() {
+    let program_id: ProgramID = "counter.aleo".parse().unwrap();
+    let genesis = genesis();
+    let a1 = produce_with(&genesis, vec![deploy(&program_id, 1)]);
+    let a2 = produce_with(
+        &a1,
+        vec![
+            execute(&program_id, &[(1, Some(1)), (2, Some(2))]),
+            execute(&program_id, &[(3, Some(3))]),
+        ],
+    );
+    let a3 = produce_with(&a2, vec![execute(&program_id, &[(1, Some(4)), (2, None)])]);
+    let a4 = produce(&a3);
+    let a5 = produce(&a4);
+    let b3 = produce(&a2);
+    let b4 = produce(&b3);
+
+    let database = genesis_database().await;
+    for block in [&a1, &a2, &a3] {
+        accept(&database, block, MAX_REORG_DEPTH).await.unwrap();
+    }
+    assert_eq!(
+        database
+            .get_block(a2.header.block_hash.clone())
+            .await
+            .unwrap(),
+        a2
+    );
+    let Transaction::Deploy(deployed) = &a1.transactions[0] else {
+        unreachable!()
+    };
+    assert_eq!(
+        database
+            .get_deployment(program_id.clone(), None)
+            .await
+            .unwrap()
+            .as_ref(),
+        Some(&deployed.deployment)
+    );
+    assert_eq!(
+        database
+            .get_deployment_by_id(deployed.deployment.id().unwrap())
+            .await
+            .unwrap()
+            .as_ref(),
+        Some(&deployed.deployment)
+    );
+    assert_counts(&database, &program_id, &[(1, 4), (3, 3)]).await;
+
+    // decommitting a3 restores the mappings as of a2
+    for block in [&b3, &b4] {
+        accept(&database, block, MAX_REORG_DEPTH).await.unwrap();
+    }
+    assert_canon(&database, &[&a1, &a2, &b3, &b4]).await;
+    assert_counts(&database, &program_id, &[(1, 1), (2, 2), (3, 3)]).await;
+
+    // recommitting a3 applies its updates again
+    for block in [&a4, &a5] {
+        accept(&database, block, MAX_REORG_DEPTH).await.unwrap();
+    }
+    assert_canon(&database, &[&a1, &a2, &a3, &a4, &a5]).await;
+    assert_counts(&database, &program_id, &[(1, 4), (3, 3)]).await;
+}
+
+#[tokio::test]
+async fn test_program_state_is_verified() {
+    let program_id: ProgramID = "counter.aleo".parse().unwrap();
+    let genesis = genesis();
+    let deployed = produce_with(&genesis, vec![deploy(&program_id, 1)]);
+
+    let redeployed = produce_with(&deployed, vec![deploy(&program_id, 1)]);
+    let undeployed = produce_with(
+        &genesis,
+        vec![execute(&"other.aleo".parse().unwrap(), &[(1, Some(1))])],
+    );
+    let undeclared = produce_with(
+        &deployed,
+        vec![execute_mapping(&program_id, "totals", &[(1, Some(1))])],
+    );
+    let duplicate = produce_with(
+        &genesis,
+        vec![deploy(&program_id, 1), deploy(&program_id, 1)],
+    );
+    let mut noncanonical = deploy(&program_id, 1);
+    if let Transaction::Deploy(transaction) = &mut noncanonical {
+        // an unknown field, which decoders skip
+        transaction.deployment.program.extend([0x78, 0x01]);
+    }
+    let noncanonical = produce_with(&genesis, vec![noncanonical]);
+    let mut invalid_program = deploy(&program_id, 1);
+    if let Transaction::Deploy(transaction) = &mut invalid_program {
+        // reads a register that is never written
+        let program = snarkd_ir::Program {
+            header: Default::default(),
+            functions: vec![snarkd_ir::Function {
+                argument_start_variable: 0,
+                instructions: vec![snarkd_ir::Instruction::Not(snarkd_ir::UnaryData {
+                    operand: snarkd_ir::Operand::Ref(1),
+                    dest: 0,
+                })],
+            }],
+        };
+        transaction.deployment.program = program.canonical_bytes();
+    }
+    let invalid_program = produce_with(&genesis, vec![invalid_program]);
+    for (parent, invalid) in [
+        (Some(&deployed), redeployed),
+        (None, undeployed),
+        (Some(&deployed), undeclared),
+        (None, duplicate),
+        (None, noncanonical),
+        (None, invalid_program),
+    ] {
+        let database = genesis_database().await;
+        if let Some(parent) = parent {
+            accept(&database, parent, MAX_REORG_DEPTH).await.unwrap();
+        }
+        let height = database.canon().await.unwrap().block_height;
+        assert!(accept(&database, &invalid, MAX_REORG_DEPTH).await.is_err());
+        assert_eq!(database.canon().await.unwrap().block_height, height);
+    }
+}